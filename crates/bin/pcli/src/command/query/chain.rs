@@ -64,8 +64,16 @@ impl ChainCmd {
                 &format!("{}", params.active_validator_limit),
             ])
             .add_row(vec![
-                "Base Reward Rate (bps^2)",
-                &format!("{}", params.base_reward_rate),
+                "Issuance Per Epoch (upenumbra)",
+                &format!("{}", params.issuance_per_epoch),
+            ])
+            .add_row(vec![
+                "DAO Issuance (bps)",
+                &format!("{}", params.dao_issuance_bps),
+            ])
+            .add_row(vec![
+                "LP Issuance (bps)",
+                &format!("{}", params.lp_issuance_bps),
             ])
             .add_row(vec![
                 "Slashing Penalty (Misbehavior) (bps^2)",
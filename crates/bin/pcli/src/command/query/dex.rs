@@ -9,9 +9,10 @@ use penumbra_dex::{
     BatchSwapOutputData, DirectedTradingPair, SwapExecution, TradingPair,
 };
 use penumbra_proto::client::v1alpha1::{
-    specific_query_service_client::SpecificQueryServiceClient, BatchSwapOutputDataRequest,
-    DenomMetadataByIdRequest, LiquidityPositionByIdRequest, LiquidityPositionsByPriceRequest,
-    LiquidityPositionsRequest, SimulateTradeRequest, SwapExecutionRequest,
+    specific_query_service_client::SpecificQueryServiceClient, ArbExecutionRequest,
+    BatchSwapOutputDataRequest, DenomMetadataByIdRequest, LiquidityPositionByIdRequest,
+    LiquidityPositionsByPriceRequest, LiquidityPositionsRequest, SimulateTradeRequest,
+    SwapExecutionRequest,
 };
 use penumbra_view::ViewClient;
 use tonic::transport::Channel;
@@ -43,6 +44,12 @@ pub enum DexCmd {
         #[clap(value_name = "asset_1:asset_2")]
         trading_pair: DirectedTradingPair,
     },
+    /// Display the arbitrage cycles closed by the chain at a specific height.
+    ArbExecution {
+        /// The height to query for the arbitrage execution.
+        #[clap(long)]
+        height: u64,
+    },
     /// Display information about all liquidity positions known to the chain.
     #[clap(display_order(900))]
     AllPositions {
@@ -120,6 +127,21 @@ impl DexCmd {
             .context("cannot parse batch swap output data")
     }
 
+    pub async fn get_arb_execution(&self, app: &mut App, height: &u64) -> Result<SwapExecution> {
+        let mut client = app.specific_client().await?;
+        client
+            .arb_execution(ArbExecutionRequest {
+                height: *height,
+                ..Default::default()
+            })
+            .await?
+            .into_inner()
+            .swap_execution
+            .ok_or_else(|| anyhow::anyhow!("proto response missing arb execution"))?
+            .try_into()
+            .context("cannot parse arb execution")
+    }
+
     pub async fn get_simulated_execution(
         &self,
         app: &mut App,
@@ -135,6 +157,7 @@ impl DexCmd {
                 routing: Some(Routing {
                     setting: Some(Setting::Default(Default::default())),
                 }),
+                ..Default::default()
             })
             .await?
             .into_inner()
@@ -153,6 +176,7 @@ impl DexCmd {
         let stream = client.liquidity_positions(LiquidityPositionsRequest {
             include_closed,
             chain_id: chain_id.unwrap_or_default(),
+            ..Default::default()
         });
         let stream = stream.await?.into_inner();
 
@@ -355,6 +379,12 @@ impl DexCmd {
 
                 self.print_swap_execution(app, &swap_execution).await?;
             }
+            DexCmd::ArbExecution { height } => {
+                let arb_execution = self.get_arb_execution(app, height).await?;
+
+                println!("Arbitrage at height {}:", height);
+                self.print_swap_execution(app, &arb_execution).await?;
+            }
             DexCmd::Simulate { input, into } => {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
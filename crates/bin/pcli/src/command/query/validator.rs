@@ -161,6 +161,7 @@ impl ValidatorCmd {
                     .validator_status(ValidatorStatusRequest {
                         chain_id: "".to_string(), // TODO: fill in
                         identity_key: Some(identity_key.into()),
+                        ..Default::default()
                     })
                     .await?
                     .into_inner()
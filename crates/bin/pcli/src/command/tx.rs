@@ -11,21 +11,28 @@ use ark_ff::UniformRand;
 use decaf377::{Fq, Fr};
 use ibc_types::core::channel::{ChannelId, PortId};
 use penumbra_asset::{asset, asset::DenomMetadata, Value, STAKING_TOKEN_ASSET_ID};
-use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
+use penumbra_dex::{gas::swap_claim_gas_cost, lp::position, swap_claim::SwapClaimPlan};
 use penumbra_fee::Fee;
 use penumbra_ibc::Ics20Withdrawal;
 use penumbra_keys::keys::AddressIndex;
 use penumbra_num::Amount;
 use penumbra_proto::{
     client::v1alpha1::{
-        EpochByHeightRequest, LiquidityPositionByIdRequest, ProposalInfoRequest,
-        ProposalInfoResponse, ProposalRateDataRequest, ValidatorPenaltyRequest,
+        EpochByHeightRequest, LiquidityPositionByIdRequest, PositionRewardsRequest,
+        ProposalInfoRequest, ProposalInfoResponse, ProposalRateDataRequest,
+        ValidatorPenaltyRequest,
     },
     core::dex::v1alpha1::PositionId,
 };
+use penumbra_shielded_pool::gas::output_gas_cost;
 use penumbra_stake::rate::RateData;
 use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::{memo::MemoPlaintext, proposal::ProposalToml, vote::Vote};
+use penumbra_transaction::{
+    gas::{clue_gas_cost, memo_gas_cost, transaction_base_gas_cost},
+    memo::MemoPlaintext,
+    proposal::ProposalToml,
+    vote::Vote,
+};
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
 use rand_core::OsRng;
@@ -51,7 +58,7 @@ pub enum TxCmd {
         to: String,
         /// The amounts to send, written as typed values 1.87penumbra, 12cubes, etc.
         values: Vec<String>,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0", display_order = 200)]
         fee: u64,
         /// Only spend funds originally received by the given account.
@@ -69,7 +76,7 @@ pub enum TxCmd {
         to: String,
         /// The amount of stake to delegate.
         amount: String,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0", display_order = 200)]
         fee: u64,
         /// Only spend funds originally received by the given account.
@@ -81,7 +88,7 @@ pub enum TxCmd {
     Undelegate {
         /// The amount of delegation tokens to undelegate.
         amount: String,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0", display_order = 200)]
         fee: u64,
         /// Only spend funds originally received by the given account.
@@ -91,7 +98,7 @@ pub enum TxCmd {
     /// Claim any undelegations that have finished unbonding.
     #[clap(display_order = 200)]
     UndelegateClaim {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
     },
@@ -110,7 +117,7 @@ pub enum TxCmd {
         /// The denomination to swap the input into, e.g. `gm`
         #[clap(long, display_order = 100)]
         into: String,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         ///
         /// A swap generates two transactions; the fee will be split equally over both.
         #[clap(long, default_value = "0", display_order = 200)]
@@ -122,7 +129,7 @@ pub enum TxCmd {
     /// Vote on a governance proposal in your role as a delegator (see also: `pcli validator vote`).
     #[clap(display_order = 400)]
     Vote {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0", global = true, display_order = 200)]
         fee: u64,
         /// Only spend funds and vote with staked delegation tokens originally received by the given
@@ -138,7 +145,7 @@ pub enum TxCmd {
     /// Deposit funds into the DAO.
     #[clap(display_order = 600)]
     DaoDeposit {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0", global = true, display_order = 200)]
         fee: u64,
         /// The amounts to send, written as typed values 1.87penumbra, 12cubes, etc.
@@ -157,7 +164,7 @@ pub enum TxCmd {
     /// slightly preferable to sweep small notes into larger ones in an isolated
     /// "sweep" transaction, rather than at the point that they should be spent.
     ///
-    /// Currently, only zero-fee sweep transactions are implemented.
+    /// Sweep transactions pay the minimum fee.
     #[clap(display_order = 990)]
     Sweep,
 
@@ -344,9 +351,19 @@ impl TxCmd {
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
 
                 // Since the swap command consists of two transactions (the swap and the swap claim),
-                // the fee is split equally over both for now.
+                // the fee is split equally over both for now. The swap claim fee is prepaid by the
+                // swap, so it must cover the minimum fee of the swap claim transaction, which also
+                // sends any excess back to us as change.
+                let gas_prices = app.view().chain_params().await?.gas_prices;
+                let swap_claim_gas = transaction_base_gas_cost()
+                    + swap_claim_gas_cost()
+                    + output_gas_cost()
+                    + memo_gas_cost()
+                    + clue_gas_cost();
                 let swap_fee = Fee::from_staking_token_amount((fee / 2).into());
-                let swap_claim_fee = Fee::from_staking_token_amount((fee / 2).into());
+                let swap_claim_fee = Fee::from_staking_token_amount(
+                    Amount::from(fee / 2).max(swap_claim_gas.price(&gas_prices)),
+                );
 
                 let fvk = app.fvk.clone();
 
@@ -964,6 +981,7 @@ impl TxCmd {
                         .liquidity_position_by_id(LiquidityPositionByIdRequest {
                             chain_id: params.chain_id.to_string(),
                             position_id: Some(position_id.into()),
+                            ..Default::default()
                         })
                         .await?
                         .into_inner();
@@ -1013,6 +1031,7 @@ impl TxCmd {
                     .liquidity_position_by_id(LiquidityPositionByIdRequest {
                         chain_id: params.chain_id.to_string(),
                         position_id: Some(PositionId::from(*position_id)),
+                        ..Default::default()
                     })
                     .await?
                     .into_inner();
@@ -1044,7 +1063,40 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
-            TxCmd::Position(PositionCmd::RewardClaim {}) => todo!(),
+            TxCmd::Position(PositionCmd::RewardClaim {
+                fee,
+                source,
+                position_id,
+            }) => {
+                let mut specific_client = app.specific_client().await?;
+
+                // Fetch the rewards accumulated by the position.  A position that
+                // never received any rewards can still be claimed.
+                let rewards: Amount = specific_client
+                    .position_rewards(PositionRewardsRequest {
+                        chain_id: app.view().chain_params().await?.chain_id,
+                        position_id: Some((*position_id).into()),
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner()
+                    .rewards
+                    .ok_or_else(|| anyhow::anyhow!("missing rewards in response"))?
+                    .try_into()?;
+
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let plan = Planner::new(OsRng)
+                    .position_reward_claim(*position_id, rewards)
+                    .fee(fee)
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        app.fvk.account_group_id(),
+                        AddressIndex::new(*source),
+                    )
+                    .await?;
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::Position(PositionCmd::Replicate(replicate_cmd)) => {
                 replicate_cmd.exec(app).await?;
             }
@@ -19,7 +19,7 @@ pub enum PositionCmd {
     Order(OrderCmd),
     /// Debits an all opened position NFTs associated with a specific source and credits closed position NFTs.
     CloseAll {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Only spend funds originally received by the given address index.
@@ -31,7 +31,7 @@ pub enum PositionCmd {
     },
     /// Debits an opened position NFT and credits a closed position NFT.
     Close {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Only spend funds originally received by the given address index.
@@ -42,7 +42,7 @@ pub enum PositionCmd {
     },
     /// Debits all closed position NFTs associated with a specific account and credits withdrawn position NFTs and the final reserves.
     WithdrawAll {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Only spend funds originally received by the given address index.
@@ -54,7 +54,7 @@ pub enum PositionCmd {
     },
     /// Debits a closed position NFT and credits a withdrawn position NFT and the final reserves.
     Withdraw {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Only spend funds originally received by the given address index.
@@ -65,8 +65,16 @@ pub enum PositionCmd {
     },
 
     /// Debits a withdrawn position NFT and credits a claimed position NFT and any liquidity incentives.
-    #[clap(hide(true))] // remove when reward claims exist
-    RewardClaim {},
+    RewardClaim {
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+        /// The [`position::Id`] of the position to claim rewards for.
+        position_id: position::Id,
+    },
     /// Replicate a trading function
     #[clap(subcommand)]
     Replicate(ReplicateCmd),
@@ -95,7 +103,7 @@ pub enum OrderCmd {
         /// An optional suffix of the form `/10bps` may be added to specify a fee spread for the
         /// resulting position, though this is less useful for buy/sell orders than passive LPs.
         buy_order: String,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Only spend funds originally received by the given address index.
@@ -112,7 +120,7 @@ pub enum OrderCmd {
         /// An optional suffix of the form `/10bps` may be added to specify a fee spread for the
         /// resulting position, though this is less useful for buy/sell orders than passive LPs.
         sell_order: String,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Only spend funds originally received by the given address index.
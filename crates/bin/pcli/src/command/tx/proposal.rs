@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 
-use penumbra_chain::params::ChainParameters;
+use ibc_types::core::client::ClientId;
+use penumbra_chain::params::{change::ParameterChange, ChainParameters};
 use penumbra_transaction::{
     plan::TransactionPlan,
     proposal::{Proposal, ProposalPayload},
@@ -13,7 +14,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or dao-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, dao-spend, or ibc-client-recovery].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -22,7 +23,7 @@ pub enum ProposalCmd {
         /// The proposal to vote on, in TOML format.
         #[clap(long)]
         file: camino::Utf8PathBuf,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Only spend funds originally received by the given account.
@@ -31,7 +32,7 @@ pub enum ProposalCmd {
     },
     /// Withdraw a governance proposal that you previously submitted.
     Withdraw {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// The proposal id to withdraw.
@@ -50,7 +51,7 @@ pub enum ProposalCmd {
     /// proposal passed, failed, or was slashed. In the case of a slash, the deposit is not returned
     /// by this action; in other cases, it is returned to you.
     DepositClaim {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// The proposal id to claim the deposit for.
@@ -78,6 +79,31 @@ pub enum ProposalKindCmd {
         #[clap(long)]
         transaction_plan: Option<camino::Utf8PathBuf>,
     },
+    /// Generate a template for an IBC client recovery proposal.
+    IbcClientRecovery {
+        /// The frozen or expired client to recover.
+        #[clap(long, default_value = "07-tendermint-0")]
+        subject_client_id: ClientId,
+        /// The active client whose state should replace that of the subject client.
+        #[clap(long, default_value = "07-tendermint-1")]
+        substitute_client_id: ClientId,
+    },
+    /// Generate a template for an upgrade plan proposal.
+    UpgradePlan {
+        /// The height of the last block before the upgrade.
+        ///
+        /// This must be after the end of the voting period for the proposal.
+        #[clap(long)]
+        height: u64,
+        /// The name of the upgrade.
+        #[clap(long)]
+        name: String,
+        /// Any other information about the upgrade.
+        #[clap(long, default_value = "")]
+        info: String,
+    },
+    /// Generate a template for a partial parameter change proposal.
+    PartialParameterChange,
 }
 
 impl ProposalKindCmd {
@@ -110,6 +136,26 @@ impl ProposalKindCmd {
                     }
                 }
             }
+            ProposalKindCmd::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::IbcClientRecovery {
+                subject_client_id: subject_client_id.clone(),
+                substitute_client_id: substitute_client_id.clone(),
+            },
+            ProposalKindCmd::UpgradePlan { height, name, info } => ProposalPayload::UpgradePlan {
+                height: *height,
+                name: name.clone(),
+                info: info.clone(),
+            },
+            ProposalKindCmd::PartialParameterChange => ProposalPayload::PartialParameterChange {
+                // An example change, to be replaced in the template before submission.
+                changes: vec![ParameterChange {
+                    parameter: "proposal_voting_blocks".to_string(),
+                    old: chain_params.proposal_voting_blocks.to_string(),
+                    new: chain_params.proposal_voting_blocks.to_string(),
+                }],
+            },
         };
 
         Ok(Proposal {
@@ -181,6 +181,7 @@ impl ConstantProduct {
             .spread(SpreadRequest {
                 chain_id: "".to_string(),
                 trading_pair: Some(self.pair.into_directed_trading_pair().to_canonical().into()),
+                ..Default::default()
             })
             .await?
             .into_inner();
@@ -32,7 +32,7 @@ pub enum ValidatorCmd {
     Definition(DefinitionCmd),
     /// Cast a vote on a proposal in your capacity as a validator (see also: `pcli tx vote`).
     Vote {
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0", global = true, display_order = 200)]
         fee: u64,
         /// Optional. Only spend funds originally received by the given account.
@@ -51,7 +51,7 @@ pub enum DefinitionCmd {
         /// The TOML file containing the ValidatorDefinition to upload.
         #[clap(long)]
         file: String,
-        /// The transaction fee (paid in upenumbra).
+        /// The transaction fee (paid in upenumbra), if more than the minimum fee.
         #[clap(long, default_value = "0")]
         fee: u64,
         /// Optional. Only spend funds originally received by the given account.
@@ -13,9 +13,13 @@ use penumbra_proto::{
     client::v1alpha1::oblivious_query_service_client::ObliviousQueryServiceClient,
     client::v1alpha1::ChainParametersRequest,
     custody::v1alpha1::custody_protocol_service_server::CustodyProtocolServiceServer,
-    view::v1alpha1::view_protocol_service_server::ViewProtocolServiceServer,
+    view::v1alpha1::{
+        detection_service_server::DetectionServiceServer,
+        view_protocol_service_server::ViewProtocolServiceServer,
+        wallet_service_server::WalletServiceServer,
+    },
 };
-use penumbra_view::{Storage, ViewService};
+use penumbra_view::{DetectionService, MultiViewService, Storage};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -85,11 +89,31 @@ pub enum Command {
         #[clap(short, long)]
         custody: bool,
     },
-    /// Start the view service.
+    /// Start the view service, syncing the wallet it was initialized with, along with any wallets
+    /// added at runtime through its wallet service.
     Start {
         /// Bind the view service to this socket.
         #[clap(long, env = "PENUMBRA_PCLIENTD_BIND", default_value = "127.0.0.1:8081")]
         bind_addr: SocketAddr,
+        /// Also serve a detection service, filtering transactions by their clues on behalf of
+        /// other clients.
+        #[clap(long)]
+        serve_detection: bool,
+        /// Sync the view service using the detection service at this URL, only scanning the
+        /// candidate transactions it detects for the addresses in use by each wallet.
+        #[clap(long, env = "PENUMBRA_PCLIENTD_DETECTION_SERVICE")]
+        detection_service: Option<Url>,
+    },
+    /// Start a standalone detection service, filtering transactions by their clues on behalf of
+    /// its clients.
+    Detect {
+        /// Bind the detection service to this socket.
+        #[clap(
+            long,
+            env = "PENUMBRA_PCLIENTD_DETECTION_BIND",
+            default_value = "127.0.0.1:8082"
+        )]
+        bind_addr: SocketAddr,
     },
 }
 
@@ -106,6 +130,12 @@ impl Opt {
         path
     }
 
+    fn wallets_path(&self) -> Utf8PathBuf {
+        let mut path = self.home.clone();
+        path.push("wallets");
+        path
+    }
+
     async fn init_sqlite(&self, fvk: &FullViewingKey) -> Result<Storage> {
         // Initialize client and storage
         let mut client = ObliviousQueryServiceClient::connect(self.node.to_string()).await?;
@@ -217,7 +247,11 @@ impl Opt {
 
                 Ok(())
             }
-            Command::Start { bind_addr } => {
+            Command::Start {
+                bind_addr,
+                serve_detection,
+                detection_service,
+            } => {
                 tracing::info!(?opt.home, ?bind_addr, ?opt.node, "starting pclientd");
                 let config = PclientdConfig::load(opt.config_path()).context(
                     "Failed to load pclientd config file. Have you run `pclientd init` with a FVK?",
@@ -233,18 +267,32 @@ impl Opt {
                 let specific_query_proxy = SpecificQueryProxy(proxy_channel.clone());
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
-                let view_service =
-                    ViewProtocolServiceServer::new(ViewService::new(storage, opt.node).await?);
+                // The wallet from the config is synced alongside any wallets added at runtime
+                // through the wallet service, which are stored separately.
+                let view_service = MultiViewService::new(
+                    opt.node.clone(),
+                    Some(opt.wallets_path()),
+                    detection_service.clone(),
+                )
+                .await?;
+                view_service.add_storage(storage).await?;
+
+                let wallet_service = WalletServiceServer::new(view_service.clone());
+                let view_service = ViewProtocolServiceServer::new(view_service);
                 let custody_service = config.kms_config.as_ref().map(|kms_config| {
                     CustodyProtocolServiceServer::new(SoftKms::new(
                         kms_config.spend_key.clone().into(),
                     ))
                 });
+                let detection_service = serve_detection
+                    .then(|| DetectionServiceServer::new(DetectionService::new(opt.node.clone())));
 
                 let server = Server::builder()
                     .accept_http1(true)
                     .add_service(tonic_web::enable(view_service))
+                    .add_service(tonic_web::enable(wallet_service))
                     .add_optional_service(custody_service.map(|s| tonic_web::enable(s)))
+                    .add_optional_service(detection_service.map(|s| tonic_web::enable(s)))
                     .add_service(tonic_web::enable(oblivious_query_proxy))
                     .add_service(tonic_web::enable(specific_query_proxy))
                     .add_service(tonic_web::enable(tendermint_proxy_proxy))
@@ -260,6 +308,29 @@ impl Opt {
 
                 tokio::spawn(server).await??;
 
+                Ok(())
+            }
+            Command::Detect { bind_addr } => {
+                tracing::info!(?bind_addr, ?opt.node, "starting detection service");
+
+                let detection_service =
+                    DetectionServiceServer::new(DetectionService::new(opt.node.clone()));
+
+                let server = Server::builder()
+                    .accept_http1(true)
+                    .add_service(tonic_web::enable(detection_service))
+                    .add_service(tonic_web::enable(
+                        tonic_reflection::server::Builder::configure()
+                            .register_encoded_file_descriptor_set(
+                                penumbra_proto::FILE_DESCRIPTOR_SET,
+                            )
+                            .build()
+                            .with_context(|| "could not configure grpc reflection service")?,
+                    ))
+                    .serve(*bind_addr);
+
+                tokio::spawn(server).await??;
+
                 Ok(())
             }
         }
@@ -141,6 +141,7 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
         .witness_and_build(WitnessAndBuildRequest {
             transaction_plan: Some(plan),
             authorization_data: Some(auth_data),
+            account_group_id: None,
         })
         .await?
         .into_inner()
@@ -152,6 +153,7 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
         .broadcast_transaction(BroadcastTransactionRequest {
             transaction: Some(tx),
             await_detection: true,
+            account_group_id: None,
         })
         .await?
         .into_inner()
@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 
+use penumbra_app::TransactionError;
 use penumbra_chain::genesis;
 use penumbra_storage::Storage;
 use tendermint::abci::{self, ConsensusRequest as Request, ConsensusResponse as Response};
@@ -7,12 +8,14 @@ use tokio::sync::mpsc;
 use tower_actor::Message;
 use tracing::Instrument;
 
-use crate::App;
+use crate::{migrate::MIGRATIONS, App};
 
 pub struct Consensus {
     queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     storage: Storage,
     app: App,
+    /// The storage version the `app` was built on top of.
+    app_version: jmt::Version,
 }
 
 fn trace_events(events: &[abci::Event]) {
@@ -31,12 +34,17 @@ impl Consensus {
         storage: Storage,
         queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     ) -> Result<Self> {
-        let app = App::new(storage.latest_snapshot()).await?;
+        let mut app = App::new(storage.latest_snapshot()).await?;
+        // If the chain halted for an upgrade, this is the upgraded node restarting, so run the
+        // state migration for the upgrade before processing any blocks.
+        app.run_pending_migration(MIGRATIONS).await?;
+        let app_version = storage.latest_version();
 
         Ok(Self {
             queue,
             storage,
             app,
+            app_version,
         })
     }
 
@@ -117,6 +125,7 @@ impl Consensus {
 
         // Note: App::commit resets internal components, so we don't need to do that ourselves.
         let app_hash = self.app.commit(self.storage.clone()).await;
+        self.app_version = self.storage.latest_version();
 
         tracing::info!(
             consensus_params = ?init_chain.consensus_params,
@@ -136,6 +145,19 @@ impl Consensus {
         &mut self,
         begin_block: abci::request::BeginBlock,
     ) -> Result<abci::response::BeginBlock> {
+        // If this node was bootstrapped using state sync, the storage was
+        // restored from a snapshot after the app was created, so we need to
+        // rebuild the app on top of the restored state.
+        if self.storage.latest_version() != self.app_version {
+            tracing::info!(
+                version = self.storage.latest_version(),
+                "storage was restored from a snapshot, reloading app"
+            );
+            self.app = App::new(self.storage.latest_snapshot()).await?;
+            self.app.run_pending_migration(MIGRATIONS).await?;
+            self.app_version = self.storage.latest_version();
+        }
+
         // We don't need to print the block height, because it will already be
         // included in the span modeling the abci request handling.
         tracing::info!(time = ?begin_block.header.time, "beginning block");
@@ -162,7 +184,8 @@ impl Consensus {
             Err(e) => {
                 tracing::info!(?e, "deliver_tx failed");
                 abci::response::DeliverTx {
-                    code: 1.into(),
+                    code: TransactionError::abci_code(&e).into(),
+                    codespace: TransactionError::CODESPACE.to_string(),
                     // Use the alternate format specifier to include the chain of error causes.
                     log: format!("{e:#}"),
                     ..Default::default()
@@ -198,6 +221,7 @@ impl Consensus {
 
     async fn commit(&mut self) -> Result<abci::response::Commit> {
         let app_hash = self.app.commit(self.storage.clone()).await;
+        self.app_version = self.storage.latest_version();
         tracing::info!(?app_hash, "committed block");
 
         Ok(abci::response::Commit {
@@ -11,7 +11,7 @@ use penumbra_chain::component::StateReadExt as _;
 use penumbra_dex::component::router::RouteAndFill;
 use penumbra_dex::component::router::RoutingParams;
 use penumbra_dex::{
-    component::{PositionRead, StateReadExt},
+    component::{PositionRead, PositionRewardsRead, StateReadExt},
     lp::{position, position::Position},
     DirectedTradingPair, SwapExecution, TradingPair,
 };
@@ -31,6 +31,8 @@ use penumbra_stake::rate::RateData;
 use penumbra_stake::StateReadExt as _;
 
 use penumbra_proto::DomainType;
+use penumbra_storage::Snapshot;
+use penumbra_storage::StateChanges;
 use penumbra_storage::StateDelta;
 use penumbra_storage::StateRead;
 use proto::client::v1alpha1::simulate_trade_request::routing;
@@ -54,12 +56,16 @@ use proto::client::v1alpha1::LiquidityPositionsRequest;
 use proto::client::v1alpha1::LiquidityPositionsResponse;
 use proto::client::v1alpha1::NextValidatorRateRequest;
 use proto::client::v1alpha1::NextValidatorRateResponse;
+use proto::client::v1alpha1::PositionRewardsRequest;
+use proto::client::v1alpha1::PositionRewardsResponse;
 use proto::client::v1alpha1::PrefixValueRequest;
 use proto::client::v1alpha1::PrefixValueResponse;
 use proto::client::v1alpha1::SimulateTradeRequest;
 use proto::client::v1alpha1::SimulateTradeResponse;
 use proto::client::v1alpha1::SpreadRequest;
 use proto::client::v1alpha1::SpreadResponse;
+use proto::client::v1alpha1::StateChangesRequest;
+use proto::client::v1alpha1::StateChangesResponse;
 use proto::client::v1alpha1::SwapExecutionRequest;
 use proto::client::v1alpha1::SwapExecutionResponse;
 use proto::client::v1alpha1::SwapExecutionsRequest;
@@ -80,6 +86,95 @@ use tracing::instrument;
 
 use super::Info;
 
+impl Info {
+    /// Returns a snapshot of the state at the given height, or of the latest
+    /// state if the height is 0.
+    ///
+    /// Snapshots of past heights reconstructed from storage can't read the
+    /// nonverifiable state, so queries using nonverifiable indexes must use
+    /// [`Info::index_snapshot_at`].
+    fn snapshot_at(&self, height: u64) -> Result<Snapshot, Status> {
+        if height == 0 {
+            return Ok(self.storage.latest_snapshot());
+        }
+        self.storage
+            .snapshot(height)
+            .ok_or_else(|| Status::not_found(format!("no snapshot of height {height}")))
+    }
+
+    /// Returns a snapshot of the latest state, for queries using the DEX's
+    /// liquidity indexes, rejecting any other height.
+    ///
+    /// The indexes are part of the nonverifiable state, which isn't versioned,
+    /// so they can't be read as of a past height.
+    fn index_snapshot_at(&self, height: u64) -> Result<Snapshot, Status> {
+        let latest = self.storage.latest_snapshot();
+        if height != 0 && height != latest.version() {
+            return Err(Status::invalid_argument(format!(
+                "liquidity indexes can only be queried at the latest height, not {height}"
+            )));
+        }
+        Ok(latest)
+    }
+}
+
+/// Converts the ICS23 proofs making up a proof to the apphash into their proto
+/// representation, for use in a response.
+fn merkle_proof_to_proto(
+    proofs: Vec<ics23::CommitmentProof>,
+) -> ibc_proto::ibc::core::commitment::v1::MerkleProof {
+    ibc_proto::ibc::core::commitment::v1::MerkleProof {
+        proofs: proofs
+            .into_iter()
+            .map(|p| {
+                let mut encoded = Vec::new();
+                prost::Message::encode(&p, &mut encoded).unwrap();
+                prost::Message::decode(&*encoded).unwrap()
+            })
+            .collect(),
+    }
+}
+
+/// Converts the changes made by a commit into their proto representation.
+fn state_changes_to_proto(changes: &StateChanges) -> StateChangesResponse {
+    let to_proto = |key: &[u8], value: &Option<Vec<u8>>| proto::client::v1alpha1::StateChange {
+        key: key.to_vec(),
+        value: value.clone().unwrap_or_default(),
+        deleted: value.is_none(),
+    };
+
+    StateChangesResponse {
+        height: changes.version,
+        root_hash: changes.root_hash.0.to_vec(),
+        verifiable_changes: changes
+            .verifiable
+            .iter()
+            .map(|(key, value)| to_proto(key.as_bytes(), value))
+            .collect(),
+        nonverifiable_changes: changes
+            .nonverifiable
+            .iter()
+            .map(|(key, value)| to_proto(key, value))
+            .collect(),
+        events: changes
+            .events
+            .iter()
+            .map(|event| proto::client::v1alpha1::AbciEvent {
+                r#type: event.kind.clone(),
+                attributes: event
+                    .attributes
+                    .iter()
+                    .map(|attribute| proto::client::v1alpha1::AbciEventAttribute {
+                        key: attribute.key.clone(),
+                        value: attribute.value.clone(),
+                        index: attribute.index,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
 #[tonic::async_trait]
 impl SpecificQueryService for Info {
     type LiquidityPositionsStream = Pin<
@@ -135,15 +230,19 @@ impl SpecificQueryService for Info {
                 tonic::Status::invalid_argument(format!("error parsing output id: {:#}", e))
             })?;
 
+        let state = self.index_snapshot_at(request.height)?;
+
+        let default_params = state.routing_params().await.map_err(|e| {
+            tonic::Status::internal(format!("error getting routing params: {:#}", e))
+        })?;
         let routing_params = match routing_strategy {
-            Setting::Default(_) => RoutingParams::default(),
+            Setting::Default(_) => default_params,
             Setting::SingleHop(_) => RoutingParams {
                 max_hops: 1,
-                ..RoutingParams::default()
+                ..default_params
             },
         };
 
-        let state = self.storage.latest_snapshot();
         let mut state_tx = Arc::new(StateDelta::new(state));
         let swap_execution = state_tx
             .route_and_fill(input.asset_id, output_id, input.amount, routing_params)
@@ -159,7 +258,7 @@ impl SpecificQueryService for Info {
         &self,
         request: tonic::Request<SpreadRequest>,
     ) -> Result<tonic::Response<SpreadResponse>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.index_snapshot_at(request.get_ref().height)?;
         let request = request.into_inner();
 
         let pair: TradingPair = request
@@ -226,7 +325,7 @@ impl SpecificQueryService for Info {
         &self,
         request: tonic::Request<LiquidityPositionsByPriceRequest>,
     ) -> Result<tonic::Response<Self::LiquidityPositionsByPriceStream>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.index_snapshot_at(request.get_ref().height)?;
         let request = request.into_inner();
 
         let pair: DirectedTradingPair = request
@@ -275,7 +374,7 @@ impl SpecificQueryService for Info {
         &self,
         request: tonic::Request<LiquidityPositionsRequest>,
     ) -> Result<tonic::Response<Self::LiquidityPositionsStream>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.snapshot_at(request.get_ref().height)?;
 
         let include_closed = request.get_ref().include_closed;
         let s = state.all_positions();
@@ -311,7 +410,7 @@ impl SpecificQueryService for Info {
         &self,
         request: tonic::Request<LiquidityPositionByIdRequest>,
     ) -> Result<tonic::Response<LiquidityPositionByIdResponse>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.snapshot_at(request.get_ref().height)?;
 
         let position_id: position::Id = request
             .into_inner()
@@ -335,12 +434,50 @@ impl SpecificQueryService for Info {
         }))
     }
 
+    #[instrument(skip(self, request))]
+    async fn position_rewards(
+        &self,
+        request: tonic::Request<PositionRewardsRequest>,
+    ) -> Result<tonic::Response<PositionRewardsResponse>, Status> {
+        let state = self.snapshot_at(request.get_ref().height)?;
+
+        let position_id: position::Id = request
+            .into_inner()
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        // A position that never received any rewards has none to claim, but
+        // still has to be known.
+        state
+            .position_by_id(&position_id)
+            .await
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error fetching position from storage: {e}"))
+            })?
+            .ok_or_else(|| Status::not_found("position not found"))?;
+
+        let rewards = state
+            .position_rewards(&position_id)
+            .await
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error fetching rewards from storage: {e}"))
+            })?;
+
+        Ok(tonic::Response::new(PositionRewardsResponse {
+            rewards: Some(rewards.into()),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn liquidity_positions_by_id(
         &self,
         request: tonic::Request<LiquidityPositionsByIdRequest>,
     ) -> Result<tonic::Response<Self::LiquidityPositionsByIdStream>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.snapshot_at(request.get_ref().height)?;
 
         let position_ids: Vec<position::Id> = request
             .into_inner()
@@ -412,7 +549,7 @@ impl SpecificQueryService for Info {
         &self,
         request: tonic::Request<ValidatorStatusRequest>,
     ) -> Result<tonic::Response<ValidatorStatusResponse>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.snapshot_at(request.get_ref().height)?;
         state
             .check_chain_id(&request.get_ref().chain_id)
             .await
@@ -771,7 +908,7 @@ impl SpecificQueryService for Info {
         &self,
         request: tonic::Request<KeyValueRequest>,
     ) -> Result<tonic::Response<KeyValueResponse>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.snapshot_at(request.get_ref().height)?;
         // We map the error here to avoid including `tonic` as a dependency
         // in the `chain` crate, to support its compilation to wasm.
         state
@@ -786,30 +923,23 @@ impl SpecificQueryService for Info {
             return Err(Status::invalid_argument("key is empty"));
         }
 
-        // TODO(erwan): we are unconditionally generating the proof here; we shouldn't do that if the
-        // request doesn't ask for it
-        let (some_value, proof) = state
-            .get_with_proof_to_apphash(request.key.into_bytes())
-            .await
-            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let (some_value, proof) = if request.proof {
+            let (some_value, proof) = state
+                .get_with_proof_to_apphash(request.key.into_bytes())
+                .await
+                .map_err(|e| tonic::Status::internal(e.to_string()))?;
+            (some_value, Some(merkle_proof_to_proto(proof.proofs)))
+        } else {
+            let some_value = state
+                .get_raw(&request.key)
+                .await
+                .map_err(|e| tonic::Status::internal(e.to_string()))?;
+            (some_value, None)
+        };
 
         Ok(tonic::Response::new(KeyValueResponse {
             value: some_value.map(Into::into),
-            proof: if request.proof {
-                Some(ibc_proto::ibc::core::commitment::v1::MerkleProof {
-                    proofs: proof
-                        .proofs
-                        .into_iter()
-                        .map(|p| {
-                            let mut encoded = Vec::new();
-                            prost::Message::encode(&p, &mut encoded).unwrap();
-                            prost::Message::decode(&*encoded).unwrap()
-                        })
-                        .collect(),
-                })
-            } else {
-                None
-            },
+            proof,
         }))
     }
 
@@ -821,7 +951,7 @@ impl SpecificQueryService for Info {
         &self,
         request: tonic::Request<PrefixValueRequest>,
     ) -> Result<tonic::Response<Self::PrefixValueStream>, Status> {
-        let state = self.storage.latest_snapshot();
+        let state = self.snapshot_at(request.get_ref().height)?;
         state
             .check_chain_id(&request.get_ref().chain_id)
             .await
@@ -833,12 +963,41 @@ impl SpecificQueryService for Info {
             return Err(Status::invalid_argument("prefix is empty"));
         }
 
+        if request.proof {
+            let proof_state = state.clone();
+            return Ok(tonic::Response::new(
+                state
+                    .prefix_with_proofs(&request.prefix)
+                    .and_then(move |(key, value, proof)| {
+                        let state = proof_state.clone();
+                        async move {
+                            let proof = state.extend_proof_to_apphash(proof).await?;
+                            Ok(PrefixValueResponse {
+                                key,
+                                value,
+                                proof: Some(merkle_proof_to_proto(proof.proofs)),
+                            })
+                        }
+                    })
+                    .map_err(|e: anyhow::Error| {
+                        tonic::Status::unavailable(format!(
+                            "error getting prefix value from storage: {e}"
+                        ))
+                    })
+                    .boxed(),
+            ));
+        }
+
         Ok(tonic::Response::new(
             state
                 .prefix_raw(&request.prefix)
                 .map_ok(|i: (String, Vec<u8>)| {
                     let (key, value) = i;
-                    PrefixValueResponse { key, value }
+                    PrefixValueResponse {
+                        key,
+                        value,
+                        proof: None,
+                    }
                 })
                 .map_err(|e: anyhow::Error| {
                     tonic::Status::unavailable(format!(
@@ -851,6 +1010,33 @@ impl SpecificQueryService for Info {
         ))
     }
 
+    type StateChangesStream =
+        Pin<Box<dyn futures::Stream<Item = Result<StateChangesResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn state_changes(
+        &self,
+        request: tonic::Request<StateChangesRequest>,
+    ) -> Result<tonic::Response<Self::StateChangesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        // A subscriber that falls too far behind gets an error, ending the
+        // stream, and must resubscribe.
+        Ok(tonic::Response::new(
+            self.storage
+                .subscribe_changes()
+                .map_ok(|changes| state_changes_to_proto(&changes))
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::data_loss(format!("error following state changes: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
     #[instrument(skip(self, request))]
     async fn arb_execution(
         &self,
@@ -6,6 +6,7 @@ mod consensus;
 mod info;
 mod mempool;
 mod metrics;
+mod migrate;
 mod snapshot;
 
 pub mod auto_https;
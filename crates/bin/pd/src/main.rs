@@ -23,7 +23,7 @@ use penumbra_proto::client::v1alpha1::{
     specific_query_service_server::SpecificQueryServiceServer,
     tendermint_proxy_service_server::TendermintProxyServiceServer,
 };
-use penumbra_storage::Storage;
+use penumbra_storage::{RetentionPolicy, Storage};
 use penumbra_tendermint_proxy::TendermintProxy;
 use penumbra_tower_trace::remote_addr;
 use rand::Rng;
@@ -110,6 +110,48 @@ enum RootCommand {
             display_order = 401
         )]
         tendermint_addr: Url,
+        /// Export a state sync snapshot every this many blocks.
+        ///
+        /// Snapshots are stored in the `snapshots` directory under `home`, and
+        /// served to peers bootstrapping with Tendermint's state sync.  If set
+        /// to 0, no snapshots are exported.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_SNAPSHOT_INTERVAL",
+            default_value = "0",
+            display_order = 500
+        )]
+        snapshot_interval: u64,
+        /// The number of most recent state sync snapshots to keep on disk.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_SNAPSHOT_KEEP_RECENT",
+            default_value = "2",
+            display_order = 501
+        )]
+        snapshot_keep_recent: usize,
+        /// Prune historical state, keeping only this many most recent versions.
+        ///
+        /// Pruned versions can no longer be queried or proven against.  If
+        /// neither this nor `--prune-keep-every` is set, no state is pruned.
+        #[clap(long, env = "PENUMBRA_PD_PRUNE_KEEP_RECENT", display_order = 502)]
+        prune_keep_recent: Option<u64>,
+        /// When pruning historical state, also keep every version that is a
+        /// multiple of this number.
+        #[clap(long, env = "PENUMBRA_PD_PRUNE_KEEP_EVERY", display_order = 503)]
+        prune_keep_every: Option<u64>,
+        /// Limit the rate at which the mempool admits new transactions to this
+        /// many bytes per second, on average.
+        ///
+        /// Transactions over the limit are rejected, and can be resubmitted
+        /// later.  If set to 0, the rate is not limited.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_MEMPOOL_MAX_BYTES_PER_SECOND",
+            default_value = "1048576",
+            display_order = 600
+        )]
+        mempool_max_bytes_per_second: u64,
     },
     /// Generate, join, or reset a testnet.
     Testnet {
@@ -221,6 +263,11 @@ async fn main() -> anyhow::Result<()> {
             grpc_auto_https,
             metrics_bind,
             tendermint_addr,
+            snapshot_interval,
+            snapshot_keep_recent,
+            prune_keep_recent,
+            prune_keep_every,
+            mempool_max_bytes_per_second,
         } => {
             tracing::info!(
                 ?abci_bind,
@@ -228,6 +275,7 @@ async fn main() -> anyhow::Result<()> {
                 ?grpc_auto_https,
                 ?metrics_bind,
                 ?tendermint_addr,
+                ?snapshot_interval,
                 "starting pd"
             );
 
@@ -246,6 +294,15 @@ async fn main() -> anyhow::Result<()> {
                 .await
                 .context("Unable to initialize RocksDB storage")?;
 
+            if prune_keep_recent.is_some() || prune_keep_every.is_some() {
+                let policy = RetentionPolicy {
+                    keep_recent: prune_keep_recent.unwrap_or(1).max(1),
+                    keep_every: prune_keep_every.filter(|k| *k > 0),
+                };
+                tracing::info!(?policy, "pruning historical state");
+                storage.spawn_pruning(policy);
+            }
+
             use penumbra_tower_trace::trace::request_span;
             use penumbra_tower_trace::RequestExt;
 
@@ -269,11 +326,32 @@ async fn main() -> anyhow::Result<()> {
                 }))
                 .service(tower_actor::Actor::new(10, |queue: _| {
                     let storage = storage.clone();
-                    async move { pd::Mempool::new(storage.clone(), queue).await?.run().await }
+                    async move {
+                        pd::Mempool::new(storage.clone(), queue, mempool_max_bytes_per_second)
+                            .await?
+                            .run()
+                            .await
+                    }
                 }));
             let info = pd::Info::new(storage.clone());
             let tm_proxy = TendermintProxy::new(tendermint_addr);
-            let snapshot = pd::Snapshot {};
+            let snapshot = pd::Snapshot::new(storage.clone(), home.join("snapshots"));
+            if snapshot_interval > 0 {
+                tokio::task::Builder::new()
+                    .name("snapshot_exporter")
+                    .spawn({
+                        let snapshot = snapshot.clone();
+                        async move {
+                            if let Err(error) = snapshot
+                                .export_periodically(snapshot_interval, snapshot_keep_recent)
+                                .await
+                            {
+                                tracing::error!(?error, "snapshot exporter failed");
+                            }
+                        }
+                    })
+                    .expect("failed to spawn snapshot exporter");
+            }
 
             let abci_server = tokio::task::Builder::new()
                 .name("abci_server")
@@ -1,6 +1,12 @@
-use anyhow::Result;
+use std::sync::Arc;
 
+use anyhow::{Context, Result};
+
+use penumbra_app::TransactionError;
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+use penumbra_proto::DomainType;
 use penumbra_storage::{Snapshot, Storage};
+use penumbra_transaction::Transaction;
 
 use tendermint::abci::{
     request::CheckTx as CheckTxReq, request::CheckTxKind, response::CheckTx as CheckTxRsp,
@@ -12,6 +18,59 @@ use tracing::Instrument;
 
 use crate::{metrics, App};
 
+mod rate_limit;
+
+use rate_limit::RateLimiter;
+
+/// The ABCI codes returned to Tendermint for transactions rejected by the
+/// mempool's own policy, rather than by the transaction checks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum RejectionCode {
+    /// The mempool is admitting new transaction bytes faster than its rate limit.
+    RateLimited = 2,
+}
+
+impl RejectionCode {
+    /// The ABCI codespace of the mempool's rejection codes.
+    pub const CODESPACE: &'static str = "mempool";
+
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+}
+
+/// The reason a transaction was rejected by the mempool.
+#[derive(Debug)]
+enum Rejection {
+    /// The transaction was rejected by the mempool's policy.
+    Policy(RejectionCode, anyhow::Error),
+    /// The transaction failed to decode or execute against the pending state.
+    Invalid(anyhow::Error),
+}
+
+impl Rejection {
+    fn code(&self) -> u32 {
+        match self {
+            Rejection::Policy(code, _) => code.code(),
+            Rejection::Invalid(e) => TransactionError::abci_code(e),
+        }
+    }
+
+    fn codespace(&self) -> &'static str {
+        match self {
+            Rejection::Policy(..) => RejectionCode::CODESPACE,
+            Rejection::Invalid(_) => TransactionError::CODESPACE,
+        }
+    }
+
+    fn error(&self) -> &anyhow::Error {
+        match self {
+            Rejection::Policy(_, e) | Rejection::Invalid(e) => e,
+        }
+    }
+}
+
 /// When using ABCI, we can't control block proposal directly, so we could
 /// potentially end up creating blocks with mutually incompatible transactions.
 /// While we'd reject one of them during execution, it's nicer to try to filter
@@ -22,18 +81,27 @@ use crate::{metrics, App};
 /// then reject the second transaction against the forked state. When we learn a
 /// new state has been committed, we discard and recreate the ephemeral fork.
 ///
+/// Executing against the fork also rejects transactions spending a nullifier
+/// spent by a pending transaction. In addition, the mempool limits the rate at
+/// which it admits new transaction bytes, and reports the fee paid per kilobyte
+/// of each transaction as its priority.
+///
 /// After switching to ABCI++, we can eliminate this mechanism and just build
 /// blocks we want.
 pub struct Mempool {
     queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     app: App,
     snapshot_rx: watch::Receiver<Snapshot>,
+    rate_limiter: RateLimiter,
 }
 
 impl Mempool {
+    /// Creates a new mempool, admitting new transactions at up to
+    /// `max_bytes_per_second` on average, or without limit if it is zero.
     pub async fn new(
         storage: Storage,
         queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
+        max_bytes_per_second: u64,
     ) -> Result<Self> {
         let app = App::new(storage.latest_snapshot()).await?;
         let snapshot_rx = storage.subscribe();
@@ -42,6 +110,7 @@ impl Mempool {
             queue,
             app,
             snapshot_rx,
+            rate_limiter: RateLimiter::new(max_bytes_per_second),
         })
     }
 
@@ -56,36 +125,81 @@ impl Mempool {
             CheckTxKind::Recheck => "recheck",
         };
 
-        match self.app.deliver_tx_bytes(tx_bytes.as_ref()).await {
-            Ok(events) => {
+        let rsp = match self.check_and_execute(tx_bytes.as_ref(), kind).await {
+            Ok(rsp) => {
                 let elapsed = start.elapsed();
-                tracing::info!(?elapsed, "tx accepted");
+                tracing::info!(?elapsed, priority = rsp.priority, "tx accepted");
                 metrics::increment_counter!(
                     metrics::MEMPOOL_CHECKTX_TOTAL,
                     "kind" => kind_str,
                     "code" => "0"
                 );
-                Ok(Response::CheckTx(CheckTxRsp {
-                    events,
-                    ..Default::default()
-                }))
+                rsp
             }
-            Err(e) => {
+            Err(rejection) => {
                 let elapsed = start.elapsed();
-                tracing::info!(?e, ?elapsed, "tx rejected");
+                tracing::info!(?rejection, ?elapsed, "tx rejected");
                 metrics::increment_counter!(
                     metrics::MEMPOOL_CHECKTX_TOTAL,
                     "kind" => kind_str,
-                    "code" => "1"
+                    "code" => format!("{}/{}", rejection.codespace(), rejection.code())
                 );
-                Ok(Response::CheckTx(CheckTxRsp {
-                    code: 1.into(),
+                CheckTxRsp {
+                    code: rejection.code().into(),
+                    codespace: rejection.codespace().to_string(),
                     // Use the alternate format specifier to include the chain of error causes.
-                    log: format!("{e:#}"),
+                    log: format!("{:#}", rejection.error()),
                     ..Default::default()
-                }))
+                }
             }
+        };
+
+        Ok(Response::CheckTx(rsp))
+    }
+
+    async fn check_and_execute(
+        &mut self,
+        tx_bytes: &[u8],
+        kind: CheckTxKind,
+    ) -> Result<CheckTxRsp, Rejection> {
+        // Rechecked transactions were already admitted once, so they don't
+        // count against the rate limit.
+        let size = tx_bytes.len() as u64;
+        let charged = matches!(kind, CheckTxKind::New);
+        if charged && !self.rate_limiter.try_admit(size) {
+            return Err(Rejection::Policy(
+                RejectionCode::RateLimited,
+                anyhow::anyhow!("mempool is over its rate limit, try again later"),
+            ));
         }
+
+        let rsp = self.execute(tx_bytes).await;
+        // Only admitted transactions use up the rate limit, so that invalid
+        // transactions can't crowd out valid ones.
+        if charged && rsp.is_err() {
+            self.rate_limiter.refund(size);
+        }
+
+        rsp
+    }
+
+    async fn execute(&mut self, tx_bytes: &[u8]) -> Result<CheckTxRsp, Rejection> {
+        let tx = Transaction::decode(tx_bytes)
+            .context(TransactionError::Malformed)
+            .map(Arc::new)
+            .map_err(Rejection::Invalid)?;
+
+        let events = self
+            .app
+            .deliver_tx(tx.clone())
+            .await
+            .map_err(Rejection::Invalid)?;
+
+        Ok(CheckTxRsp {
+            events,
+            priority: priority(&tx, tx_bytes.len()),
+            ..Default::default()
+        })
     }
 
     pub async fn run(mut self) -> Result<(), tower::BoxError> {
@@ -120,3 +234,18 @@ impl Mempool {
         }
     }
 }
+
+/// The priority of a transaction in Tendermint's mempool: the fee it pays per
+/// kilobyte, so that block space goes to the transactions paying most for it.
+///
+/// Fees paid in assets other than the staking token aren't comparable, so they
+/// get the lowest priority.
+fn priority(tx: &Transaction, size: usize) -> i64 {
+    let fee = &tx.transaction_body.fee;
+    if fee.asset_id() != *STAKING_TOKEN_ASSET_ID || size == 0 {
+        return 0;
+    }
+
+    let fee_per_kilobyte = fee.amount().value().saturating_mul(1024) / size as u128;
+    fee_per_kilobyte.try_into().unwrap_or(i64::MAX)
+}
@@ -0,0 +1,119 @@
+use tokio::time::Instant;
+
+/// The number of seconds' worth of transaction bytes that can be admitted in a burst.
+const BURST_SECONDS: u64 = 10;
+
+/// A token bucket limiting the rate at which the mempool admits new transaction bytes.
+///
+/// Penumbra transactions are shielded, so there's no sender to rate-limit
+/// separately: every new transaction draws from the same budget, in proportion
+/// to its size.
+#[derive(Debug)]
+pub struct RateLimiter {
+    bytes_per_second: u64,
+    available: u64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter admitting `bytes_per_second` on average, or any
+    /// number of bytes if `bytes_per_second` is zero.
+    pub fn new(bytes_per_second: u64) -> Self {
+        Self {
+            bytes_per_second,
+            available: bytes_per_second.saturating_mul(BURST_SECONDS),
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Attempts to admit `bytes` bytes, returning `false` if doing so would
+    /// exceed the rate limit.
+    pub fn try_admit(&mut self, bytes: u64) -> bool {
+        self.try_admit_at(bytes, Instant::now())
+    }
+
+    fn try_admit_at(&mut self, bytes: u64, now: Instant) -> bool {
+        if self.bytes_per_second == 0 {
+            return true;
+        }
+
+        self.refill(now);
+        if bytes <= self.available {
+            self.available -= bytes;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns `bytes` bytes admitted by [`RateLimiter::try_admit`] to the
+    /// budget, because the transaction they were admitted for was rejected.
+    pub fn refund(&mut self, bytes: u64) {
+        let capacity = self.bytes_per_second.saturating_mul(BURST_SECONDS);
+        self.available = self.available.saturating_add(bytes).min(capacity);
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refill = elapsed.as_micros() * u128::from(self.bytes_per_second) / 1_000_000;
+        // Only advance the refill time once at least one byte has been
+        // refilled, so that frequent calls don't round the refill down to zero.
+        if refill > 0 {
+            let capacity = self.bytes_per_second.saturating_mul(BURST_SECONDS);
+            self.available = u128::from(self.available)
+                .saturating_add(refill)
+                .min(u128::from(capacity)) as u64;
+            self.last_refill = now;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn admits_bursts_then_refills() {
+        let mut limiter = RateLimiter::new(1000);
+        let start = limiter.last_refill;
+
+        // The full burst is available up front, but no more.
+        assert!(limiter.try_admit_at(10_000, start));
+        assert!(!limiter.try_admit_at(1, start));
+
+        // Bytes are refilled in proportion to the elapsed time.
+        let later = start + Duration::from_millis(500);
+        assert!(!limiter.try_admit_at(501, later));
+        assert!(limiter.try_admit_at(500, later));
+
+        // The refill is capped at the burst size.
+        let much_later = later + Duration::from_secs(1000);
+        assert!(!limiter.try_admit_at(10_001, much_later));
+        assert!(limiter.try_admit_at(10_000, much_later));
+    }
+
+    #[test]
+    fn refunds_are_capped_at_burst() {
+        let mut limiter = RateLimiter::new(1000);
+        let start = limiter.last_refill;
+
+        assert!(limiter.try_admit_at(10_000, start));
+        limiter.refund(4_000);
+        assert!(!limiter.try_admit_at(4_001, start));
+        assert!(limiter.try_admit_at(4_000, start));
+
+        // Refunding more than was admitted doesn't exceed the burst size.
+        limiter.refund(u64::MAX);
+        assert!(!limiter.try_admit_at(10_001, start));
+        assert!(limiter.try_admit_at(10_000, start));
+    }
+
+    #[test]
+    fn zero_rate_is_unlimited() {
+        let mut limiter = RateLimiter::new(0);
+        assert!(limiter.try_admit(u64::MAX));
+        assert!(limiter.try_admit(u64::MAX));
+    }
+}
@@ -0,0 +1,15 @@
+//! State migrations run by `pd` when it restarts after the chain halted for a scheduled upgrade.
+//!
+//! When an upgrade plan proposal passes, the chain halts after committing the block at the planned
+//! height. To upgrade, a node operator replaces `pd` with the upgraded software and restarts it;
+//! the upgraded node then runs the [`Migration`] registered here under the name of the upgrade
+//! plan, if any, before processing the next block.
+//!
+//! To add a migration for an upgrade, implement [`Migration`] for a new type named after the
+//! upgrade, and register it in [`MIGRATIONS`]. The upgraded software must also increment the total
+//! halt count of the app, so that it can proceed past the upgrade height.
+
+use penumbra_app::Migration;
+
+/// The state migrations known to this version of `pd`, by upgrade name.
+pub static MIGRATIONS: &[&dyn Migration] = &[];
@@ -1,15 +1,218 @@
 use std::{
     future::Future,
+    path::PathBuf,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
+use anyhow::{Context as _, Result};
 use futures::FutureExt;
-use tendermint::abci::{SnapshotRequest, SnapshotResponse};
+use penumbra_chain::component::AppHash;
+use penumbra_storage::{ExportedSnapshot, Restore, RootHash, SnapshotManifest, Storage};
+use tendermint::abci::{
+    request, response, response::ApplySnapshotChunkResult, types, SnapshotRequest, SnapshotResponse,
+};
+use tokio::sync::Mutex;
 use tower_abci::BoxError;
 
+/// The format of the snapshots produced by this version of `pd`.
+///
+/// Tendermint rejects snapshots whose format the application doesn't support,
+/// so this should be bumped whenever the chunk encoding changes.
+const SNAPSHOT_FORMAT: u32 = 1;
+
+/// The approximate size of each snapshot chunk.
+///
+/// Tendermint limits chunks to 16 MB, so this leaves some headroom.
+const CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// Implements ABCI state sync, by serving snapshots exported from the
+/// [`Storage`] to peers, and by restoring snapshots offered by peers into an
+/// empty [`Storage`].
+///
+/// Snapshots are written to disk by [`Snapshot::export_periodically`], so that
+/// they can be served after a restart.
 #[derive(Clone, Debug)]
-pub struct Snapshot {}
+pub struct Snapshot {
+    storage: Storage,
+    store: SnapshotStore,
+    /// The snapshot currently being restored, if any.
+    restore: Arc<Mutex<Option<Restore>>>,
+}
+
+impl Snapshot {
+    /// Creates a new snapshot service, storing exported snapshots in `dir`.
+    pub fn new(storage: Storage, dir: PathBuf) -> Self {
+        Self {
+            storage,
+            store: SnapshotStore { dir },
+            restore: Default::default(),
+        }
+    }
+
+    /// Exports a snapshot of the chain state every `interval` blocks, keeping
+    /// only the `keep_recent` most recent snapshots on disk.
+    pub async fn export_periodically(self, interval: u64, keep_recent: usize) -> Result<()> {
+        anyhow::ensure!(interval > 0, "snapshot interval must be nonzero");
+        let mut snapshot_rx = self.storage.subscribe();
+
+        while snapshot_rx.changed().await.is_ok() {
+            let snapshot = snapshot_rx.borrow().clone();
+            let version = snapshot.version();
+            if version == u64::MAX || version % interval != 0 {
+                continue;
+            }
+
+            tracing::info!(version, "exporting state sync snapshot");
+            match snapshot.export(CHUNK_SIZE).await {
+                Ok(exported) => {
+                    let store = self.store.clone();
+                    tokio::task::spawn_blocking(move || {
+                        store.save(&exported)?;
+                        store.prune(keep_recent)
+                    })
+                    .await??;
+                    tracing::info!(version, "exported state sync snapshot");
+                }
+                Err(error) => {
+                    tracing::warn!(version, ?error, "failed to export state sync snapshot");
+                }
+            }
+        }
+
+        tracing::info!("state notification channel closed, stopping snapshot exporter");
+        Ok(())
+    }
+
+    fn list_snapshots(&self) -> Result<response::ListSnapshots> {
+        let snapshots = self
+            .store
+            .list()?
+            .into_iter()
+            .map(|manifest| {
+                Ok(types::Snapshot {
+                    height: manifest.version.try_into()?,
+                    format: SNAPSHOT_FORMAT,
+                    chunks: manifest.chunk_count(),
+                    hash: manifest.hash().to_vec().into(),
+                    metadata: manifest.encode().into(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(response::ListSnapshots { snapshots })
+    }
+
+    async fn offer_snapshot(&self, offer: request::OfferSnapshot) -> response::OfferSnapshot {
+        let request::OfferSnapshot { snapshot, app_hash } = offer;
+
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return response::OfferSnapshot::RejectFormat;
+        }
+
+        let manifest = match SnapshotManifest::decode(&snapshot.metadata) {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                tracing::info!(?error, "rejecting snapshot with invalid metadata");
+                return response::OfferSnapshot::Reject;
+            }
+        };
+
+        // The snapshot hash commits to the manifest, and the app hash (which was
+        // verified by Tendermint's light client) commits to the restored state.
+        let expected_app_hash = AppHash::from(RootHash(manifest.root_hash));
+        if manifest.hash().as_slice() != snapshot.hash.as_ref()
+            || manifest.version != snapshot.height.value()
+            || manifest.chunk_count() != snapshot.chunks
+            || expected_app_hash.0.as_slice() != app_hash.as_bytes()
+        {
+            tracing::info!(height = ?snapshot.height, "rejecting snapshot not matching its metadata");
+            return response::OfferSnapshot::Reject;
+        }
+
+        match self.storage.restore(manifest) {
+            Ok(restore) => {
+                tracing::info!(height = ?snapshot.height, "accepted state sync snapshot");
+                *self.restore.lock().await = Some(restore);
+                response::OfferSnapshot::Accept
+            }
+            Err(error) => {
+                tracing::warn!(?error, "unable to restore snapshot, aborting state sync");
+                response::OfferSnapshot::Abort
+            }
+        }
+    }
+
+    fn load_snapshot_chunk(
+        &self,
+        load: request::LoadSnapshotChunk,
+    ) -> Result<response::LoadSnapshotChunk> {
+        anyhow::ensure!(
+            load.format == SNAPSHOT_FORMAT,
+            "unsupported snapshot format {}",
+            load.format
+        );
+        let chunk = self.store.load_chunk(load.height.value(), load.chunk)?;
+        Ok(response::LoadSnapshotChunk {
+            chunk: chunk.into(),
+        })
+    }
+
+    async fn apply_snapshot_chunk(
+        &self,
+        apply: request::ApplySnapshotChunk,
+    ) -> response::ApplySnapshotChunk {
+        let request::ApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        } = apply;
+
+        let mut restore = self.restore.lock().await;
+        let Some(in_progress) = restore.as_mut() else {
+            tracing::warn!(
+                index,
+                "received snapshot chunk without an accepted snapshot"
+            );
+            return response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Abort,
+                ..Default::default()
+            };
+        };
+
+        // A chunk that doesn't match the manifest was corrupted by the sender, so
+        // ask Tendermint to fetch it again from someone else.
+        if !in_progress.chunk_is_valid(index, &chunk) {
+            tracing::info!(index, %sender, "rejecting invalid snapshot chunk");
+            return response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![index],
+                reject_senders: vec![sender],
+            };
+        }
+
+        match in_progress.apply_chunk(index, chunk.to_vec()).await {
+            Ok(()) => {
+                if in_progress.is_complete() {
+                    *restore = None;
+                }
+                response::ApplySnapshotChunk {
+                    result: ApplySnapshotChunkResult::Accept,
+                    ..Default::default()
+                }
+            }
+            Err(error) => {
+                tracing::warn!(index, ?error, "failed to apply snapshot chunk");
+                *restore = None;
+                response::ApplySnapshotChunk {
+                    result: ApplySnapshotChunkResult::RejectSnapshot,
+                    ..Default::default()
+                }
+            }
+        }
+    }
+}
 
 impl tower_service::Service<SnapshotRequest> for Snapshot {
     type Response = SnapshotResponse;
@@ -22,17 +225,106 @@ impl tower_service::Service<SnapshotRequest> for Snapshot {
     }
 
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
-        // No-op, we don't implement snapshot support
         use SnapshotRequest as Request;
         use SnapshotResponse as Response;
+        let snapshot = self.clone();
         async move {
             Ok(match req {
-                Request::ListSnapshots => Response::ListSnapshots(Default::default()),
-                Request::OfferSnapshot(_) => Response::OfferSnapshot(Default::default()),
-                Request::LoadSnapshotChunk(_) => Response::LoadSnapshotChunk(Default::default()),
-                Request::ApplySnapshotChunk(_) => Response::ApplySnapshotChunk(Default::default()),
+                Request::ListSnapshots => {
+                    Response::ListSnapshots(snapshot.list_snapshots().unwrap_or_else(|error| {
+                        tracing::warn!(?error, "failed to list snapshots");
+                        Default::default()
+                    }))
+                }
+                Request::OfferSnapshot(offer) => {
+                    Response::OfferSnapshot(snapshot.offer_snapshot(offer).await)
+                }
+                Request::LoadSnapshotChunk(load) => Response::LoadSnapshotChunk(
+                    snapshot.load_snapshot_chunk(load).unwrap_or_else(|error| {
+                        tracing::warn!(?error, "failed to load snapshot chunk");
+                        Default::default()
+                    }),
+                ),
+                Request::ApplySnapshotChunk(apply) => {
+                    Response::ApplySnapshotChunk(snapshot.apply_snapshot_chunk(apply).await)
+                }
             })
         }
         .boxed()
     }
 }
+
+/// On-disk storage for exported snapshots.
+///
+/// Each snapshot is stored in its own directory, named by its version, which
+/// contains the encoded manifest and one file per chunk.
+#[derive(Clone, Debug)]
+struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    fn snapshot_dir(&self, version: u64) -> PathBuf {
+        self.dir.join(version.to_string())
+    }
+
+    /// Writes an exported snapshot to disk.
+    fn save(&self, exported: &ExportedSnapshot) -> Result<()> {
+        let version = exported.manifest.version;
+        // Write into a temporary directory first, so that a partially-written
+        // snapshot is never served.
+        let tmp_dir = self.dir.join(format!("{version}.tmp"));
+        if tmp_dir.exists() {
+            std::fs::remove_dir_all(&tmp_dir)?;
+        }
+        std::fs::create_dir_all(&tmp_dir)?;
+
+        for (index, chunk) in exported.chunks.iter().enumerate() {
+            std::fs::write(tmp_dir.join(format!("{index}.chunk")), chunk)?;
+        }
+        std::fs::write(tmp_dir.join("manifest"), exported.manifest.encode())?;
+
+        let dir = self.snapshot_dir(version);
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+        std::fs::rename(tmp_dir, dir)?;
+        Ok(())
+    }
+
+    /// Returns the manifests of all stored snapshots, most recent first.
+    fn list(&self) -> Result<Vec<SnapshotManifest>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut manifests = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path().join("manifest");
+            // Skip temporary directories, which have no manifest.
+            if !path.exists() {
+                continue;
+            }
+            let bytes = std::fs::read(&path)
+                .with_context(|| format!("could not read snapshot manifest {path:?}"))?;
+            manifests.push(SnapshotManifest::decode(&bytes)?);
+        }
+        manifests.sort_by_key(|manifest| std::cmp::Reverse(manifest.version));
+
+        Ok(manifests)
+    }
+
+    fn load_chunk(&self, version: u64, index: u32) -> Result<Vec<u8>> {
+        let path = self.snapshot_dir(version).join(format!("{index}.chunk"));
+        std::fs::read(&path).with_context(|| format!("could not read snapshot chunk {path:?}"))
+    }
+
+    /// Deletes all but the `keep_recent` most recent snapshots.
+    fn prune(&self, keep_recent: usize) -> Result<()> {
+        for manifest in self.list()?.into_iter().skip(keep_recent) {
+            tracing::debug!(version = manifest.version, "pruning old snapshot");
+            std::fs::remove_dir_all(self.snapshot_dir(manifest.version))?;
+        }
+        Ok(())
+    }
+}
@@ -2,11 +2,14 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use penumbra_fee::{Gas, GasCost};
 use penumbra_storage::{StateRead, StateWrite};
 
 mod actions;
 mod transaction;
 
+pub use actions::IbcAppRouter;
+
 /// Stub: to be replaced with impls of penumbra_component::ActionHandler
 ///
 /// This trait should move to that crate, but the orphan rules make it tricky to
@@ -19,6 +22,10 @@ mod transaction;
 #[async_trait]
 pub trait ActionHandler {
     type CheckStatelessContext: Clone + Send + Sync + 'static;
+    /// The gas used by this action or transaction, which the transaction's fee must pay for.
+    ///
+    /// Actions handled by other components report the gas cost defined alongside the action.
+    fn gas_cost(&self) -> Gas;
     async fn check_stateless(&self, context: Self::CheckStatelessContext) -> Result<()>;
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()>;
     async fn execute<S: StateWrite>(&self, state: S) -> Result<()>;
@@ -27,8 +34,11 @@ pub trait ActionHandler {
 use penumbra_component::ActionHandler as ComponentActionHandler;
 
 #[async_trait]
-impl<'a, T: ComponentActionHandler + Sync> ActionHandler for crate::Compat<'a, T> {
+impl<'a, T: ComponentActionHandler + GasCost + Sync> ActionHandler for crate::Compat<'a, T> {
     type CheckStatelessContext = T::CheckStatelessContext;
+    fn gas_cost(&self) -> Gas {
+        self.0.gas_cost()
+    }
     async fn check_stateless(&self, context: Self::CheckStatelessContext) -> Result<()> {
         ComponentActionHandler::check_stateless(self.0, context).await
     }
@@ -4,6 +4,8 @@ use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_chain::component::StateReadExt as _;
 use penumbra_chain::TransactionContext;
+use penumbra_fee::{Gas, GasCost as _};
+use penumbra_ibc::component::{AppRouter, Ics20Transfer};
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::Action;
 
@@ -14,10 +16,40 @@ mod delegator_vote;
 mod proposal;
 mod validator_vote;
 
+/// The IBC applications served by Penumbra, routed by the port they are bound to.
+pub type IbcAppRouter = AppRouter<Ics20Transfer>;
+
 #[async_trait]
 impl ActionHandler for Action {
     type CheckStatelessContext = TransactionContext;
 
+    fn gas_cost(&self) -> Gas {
+        match self {
+            Action::Delegate(action) => action.gas_cost(),
+            Action::Undelegate(action) => action.gas_cost(),
+            Action::UndelegateClaim(action) => action.gas_cost(),
+            Action::ValidatorDefinition(action) => action.gas_cost(),
+            Action::DelegatorVote(action) => action.gas_cost(),
+            Action::ValidatorVote(action) => action.gas_cost(),
+            Action::PositionClose(action) => action.gas_cost(),
+            Action::PositionOpen(action) => action.gas_cost(),
+            Action::PositionRewardClaim(action) => action.gas_cost(),
+            Action::PositionWithdraw(action) => action.gas_cost(),
+            Action::ProposalSubmit(action) => action.gas_cost(),
+            Action::ProposalWithdraw(action) => action.gas_cost(),
+            Action::ProposalDepositClaim(action) => action.gas_cost(),
+            Action::Swap(action) => action.gas_cost(),
+            Action::SwapClaim(action) => action.gas_cost(),
+            Action::Spend(action) => action.gas_cost(),
+            Action::Output(action) => action.gas_cost(),
+            Action::IbcAction(action) => action.gas_cost(),
+            Action::Ics20Withdrawal(action) => action.gas_cost(),
+            Action::DaoSpend(action) => action.gas_cost(),
+            Action::DaoOutput(action) => action.gas_cost(),
+            Action::DaoDeposit(action) => action.gas_cost(),
+        }
+    }
+
     async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
         match self {
             // These actions require a context
@@ -39,7 +71,13 @@ impl ActionHandler for Action {
             Action::ProposalDepositClaim(action) => action.check_stateless(()),
             Action::Swap(action) => action.check_stateless(()),
             Action::Output(action) => action.check_stateless(()),
-            Action::IbcAction(action) => action.check_stateless(()),
+            // The handler is a temporary that the check borrows, so it has to be awaited here.
+            Action::IbcAction(action) => {
+                return action
+                    .with_handler::<IbcAppRouter>()
+                    .check_stateless(())
+                    .await
+            }
             Action::Ics20Withdrawal(action) => action.check_stateless(()),
             Action::DaoSpend(action) => action.check_stateless(()),
             Action::DaoOutput(action) => action.check_stateless(()),
@@ -74,7 +112,10 @@ impl ActionHandler for Action {
                     ));
                 }
 
-                action.check_stateful(state).await
+                action
+                    .with_handler::<IbcAppRouter>()
+                    .check_stateful(state)
+                    .await
             }
             Action::Ics20Withdrawal(action) => action.check_stateful(state).await,
             Action::DaoSpend(action) => action.check_stateful(state).await,
@@ -102,7 +143,7 @@ impl ActionHandler for Action {
             Action::SwapClaim(action) => action.execute(state).await,
             Action::Spend(action) => action.execute(state).await,
             Action::Output(action) => action.execute(state).await,
-            Action::IbcAction(action) => action.execute(state).await,
+            Action::IbcAction(action) => action.with_handler::<IbcAppRouter>().execute(state).await,
             Action::Ics20Withdrawal(action) => action.execute(state).await,
             Action::DaoSpend(action) => action.execute(state).await,
             Action::DaoOutput(action) => action.execute(state).await,
@@ -5,9 +5,12 @@ use ark_ff::Zero;
 use async_trait::async_trait;
 use decaf377::Fr;
 use penumbra_chain::TransactionContext;
+use penumbra_component::TransactionError;
+use penumbra_fee::Gas;
 use penumbra_proof_params::DELEGATOR_VOTE_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::action::{DelegatorVote, DelegatorVoteBody};
+use penumbra_transaction::gas::delegator_vote_gas_cost;
 
 use crate::{
     governance::{StateReadExt as _, StateWriteExt as _},
@@ -18,6 +21,10 @@ use crate::{
 impl ActionHandler for DelegatorVote {
     type CheckStatelessContext = TransactionContext;
 
+    fn gas_cost(&self) -> Gas {
+        delegator_vote_gas_cost()
+    }
+
     async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
         let DelegatorVote {
             auth_sig,
@@ -37,7 +44,8 @@ impl ActionHandler for DelegatorVote {
 
         // 1. Check spend auth signature using provided spend auth key.
         rk.verify(context.effect_hash.as_ref(), auth_sig)
-            .context("delegator vote auth signature failed to verify")?;
+            .context("delegator vote auth signature failed to verify")
+            .context(TransactionError::InvalidSignature)?;
 
         // 2. Verify the proof against the provided anchor and start position:
         proof
@@ -49,7 +57,8 @@ impl ActionHandler for DelegatorVote {
                 *rk,
                 *start_position,
             )
-            .context("a delegator vote proof did not verify")?;
+            .context("a delegator vote proof did not verify")
+            .context(TransactionError::InvalidProof)?;
 
         Ok(())
     }
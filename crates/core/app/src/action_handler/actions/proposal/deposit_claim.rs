@@ -2,10 +2,12 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use penumbra_fee::Gas;
 use penumbra_governance::ProposalNft;
 use penumbra_shielded_pool::component::SupplyWrite;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::action::ProposalDepositClaim;
+use penumbra_transaction::gas::proposal_deposit_claim_gas_cost;
 use penumbra_transaction::proposal::{self, Outcome};
 
 use crate::action_handler::ActionHandler;
@@ -14,6 +16,11 @@ use crate::governance::{StateReadExt as _, StateWriteExt as _};
 #[async_trait]
 impl ActionHandler for ProposalDepositClaim {
     type CheckStatelessContext = ();
+
+    fn gas_cost(&self) -> Gas {
+        proposal_deposit_claim_gas_cost(self)
+    }
+
     async fn check_stateless(&self, _context: ()) -> Result<()> {
         // No stateless checks are required for this action (all checks require state access)
         Ok(())
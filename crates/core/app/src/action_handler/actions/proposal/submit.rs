@@ -7,12 +7,15 @@ use decaf377::Fq;
 use decaf377_rdsa::{VerificationKey, VerificationKeyBytes};
 use once_cell::sync::Lazy;
 use penumbra_asset::STAKING_TOKEN_DENOM;
-use penumbra_chain::component::StateReadExt as _;
+use penumbra_chain::{component::StateReadExt as _, params::change::check_valid_changes};
+use penumbra_fee::Gas;
 use penumbra_governance::{ProposalNft, VotingReceiptToken};
+use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_keys::keys::{FullViewingKey, NullifierKey};
 use penumbra_sct::component::StateReadExt as _;
 use penumbra_shielded_pool::component::SupplyWrite;
 use penumbra_storage::{StateDelta, StateRead, StateWrite};
+use penumbra_transaction::gas::proposal_submit_gas_cost;
 use penumbra_transaction::plan::TransactionPlan;
 use penumbra_transaction::proposal::{self, Proposal, ProposalPayload};
 use penumbra_transaction::{action::ProposalSubmit, Transaction};
@@ -38,6 +41,11 @@ pub const PROPOSAL_DESCRIPTION_LIMIT: usize = 10_000; // ⚠️ DON'T CHANGE THI
 #[async_trait]
 impl ActionHandler for ProposalSubmit {
     type CheckStatelessContext = ();
+
+    fn gas_cost(&self) -> Gas {
+        proposal_submit_gas_cost(self)
+    }
+
     async fn check_stateless(&self, _context: ()) -> Result<()> {
         let ProposalSubmit {
             proposal,
@@ -70,6 +78,9 @@ impl ActionHandler for ProposalSubmit {
                 old.check_valid_update(new)
                     .context("invalid change to chain parameters")?;
             }
+            PartialParameterChange { changes } => {
+                check_valid_changes(changes).context("invalid change to chain parameters")?;
+            }
             DaoSpend { transaction_plan } => {
                 // Check to make sure that the transaction plan contains only valid actions for the
                 // DAO (none of them should require proving to build):
@@ -108,6 +119,26 @@ impl ActionHandler for ProposalSubmit {
                     }
                 }
             }
+            IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                anyhow::ensure!(
+                    subject_client_id != substitute_client_id,
+                    "subject and substitute clients of an IBC client recovery must be different"
+                );
+            }
+            UpgradePlan {
+                height: _, // the height is checked against the end of voting, which is stateful
+                name,
+                info,
+            } => {
+                anyhow::ensure!(!name.is_empty(), "upgrade plan must have a name");
+                anyhow::ensure!(
+                    info.len() <= PROPOSAL_DESCRIPTION_LIMIT,
+                    "upgrade plan info must fit within {PROPOSAL_DESCRIPTION_LIMIT} characters"
+                );
+            }
         }
 
         Ok(())
@@ -144,7 +175,8 @@ impl ActionHandler for ProposalSubmit {
         match &proposal.payload {
             ProposalPayload::Signaling { .. } => { /* no stateful checks for signaling */ }
             ProposalPayload::Emergency { .. } => { /* no stateful checks for emergency */ }
-            ProposalPayload::ParameterChange { .. } => {
+            ProposalPayload::ParameterChange { .. }
+            | ProposalPayload::PartialParameterChange { .. } => {
                 /* no stateful checks for parameter change (checks are applied when proposal finishes) */
             }
             ProposalPayload::DaoSpend { transaction_plan } => {
@@ -172,6 +204,28 @@ impl ActionHandler for ProposalSubmit {
                     "submitted DAO spend transaction failed to execute in current chain state",
                 )?;
             }
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                // Check that the recovery would succeed in the current chain state. The clients
+                // may change while the proposal is pending, so this is checked again when the
+                // proposal passes.
+                state
+                    .check_client_recovery(subject_client_id, substitute_client_id)
+                    .await
+                    .context("submitted IBC client recovery is not valid in current chain state")?;
+            }
+            ProposalPayload::UpgradePlan { height, .. } => {
+                // The upgrade can't be scheduled before the proposal passes, so it must be planned
+                // for a height after the end of the voting period.
+                let voting_end =
+                    state.get_block_height().await? + chain_parameters.proposal_voting_blocks;
+                anyhow::ensure!(
+                    *height > voting_end,
+                    "upgrade height {height} must be after the end of voting at height {voting_end}",
+                );
+            }
         }
 
         Ok(())
@@ -2,9 +2,11 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use penumbra_fee::Gas;
 use penumbra_governance::ProposalNft;
 use penumbra_shielded_pool::component::SupplyWrite;
 use penumbra_storage::{StateRead, StateWrite};
+use penumbra_transaction::gas::proposal_withdraw_gas_cost;
 use penumbra_transaction::{action::ProposalWithdraw, proposal};
 
 use crate::{
@@ -15,6 +17,11 @@ use crate::{
 #[async_trait]
 impl ActionHandler for ProposalWithdraw {
     type CheckStatelessContext = ();
+
+    fn gas_cost(&self) -> Gas {
+        proposal_withdraw_gas_cost(self)
+    }
+
     async fn check_stateless(&self, _context: ()) -> Result<()> {
         // Enforce a maximum length on proposal withdrawal reasons; 80 characters seems reasonable.
         const PROPOSAL_WITHDRAWAL_REASON_LIMIT: usize = 80;
@@ -3,8 +3,10 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::component::StateReadExt as _;
+use penumbra_fee::Gas;
 use penumbra_proto::DomainType;
 use penumbra_storage::{StateRead, StateWrite};
+use penumbra_transaction::gas::validator_vote_gas_cost;
 use penumbra_transaction::{
     action::{ValidatorVote, ValidatorVoteBody},
     proposal,
@@ -18,6 +20,11 @@ use crate::{
 #[async_trait]
 impl ActionHandler for ValidatorVote {
     type CheckStatelessContext = ();
+
+    fn gas_cost(&self) -> Gas {
+        validator_vote_gas_cost(self)
+    }
+
     async fn check_stateless(&self, _context: ()) -> Result<()> {
         let ValidatorVote { body, auth_sig } = self;
 
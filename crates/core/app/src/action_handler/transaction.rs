@@ -3,9 +3,13 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_chain::NoteSource;
+use penumbra_fee::{component::StateWriteExt as _, Gas};
 use penumbra_storage::{StateRead, StateWrite};
-use penumbra_transaction::Transaction;
-use tokio::task::JoinSet;
+use penumbra_transaction::{
+    gas::{clue_gas_cost, memo_gas_cost, transaction_base_gas_cost},
+    Transaction,
+};
+use tokio::task::JoinHandle;
 use tracing::{instrument, Instrument};
 
 use super::ActionHandler;
@@ -13,7 +17,9 @@ use super::ActionHandler;
 mod stateful;
 mod stateless;
 
-use self::stateful::{claimed_anchor_is_valid, fmd_parameters_valid};
+use self::stateful::{
+    claimed_anchor_is_valid, expiry_height_is_valid, fee_is_sufficient, fmd_parameters_valid,
+};
 use stateless::{
     check_memo_exists_if_outputs_absent_if_not, no_duplicate_nullifiers,
     num_clues_equal_to_num_outputs, valid_binding_signature,
@@ -23,6 +29,23 @@ use stateless::{
 impl ActionHandler for Transaction {
     type CheckStatelessContext = ();
 
+    fn gas_cost(&self) -> Gas {
+        let body = &self.transaction_body;
+        let num_clues = body
+            .detection_data
+            .as_ref()
+            .map(|data| data.fmd_clues.len())
+            .unwrap_or(0);
+
+        let mut gas = transaction_base_gas_cost();
+        gas += self.actions().map(ActionHandler::gas_cost).sum();
+        if body.memo.is_some() {
+            gas += memo_gas_cost();
+        }
+        gas += clue_gas_cost() * num_clues as u64;
+        gas
+    }
+
     // We only instrument the top-level `check_stateless`, so we get one span for each transaction.
     #[instrument(skip(self, _context))]
     async fn check_stateless(&self, _context: ()) -> Result<()> {
@@ -40,17 +63,16 @@ impl ActionHandler for Transaction {
         // futures can have 'static lifetimes. In the future, we could try to
         // use the yoke crate, but cloning is almost certainly not a big deal
         // for now.
-        let mut action_checks = JoinSet::new();
+        let mut action_checks = Vec::new();
         for (i, action) in self.actions().cloned().enumerate() {
             let context2 = context.clone();
             let span = action.create_span(i);
-            action_checks
-                .spawn(async move { action.check_stateless(context2).await }.instrument(span));
+            action_checks.push(tokio::spawn(
+                async move { action.check_stateless(context2).await }.instrument(span),
+            ));
         }
         // Now check if any component action failed verification.
-        while let Some(check) = action_checks.join_next().await {
-            check??;
-        }
+        join_in_action_order(action_checks).await?;
 
         Ok(())
     }
@@ -58,24 +80,25 @@ impl ActionHandler for Transaction {
     // We only instrument the top-level `check_stateful`, so we get one span for each transaction.
     #[instrument(skip(self, state))]
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        expiry_height_is_valid(state.clone(), self).await?;
         claimed_anchor_is_valid(state.clone(), self).await?;
         fmd_parameters_valid(state.clone(), self).await?;
+        fee_is_sufficient(state.clone(), self).await?;
 
         // Currently, we need to clone the component actions so that the spawned
         // futures can have 'static lifetimes. In the future, we could try to
         // use the yoke crate, but cloning is almost certainly not a big deal
         // for now.
-        let mut action_checks = JoinSet::new();
+        let mut action_checks = Vec::new();
         for (i, action) in self.actions().cloned().enumerate() {
             let state2 = state.clone();
             let span = action.create_span(i);
-            action_checks
-                .spawn(async move { action.check_stateful(state2).await }.instrument(span));
+            action_checks.push(tokio::spawn(
+                async move { action.check_stateful(state2).await }.instrument(span),
+            ));
         }
         // Now check if any component action failed verification.
-        while let Some(check) = action_checks.join_next().await {
-            check??;
-        }
+        join_in_action_order(action_checks).await?;
 
         Ok(())
     }
@@ -96,10 +119,34 @@ impl ActionHandler for Transaction {
         // Delete the note source, in case someone else tries to read it.
         state.object_delete("source");
 
+        // Record the fee, so that fees paid in other assets can be converted
+        // to the staking token at the end of the block.
+        state.record_fee(&self.transaction_body.fee);
+
         Ok(())
     }
 }
 
+/// Awaits the checks of a transaction's actions, spawned in action order,
+/// returning the error of the first action to fail in that order.
+///
+/// The category of the error becomes the transaction's ABCI code, which is
+/// part of consensus, so it mustn't depend on which check happens to finish
+/// first.
+async fn join_in_action_order(checks: Vec<JoinHandle<Result<()>>>) -> Result<()> {
+    let mut checks = checks.into_iter();
+    let mut result = Ok(());
+    for check in checks.by_ref() {
+        result = check.await.map_err(anyhow::Error::from).and_then(|r| r);
+        if result.is_err() {
+            break;
+        }
+    }
+    // The checks of any later actions no longer matter.
+    checks.for_each(|check| check.abort());
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
@@ -108,9 +155,15 @@ mod tests {
     use penumbra_fee::Fee;
     use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
     use penumbra_tct as tct;
-    use penumbra_transaction::{plan::TransactionPlan, WitnessData};
+    use penumbra_transaction::{plan::TransactionPlan, Transaction, WitnessData};
     use rand_core::OsRng;
+    use std::sync::Arc;
+
+    use penumbra_chain::component::StateWriteExt as _;
+    use penumbra_component::TransactionError;
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
 
+    use super::{expiry_height_is_valid, join_in_action_order};
     use crate::ActionHandler;
 
     #[tokio::test]
@@ -253,4 +306,54 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn expiry_height_is_enforced() -> Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(10);
+        state_tx.apply();
+
+        // A transaction with an expiry height of 0 never expires.
+        let mut tx = Transaction::default();
+        expiry_height_is_valid(state.clone(), &tx).await?;
+
+        // A transaction is valid up to and including its expiry height...
+        tx.transaction_body.transaction_parameters.expiry_height = 10;
+        expiry_height_is_valid(state.clone(), &tx).await?;
+
+        // ...but not after it.
+        tx.transaction_body.transaction_parameters.expiry_height = 9;
+        let error = expiry_height_is_valid(state.clone(), &tx)
+            .await
+            .expect_err("expired transactions are rejected");
+        assert_eq!(
+            TransactionError::of(&error),
+            Some(TransactionError::Expired)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn action_checks_fail_with_the_first_failing_action() -> Result<()> {
+        // The first action's check fails after the second action's check.
+        let checks: Vec<tokio::task::JoinHandle<Result<()>>> = vec![
+            tokio::spawn(async {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                Err(TransactionError::InvalidProof.into())
+            }),
+            tokio::spawn(async { Err(TransactionError::NullifierSpent.into()) }),
+        ];
+
+        let error = join_in_action_order(checks)
+            .await
+            .expect_err("the action checks fail");
+        assert_eq!(
+            TransactionError::of(&error),
+            Some(TransactionError::InvalidProof)
+        );
+
+        Ok(())
+    }
 }
@@ -1,15 +1,55 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use penumbra_chain::component::StateReadExt as _;
 use penumbra_chain::params::FmdParameters;
+use penumbra_component::TransactionError;
+use penumbra_dex::component::FeeLiquidity as _;
+use penumbra_fee::component::StateReadExt as _;
 use penumbra_sct::component::StateReadExt as _;
 use penumbra_storage::StateRead;
 use penumbra_transaction::Transaction;
 
+use crate::action_handler::ActionHandler;
+
 pub(super) async fn claimed_anchor_is_valid<S: StateRead>(
     state: S,
     transaction: &Transaction,
 ) -> Result<()> {
-    state.check_claimed_anchor(transaction.anchor).await
+    state
+        .check_claimed_anchor(transaction.anchor)
+        .await
+        .context(TransactionError::StaleAnchor)
+}
+
+/// Rejects transactions included in a block after their expiry height.
+///
+/// NOTE: this is a new consensus rule. The expiry height was already bound to
+/// the transaction by its effect hash, but wasn't enforced, so nodes running
+/// this check will reject blocks with expired transactions that older nodes
+/// accept; it must ship as part of a chain upgrade.
+pub(super) async fn expiry_height_is_valid<S: StateRead>(
+    state: S,
+    transaction: &Transaction,
+) -> Result<()> {
+    let expiry_height = transaction
+        .transaction_body
+        .transaction_parameters
+        .expiry_height;
+    // An expiry height of 0 means the transaction never expires.
+    if expiry_height == 0 {
+        return Ok(());
+    }
+
+    let height = state.get_block_height().await?;
+    if height > expiry_height {
+        return Err(anyhow::anyhow!(
+            "transaction expired at height {}, current height is {}",
+            expiry_height,
+            height
+        )
+        .context(TransactionError::Expired));
+    }
+
+    Ok(())
 }
 
 pub(super) async fn fmd_parameters_valid<S: StateRead>(
@@ -31,6 +71,28 @@ pub(super) async fn fmd_parameters_valid<S: StateRead>(
         current_fmd_parameters,
         height,
     )
+    .context(TransactionError::InvalidClues)
+}
+
+pub(super) async fn fee_is_sufficient<S: StateRead + Clone + 'static>(
+    state: S,
+    transaction: &Transaction,
+) -> Result<()> {
+    let fee = &transaction.transaction_body.fee;
+
+    // DAO transactions are scheduled by the chain itself, rather than submitted
+    // by a user, so they don't pay for the gas they use.
+    let is_dao_transaction =
+        transaction.dao_spends().next().is_some() || transaction.dao_outputs().next().is_some();
+    if is_dao_transaction {
+        return state.check_fee_asset(fee).await;
+    }
+
+    state.check_fee(fee, transaction.gas_cost()).await?;
+
+    // Fees paid in other assets are swapped to the staking token at the end of
+    // the block, so there must be enough liquidity on the DEX to convert them.
+    state.check_fee_liquidity(fee).await
 }
 
 const FMD_GRACE_PERIOD_BLOCKS: u64 = 10;
@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 
 use anyhow::{Context, Result};
+use penumbra_component::TransactionError;
 use penumbra_transaction::{AuthorizingData, Transaction};
 
 #[tracing::instrument(skip(tx))]
@@ -13,6 +14,7 @@ pub(super) fn valid_binding_signature(tx: &Transaction) -> Result<()> {
     tx.binding_verification_key()
         .verify(auth_hash.as_bytes(), tx.binding_sig())
         .context("binding signature failed to verify")
+        .context(TransactionError::InvalidSignature)
 }
 
 pub(super) fn no_duplicate_nullifiers(tx: &Transaction) -> Result<()> {
@@ -23,10 +25,10 @@ pub(super) fn no_duplicate_nullifiers(tx: &Transaction) -> Result<()> {
     let mut spent_nullifiers = BTreeSet::new();
     for nf in tx.spent_nullifiers() {
         if let Some(duplicate) = spent_nullifiers.replace(nf) {
-            return Err(anyhow::anyhow!(
-                "Duplicate nullifier in transaction: {}",
-                duplicate
-            ));
+            return Err(
+                anyhow::anyhow!("Duplicate nullifier in transaction: {}", duplicate)
+                    .context(TransactionError::DuplicateNullifier),
+            );
         }
     }
 
@@ -44,7 +46,8 @@ pub fn num_clues_equal_to_num_outputs(tx: &Transaction) -> anyhow::Result<()> {
     {
         Err(anyhow::anyhow!(
             "consensus rule violated: must have equal number of outputs and FMD clues"
-        ))
+        )
+        .context(TransactionError::InvalidClues))
     } else {
         Ok(())
     }
@@ -54,16 +57,18 @@ pub fn num_clues_equal_to_num_outputs(tx: &Transaction) -> anyhow::Result<()> {
 pub fn check_memo_exists_if_outputs_absent_if_not(tx: &Transaction) -> anyhow::Result<()> {
     let num_outputs = tx.outputs().count();
     if num_outputs > 0 && tx.transaction_body().memo.is_none() {
-        Err(anyhow::anyhow!(
-            "consensus rule violated: must have memo if outputs present"
-        ))
+        Err(
+            anyhow::anyhow!("consensus rule violated: must have memo if outputs present")
+                .context(TransactionError::InvalidMemo),
+        )
     } else if num_outputs > 0 && tx.transaction_body().memo.is_some() {
         Ok(())
     } else if num_outputs == 0 && tx.transaction_body().memo.is_none() {
         Ok(())
     } else {
-        Err(anyhow::anyhow!(
-            "consensus rule violated: cannot have memo if no outputs present"
-        ))
+        Err(
+            anyhow::anyhow!("consensus rule violated: cannot have memo if no outputs present")
+                .context(TransactionError::InvalidMemo),
+        )
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use penumbra_chain::params::FmdParameters;
 use penumbra_chain::{
     component::{AppHash, StateReadExt as _, StateWriteExt as _},
@@ -8,7 +8,7 @@ use penumbra_chain::{
 };
 use penumbra_compact_block::component::StateWriteExt as _;
 use penumbra_compact_block::CompactBlock;
-use penumbra_component::Component;
+use penumbra_component::{Component, TransactionError};
 use penumbra_dex::component::{Dex, SwapManager};
 use penumbra_distributions::component::Distributions;
 use penumbra_ibc::component::IBCComponent;
@@ -22,7 +22,8 @@ use tendermint::validator::Update;
 use tracing::Instrument;
 
 use crate::action_handler::ActionHandler;
-use crate::governance::{Governance, StateReadExt as _};
+use crate::governance::{Governance, StateReadExt as _, StateWriteExt as _};
+use crate::Migration;
 use penumbra_stake::component::{Staking, ValidatorUpdates};
 
 pub mod state_key;
@@ -57,6 +58,38 @@ impl App {
         Ok(Self { state })
     }
 
+    /// Runs the state migration for the upgrade that the chain halted for, if it hasn't been run
+    /// yet, picking it by name out of the `migrations` registered by the upgraded node.
+    ///
+    /// The migrated state is committed along with the next block, so this must be called before
+    /// the first block is processed by the restarted node.
+    pub async fn run_pending_migration(&mut self, migrations: &[&dyn Migration]) -> Result<()> {
+        let Some(proposal_id) = self.state.pending_upgrade().await? else {
+            return Ok(());
+        };
+        let (height, name) = self.state.upgrade_plan(proposal_id).await?;
+
+        let state =
+            Arc::get_mut(&mut self.state).expect("no other references to inter-block state");
+
+        match migrations.iter().find(|migration| migration.name() == name) {
+            Some(migration) => {
+                tracing::info!(proposal = %proposal_id, %height, %name, "running state migration for upgrade");
+                migration
+                    .migrate(state)
+                    .await
+                    .with_context(|| format!("state migration for upgrade {name} failed"))?;
+            }
+            None => {
+                tracing::info!(proposal = %proposal_id, %height, %name, "no state migration registered for upgrade");
+            }
+        }
+
+        state.clear_pending_upgrade();
+
+        Ok(())
+    }
+
     // StateDelta::apply only works when the StateDelta wraps an underlying
     // StateWrite.  But if we want to share the StateDelta with spawned tasks,
     // we usually can't wrap a StateWrite instance, which requires exclusive
@@ -71,9 +104,10 @@ impl App {
         // Now there is only one reference to the inter-block state: self.state
 
         let events = cache.take_events();
-        cache.apply_to(
-            Arc::get_mut(&mut self.state).expect("no other references to inter-block state"),
-        );
+        let state =
+            Arc::get_mut(&mut self.state).expect("no other references to inter-block state");
+        cache.apply_to(&mut *state);
+        retain_events(state, &events);
 
         events
     }
@@ -191,7 +225,7 @@ impl App {
 
     /// Wrapper function for [`Self::deliver_tx`]  that decodes from bytes.
     pub async fn deliver_tx_bytes(&mut self, tx_bytes: &[u8]) -> Result<Vec<abci::Event>> {
-        let tx = Arc::new(Transaction::decode(tx_bytes)?);
+        let tx = Arc::new(Transaction::decode(tx_bytes).context(TransactionError::Malformed)?);
         self.deliver_tx(tx).await
     }
 
@@ -246,7 +280,10 @@ impl App {
         // At this point, we've completed execution successfully with no errors,
         // so we can apply the transaction to the State. Otherwise, we'd have
         // bubbled up an error and dropped the StateTransaction.
-        Ok(state_tx.apply().1)
+        let (state, events) = state_tx.apply();
+        retain_events(state, &events);
+
+        Ok(events)
     }
 
     pub async fn end_block(&mut self, end_block: &abci::request::EndBlock) -> Vec<abci::Event> {
@@ -456,4 +493,16 @@ impl App {
 ///
 /// Increment this manually after fixing the root cause for a chain halt: updated nodes will then be
 /// able to proceed past the block height of the halt.
+///
+/// Chain halts include the halt at the height of a scheduled upgrade, so this must also be
+/// incremented in the software for the upgrade.
 const TOTAL_HALT_COUNT: u64 = 0;
+
+/// Records copies of `events` in the inter-block state, so that they're included
+/// in the state changes reported by [`Storage::subscribe_changes`] once the block
+/// is committed.
+fn retain_events<S: StateWrite>(mut state: S, events: &[Event]) {
+    for event in events {
+        state.record(event.clone());
+    }
+}
@@ -43,6 +43,13 @@ impl Component for Governance {
         enact_all_passed_proposals(&mut state)
             .await
             .expect("enacting proposals should never fail");
+
+        // Finally, if this block reaches the height of a scheduled upgrade (possibly scheduled by a
+        // proposal enacted just now), halt the chain once it is committed.
+        state
+            .halt_for_scheduled_upgrade()
+            .await
+            .expect("halting for a scheduled upgrade should never fail");
     }
 
     #[instrument(name = "governance", skip(state))]
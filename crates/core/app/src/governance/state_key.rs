@@ -113,6 +113,14 @@ pub fn change_chain_params_at_height(block_height: u64) -> String {
     format!("governance/change_chain_params/{block_height}/")
 }
 
+pub fn scheduled_upgrade() -> &'static str {
+    "governance/scheduled_upgrade"
+}
+
+pub fn pending_upgrade() -> &'static str {
+    "governance/pending_upgrade"
+}
+
 // Used for object store:
 
 pub fn proposal_started() -> &'static str {
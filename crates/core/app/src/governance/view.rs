@@ -11,6 +11,7 @@ use penumbra_chain::{
     component::{StateReadExt as _, StateWriteExt as _},
     params::ChainParameters,
 };
+use penumbra_ibc::component::ClientStateWriteExt as _;
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_sct::Nullifier;
@@ -552,6 +553,27 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
             .await?)
     }
 
+    /// Get the id of the passed upgrade plan proposal that is scheduled to halt the chain, if any.
+    async fn scheduled_upgrade(&self) -> Result<Option<u64>> {
+        self.get_proto::<u64>(state_key::scheduled_upgrade()).await
+    }
+
+    /// Get the id of the upgrade plan proposal that the chain halted for, if the state migration
+    /// for the upgrade has not been run yet.
+    async fn pending_upgrade(&self) -> Result<Option<u64>> {
+        self.get_proto::<u64>(state_key::pending_upgrade()).await
+    }
+
+    /// Get the height and name of the upgrade planned by an upgrade plan proposal.
+    async fn upgrade_plan(&self, proposal_id: u64) -> Result<(u64, String)> {
+        match self.proposal_payload(proposal_id).await? {
+            Some(ProposalPayload::UpgradePlan { height, name, .. }) => Ok((height, name)),
+            _ => Err(anyhow::anyhow!(
+                "proposal {proposal_id} is not an upgrade plan"
+            )),
+        }
+    }
+
     /// Check if any proposal is started in this block.
     fn proposal_started(&self) -> bool {
         self.object_get::<()>(&state_key::proposal_started())
@@ -875,6 +897,34 @@ pub trait StateWriteExt: StateWrite {
 
                 tracing::info!("chain parameters updated successfully");
             }
+            ProposalPayload::PartialParameterChange { changes } => {
+                tracing::info!(
+                    changes = changes.len(),
+                    "partial parameter change proposal passed, attempting to update chain parameters"
+                );
+
+                // As for a full parameter change, sequence the update on top of any pending
+                // parameter change, so that changes to different parameters don't clash.
+                let current =
+                    if let Some(params) = self.next_block_pending_chain_parameters().await? {
+                        params
+                    } else {
+                        self.get_chain_params().await?
+                    };
+
+                // Each of the changed parameters has to have the old value specified in the
+                // proposal, and the changed parameters have to be valid as a whole, or else none of
+                // the changes are applied.
+                let new = match current.apply_changes(changes) {
+                    Ok(new) => new,
+                    Err(e) => return Ok(Err(e)),
+                };
+
+                // Tell the app to update the chain parameters in the next block
+                self.schedule_chain_params_change(new).await?;
+
+                tracing::info!("chain parameters updated successfully");
+            }
             ProposalPayload::DaoSpend {
                 transaction_plan: _,
             } => {
@@ -882,11 +932,76 @@ pub trait StateWriteExt: StateWrite {
                 // be slotted in at the end of the block:
                 self.deliver_dao_transaction(proposal_id).await?;
             }
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                tracing::info!(
+                    %subject_client_id,
+                    %substitute_client_id,
+                    "IBC client recovery proposal passed, attempting to recover client"
+                );
+
+                // The subject and substitute clients may have changed since the proposal was
+                // submitted, in which case the recovery fails without halting the chain.
+                if let Err(e) = self
+                    .recover_client(subject_client_id, substitute_client_id)
+                    .await
+                {
+                    return Ok(Err(e));
+                }
+            }
+            ProposalPayload::UpgradePlan { height, name, .. } => {
+                tracing::info!(%height, %name, "upgrade plan proposal passed, scheduling upgrade");
+
+                // The upgrade height is checked against the end of voting when the proposal is
+                // submitted, but check it again in case the voting period was extended since.
+                let current_height = self.get_block_height().await?;
+                if *height <= current_height {
+                    return Ok(Err(anyhow::anyhow!(
+                        "upgrade height {height} is not after the current height {current_height}"
+                    )));
+                }
+
+                // Only one upgrade can be scheduled at a time: a newer plan replaces an older one.
+                if let Some(previous) = self.scheduled_upgrade().await? {
+                    tracing::info!(%previous, "replacing previously scheduled upgrade plan");
+                }
+                self.schedule_upgrade(proposal_id);
+            }
         }
 
         Ok(Ok(()))
     }
 
+    /// Schedule the upgrade planned by a passed upgrade plan proposal.
+    fn schedule_upgrade(&mut self, proposal_id: u64) {
+        self.put_proto(state_key::scheduled_upgrade().to_string(), proposal_id);
+    }
+
+    /// Halt the chain after this block if it reaches the height of the scheduled upgrade, recording
+    /// the plan so that the upgraded node can run its state migration when it restarts.
+    async fn halt_for_scheduled_upgrade(&mut self) -> Result<()> {
+        let Some(proposal_id) = self.scheduled_upgrade().await? else {
+            return Ok(());
+        };
+        let (height, name) = self.upgrade_plan(proposal_id).await?;
+
+        if self.get_block_height().await? < height {
+            return Ok(());
+        }
+
+        tracing::info!(proposal = %proposal_id, %height, %name, "reached upgrade height, halting chain");
+        self.delete(state_key::scheduled_upgrade().to_string());
+        self.put_proto(state_key::pending_upgrade().to_string(), proposal_id);
+        self.signal_halt().await
+    }
+
+    /// Mark the state migration for the upgrade that the chain halted for as done.
+    fn clear_pending_upgrade(&mut self) {
+        self.delete(state_key::pending_upgrade().to_string());
+    }
+
     fn put_dao_transaction(&mut self, proposal: u64, transaction: Transaction) {
         self.put(state_key::dao_transaction(proposal), transaction);
     }
@@ -1,9 +1,12 @@
 mod action_handler;
+mod migration;
 mod mock_client;
 mod temp_storage_ext;
 
-pub use action_handler::ActionHandler;
+pub use action_handler::{ActionHandler, IbcAppRouter};
+pub use migration::Migration;
 pub use mock_client::MockClient;
+pub use penumbra_component::TransactionError;
 pub use temp_storage_ext::TempStorageExt;
 
 pub mod app;
@@ -0,0 +1,18 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_storage::{Snapshot, StateDelta};
+
+/// A state migration, run by an upgraded node when it restarts after the chain halted for the
+/// upgrade planned by an [`UpgradePlan`](penumbra_transaction::proposal::ProposalPayload::UpgradePlan)
+/// proposal.
+///
+/// The migration writes to the inter-block state of the restarted node, so its changes are
+/// committed along with the first block after the upgrade.
+#[async_trait]
+pub trait Migration: Send + Sync {
+    /// The name of the upgrade this migration is run for, matching the name of the upgrade plan.
+    fn name(&self) -> &'static str;
+
+    /// Migrate the state left by the previous software to the state expected by the upgraded one.
+    async fn migrate(&self, state: &mut StateDelta<Snapshot>) -> Result<()>;
+}
@@ -1 +1,2 @@
 mod swap_and_swap_claim;
+mod upgrade;
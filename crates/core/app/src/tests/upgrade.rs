@@ -0,0 +1,175 @@
+use std::{
+    ops::Deref,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_chain::component::{StateReadExt as _, StateWriteExt as _};
+use penumbra_proto::StateWriteProto;
+use penumbra_storage::{
+    ArcStateDeltaExt, Snapshot, StateDelta, StateRead, StateWrite, TempStorage,
+};
+use penumbra_transaction::proposal::{Proposal, ProposalPayload};
+
+use crate::{
+    app::App,
+    governance::{state_key, StateReadExt as _, StateWriteExt as _},
+    Migration, TempStorageExt,
+};
+
+/// The payload of an upgrade plan proposal for the upgrade `name` at `height`.
+fn upgrade_plan(height: u64, name: &str) -> ProposalPayload {
+    ProposalPayload::UpgradePlan {
+        height,
+        name: name.to_string(),
+        info: String::new(),
+    }
+}
+
+/// Stores a proposal with the given payload, as if it had been submitted.
+fn put_proposal<S: StateWrite>(mut state: S, proposal_id: u64, payload: ProposalPayload) {
+    state.put(
+        state_key::proposal_definition(proposal_id),
+        Proposal {
+            id: proposal_id,
+            title: format!("proposal {proposal_id}"),
+            description: String::new(),
+            payload,
+        },
+    );
+}
+
+/// A migration that counts how many times it was run, and records that it ran in the state.
+struct TestMigration {
+    name: &'static str,
+    runs: AtomicUsize,
+}
+
+impl TestMigration {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            runs: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl Migration for TestMigration {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn migrate(&self, state: &mut StateDelta<Snapshot>) -> Result<()> {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        state.put_raw(format!("test/migrated/{}", self.name), vec![]);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn upgrade_plan_is_scheduled_and_replaced() -> Result<()> {
+    let mut state = Arc::new(StateDelta::new(()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(10);
+    put_proposal(&mut state_tx, 0, upgrade_plan(20, "first"));
+    put_proposal(&mut state_tx, 1, upgrade_plan(30, "second"));
+    put_proposal(&mut state_tx, 2, upgrade_plan(10, "too-late"));
+    state_tx.apply();
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .enact_proposal(0, &upgrade_plan(20, "first"))
+        .await??;
+    state_tx.apply();
+    assert_eq!(state.scheduled_upgrade().await?, Some(0));
+
+    // A newer plan replaces the scheduled one.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .enact_proposal(1, &upgrade_plan(30, "second"))
+        .await??;
+    state_tx.apply();
+    assert_eq!(state.scheduled_upgrade().await?, Some(1));
+    assert_eq!(state.upgrade_plan(1).await?, (30, "second".to_string()));
+
+    // A plan for a height that was already reached fails without replacing the scheduled one.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    assert!(state_tx
+        .enact_proposal(2, &upgrade_plan(10, "too-late"))
+        .await?
+        .is_err());
+    drop(state_tx);
+    assert_eq!(state.scheduled_upgrade().await?, Some(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chain_halts_at_upgrade_height() -> Result<()> {
+    let mut state = Arc::new(StateDelta::new(()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(10);
+    put_proposal(&mut state_tx, 0, upgrade_plan(20, "upgrade"));
+    state_tx
+        .enact_proposal(0, &upgrade_plan(20, "upgrade"))
+        .await??;
+    state_tx.apply();
+
+    // The chain keeps running up to the block before the upgrade height...
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(19);
+    state_tx.halt_for_scheduled_upgrade().await?;
+    state_tx.apply();
+    assert!(!state.is_chain_halted(0).await?);
+    assert_eq!(state.scheduled_upgrade().await?, Some(0));
+    assert_eq!(state.pending_upgrade().await?, None);
+
+    // ...and halts after the block at exactly the upgrade height, leaving the migration pending.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(20);
+    state_tx.halt_for_scheduled_upgrade().await?;
+    state_tx.apply();
+    assert!(state.is_chain_halted(0).await?);
+    assert_eq!(state.scheduled_upgrade().await?, None);
+    assert_eq!(state.pending_upgrade().await?, Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pending_migration_runs_once_and_is_cleared() -> Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+
+    // Leave the state as the chain halted for the upgrade planned by proposal 0. The halt itself
+    // isn't recorded, as the upgraded node would be built with an incremented halt count.
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    put_proposal(&mut delta, 0, upgrade_plan(20, "upgrade"));
+    delta.put_proto(state_key::pending_upgrade().to_string(), 0u64);
+    storage.commit(delta).await?;
+
+    let other = TestMigration::new("other-upgrade");
+    let migration = TestMigration::new("upgrade");
+
+    let mut app = App::new(storage.latest_snapshot()).await?;
+    app.run_pending_migration(&[&other, &migration]).await?;
+    app.commit(storage.deref().clone()).await;
+
+    // Only the migration registered for the upgrade is run, and its changes are committed.
+    assert_eq!(other.runs.load(Ordering::SeqCst), 0);
+    assert_eq!(migration.runs.load(Ordering::SeqCst), 1);
+    let snapshot = storage.latest_snapshot();
+    assert!(snapshot.get_raw("test/migrated/upgrade").await?.is_some());
+    assert_eq!(snapshot.pending_upgrade().await?, None);
+
+    // Once cleared, the migration isn't run again when the node restarts.
+    let mut app = App::new(storage.latest_snapshot()).await?;
+    app.run_pending_migration(&[&other, &migration]).await?;
+    assert_eq!(migration.runs.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -78,6 +78,11 @@ pub trait AppHashRead {
         key: Vec<u8>,
     ) -> Result<Option<(Vec<u8>, tendermint::merkle::proof::ProofOps)>, anyhow::Error>;
 
+    async fn extend_proof_to_apphash(
+        &self,
+        proof: ics23::CommitmentProof,
+    ) -> Result<MerkleProof, anyhow::Error>;
+
     async fn app_hash(&self) -> Result<AppHash, anyhow::Error>;
 }
 
@@ -97,6 +102,19 @@ impl AppHashRead for Snapshot {
     ) -> anyhow::Result<(Option<Vec<u8>>, MerkleProof)> {
         let (some_value, membership_proof) = self.get_with_proof(key.clone()).await?;
 
+        Ok((
+            some_value,
+            self.extend_proof_to_apphash(membership_proof).await?,
+        ))
+    }
+
+    /// Extends an ICS23 proof up to the JMT root hash, e.g., one of the proofs
+    /// yielded by [`Snapshot::prefix_with_proofs`], into a proof up to the
+    /// current apphash.
+    async fn extend_proof_to_apphash(
+        &self,
+        proof: ics23::CommitmentProof,
+    ) -> anyhow::Result<MerkleProof> {
         let jmt_root = self.root_hash().await?;
         let root_proof = ics23::CommitmentProof {
             proof: Some(ics23::commitment_proof::Proof::Exist(
@@ -109,12 +127,9 @@ impl AppHashRead for Snapshot {
             )),
         };
 
-        Ok((
-            some_value,
-            MerkleProof {
-                proofs: vec![membership_proof, root_proof],
-            },
-        ))
+        Ok(MerkleProof {
+            proofs: vec![proof, root_proof],
+        })
     }
 
     /// Returns the value and a proof of inclusion up to the current app hash,
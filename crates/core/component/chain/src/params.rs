@@ -6,6 +6,7 @@ use std::{
 };
 
 use anyhow::Context;
+use penumbra_asset::asset;
 use penumbra_num::Amount;
 use penumbra_proto::client::v1alpha1 as pb_client;
 use penumbra_proto::core::chain::v1alpha1 as pb_chain;
@@ -28,8 +29,6 @@ pub struct ChainParameters {
     pub unbonding_epochs: u64,
     /// The number of validators allowed in the consensus set (Active state).
     pub active_validator_limit: u64,
-    /// The base reward rate, expressed in basis points of basis points
-    pub base_reward_rate: u64,
     /// The penalty for slashing due to misbehavior, expressed in basis points squared (10^-8)
     pub slashing_penalty_misbehavior: u64,
     /// The penalty for slashing due to downtime, expressed in basis points squared (10^-8)
@@ -60,6 +59,31 @@ pub struct ChainParameters {
 
     /// Whether DAO spend proposals are enabled.
     pub dao_spend_proposals_enabled: bool,
+
+    /// The assets, other than the staking token, that fees may be paid in.
+    pub allowed_fee_assets: Vec<asset::Id>,
+    /// The prices used to compute the minimum fee of a transaction from its gas cost.
+    pub gas_prices: GasPrices,
+    /// The rates at which the minimum fee is converted into each of the allowed fee assets.
+    pub fee_asset_rates: Vec<FeeAssetRate>,
+
+    /// The assets always considered as intermediate hops when routing trades through the DEX.
+    pub dex_fixed_candidates: Vec<asset::Id>,
+    /// The maximum number of hops in a route through the DEX.
+    pub dex_max_hops: u32,
+    /// The assets considered as intermediate hops when searching for arbitrage opportunities.
+    pub dex_arb_candidates: Vec<asset::Id>,
+    /// The number of the most liquid assets, by their liquidity against the staking token, to
+    /// consider as intermediate hops in addition to the fixed candidates.
+    pub dex_auto_candidates: u32,
+    /// The amount of the staking token issued each epoch.
+    pub issuance_per_epoch: u64,
+    /// The share of each epoch's issuance deposited into the DAO, in basis points.
+    pub dao_issuance_bps: u32,
+    /// The share of each epoch's issuance paid as rewards to liquidity providers, in basis points.
+    ///
+    /// The remainder of the issuance is paid as staking rewards.
+    pub lp_issuance_bps: u32,
 }
 
 impl TypeUrl for ChainParameters {
@@ -74,14 +98,13 @@ impl TryFrom<pb_chain::ChainParameters> for ChainParameters {
     type Error = anyhow::Error;
 
     fn try_from(msg: pb_chain::ChainParameters) -> anyhow::Result<Self> {
-        Ok(ChainParameters {
+        let mut params = ChainParameters {
             chain_id: msg.chain_id,
             epoch_duration: msg.epoch_duration,
             unbonding_epochs: msg.unbonding_epochs,
             active_validator_limit: msg.active_validator_limit,
             slashing_penalty_downtime: msg.slashing_penalty_downtime,
             slashing_penalty_misbehavior: msg.slashing_penalty_misbehavior,
-            base_reward_rate: msg.base_reward_rate,
             missed_blocks_maximum: msg.missed_blocks_maximum,
             signed_blocks_window_len: msg.signed_blocks_window_len,
             ibc_enabled: msg.ibc_enabled,
@@ -105,7 +128,49 @@ impl TryFrom<pb_chain::ChainParameters> for ChainParameters {
                 .parse()
                 .context("couldn't parse proposal_slash_threshold")?,
             dao_spend_proposals_enabled: msg.dao_spend_proposals_enabled,
-        })
+            allowed_fee_assets: msg
+                .allowed_fee_assets
+                .into_iter()
+                .map(asset::Id::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()
+                .context("couldn't parse allowed_fee_assets")?,
+            gas_prices: msg.gas_prices.unwrap_or_default().into(),
+            fee_asset_rates: msg
+                .fee_asset_rates
+                .into_iter()
+                .map(FeeAssetRate::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()
+                .context("couldn't parse fee_asset_rates")?,
+            dex_fixed_candidates: msg
+                .dex_fixed_candidates
+                .into_iter()
+                .map(asset::Id::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()
+                .context("couldn't parse dex_fixed_candidates")?,
+            dex_max_hops: msg.dex_max_hops,
+            dex_arb_candidates: msg
+                .dex_arb_candidates
+                .into_iter()
+                .map(asset::Id::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()
+                .context("couldn't parse dex_arb_candidates")?,
+            dex_auto_candidates: msg.dex_auto_candidates,
+            issuance_per_epoch: msg.issuance_per_epoch,
+            dao_issuance_bps: msg.dao_issuance_bps,
+            lp_issuance_bps: msg.lp_issuance_bps,
+        };
+
+        // Chains whose parameters predate the DEX routing parameters decode a
+        // maximum of zero hops, which no valid parameter change can set: keep
+        // routing the way those chains always have.
+        if params.dex_max_hops == 0 {
+            let default = ChainParameters::default();
+            params.dex_fixed_candidates = default.dex_fixed_candidates;
+            params.dex_max_hops = default.dex_max_hops;
+            params.dex_arb_candidates = default.dex_arb_candidates;
+        }
+
+        Ok(params)
     }
 }
 
@@ -142,7 +207,6 @@ impl From<ChainParameters> for pb_chain::ChainParameters {
             missed_blocks_maximum: params.missed_blocks_maximum,
             slashing_penalty_downtime: params.slashing_penalty_downtime,
             slashing_penalty_misbehavior: params.slashing_penalty_misbehavior,
-            base_reward_rate: params.base_reward_rate,
             ibc_enabled: params.ibc_enabled,
             inbound_ics20_transfers_enabled: params.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: params.outbound_ics20_transfers_enabled,
@@ -152,6 +216,28 @@ impl From<ChainParameters> for pb_chain::ChainParameters {
             proposal_pass_threshold: params.proposal_pass_threshold.to_string(),
             proposal_slash_threshold: params.proposal_slash_threshold.to_string(),
             dao_spend_proposals_enabled: params.dao_spend_proposals_enabled,
+            allowed_fee_assets: params
+                .allowed_fee_assets
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            gas_prices: Some(params.gas_prices.into()),
+            fee_asset_rates: params.fee_asset_rates.into_iter().map(Into::into).collect(),
+            dex_fixed_candidates: params
+                .dex_fixed_candidates
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            dex_max_hops: params.dex_max_hops,
+            dex_arb_candidates: params
+                .dex_arb_candidates
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            dex_auto_candidates: params.dex_auto_candidates,
+            issuance_per_epoch: params.issuance_per_epoch,
+            dao_issuance_bps: params.dao_issuance_bps,
+            lp_issuance_bps: params.lp_issuance_bps,
         }
     }
 }
@@ -172,8 +258,6 @@ impl Default for ChainParameters {
             slashing_penalty_misbehavior: 1000_0000,
             // 1 basis point = 0.01%
             slashing_penalty_downtime: 1_0000,
-            // 3bps -> 11% return over 365 epochs
-            base_reward_rate: 3_0000,
             ibc_enabled: true,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
@@ -186,10 +270,50 @@ impl Default for ChainParameters {
             // slash threshold means if (no / no + yes + abstain) > slash_threshold, then proposal is slashed
             proposal_slash_threshold: Ratio::new(80, 100),
             dao_spend_proposals_enabled: true,
+            allowed_fee_assets: Vec::new(),
+            // zero prices, so that transactions don't need to pay fees unless governance says so
+            gas_prices: GasPrices::default(),
+            fee_asset_rates: Vec::new(),
+            // dex routing, using the testnet assets
+            dex_fixed_candidates: known_asset_ids(&[
+                "test_usd",
+                "penumbra",
+                "gm",
+                "gn",
+                "test_atom",
+                "test_osmo",
+                "test_btc",
+            ]),
+            dex_max_hops: 4,
+            dex_arb_candidates: known_asset_ids(&[
+                "penumbra",
+                "gm",
+                "gn",
+                "test_usd",
+                "test_btc",
+                "test_atom",
+                "test_osmo",
+            ]),
+            dex_auto_candidates: 0,
+            // 1,000 penumbra per epoch
+            issuance_per_epoch: 1_000_000_000,
+            // 500 basis points = 5%
+            dao_issuance_bps: 500,
+            // 1000 basis points = 10%
+            lp_issuance_bps: 1000,
         }
     }
 }
 
+/// Looks up the asset IDs of the given units of known assets.
+fn known_asset_ids(units: &[&str]) -> Vec<asset::Id> {
+    let cache = asset::Cache::with_known_assets();
+    units
+        .iter()
+        .map(|unit| cache.get_unit(unit).expect("unit is a known asset").id())
+        .collect()
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(try_from = "pb_chain::FmdParameters", into = "pb_chain::FmdParameters")]
 pub struct FmdParameters {
@@ -236,6 +360,128 @@ impl Default for FmdParameters {
     }
 }
 
+/// The prices of each kind of gas, denominated in the staking token.
+///
+/// The minimum fee for a transaction is the sum of the amount of each kind of
+/// gas it uses, multiplied by the price of that kind of gas.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "pb_chain::GasPrices", into = "pb_chain::GasPrices")]
+pub struct GasPrices {
+    /// The price per byte of block space.
+    pub block_space_price: u64,
+    /// The price per byte of compact block space.
+    pub compact_block_space_price: u64,
+    /// The price per unit of verification cost.
+    pub verification_price: u64,
+    /// The price per unit of execution cost.
+    pub execution_price: u64,
+}
+
+impl TypeUrl for GasPrices {
+    const TYPE_URL: &'static str = "/penumbra.core.chain.v1alpha1.GasPrices";
+}
+
+impl DomainType for GasPrices {
+    type Proto = pb_chain::GasPrices;
+}
+
+impl From<pb_chain::GasPrices> for GasPrices {
+    fn from(msg: pb_chain::GasPrices) -> Self {
+        GasPrices {
+            block_space_price: msg.block_space_price,
+            compact_block_space_price: msg.compact_block_space_price,
+            verification_price: msg.verification_price,
+            execution_price: msg.execution_price,
+        }
+    }
+}
+
+impl From<GasPrices> for pb_chain::GasPrices {
+    fn from(prices: GasPrices) -> Self {
+        pb_chain::GasPrices {
+            block_space_price: prices.block_space_price,
+            compact_block_space_price: prices.compact_block_space_price,
+            verification_price: prices.verification_price,
+            execution_price: prices.execution_price,
+        }
+    }
+}
+
+impl ChainParameters {
+    /// Gets the rate at which the minimum fee is converted into the allowed fee asset `asset_id`.
+    pub fn fee_asset_rate(&self, asset_id: &asset::Id) -> Option<&FeeAssetRate> {
+        self.fee_asset_rates
+            .iter()
+            .find(|rate| rate.asset_id == *asset_id)
+    }
+}
+
+/// The rate at which fees paid in an asset other than the staking token are priced.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb_chain::FeeAssetRate", into = "pb_chain::FeeAssetRate")]
+pub struct FeeAssetRate {
+    /// The asset fees are paid in.
+    pub asset_id: asset::Id,
+    /// The number of units of the asset charged per unit of the staking token.
+    pub rate: Ratio,
+}
+
+impl FeeAssetRate {
+    /// Converts an amount of the staking token into an amount of this asset, rounding up.
+    ///
+    /// Returns `None` if the rate has a zero denominator, or the result overflows.
+    pub fn convert(&self, staking_token_amount: Amount) -> Option<Amount> {
+        let Ratio {
+            numerator,
+            denominator,
+        } = self.rate;
+        if denominator == 0 {
+            return None;
+        }
+
+        let product = staking_token_amount
+            .value()
+            .checked_mul(u128::from(numerator))?;
+        let denominator = u128::from(denominator);
+        let rounded_up = product / denominator + u128::from(product % denominator != 0);
+        Some(rounded_up.into())
+    }
+}
+
+impl TypeUrl for FeeAssetRate {
+    const TYPE_URL: &'static str = "/penumbra.core.chain.v1alpha1.FeeAssetRate";
+}
+
+impl DomainType for FeeAssetRate {
+    type Proto = pb_chain::FeeAssetRate;
+}
+
+impl TryFrom<pb_chain::FeeAssetRate> for FeeAssetRate {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb_chain::FeeAssetRate) -> anyhow::Result<Self> {
+        Ok(FeeAssetRate {
+            asset_id: msg
+                .asset_id
+                .ok_or_else(|| anyhow::anyhow!("missing asset_id"))?
+                .try_into()?,
+            rate: msg
+                .rate
+                .ok_or_else(|| anyhow::anyhow!("missing rate"))?
+                .into(),
+        })
+    }
+}
+
+impl From<FeeAssetRate> for pb_chain::FeeAssetRate {
+    fn from(rate: FeeAssetRate) -> Self {
+        pb_chain::FeeAssetRate {
+            asset_id: Some(rate.asset_id.into()),
+            rate: Some(rate.rate.into()),
+        }
+    }
+}
+
 /// This is a ratio of two `u64` values, intended to be used solely in governance parameters and
 /// tallying. It only implements construction and comparison, not arithmetic, to reduce the trusted
 /// codebase for governance.
@@ -325,3 +571,22 @@ impl From<pb_chain::Ratio> for Ratio {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_without_dex_routing_use_the_default_routing() {
+        let mut msg = pb_chain::ChainParameters::from(ChainParameters::default());
+        msg.dex_fixed_candidates = Vec::new();
+        msg.dex_max_hops = 0;
+        msg.dex_arb_candidates = Vec::new();
+
+        let params = ChainParameters::try_from(msg).unwrap();
+        let default = ChainParameters::default();
+        assert_eq!(params.dex_max_hops, default.dex_max_hops);
+        assert_eq!(params.dex_fixed_candidates, default.dex_fixed_candidates);
+        assert_eq!(params.dex_arb_candidates, default.dex_arb_candidates);
+    }
+}
@@ -1,8 +1,13 @@
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
+use penumbra_proto::core::chain::v1alpha1 as pb_chain;
+use penumbra_proto::{DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
 
-use super::{ChainParameters, Ratio};
+use super::{ChainParameters, FeeAssetRate, Ratio};
 
 // The checks below validate that a parameter change is valid, since some parameter settings or
 // combinations are nonsensical and should be rejected outright, regardless of governance.
@@ -17,7 +22,6 @@ impl ChainParameters {
             epoch_duration,
             unbonding_epochs: _,
             active_validator_limit,
-            base_reward_rate: _,
             slashing_penalty_misbehavior: _,
             slashing_penalty_downtime: _,
             signed_blocks_window_len,
@@ -31,6 +35,16 @@ impl ChainParameters {
             proposal_pass_threshold,
             proposal_slash_threshold,
             dao_spend_proposals_enabled: _,
+            allowed_fee_assets: _,
+            gas_prices: _,
+            fee_asset_rates: _,
+            dex_fixed_candidates: _,
+            dex_max_hops: _,
+            dex_arb_candidates: _,
+            dex_auto_candidates: _,
+            issuance_per_epoch: _,
+            dao_issuance_bps: _,
+            lp_issuance_bps: _,
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
 
@@ -76,7 +90,6 @@ impl ChainParameters {
             epoch_duration,
             unbonding_epochs,
             active_validator_limit,
-            base_reward_rate,
             slashing_penalty_misbehavior,
             slashing_penalty_downtime,
             signed_blocks_window_len,
@@ -90,6 +103,16 @@ impl ChainParameters {
             proposal_pass_threshold,
             proposal_slash_threshold,
             dao_spend_proposals_enabled: _,
+            allowed_fee_assets,
+            gas_prices: _,
+            fee_asset_rates,
+            dex_fixed_candidates: _,
+            dex_max_hops,
+            dex_arb_candidates: _,
+            dex_auto_candidates,
+            issuance_per_epoch: _,
+            dao_issuance_bps,
+            lp_issuance_bps,
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
 
@@ -107,10 +130,6 @@ impl ChainParameters {
                 *active_validator_limit > 3,
                 "active validator limit must be at least 4",
             ),
-            (
-                *base_reward_rate >= 1,
-                "base reward rate must be at least 1 basis point",
-            ),
             (
                 *slashing_penalty_misbehavior >= 1,
                 "slashing penalty (misbehavior) must be at least 1 basis point",
@@ -160,10 +179,282 @@ impl ChainParameters {
                 *proposal_slash_threshold > Ratio::new(1, 2),
                 "proposal slash threshold must be greater than 1/2",
             ),
+            (
+                !allowed_fee_assets.contains(&*STAKING_TOKEN_ASSET_ID),
+                "allowed fee assets must not include the staking token",
+            ),
+            (
+                allowed_fee_assets.iter().collect::<BTreeSet<_>>().len()
+                    == allowed_fee_assets.len(),
+                "allowed fee assets must not contain duplicates",
+            ),
+            (
+                fee_asset_rates
+                    .iter()
+                    .map(|rate| rate.asset_id)
+                    .collect::<BTreeSet<_>>()
+                    == allowed_fee_assets.iter().copied().collect::<BTreeSet<_>>()
+                    && fee_asset_rates.len() == allowed_fee_assets.len(),
+                "each allowed fee asset must have exactly one fee asset rate",
+            ),
+            (
+                fee_asset_rates
+                    .iter()
+                    .all(|rate| rate.rate.numerator > 0 && rate.rate.denominator > 0),
+                "fee asset rates must be positive",
+            ),
+            (
+                (1..=8).contains(dex_max_hops),
+                "dex max hops must be between 1 and 8",
+            ),
+            (
+                *dex_auto_candidates <= 10,
+                "dex auto candidates must be at most 10",
+            ),
+            (
+                u64::from(*dao_issuance_bps) + u64::from(*lp_issuance_bps) <= 10_000,
+                "dao and lp issuance must be at most 10,000 basis points in total",
+            ),
         ])
     }
 }
 
+/// A change to a single chain parameter, as part of a partial parameter change proposal.
+///
+/// Parameters are named by the fields of [`ChainParameters`], with the fields of the gas prices
+/// named `gas_prices.block_space_price` and so on. Values are written as they are displayed:
+/// numbers and amounts in decimal, booleans as `true` or `false`, ratios as
+/// `numerator/denominator`, lists of assets as comma-separated asset IDs, and fee asset rates as
+/// comma-separated `asset_id=numerator/denominator` entries.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "pb_chain::ParameterChange", into = "pb_chain::ParameterChange")]
+pub struct ParameterChange {
+    /// The name of the parameter to change.
+    pub parameter: String,
+    /// The value the parameter must have for the change to be applied.
+    pub old: String,
+    /// The value the parameter is changed to.
+    pub new: String,
+}
+
+impl TypeUrl for ParameterChange {
+    const TYPE_URL: &'static str = "/penumbra.core.chain.v1alpha1.ParameterChange";
+}
+
+impl DomainType for ParameterChange {
+    type Proto = pb_chain::ParameterChange;
+}
+
+impl From<pb_chain::ParameterChange> for ParameterChange {
+    fn from(msg: pb_chain::ParameterChange) -> Self {
+        ParameterChange {
+            parameter: msg.parameter,
+            old: msg.old_value,
+            new: msg.new_value,
+        }
+    }
+}
+
+impl From<ParameterChange> for pb_chain::ParameterChange {
+    fn from(change: ParameterChange) -> Self {
+        pb_chain::ParameterChange {
+            parameter: change.parameter,
+            old_value: change.old,
+            new_value: change.new,
+        }
+    }
+}
+
+impl ParameterChange {
+    /// Check that the change is well-formed, regardless of the current chain parameters: it must
+    /// name a parameter which can be changed by governance, and its old and new values must be
+    /// distinct valid values for that parameter.
+    pub fn check_valid(&self) -> Result<()> {
+        self.update(&mut ChainParameters::default(), false)
+    }
+
+    /// Apply the change to `params`, failing if the parameter doesn't have the expected old value.
+    fn apply_to(&self, params: &mut ChainParameters) -> Result<()> {
+        self.update(params, true)
+    }
+
+    fn update(&self, params: &mut ChainParameters, check_old: bool) -> Result<()> {
+        let update = Update {
+            change: self,
+            check_old,
+        };
+
+        match self.parameter.as_str() {
+            "unbonding_epochs" => update.value(&mut params.unbonding_epochs),
+            "slashing_penalty_misbehavior" => {
+                update.value(&mut params.slashing_penalty_misbehavior)
+            }
+            "slashing_penalty_downtime" => update.value(&mut params.slashing_penalty_downtime),
+            "missed_blocks_maximum" => update.value(&mut params.missed_blocks_maximum),
+            "ibc_enabled" => update.value(&mut params.ibc_enabled),
+            "inbound_ics20_transfers_enabled" => {
+                update.value(&mut params.inbound_ics20_transfers_enabled)
+            }
+            "outbound_ics20_transfers_enabled" => {
+                update.value(&mut params.outbound_ics20_transfers_enabled)
+            }
+            "proposal_voting_blocks" => update.value(&mut params.proposal_voting_blocks),
+            "proposal_deposit_amount" => {
+                update.with(&mut params.proposal_deposit_amount, parse_amount)
+            }
+            "dao_spend_proposals_enabled" => update.value(&mut params.dao_spend_proposals_enabled),
+            "allowed_fee_assets" => update.with(&mut params.allowed_fee_assets, parse_asset_ids),
+            "gas_prices.block_space_price" => {
+                update.value(&mut params.gas_prices.block_space_price)
+            }
+            "gas_prices.compact_block_space_price" => {
+                update.value(&mut params.gas_prices.compact_block_space_price)
+            }
+            "gas_prices.verification_price" => {
+                update.value(&mut params.gas_prices.verification_price)
+            }
+            "gas_prices.execution_price" => update.value(&mut params.gas_prices.execution_price),
+            "fee_asset_rates" => update.with(&mut params.fee_asset_rates, parse_fee_asset_rates),
+            "dex_fixed_candidates" => {
+                update.with(&mut params.dex_fixed_candidates, parse_asset_ids)
+            }
+            "dex_max_hops" => update.value(&mut params.dex_max_hops),
+            "dex_arb_candidates" => update.with(&mut params.dex_arb_candidates, parse_asset_ids),
+            "dex_auto_candidates" => update.value(&mut params.dex_auto_candidates),
+            "issuance_per_epoch" => update.value(&mut params.issuance_per_epoch),
+            "dao_issuance_bps" => update.value(&mut params.dao_issuance_bps),
+            "lp_issuance_bps" => update.value(&mut params.lp_issuance_bps),
+            // These are the parameters that `check_valid_update` doesn't allow to change.
+            "chain_id"
+            | "epoch_duration"
+            | "active_validator_limit"
+            | "signed_blocks_window_len"
+            | "proposal_valid_quorum"
+            | "proposal_pass_threshold"
+            | "proposal_slash_threshold" => {
+                Err(anyhow::anyhow!("{} can't be changed", self.parameter))
+            }
+            _ => Err(anyhow::anyhow!(
+                "unknown chain parameter {}",
+                self.parameter
+            )),
+        }
+    }
+}
+
+/// Check that a set of changes is valid for a partial parameter change proposal, regardless of the
+/// current chain parameters: there must be at least one change, each change must be valid, and no
+/// parameter can be changed more than once.
+pub fn check_valid_changes(changes: &[ParameterChange]) -> Result<()> {
+    anyhow::ensure!(
+        !changes.is_empty(),
+        "a partial parameter change must change at least one parameter"
+    );
+
+    let mut changed = BTreeSet::new();
+    for change in changes {
+        anyhow::ensure!(
+            changed.insert(&change.parameter),
+            "{} is changed more than once",
+            change.parameter
+        );
+        change.check_valid()?;
+    }
+
+    Ok(())
+}
+
+impl ChainParameters {
+    /// Apply the changes of a partial parameter change proposal, returning the new parameters.
+    ///
+    /// This fails if any of the changed parameters doesn't have the old value expected by its
+    /// change, or if the new parameters are not a valid update of these ones, in which case none of
+    /// the changes are applied.
+    pub fn apply_changes(&self, changes: &[ParameterChange]) -> Result<ChainParameters> {
+        check_valid_changes(changes)?;
+
+        let mut new = self.clone();
+        for change in changes {
+            change.apply_to(&mut new)?;
+        }
+        self.check_valid_update(&new)?;
+
+        Ok(new)
+    }
+}
+
+/// An update of a single parameter, according to a [`ParameterChange`].
+struct Update<'a> {
+    change: &'a ParameterChange,
+    /// Whether to check that the parameter currently has the old value of the change.
+    check_old: bool,
+}
+
+impl Update<'_> {
+    /// Update a parameter whose values are parsed with [`FromStr`].
+    fn value<T: FromStr + PartialEq>(&self, field: &mut T) -> Result<()>
+    where
+        T::Err: Into<anyhow::Error>,
+    {
+        self.with(field, |value| value.parse().map_err(Into::into))
+    }
+
+    /// Update a parameter whose values are parsed with `parse`.
+    fn with<T: PartialEq>(&self, field: &mut T, parse: impl Fn(&str) -> Result<T>) -> Result<()> {
+        let ParameterChange {
+            parameter,
+            old,
+            new,
+        } = self.change;
+
+        let old_value = parse(old).with_context(|| format!("invalid old value for {parameter}"))?;
+        let new_value = parse(new).with_context(|| format!("invalid new value for {parameter}"))?;
+        anyhow::ensure!(
+            old_value != new_value,
+            "{parameter} is changed to its old value"
+        );
+        if self.check_old {
+            anyhow::ensure!(
+                *field == old_value,
+                "{parameter} doesn't have the old value {old}"
+            );
+        }
+
+        *field = new_value;
+        Ok(())
+    }
+}
+
+fn parse_amount(value: &str) -> Result<Amount> {
+    Ok(value.parse::<u128>()?.into())
+}
+
+fn parse_asset_ids(value: &str) -> Result<Vec<asset::Id>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(asset::Id::from_str)
+        .collect()
+}
+
+fn parse_fee_asset_rates(value: &str) -> Result<Vec<FeeAssetRate>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|rate| !rate.is_empty())
+        .map(|rate| {
+            let (asset_id, rate) = rate
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("fee asset rate {rate} is not asset_id=rate"))?;
+            Ok(FeeAssetRate {
+                asset_id: asset_id.trim().parse()?,
+                rate: rate.trim().parse()?,
+            })
+        })
+        .collect()
+}
+
 /// Ensure all of the booleans are true, and if any are false, generate an error describing which
 /// failed, based on the provided descriptions.
 fn check_all<'a>(checks: impl IntoIterator<Item = (bool, impl Display + 'a)>) -> Result<()> {
@@ -196,3 +487,99 @@ fn check_invariant<'a, T: Eq + 'a>(
             .map(|(old, new, name)| ((*old == *new), format!("{name} can't be changed"))),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(parameter: &str, old: &str, new: &str) -> ParameterChange {
+        ParameterChange {
+            parameter: parameter.to_string(),
+            old: old.to_string(),
+            new: new.to_string(),
+        }
+    }
+
+    #[test]
+    fn partial_changes_only_touch_changed_parameters() {
+        let params = ChainParameters {
+            chain_id: "penumbra-test".to_string(),
+            ..Default::default()
+        };
+
+        // Changes to different parameters apply on top of each other.
+        let new = params
+            .apply_changes(&[change("proposal_voting_blocks", "17280", "720")])
+            .unwrap()
+            .apply_changes(&[
+                change("gas_prices.block_space_price", "0", "5"),
+                change("ibc_enabled", "true", "false"),
+                change("inbound_ics20_transfers_enabled", "true", "false"),
+                change("outbound_ics20_transfers_enabled", "true", "false"),
+            ])
+            .unwrap();
+        assert_eq!(new.proposal_voting_blocks, 720);
+        assert_eq!(new.gas_prices.block_space_price, 5);
+        assert!(!new.ibc_enabled);
+        assert_eq!(new.dex_max_hops, params.dex_max_hops);
+
+        // A change is only applied if the parameter has the expected old value...
+        assert!(params
+            .apply_changes(&[change("proposal_voting_blocks", "720", "1440")])
+            .is_err());
+        // ...and the resulting parameters must be valid as a whole.
+        assert!(params
+            .apply_changes(&[change("ibc_enabled", "true", "false")])
+            .is_err());
+    }
+
+    #[test]
+    fn allowed_fee_assets_need_rates() {
+        let params = ChainParameters {
+            chain_id: "penumbra-test".to_string(),
+            ..Default::default()
+        };
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+
+        // An asset can't be allowed for fees without a rate to price them...
+        assert!(params
+            .apply_changes(&[change("allowed_fee_assets", "", &gm.to_string())])
+            .is_err());
+        assert!(params
+            .apply_changes(&[
+                change("allowed_fee_assets", "", &gm.to_string()),
+                change("fee_asset_rates", "", &format!("{gm}=0/1")),
+            ])
+            .is_err());
+
+        // ...and the rate is applied to the minimum fee, rounding up.
+        let new = params
+            .apply_changes(&[
+                change("allowed_fee_assets", "", &gm.to_string()),
+                change("fee_asset_rates", "", &format!("{gm}=3/2")),
+            ])
+            .unwrap();
+        let rate = new.fee_asset_rate(&gm).unwrap();
+        assert_eq!(rate.convert(10u64.into()), Some(15u64.into()));
+        assert_eq!(rate.convert(1u64.into()), Some(2u64.into()));
+        assert_eq!(rate.convert(0u64.into()), Some(0u64.into()));
+    }
+
+    #[test]
+    fn invalid_partial_changes() {
+        assert!(check_valid_changes(&[]).is_err());
+        assert!(check_valid_changes(&[change("chain_id", "a", "b")]).is_err());
+        assert!(check_valid_changes(&[change("no_such_parameter", "1", "2")]).is_err());
+        assert!(check_valid_changes(&[change("dex_max_hops", "4", "four")]).is_err());
+        assert!(check_valid_changes(&[change("dex_max_hops", "4", "4")]).is_err());
+        assert!(check_valid_changes(&[
+            change("dex_max_hops", "4", "5"),
+            change("dex_max_hops", "5", "6"),
+        ])
+        .is_err());
+        check_valid_changes(&[change("dex_max_hops", "4", "5")]).unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+/// A category of transaction validation failure, reported to clients as a
+/// stable ABCI code.
+///
+/// Action handlers and transaction-wide checks return `anyhow` errors.  They
+/// can categorize an error by adding a `TransactionError` as its outermost
+/// context, e.g.
+///
+/// ```ignore
+/// proof.verify(..).context(TransactionError::InvalidProof)?;
+/// ```
+///
+/// and [`TransactionError::abci_code`] recovers the category when the error is
+/// reported.  Uncategorized errors are reported with
+/// [`TransactionError::UNCATEGORIZED_CODE`].
+///
+/// The codes are part of the public API of the chain, so existing variants
+/// must never be renumbered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionError {
+    /// The transaction could not be decoded.
+    Malformed,
+    /// The transaction's expiry height has passed.
+    Expired,
+    /// The transaction's fee doesn't cover the gas it uses.
+    InsufficientFee,
+    /// The transaction's fee is paid in an asset the chain doesn't accept.
+    UnsupportedFeeAsset,
+    /// The transaction's anchor is not a known state commitment tree root.
+    StaleAnchor,
+    /// The transaction spends a nullifier that was already spent.
+    NullifierSpent,
+    /// The transaction spends the same nullifier more than once.
+    DuplicateNullifier,
+    /// A zero-knowledge proof in the transaction failed to verify.
+    InvalidProof,
+    /// A signature in the transaction failed to verify.
+    InvalidSignature,
+    /// The transaction's fuzzy message detection clues don't match its
+    /// outputs or the chain's FMD parameters.
+    InvalidClues,
+    /// The transaction has a memo without outputs, or outputs without a memo.
+    InvalidMemo,
+}
+
+impl TransactionError {
+    /// The ABCI codespace of the codes returned by [`TransactionError::code`].
+    pub const CODESPACE: &'static str = "penumbra";
+
+    /// The ABCI code of errors that don't have a [`TransactionError`] category.
+    pub const UNCATEGORIZED_CODE: u32 = 1;
+
+    /// The ABCI code of this category.
+    pub fn code(&self) -> u32 {
+        match self {
+            TransactionError::Malformed => 2,
+            TransactionError::Expired => 3,
+            TransactionError::InsufficientFee => 4,
+            TransactionError::UnsupportedFeeAsset => 5,
+            TransactionError::StaleAnchor => 6,
+            TransactionError::NullifierSpent => 7,
+            TransactionError::DuplicateNullifier => 8,
+            TransactionError::InvalidProof => 9,
+            TransactionError::InvalidSignature => 10,
+            TransactionError::InvalidClues => 11,
+            TransactionError::InvalidMemo => 12,
+        }
+    }
+
+    /// The category with the given ABCI code, if any.
+    pub fn from_code(code: u32) -> Option<Self> {
+        [
+            TransactionError::Malformed,
+            TransactionError::Expired,
+            TransactionError::InsufficientFee,
+            TransactionError::UnsupportedFeeAsset,
+            TransactionError::StaleAnchor,
+            TransactionError::NullifierSpent,
+            TransactionError::DuplicateNullifier,
+            TransactionError::InvalidProof,
+            TransactionError::InvalidSignature,
+            TransactionError::InvalidClues,
+            TransactionError::InvalidMemo,
+        ]
+        .into_iter()
+        .find(|category| category.code() == code)
+    }
+
+    /// The category of `error`, if it has one.
+    pub fn of(error: &anyhow::Error) -> Option<Self> {
+        error.downcast_ref::<TransactionError>().copied()
+    }
+
+    /// The ABCI code to report `error` with.
+    pub fn abci_code(error: &anyhow::Error) -> u32 {
+        Self::of(error)
+            .map(|category| category.code())
+            .unwrap_or(Self::UNCATEGORIZED_CODE)
+    }
+}
+
+impl std::fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TransactionError::Malformed => "malformed transaction",
+            TransactionError::Expired => "transaction expired",
+            TransactionError::InsufficientFee => "insufficient fee",
+            TransactionError::UnsupportedFeeAsset => "unsupported fee asset",
+            TransactionError::StaleAnchor => "stale anchor",
+            TransactionError::NullifierSpent => "nullifier already spent",
+            TransactionError::DuplicateNullifier => "duplicate nullifier",
+            TransactionError::InvalidProof => "invalid proof",
+            TransactionError::InvalidSignature => "invalid signature",
+            TransactionError::InvalidClues => "invalid detection clues",
+            TransactionError::InvalidMemo => "invalid memo",
+        })
+    }
+}
+
+impl std::error::Error for TransactionError {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+
+    use super::*;
+
+    #[test]
+    fn codes_round_trip() {
+        for code in 0..100 {
+            if let Some(category) = TransactionError::from_code(code) {
+                assert_eq!(category.code(), code);
+                assert_ne!(code, TransactionError::UNCATEGORIZED_CODE);
+            }
+        }
+    }
+
+    #[test]
+    fn category_survives_context() {
+        let error: anyhow::Result<()> = Err(anyhow::anyhow!("proof did not verify"));
+        let error = error
+            .context(TransactionError::InvalidProof)
+            .context("while checking action 3")
+            .unwrap_err();
+        assert_eq!(
+            TransactionError::of(&error),
+            Some(TransactionError::InvalidProof)
+        );
+        assert_eq!(TransactionError::abci_code(&error), 9);
+
+        let uncategorized = anyhow::anyhow!("something else went wrong");
+        assert_eq!(TransactionError::of(&uncategorized), None);
+        assert_eq!(
+            TransactionError::abci_code(&uncategorized),
+            TransactionError::UNCATEGORIZED_CODE
+        );
+    }
+}
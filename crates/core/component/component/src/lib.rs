@@ -8,6 +8,10 @@
 //! - [`ActionHandler`], which defines the _externally driven_ behavior of a
 //! component, triggered by actions in blockchain transactions.
 //!
+//! It also defines [`TransactionError`], the categories of validation failure
+//! that `ActionHandler` implementations attach to their errors, so that they
+//! can be reported to clients as stable ABCI codes.
+//!
 //! Component crates should be structured as follows:
 //!
 //! - Definitions of any transaction actions related to the component, and their
@@ -34,6 +38,8 @@
 
 mod action_handler;
 mod component;
+mod error;
 
 pub use action_handler::ActionHandler;
 pub use component::Component;
+pub use error::TransactionError;
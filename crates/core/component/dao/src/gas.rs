@@ -0,0 +1,25 @@
+//! Gas costs of the DAO's actions, which are charged for the block space of
+//! their encoding.
+
+use penumbra_fee::{gas::variable_gas_cost, Gas, GasCost};
+use penumbra_proto::DomainType;
+
+use crate::{DaoDeposit, DaoOutput, DaoSpend};
+
+impl GasCost for DaoSpend {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
+
+impl GasCost for DaoOutput {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
+
+impl GasCost for DaoDeposit {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
@@ -5,6 +5,7 @@
 pub mod component;
 
 pub mod event;
+pub mod gas;
 
 mod action;
 pub use action::{DaoDeposit, DaoOutput, DaoSpend};
@@ -6,7 +6,7 @@ use penumbra_component::ActionHandler;
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::{
-    component::{PositionManager, PositionRead},
+    component::{PositionManager, PositionRead, StateWriteExt},
     event,
     lp::action::PositionOpen,
 };
@@ -37,6 +37,7 @@ impl ActionHandler for PositionOpen {
 
     async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
         state.put_position(self.position.clone()).await?;
+        state.touch_pair(self.position.phi.pair);
         state.record(event::position_open(&self));
         Ok(())
     }
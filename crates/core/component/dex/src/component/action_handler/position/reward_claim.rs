@@ -1,34 +1,95 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use ark_ff::Zero;
 use async_trait::async_trait;
+use decaf377::Fr;
+use penumbra_asset::{Balance, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_component::ActionHandler;
+use penumbra_shielded_pool::component::SupplyWrite;
 use penumbra_storage::{StateRead, StateWrite};
 
-use crate::lp::action::PositionRewardClaim;
+use crate::{
+    component::{PositionManager, PositionRead, PositionRewardsManager, PositionRewardsRead},
+    event,
+    lp::{action::PositionRewardClaim, position},
+};
 
 #[async_trait]
 /// Debits a withdrawn position NFT and credits a claimed position NFT and any liquidity incentives.
 impl ActionHandler for PositionRewardClaim {
     type CheckStatelessContext = ();
     async fn check_stateless(&self, _context: ()) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp rewards not supported yet"))
+        // Nothing to do: the only validation is of the state change,
+        // and that's done by the value balance mechanism.
+        Ok(())
     }
 
-    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp rewards not supported yet"))
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // Check that the position has been withdrawn.  Unlike a withdrawal,
+        // a reward claim can't be made in the same transaction as the action
+        // it depends on, since the planner claims rewards separately.
+        let position = state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow!("claimed rewards for unknown position {}", self.position_id))?;
+
+        if position.state != position::State::Withdrawn {
+            return Err(anyhow!(
+                "attempted to claim rewards for position {} with state {}, expected Withdrawn",
+                self.position_id,
+                position.state
+            ));
+        }
+
+        // Check that the committed rewards in the action match the state.
+
+        let rewards = state.position_rewards(&self.position_id).await?;
+        let expected_rewards_commitment = Balance::from(Value {
+            amount: rewards,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        })
+        .commit(Fr::zero());
+
+        if self.rewards_commitment != expected_rewards_commitment {
+            return Err(anyhow!(
+                "rewards commitment {:?} is incorrect, expected {:?}",
+                self.rewards_commitment,
+                expected_rewards_commitment
+            ));
+        }
+
+        Ok(())
     }
 
-    async fn execute<S: StateWrite>(&self, _state: S) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp rewards not supported yet"))
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Check the position state again, since another action in the same block
+        // may have claimed the rewards after this transaction's stateful checks.
+        let mut metadata = state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow!("claimed rewards for unknown position {}", self.position_id))?;
+
+        if metadata.state != position::State::Withdrawn {
+            return Err(anyhow::anyhow!(
+                "attempted to claim rewards for position {} with state {}, expected Withdrawn",
+                self.position_id,
+                metadata.state
+            ));
+        }
+
+        // The rewards are minted by the value balance of the transaction, so
+        // record them as new supply of the staking token.
+        let rewards = state.take_position_rewards(&self.position_id).await?;
+        state
+            .update_token_supply(&STAKING_TOKEN_ASSET_ID, rewards.value() as i128)
+            .await?;
+
+        state.record(event::position_reward_claim(&self, rewards));
+
+        metadata.state = position::State::Claimed;
+        state.put_position(metadata).await?;
+
+        Ok(())
     }
 }
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
-use penumbra_component::ActionHandler;
+use penumbra_component::{ActionHandler, TransactionError};
 use penumbra_proof_params::SWAP_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -21,12 +21,15 @@ impl ActionHandler for Swap {
             return Err(anyhow::anyhow!("Trading pair must be distinct"));
         }
 
-        self.proof.verify(
-            &SWAP_PROOF_VERIFICATION_KEY,
-            self.balance_commitment_inner(),
-            self.body.payload.commitment,
-            self.body.fee_commitment,
-        )?;
+        self.proof
+            .verify(
+                &SWAP_PROOF_VERIFICATION_KEY,
+                self.balance_commitment_inner(),
+                self.body.payload.commitment,
+                self.body.fee_commitment,
+            )
+            .context("a swap proof did not verify")
+            .context(TransactionError::InvalidProof)?;
 
         Ok(())
     }
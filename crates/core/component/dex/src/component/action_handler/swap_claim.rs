@@ -4,7 +4,7 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::component::StateReadExt as _;
 use penumbra_chain::TransactionContext;
-use penumbra_component::ActionHandler;
+use penumbra_component::{ActionHandler, TransactionError};
 
 use penumbra_proof_params::SWAPCLAIM_PROOF_VERIFICATION_KEY;
 use penumbra_shielded_pool::component::{NoteManager, StateReadExt as _};
@@ -26,7 +26,8 @@ impl ActionHandler for SwapClaim {
                 self.body.output_1_commitment,
                 self.body.output_2_commitment,
             )
-            .context("a swap claim proof did not verify")?;
+            .context("a swap claim proof did not verify")
+            .context(TransactionError::InvalidProof)?;
 
         Ok(())
     }
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_asset::{asset, Value};
-use penumbra_chain::component::StateReadExt;
+use penumbra_chain::component::StateReadExt as _;
 use penumbra_storage::{StateDelta, StateWrite};
 use tracing::instrument;
 
@@ -11,7 +11,7 @@ use crate::SwapExecution;
 
 use super::{
     router::{RouteAndFill, RoutingParams},
-    StateWriteExt,
+    StateReadExt as _, StateWriteExt,
 };
 
 #[async_trait]
@@ -27,6 +27,18 @@ pub trait Arbitrage: StateWrite + Sized {
     where
         Self: 'static,
     {
+        // Extend the fixed candidates with both ends of every trading pair that saw
+        // swaps or newly opened positions in this block, since those are the pairs
+        // whose prices are most likely to have moved out of line with the others.
+        let mut fixed_candidates = fixed_candidates;
+        for pair in self.touched_pairs() {
+            for asset_id in [pair.asset_1(), pair.asset_2()] {
+                if !fixed_candidates.contains(&asset_id) {
+                    fixed_candidates.push(asset_id);
+                }
+            }
+        }
+
         tracing::debug!(?arb_token, ?fixed_candidates, "beginning arb search");
 
         // Work in a new `StateDelta`, so we can transactionally apply any state
@@ -34,11 +46,11 @@ pub trait Arbitrage: StateWrite + Sized {
         // discover at the end that the arb wasn't profitable).
         let mut this = Arc::new(StateDelta::new(self.clone()));
 
-        // TODO: Build an extended candidate set with:
-        // - both ends of all trading pairs for which there were swaps in the block
-        // - both ends of all trading pairs for which positions were opened
+        // An arbitrage cycle ends where it starts, so allow it one more hop than a route.
+        let max_hops = self.get_chain_params().await?.dex_max_hops as usize + 1;
+
         let params = RoutingParams {
-            max_hops: 5,
+            max_hops,
             price_limit: Some(1u64.into()),
             fixed_candidates: Arc::new(fixed_candidates),
         };
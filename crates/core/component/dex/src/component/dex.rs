@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -17,7 +20,7 @@ use crate::{
 
 use super::{
     router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager,
+    Arbitrage, ConvertFees, PositionManager, PositionRead,
 };
 
 pub struct Dex {}
@@ -42,6 +45,10 @@ impl Component for Dex {
         end_block: &abci::request::EndBlock,
     ) {
         let current_epoch = state.epoch().await.unwrap();
+        let routing_params = state
+            .routing_params()
+            .await
+            .expect("routing params are always readable");
 
         // For each batch swap during the block, calculate clearing prices and set in the JMT.
         for (trading_pair, swap_flows) in state.swap_flows() {
@@ -55,47 +62,34 @@ impl Component for Dex {
                         .expect("height is part of the end block data"),
                     current_epoch.start_height,
                     // Always include both ends of the target pair as fixed candidates.
-                    RoutingParams::default_with_extra_candidates([
-                        trading_pair.asset_1(),
-                        trading_pair.asset_2(),
-                    ]),
+                    routing_params
+                        .clone()
+                        .with_extra_candidates([trading_pair.asset_1(), trading_pair.asset_2()]),
                 )
                 .await
                 .expect("handling batch swaps is infaillible");
         }
 
+        // Swap the fees collected in non-native assets into the staking token,
+        // before arbitrage, so that any price impact can be arbitraged away.
+        // If the conversion fails, the fees are burned unconverted.
+        match state.convert_fees().await {
+            Ok(fee_burn) if fee_burn != 0u64.into() => {
+                tracing::debug!(?fee_burn, "converted non-native fees");
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(?e, "failed to convert non-native fees, burning them as-is");
+            }
+        }
+
         // Then, perform arbitrage:
+        let arb_candidates = state
+            .arb_candidates()
+            .await
+            .expect("arb candidates are always readable");
         let arb_burn = state
-            .arbitrage(
-                *STAKING_TOKEN_ASSET_ID,
-                vec![
-                    *STAKING_TOKEN_ASSET_ID,
-                    asset::Cache::with_known_assets()
-                        .get_unit("gm")
-                        .unwrap()
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("gn")
-                        .unwrap()
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_usd")
-                        .unwrap()
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_btc")
-                        .unwrap()
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_atom")
-                        .unwrap()
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_osmo")
-                        .unwrap()
-                        .id(),
-                ],
-            )
+            .arbitrage(*STAKING_TOKEN_ASSET_ID, arb_candidates)
             .await
             .expect("must be able to process arbitrage");
 
@@ -147,6 +141,49 @@ pub trait StateReadExt: StateRead {
         self.get(&state_key::arb_execution(height)).await
     }
 
+    /// Gets the parameters for routing trades, as set by the chain parameters.
+    ///
+    /// The fixed candidates are extended with the most liquid assets against the
+    /// staking token, if the chain parameters ask for any.
+    async fn routing_params(&self) -> Result<RoutingParams> {
+        let chain_params = self.get_chain_params().await?;
+        let auto_candidates = self
+            .most_liquid_assets(
+                &STAKING_TOKEN_ASSET_ID,
+                chain_params.dex_auto_candidates as usize,
+            )
+            .await?;
+
+        Ok(RoutingParams {
+            price_limit: None,
+            fixed_candidates: Arc::new(chain_params.dex_fixed_candidates),
+            max_hops: chain_params.dex_max_hops as usize,
+        }
+        .with_extra_candidates(auto_candidates))
+    }
+
+    /// Gets the candidate assets to search for arbitrage through, as set by the
+    /// chain parameters.
+    ///
+    /// Like the routing candidates, these are extended with the most liquid assets
+    /// against the staking token, if the chain parameters ask for any.
+    async fn arb_candidates(&self) -> Result<Vec<asset::Id>> {
+        let chain_params = self.get_chain_params().await?;
+        let mut candidates = chain_params.dex_arb_candidates;
+        for asset_id in self
+            .most_liquid_assets(
+                &STAKING_TOKEN_ASSET_ID,
+                chain_params.dex_auto_candidates as usize,
+            )
+            .await?
+        {
+            if !candidates.contains(&asset_id) {
+                candidates.push(asset_id);
+            }
+        }
+        Ok(candidates)
+    }
+
     /// Get the swap flow for the given trading pair accumulated in this block so far.
     fn swap_flow(&self, pair: &TradingPair) -> SwapFlow {
         self.swap_flows().get(pair).cloned().unwrap_or_default()
@@ -156,6 +193,12 @@ pub trait StateReadExt: StateRead {
         self.object_get::<BTreeMap<TradingPair, SwapFlow>>(state_key::swap_flows())
             .unwrap_or_default()
     }
+
+    /// Get the trading pairs that saw swaps or newly opened positions in this block so far.
+    fn touched_pairs(&self) -> BTreeSet<TradingPair> {
+        self.object_get(state_key::touched_pairs())
+            .unwrap_or_default()
+    }
 }
 
 impl<T: StateRead> StateReadExt for T {}
@@ -206,7 +249,16 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         // TODO: replace with IM struct later
         let mut swap_flows = self.swap_flows();
         swap_flows.insert(*trading_pair, swap_flow);
-        self.object_put(state_key::swap_flows(), swap_flows)
+        self.object_put(state_key::swap_flows(), swap_flows);
+        self.touch_pair(*trading_pair);
+    }
+
+    /// Records that a trading pair saw activity in this block, so that both of its
+    /// assets are searched through for arbitrage at the end of the block.
+    fn touch_pair(&mut self, trading_pair: TradingPair) {
+        let mut touched_pairs = self.touched_pairs();
+        touched_pairs.insert(trading_pair);
+        self.object_put(state_key::touched_pairs(), touched_pairs);
     }
 }
 
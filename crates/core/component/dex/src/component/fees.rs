@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_component::TransactionError;
+use penumbra_fee::{component::StateWriteExt as _, Fee};
+use penumbra_num::Amount;
+use penumbra_proto::StateWriteProto;
+use penumbra_storage::{StateDelta, StateRead, StateWrite};
+use tracing::instrument;
+
+use crate::state_key;
+
+use super::{router::RouteAndFill, StateReadExt as _};
+
+#[async_trait]
+pub trait ConvertFees: StateWrite + Sized {
+    /// Swaps the fees collected in this block that were paid in assets other
+    /// than the staking token into the staking token, returning the total
+    /// amount of the staking token they were converted into.
+    ///
+    /// Like fees paid in the staking token, the proceeds are burned.  Any part of
+    /// a fee that can't be filled by the available liquidity is burned as-is.
+    ///
+    /// The conversion is transactional: if it fails, no state changes are
+    /// applied, and the collected fees are burned unconverted.
+    #[instrument(skip(self))]
+    async fn convert_fees(self: &mut Arc<Self>) -> Result<Amount>
+    where
+        Self: 'static,
+    {
+        let height = self.get_block_height().await?;
+        let routing_params = self.routing_params().await?;
+
+        // Work in a new `StateDelta`, so we can transactionally apply the
+        // conversions, and roll them back if any of them fails.
+        let mut this = Arc::new(StateDelta::new(self.clone()));
+        let collected_fees = Arc::get_mut(&mut this)
+            .ok_or_else(|| anyhow!("fee conversion state should be uniquely referenced"))?
+            .take_collected_fees();
+
+        let mut total_output = Amount::zero();
+        for (asset_id, amount) in collected_fees {
+            if asset_id == *STAKING_TOKEN_ASSET_ID {
+                continue;
+            }
+
+            let swap_execution = this
+                .route_and_fill(
+                    asset_id,
+                    *STAKING_TOKEN_ASSET_ID,
+                    amount,
+                    routing_params.clone().with_extra_candidates([asset_id]),
+                )
+                .await?;
+
+            tracing::debug!(
+                ?asset_id,
+                ?amount,
+                input = ?swap_execution.input.amount,
+                output = ?swap_execution.output.amount,
+                "converted collected fees to the staking token"
+            );
+            total_output += swap_execution.output.amount;
+
+            Arc::get_mut(&mut this)
+                .ok_or_else(|| anyhow!("fee conversion state should be uniquely referenced"))?
+                .put(
+                    state_key::fee_swap_execution(height, &asset_id),
+                    swap_execution,
+                );
+        }
+
+        let (parent, cache) = Arc::try_unwrap(this)
+            .map_err(|_| anyhow!("no more outstanding refs to state after converting fees"))?
+            .flatten();
+        std::mem::drop(parent);
+        // Now there is only one reference to self again
+        let self_mut = Arc::get_mut(self)
+            .ok_or_else(|| anyhow!("state should be uniquely referenced after converting fees"))?;
+        cache.apply_to(self_mut);
+
+        Ok(total_output)
+    }
+}
+
+impl<T: StateWrite> ConvertFees for T {}
+
+#[async_trait]
+pub trait FeeLiquidity: StateRead + Clone + 'static {
+    /// Checks that a `fee` paid in an asset other than the staking token can be
+    /// converted into the staking token with the liquidity currently on the DEX.
+    ///
+    /// The conversion is simulated against a throwaway copy of the state, and
+    /// must fill the entire fee.  Fees paid in the staking token always pass.
+    #[instrument(skip(self))]
+    async fn check_fee_liquidity(&self, fee: &Fee) -> Result<()> {
+        let asset_id = fee.asset_id();
+        if asset_id == *STAKING_TOKEN_ASSET_ID || fee.amount() == Amount::zero() {
+            return Ok(());
+        }
+
+        let routing_params = self
+            .routing_params()
+            .await?
+            .with_extra_candidates([asset_id]);
+        let mut simulation = Arc::new(StateDelta::new(self.clone()));
+        let swap_execution = simulation
+            .route_and_fill(
+                asset_id,
+                *STAKING_TOKEN_ASSET_ID,
+                fee.amount(),
+                routing_params,
+            )
+            .await?;
+
+        if swap_execution.input.amount < fee.amount() {
+            return Err(anyhow!(
+                "only {} of the fee of {} in asset {} can be converted to the staking token",
+                swap_execution.input.amount,
+                fee.amount(),
+                asset_id
+            )
+            .context(TransactionError::UnsupportedFeeAsset));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateRead + Clone + 'static> FeeLiquidity for T {}
@@ -6,14 +6,18 @@ pub mod router;
 mod action_handler;
 mod arb;
 mod dex;
+mod fees;
 mod flow;
 mod position_manager;
+mod rewards;
 mod swap_manager;
 
 pub use self::metrics::register_metrics;
 pub use arb::Arbitrage;
 pub use dex::{Dex, StateReadExt, StateWriteExt};
+pub use fees::{ConvertFees, FeeLiquidity};
 pub use position_manager::{PositionManager, PositionRead};
+pub use rewards::{PositionRewardsManager, PositionRewardsRead};
 pub use swap_manager::SwapManager;
 
 #[cfg(test)]
@@ -5,7 +5,7 @@ use anyhow::Result;
 use async_stream::try_stream;
 use async_trait::async_trait;
 use futures::Stream;
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::asset;
 use penumbra_num::Amount;
 use penumbra_proto::DomainType;
@@ -18,6 +18,8 @@ use crate::{
     state_key, DirectedTradingPair,
 };
 
+use super::PositionRewardsManager;
+
 const DYNAMIC_ASSET_LIMIT: usize = 10;
 
 #[async_trait]
@@ -78,6 +80,41 @@ pub trait PositionRead: StateRead {
         }
     }
 
+    /// Returns up to `limit` assets with the most liquidity available against `numeraire`,
+    /// most liquid first.
+    async fn most_liquid_assets(
+        &self,
+        numeraire: &asset::Id,
+        limit: usize,
+    ) -> Result<Vec<asset::Id>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        // The routable assets index is ordered by ascending liquidity, so we have to
+        // scan all of the assets routable from the numeraire to find the most liquid.
+        let prefix = state_key::internal::routable_assets::prefix(numeraire);
+        let mut assets = self
+            .nonverifiable_prefix_raw(&prefix)
+            .try_filter_map(|(k, v)| async move {
+                let liquidity = Amount::from_be_bytes(
+                    k[39..55]
+                        .try_into()
+                        .expect("routable assets key contains liquidity amount"),
+                );
+                if liquidity == Amount::zero() {
+                    return Ok(None);
+                }
+                Ok(Some(asset::Id::decode(&*v)?))
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        assets.reverse();
+        assets.truncate(limit);
+        Ok(assets)
+    }
+
     /// Fetch the list of pending position closures.
     fn pending_position_closures(&self) -> im::Vector<position::Id> {
         self.object_get(state_key::pending_position_closures())
@@ -154,6 +191,11 @@ pub trait PositionManager: StateWrite + PositionRead {
         // Update the available liquidity for this position's trading pair.
         self.update_available_liquidity(&position, &prev).await?;
 
+        // Record any volume the position executed, which determines its share of LP rewards.
+        if let Some(prev) = &prev {
+            self.record_position_volume(prev, &position).await?;
+        }
+
         self.put(state_key::position_by_id(&id), position);
         Ok(())
     }
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt};
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+use penumbra_num::Amount;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::{
+    lp::position::{self, Position},
+    state_key,
+};
+
+/// Reads the rewards accumulated by liquidity positions.
+#[async_trait]
+pub trait PositionRewardsRead: StateRead {
+    /// Returns the rewards accumulated by a position, in the staking token.
+    async fn position_rewards(&self, id: &position::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::position_rewards(id))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Returns the volume executed by a position during the current epoch, in the staking token.
+    async fn position_volume(&self, id: &position::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::position_volume(id))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Returns the volume executed by every position that traded during the current epoch.
+    async fn position_volumes(&self) -> Result<Vec<(position::Id, Amount)>> {
+        self.prefix::<Amount>(state_key::position_volumes())
+            .map(|entry| {
+                let (key, volume) = entry?;
+                let id = key
+                    .rsplit('/')
+                    .next()
+                    .expect("position volume key has a position id")
+                    .parse()?;
+                Ok((id, volume))
+            })
+            .try_collect()
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> PositionRewardsRead for T {}
+
+/// Tracks the volume executed by liquidity positions, and the rewards they accumulate.
+#[async_trait]
+pub trait PositionRewardsManager: StateWrite + PositionRewardsRead {
+    /// Records the volume executed by a position, given its previous and updated states.
+    ///
+    /// Volume is measured by the change in the position's reserves of the staking token,
+    /// so only positions quoted against the staking token accumulate volume.
+    async fn record_position_volume(&mut self, prev: &Position, position: &Position) -> Result<()> {
+        if prev.state != position::State::Opened {
+            return Ok(());
+        }
+
+        let (Some(prev_reserves), Some(reserves)) = (
+            prev.reserves_for(*STAKING_TOKEN_ASSET_ID),
+            position.reserves_for(*STAKING_TOKEN_ASSET_ID),
+        ) else {
+            return Ok(());
+        };
+
+        let volume = reserves
+            .checked_sub(&prev_reserves)
+            .or_else(|| prev_reserves.checked_sub(&reserves))
+            .expect("one of the differences is non-negative");
+        if volume == Amount::zero() {
+            return Ok(());
+        }
+
+        let id = position.id();
+        let total_volume = self.position_volume(&id).await? + volume;
+        tracing::debug!(?id, ?volume, ?total_volume, "recording position volume");
+        self.put(state_key::position_volume(&id), total_volume);
+        Ok(())
+    }
+
+    /// Clears the volume executed by every position, returning it.
+    ///
+    /// This should be called at the end of each epoch, once the epoch's rewards
+    /// have been allocated.
+    async fn take_position_volumes(&mut self) -> Result<Vec<(position::Id, Amount)>> {
+        let volumes = self.position_volumes().await?;
+        for (id, _) in &volumes {
+            self.delete(state_key::position_volume(id));
+        }
+        Ok(volumes)
+    }
+
+    /// Credits rewards to a position, to be paid out when it is claimed.
+    async fn reward_position(&mut self, id: &position::Id, rewards: Amount) -> Result<()> {
+        let total_rewards = self.position_rewards(id).await? + rewards;
+        self.put(state_key::position_rewards(id), total_rewards);
+        Ok(())
+    }
+
+    /// Removes the rewards accumulated by a position, returning them.
+    async fn take_position_rewards(&mut self, id: &position::Id) -> Result<Amount> {
+        let rewards = self.position_rewards(id).await?;
+        self.delete(state_key::position_rewards(id));
+        Ok(rewards)
+    }
+}
+
+impl<T: StateWrite + ?Sized> PositionRewardsManager for T {}
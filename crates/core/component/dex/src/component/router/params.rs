@@ -11,6 +11,12 @@ pub struct RoutingParams {
 }
 
 impl Default for RoutingParams {
+    /// Routing parameters using the testnet assets as fixed candidates.
+    ///
+    /// Consensus code should use the chain's routing parameters instead; see
+    /// [`StateReadExt::routing_params`].
+    ///
+    /// [`StateReadExt::routing_params`]: crate::component::StateReadExt::routing_params
     fn default() -> Self {
         Self {
             price_limit: None,
@@ -50,15 +56,15 @@ impl Default for RoutingParams {
 }
 
 impl RoutingParams {
-    /// Like `Default::default()`, but extends the default fixed candidates with the given list.
-    ///
-    /// If you want to _set_ the fixed candidates, just use `..Default::default()`.
-    pub fn default_with_extra_candidates(iter: impl IntoIterator<Item = asset::Id>) -> Self {
-        let mut params = Self::default();
-        Arc::get_mut(&mut params.fixed_candidates)
-            .expect("just created unique ref")
-            .extend(iter);
-        params
+    /// Extends the fixed candidates with the given list, skipping any already present.
+    pub fn with_extra_candidates(mut self, iter: impl IntoIterator<Item = asset::Id>) -> Self {
+        let fixed_candidates = Arc::make_mut(&mut self.fixed_candidates);
+        for candidate in iter {
+            if !fixed_candidates.contains(&candidate) {
+                fixed_candidates.push(candidate);
+            }
+        }
+        self
     }
 
     /// Clamps the spill price to the price limit and returns whether or not it was clamped.
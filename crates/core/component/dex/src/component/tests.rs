@@ -15,10 +15,11 @@ use crate::{
     component::{
         router::FillRoute,
         router::{limit_buy, limit_sell, HandleBatchSwaps, RoutingParams},
-        Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        Arbitrage, ConvertFees, FeeLiquidity, PositionManager, PositionRead,
+        PositionRewardsManager, PositionRewardsRead, StateReadExt, StateWriteExt,
     },
     lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair, SwapExecution, TradingPair,
 };
 
 // TODO: what's the right way to mock genesis? if component A needs component B,
@@ -41,6 +42,7 @@ impl TempStorageExt for TempStorage {
         // penumbra_chain or something (TBD: how to split up penumbra-chain?
         // params should be at the top, stuff like this should be at the bottom)
 
+        state.put_chain_params(Default::default());
         state.put_block_height(0);
         state.put_epoch_by_height(
             0,
@@ -901,3 +903,364 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
     tracing::info!(?arb_execution, "fetched arb execution!");
     Ok(())
 }
+
+#[tokio::test]
+/// Test that fees paid in a non-native asset are swapped to the staking token.
+async fn convert_non_native_fees() -> anyhow::Result<()> {
+    use penumbra_fee::{
+        component::{StateReadExt as _, StateWriteExt as _},
+        Fee,
+    };
+    use penumbra_proto::StateReadProto as _;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+
+    // Sell 100 penumbra at 1 gm each.
+    state_tx
+        .put_position(limit_sell(
+            DirectedUnitPair::new(penumbra.clone(), gm.clone()),
+            100u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+
+    // Two transactions paid their fees in gm, and one in the staking token.
+    state_tx.record_fee(&Fee(gm.value(4u32.into())));
+    state_tx.record_fee(&Fee(gm.value(6u32.into())));
+    state_tx.record_fee(&Fee(penumbra.value(1u32.into())));
+    state_tx.apply();
+
+    // Only the gm fees should be converted, at the position's price.
+    let output = state.convert_fees().await?;
+    assert_eq!(output, penumbra.value(10u32.into()).amount);
+
+    let fee_swap_execution: SwapExecution = state
+        .get(&crate::state_key::fee_swap_execution(0, &gm.id()))
+        .await?
+        .expect("fees were converted");
+    assert_eq!(fee_swap_execution.input, gm.value(10u32.into()));
+    assert_eq!(fee_swap_execution.output, penumbra.value(10u32.into()));
+
+    // The collected fees were consumed by the conversion.
+    assert!(state.collected_fees().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that converting fees while the state is shared fails without changing it,
+/// rather than panicking.
+async fn convert_fees_with_shared_state_is_rolled_back() -> anyhow::Result<()> {
+    use penumbra_fee::{
+        component::{StateReadExt as _, StateWriteExt as _},
+        Fee,
+    };
+    use penumbra_proto::StateReadProto as _;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+
+    state_tx
+        .put_position(limit_sell(
+            DirectedUnitPair::new(penumbra.clone(), gm.clone()),
+            100u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    state_tx.record_fee(&Fee(gm.value(10u32.into())));
+    state_tx.apply();
+
+    let shared = state.clone();
+    assert!(state.convert_fees().await.is_err());
+    std::mem::drop(shared);
+
+    // Neither the fees nor the position were touched.
+    assert_eq!(
+        state.collected_fees().get(&gm.id()),
+        Some(&gm.value(10u32.into()).amount)
+    );
+    assert!(state
+        .get::<SwapExecution>(&crate::state_key::fee_swap_execution(0, &gm.id()))
+        .await?
+        .is_none());
+
+    // Once the state is no longer shared, the conversion goes through.
+    let output = state.convert_fees().await?;
+    assert_eq!(output, penumbra.value(10u32.into()).amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that fees in a non-native asset are only accepted if there's enough
+/// liquidity to convert them into the staking token.
+async fn fee_liquidity_is_checked() -> anyhow::Result<()> {
+    use penumbra_fee::Fee;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // Fees in the staking token never need liquidity.
+    state
+        .check_fee_liquidity(&Fee(penumbra.value(1000u32.into())))
+        .await?;
+
+    // Without any positions, there's no route from gm to the staking token.
+    assert!(state
+        .check_fee_liquidity(&Fee(gm.value(10u32.into())))
+        .await
+        .is_err());
+
+    // Sell 100 penumbra at 1 gm each.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .put_position(limit_sell(
+            DirectedUnitPair::new(penumbra.clone(), gm.clone()),
+            100u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    state_tx.apply();
+
+    // Now a fee in gm can be converted, as long as it fits in the position...
+    state
+        .check_fee_liquidity(&Fee(gm.value(10u32.into())))
+        .await?;
+    assert!(state
+        .check_fee_liquidity(&Fee(gm.value(1000u32.into())))
+        .await
+        .is_err());
+    // ...while other assets still have no route.
+    assert!(state
+        .check_fee_liquidity(&Fee(gn.value(10u32.into())))
+        .await
+        .is_err());
+
+    // The simulated conversions don't consume any of the position's reserves.
+    for _ in 0..2 {
+        state
+            .check_fee_liquidity(&Fee(gm.value(95u32.into())))
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that the routing parameters are taken from the chain parameters, with
+/// the fixed candidates extended by the assets most liquid against the staking token.
+async fn routing_params_from_chain_params() -> anyhow::Result<()> {
+    use penumbra_chain::component::StateWriteExt as _;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let test_usd = asset::Cache::with_known_assets()
+        .get_unit("test_usd")
+        .unwrap();
+
+    state_tx.put_chain_params(penumbra_chain::params::ChainParameters {
+        dex_fixed_candidates: vec![test_usd.id()],
+        dex_max_hops: 3,
+        dex_auto_candidates: 1,
+        ..Default::default()
+    });
+
+    // There's more penumbra available for gm than for gn.
+    state_tx
+        .put_position(limit_sell(
+            DirectedUnitPair::new(penumbra.clone(), gm.clone()),
+            100u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    state_tx
+        .put_position(limit_sell(
+            DirectedUnitPair::new(penumbra.clone(), gn.clone()),
+            10u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    state_tx.apply();
+
+    assert_eq!(
+        state.most_liquid_assets(&penumbra.id(), 2).await?,
+        vec![gm.id(), gn.id()]
+    );
+
+    let routing_params = state.routing_params().await?;
+    assert_eq!(routing_params.max_hops, 3);
+    assert_eq!(
+        *routing_params.fixed_candidates,
+        vec![test_usd.id(), gm.id()]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that the trading pairs that saw swaps or newly opened positions are
+/// tracked, so they can be searched for arbitrage.
+async fn touched_pairs_are_tracked() -> anyhow::Result<()> {
+    use penumbra_component::ActionHandler;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // A swap in the gm:gn pair...
+    let swap_pair = TradingPair::new(gm.id(), gn.id());
+    let mut swap_flow = state_tx.swap_flow(&swap_pair);
+    swap_flow.0 += 1u32.into();
+    state_tx.put_swap_flow(&swap_pair, swap_flow);
+
+    // ...and a position opened in the penumbra:gm pair.
+    let position = limit_sell(
+        DirectedUnitPair::new(penumbra.clone(), gm.clone()),
+        1u64.into(),
+        1u64.into(),
+    );
+    let position_pair = position.phi.pair;
+    PositionOpen { position }.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert_eq!(state.touched_pairs().into_iter().collect::<Vec<_>>(), {
+        let mut pairs = vec![swap_pair, position_pair];
+        pairs.sort();
+        pairs
+    });
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that a position accumulates volume as it is filled, and that its
+/// rewards are paid out when it is claimed.
+async fn position_rewards_are_claimed() -> anyhow::Result<()> {
+    use crate::lp::{action::PositionRewardClaim, plan::PositionRewardClaimPlan, position};
+    use penumbra_component::ActionHandler;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+
+    let position = limit_sell(
+        DirectedUnitPair::new(penumbra.clone(), gm.clone()),
+        10u64.into(),
+        1u64.into(),
+    );
+    let id = position.id();
+    state_tx.put_position(position.clone()).await?;
+
+    // Fill the position, trading 1penumbra of its reserves for 1gm.
+    let mut filled = position.clone();
+    let penumbra_amount = penumbra.unit_amount();
+    let gm_amount = gm.unit_amount();
+    if filled.phi.pair.asset_1() == penumbra.id() {
+        filled.reserves.r1 = filled.reserves.r1 - penumbra_amount;
+        filled.reserves.r2 = filled.reserves.r2 + gm_amount;
+    } else {
+        filled.reserves.r1 = filled.reserves.r1 + gm_amount;
+        filled.reserves.r2 = filled.reserves.r2 - penumbra_amount;
+    }
+    state_tx.put_position(filled.clone()).await?;
+    assert_eq!(state_tx.position_volume(&id).await?, penumbra_amount);
+
+    // Allocate some rewards, as the distributions component would at the end of the epoch.
+    assert_eq!(
+        state_tx.take_position_volumes().await?,
+        vec![(id, penumbra_amount)]
+    );
+    assert_eq!(state_tx.position_volume(&id).await?, Amount::zero());
+    state_tx.reward_position(&id, 1234u64.into()).await?;
+
+    state_tx
+        .put_position(Position {
+            state: position::State::Closed,
+            ..filled.clone()
+        })
+        .await?;
+    state_tx.apply();
+
+    // A claim for a position that hasn't been withdrawn is rejected.
+    let claim: PositionRewardClaim =
+        PositionRewardClaimPlan::new(id, 1234u64.into()).position_reward_claim();
+    assert!(claim.check_stateful(state.clone()).await.is_err());
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .put_position(Position {
+            state: position::State::Withdrawn,
+            ..filled
+        })
+        .await?;
+    state_tx.apply();
+
+    // A claim committing to the wrong rewards is rejected.
+    let wrong_claim = PositionRewardClaimPlan::new(id, 1000u64.into()).position_reward_claim();
+    assert!(wrong_claim.check_stateful(state.clone()).await.is_err());
+
+    claim.check_stateful(state.clone()).await?;
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    claim.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert_eq!(state.position_rewards(&id).await?, Amount::zero());
+    assert_eq!(
+        state.position_by_id(&id).await?.unwrap().state,
+        position::State::Claimed
+    );
+
+    // The rewards can't be claimed twice.
+    assert!(claim.check_stateful(state.clone()).await.is_err());
+
+    Ok(())
+}
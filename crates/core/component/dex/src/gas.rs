@@ -0,0 +1,116 @@
+//! Gas costs of the DEX's actions.
+//!
+//! Swaps, swap claims, and position withdrawals have a fixed size, so their
+//! costs are constants shared between each action and its plan.  The other
+//! actions are charged for the block space of their encoding.
+
+use penumbra_fee::{
+    gas::{variable_gas_cost, ACTION_EXECUTION_COST, PROOF_VERIFICATION_COST},
+    Gas, GasCost,
+};
+use penumbra_proto::DomainType;
+use penumbra_shielded_pool::gas::{NOTE_COMMITMENT_SIZE, NULLIFIER_SIZE};
+
+use crate::{
+    lp::{
+        action::{PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw},
+        plan::{PositionRewardClaimPlan, PositionWithdrawPlan},
+    },
+    swap::{Swap, SwapPlan},
+    swap_claim::{SwapClaim, SwapClaimPlan},
+};
+
+/// The size of a swap payload in the compact block.
+const SWAP_PAYLOAD_SIZE: u64 = 304;
+
+/// The gas used by a swap.
+pub fn swap_gas_cost() -> Gas {
+    Gas {
+        block_space: 640,
+        compact_block_space: SWAP_PAYLOAD_SIZE,
+        verification: PROOF_VERIFICATION_COST,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+/// The gas used by a swap claim.
+///
+/// A swap claim spends the swap and creates both of its output notes.
+pub fn swap_claim_gas_cost() -> Gas {
+    Gas {
+        block_space: 500,
+        compact_block_space: NULLIFIER_SIZE + 2 * NOTE_COMMITMENT_SIZE,
+        verification: PROOF_VERIFICATION_COST,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+/// The gas used by a position withdrawal or reward claim.
+fn position_withdraw_gas_cost() -> Gas {
+    Gas {
+        block_space: 70,
+        compact_block_space: 0,
+        verification: 0,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+impl GasCost for Swap {
+    fn gas_cost(&self) -> Gas {
+        swap_gas_cost()
+    }
+}
+
+impl GasCost for SwapPlan {
+    fn gas_cost(&self) -> Gas {
+        swap_gas_cost()
+    }
+}
+
+impl GasCost for SwapClaim {
+    fn gas_cost(&self) -> Gas {
+        swap_claim_gas_cost()
+    }
+}
+
+impl GasCost for SwapClaimPlan {
+    fn gas_cost(&self) -> Gas {
+        swap_claim_gas_cost()
+    }
+}
+
+impl GasCost for PositionWithdraw {
+    fn gas_cost(&self) -> Gas {
+        position_withdraw_gas_cost()
+    }
+}
+
+impl GasCost for PositionWithdrawPlan {
+    fn gas_cost(&self) -> Gas {
+        position_withdraw_gas_cost()
+    }
+}
+
+impl GasCost for PositionRewardClaim {
+    fn gas_cost(&self) -> Gas {
+        position_withdraw_gas_cost()
+    }
+}
+
+impl GasCost for PositionRewardClaimPlan {
+    fn gas_cost(&self) -> Gas {
+        position_withdraw_gas_cost()
+    }
+}
+
+impl GasCost for PositionOpen {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
+
+impl GasCost for PositionClose {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
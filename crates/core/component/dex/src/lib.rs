@@ -4,6 +4,7 @@
 #[cfg(feature = "component")]
 pub mod component;
 pub mod event;
+pub mod gas;
 pub mod state_key;
 
 mod batch_swap_output_data;
@@ -86,7 +86,8 @@ pub struct PositionWithdraw {
 /// position.
 ///
 /// This action's contribution to the transaction's value balance is to consume a
-/// withdrawn position NFT and contribute its reward balance.
+/// withdrawn position NFT and contribute a claimed position NFT, as well as its
+/// reward balance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(try_from = "pb::PositionRewardClaim", into = "pb::PositionRewardClaim")]
 pub struct PositionRewardClaim {
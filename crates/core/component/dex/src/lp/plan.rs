@@ -1,10 +1,8 @@
 use ark_ff::Zero;
 use decaf377::Fr;
-use decaf377_rdsa::{Signature, SpendAuth};
-use penumbra_asset::{balance, Balance, Value};
+use penumbra_asset::{balance, Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
 use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType, TypeUrl};
-use penumbra_tct as tct;
-use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -114,31 +112,55 @@ impl TryFrom<pb::PositionWithdrawPlan> for PositionWithdrawPlan {
     try_from = "pb::PositionRewardClaimPlan",
     into = "pb::PositionRewardClaimPlan"
 )]
-pub struct PositionRewardClaimPlan {}
+pub struct PositionRewardClaimPlan {
+    pub position_id: position::Id,
+    /// The rewards accumulated by the position, paid in the staking token.
+    pub rewards: Amount,
+}
 
 impl PositionRewardClaimPlan {
     /// Create a new [`PositionRewardClaimPlan`]
-    pub fn new<R: CryptoRng + RngCore>(_rng: &mut R) -> PositionRewardClaimPlan {
-        todo!()
+    pub fn new(position_id: position::Id, rewards: Amount) -> PositionRewardClaimPlan {
+        PositionRewardClaimPlan {
+            position_id,
+            rewards,
+        }
     }
 
-    /// Create a dummy [`PositionRewardClaimPlan`].
-    pub fn dummy<R: CryptoRng + RngCore>(_rng: &mut R) -> PositionRewardClaimPlan {
-        todo!()
+    /// Convenience method to construct the [`PositionRewardClaim`] described by this [`PositionRewardClaimPlan`].
+    pub fn position_reward_claim(&self) -> PositionRewardClaim {
+        PositionRewardClaim {
+            position_id: self.position_id,
+            rewards_commitment: self.rewards_commitment(),
+        }
     }
 
-    /// Convenience method to construct the [`PositionRewardClaim`] described by this [`PositionRewardClaimPlan`].
-    pub fn position_reward_claim(
-        &self,
-        // fvk: &FullViewingKey,
-        _auth_sig: Signature<SpendAuth>,
-        _auth_path: tct::Proof,
-    ) -> PositionRewardClaim {
-        todo!()
+    pub fn rewards_commitment(&self) -> balance::Commitment {
+        self.rewards_balance().commit(Fr::zero())
+    }
+
+    fn rewards_balance(&self) -> Balance {
+        Balance::from(Value {
+            amount: self.rewards,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        })
     }
 
     pub fn balance(&self) -> Balance {
-        todo!()
+        // PositionRewardClaim outputs will correspond to the rewards
+        // and a PositionRewardClaim token.
+        // Spends will be the PositionWithdraw token.
+        let mut balance = self.rewards_balance();
+        balance -= Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Withdrawn).asset_id(),
+        };
+        balance += Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Claimed).asset_id(),
+        };
+
+        balance
     }
 }
 
@@ -151,14 +173,26 @@ impl DomainType for PositionRewardClaimPlan {
 }
 
 impl From<PositionRewardClaimPlan> for pb::PositionRewardClaimPlan {
-    fn from(_msg: PositionRewardClaimPlan) -> Self {
-        todo!()
+    fn from(msg: PositionRewardClaimPlan) -> Self {
+        Self {
+            position_id: Some(msg.position_id.into()),
+            rewards: Some(msg.rewards.into()),
+        }
     }
 }
 
 impl TryFrom<pb::PositionRewardClaimPlan> for PositionRewardClaimPlan {
     type Error = anyhow::Error;
-    fn try_from(_msg: pb::PositionRewardClaimPlan) -> Result<Self, Self::Error> {
-        todo!()
+    fn try_from(msg: pb::PositionRewardClaimPlan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: msg
+                .position_id
+                .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                .try_into()?,
+            rewards: msg
+                .rewards
+                .ok_or_else(|| anyhow::anyhow!("missing rewards"))?
+                .try_into()?,
+        })
     }
 }
@@ -1,5 +1,7 @@
 use std::string::String;
 
+use penumbra_asset::asset;
+
 use crate::{lp::position, DirectedTradingPair, TradingPair};
 
 pub fn positions(trading_pair: &TradingPair, position_id: &str) -> String {
@@ -15,6 +17,20 @@ pub fn all_positions() -> &'static str {
     "dex/position/"
 }
 
+/// The rewards accumulated by a position, to be paid out when it is claimed.
+pub fn position_rewards(id: &position::Id) -> String {
+    format!("dex/position_rewards/{id}")
+}
+
+/// The volume executed by a position during the current epoch.
+pub fn position_volume(id: &position::Id) -> String {
+    format!("dex/position_volume/{id}")
+}
+
+pub fn position_volumes() -> &'static str {
+    "dex/position_volume/"
+}
+
 pub fn output_data(height: u64, trading_pair: TradingPair) -> String {
     format!(
         "dex/output/{:020}/{}/{}",
@@ -43,10 +59,18 @@ pub fn arb_executions() -> &'static str {
     "dex/arb_execution/"
 }
 
+pub fn fee_swap_execution(height: u64, asset_id: &asset::Id) -> String {
+    format!("dex/fee_swap_execution/{height:020}/{asset_id}")
+}
+
 pub fn swap_flows() -> &'static str {
     "dex/swap_flows"
 }
 
+pub fn touched_pairs() -> &'static str {
+    "dex/touched_pairs"
+}
+
 pub fn pending_position_closures() -> &'static str {
     "dex/pending_position_closures"
 }
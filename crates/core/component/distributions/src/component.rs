@@ -6,8 +6,14 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_chain::genesis;
 use penumbra_component::Component;
+use penumbra_dao::component::StateWriteExt as _;
+use penumbra_dex::component::{PositionRead, PositionRewardsManager};
+use penumbra_dex::lp::position;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_shielded_pool::component::SupplyWrite;
 use penumbra_storage::StateWrite;
 use tendermint::abci;
 pub use view::{StateReadExt, StateWriteExt};
@@ -32,7 +38,77 @@ impl Component for Distributions {
     ) {
     }
 
-    async fn end_epoch<S: StateWrite + 'static>(_state: &mut Arc<S>) -> Result<()> {
+    async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> Result<()> {
+        let state = Arc::get_mut(state).expect("state should be unique");
+        // The staking share of the budget is issued by the staking component,
+        // through the base reward rate of the next epoch.
+        let budget = state.issuance_budget().await?;
+        tracing::debug!(?budget, "issuing staking token for epoch");
+
+        state.issue_dao_funding(budget.dao).await?;
+        state.distribute_lp_rewards(budget.lp).await
+    }
+}
+
+#[async_trait]
+trait DistributionsImpl: StateWrite {
+    /// Deposits the DAO's share of the epoch's issuance into the DAO.
+    async fn issue_dao_funding(&mut self, amount: Amount) -> Result<()> {
+        if amount == Amount::zero() {
+            return Ok(());
+        }
+
+        self.update_token_supply(&STAKING_TOKEN_ASSET_ID, amount.value() as i128)
+            .await?;
+        self.dao_deposit(Value {
+            amount,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        })
+        .await
+    }
+
+    /// Splits the epoch's LP rewards between the positions that traded during
+    /// the epoch, in proportion to the volume each of them executed.
+    ///
+    /// The rewards are only issued when they are claimed.
+    async fn distribute_lp_rewards(&mut self, budget: Amount) -> Result<()> {
+        // Positions whose rewards were already claimed can't receive any more,
+        // so they don't take a share of the budget.
+        let mut volumes = Vec::new();
+        for (id, volume) in self.take_position_volumes().await? {
+            let state = self
+                .position_by_id(&id)
+                .await?
+                .map(|position| position.state);
+            if state != Some(position::State::Claimed) {
+                volumes.push((id, volume));
+            }
+        }
+
+        let total_volume = volumes
+            .iter()
+            .fold(Amount::zero(), |total, (_, volume)| total + *volume);
+        if budget == Amount::zero() || total_volume == Amount::zero() {
+            return Ok(());
+        }
+
+        tracing::debug!(
+            ?budget,
+            ?total_volume,
+            positions = volumes.len(),
+            "distributing lp rewards"
+        );
+        for (id, volume) in volumes {
+            // Any remainder from rounding down is simply not issued.
+            let share = U128x128::ratio(volume, total_volume)?;
+            let rewards: Amount = share.checked_mul(&budget.into())?.round_down().try_into()?;
+            if rewards > Amount::zero() {
+                self.reward_position(&id, rewards).await?;
+            }
+        }
+
         Ok(())
     }
 }
+
+impl<T: StateWrite + ?Sized> DistributionsImpl for T {}
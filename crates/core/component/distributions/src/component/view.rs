@@ -1,9 +1,20 @@
+use anyhow::Result;
 use async_trait::async_trait;
 
+use penumbra_chain::component::StateReadExt as _;
 use penumbra_storage::{StateRead, StateWrite};
 
+use crate::IssuanceBudget;
+
 #[async_trait]
-pub trait StateReadExt: StateRead {}
+pub trait StateReadExt: StateRead {
+    /// Gets the staking token issuance budget for the current epoch.
+    async fn issuance_budget(&self) -> Result<IssuanceBudget> {
+        Ok(IssuanceBudget::from_chain_params(
+            &self.get_chain_params().await?,
+        ))
+    }
+}
 
 impl<T> StateReadExt for T where T: StateRead + ?Sized {}
 
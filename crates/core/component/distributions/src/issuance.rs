@@ -0,0 +1,57 @@
+use penumbra_chain::params::ChainParameters;
+use penumbra_num::Amount;
+
+/// The staking token issued in a single epoch, split between its recipients.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IssuanceBudget {
+    /// The issuance paid as staking rewards, to delegators and validators' funding streams.
+    pub staking: Amount,
+    /// The issuance deposited into the DAO.
+    pub dao: Amount,
+    /// The issuance paid as rewards to liquidity providers.
+    pub lp: Amount,
+}
+
+impl IssuanceBudget {
+    /// Computes the issuance budget for an epoch from the chain parameters.
+    pub fn from_chain_params(params: &ChainParameters) -> Self {
+        let total = u128::from(params.issuance_per_epoch);
+        let dao = total * u128::from(params.dao_issuance_bps) / 10_000;
+        let lp = total * u128::from(params.lp_issuance_bps) / 10_000;
+        // Valid chain parameters never allocate more than the total to the DAO
+        // and LPs, so this only saturates if the parameters are invalid.
+        let staking = total.saturating_sub(dao + lp);
+
+        IssuanceBudget {
+            staking: staking.into(),
+            dao: dao.into(),
+            lp: lp.into(),
+        }
+    }
+
+    /// The total amount of the staking token issued in the epoch.
+    pub fn total(&self) -> Amount {
+        self.staking + self.dao + self.lp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issuance_is_split_by_share() {
+        let params = ChainParameters {
+            issuance_per_epoch: 1_000_000,
+            dao_issuance_bps: 500,
+            lp_issuance_bps: 1_000,
+            ..Default::default()
+        };
+
+        let budget = IssuanceBudget::from_chain_params(&params);
+        assert_eq!(budget.dao, 50_000u64.into());
+        assert_eq!(budget.lp, 100_000u64.into());
+        assert_eq!(budget.staking, 850_000u64.into());
+        assert_eq!(budget.total(), 1_000_000u64.into());
+    }
+}
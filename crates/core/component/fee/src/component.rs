@@ -1 +1,105 @@
-// TODO: fill in when implementing fees
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_component::TransactionError;
+use penumbra_num::Amount;
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::{state_key, Fee, Gas};
+
+/// Extension trait providing read access to fee data.
+#[async_trait]
+pub trait StateReadExt: StateRead {
+    /// Gets the assets, other than the staking token, that fees may be paid in.
+    async fn allowed_fee_assets(&self) -> Result<Vec<asset::Id>> {
+        Ok(self.get_chain_params().await?.allowed_fee_assets)
+    }
+
+    /// Checks that the `fee` is denominated in an asset that fees may be paid in.
+    ///
+    /// Fees can always be paid in the staking token; any other asset must be on
+    /// the governance-controlled allowlist in the chain parameters.
+    async fn check_fee_asset(&self, fee: &Fee) -> Result<()> {
+        let asset_id = fee.asset_id();
+        if asset_id == *STAKING_TOKEN_ASSET_ID {
+            return Ok(());
+        }
+
+        if !self.allowed_fee_assets().await?.contains(&asset_id) {
+            return Err(anyhow::anyhow!("fees cannot be paid in asset {}", asset_id)
+                .context(TransactionError::UnsupportedFeeAsset));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the `fee` is acceptable for a transaction using `gas`.
+    ///
+    /// The fee must be paid in an allowed asset, and must cover the price of the
+    /// gas at the current [`GasPrices`].  Fees paid in assets other than the
+    /// staking token are priced by converting the minimum fee into that asset at
+    /// the asset's [`FeeAssetRate`].
+    ///
+    /// [`GasPrices`]: penumbra_chain::params::GasPrices
+    /// [`FeeAssetRate`]: penumbra_chain::params::FeeAssetRate
+    async fn check_fee(&self, fee: &Fee, gas: Gas) -> Result<()> {
+        self.check_fee_asset(fee).await?;
+
+        let chain_params = self.get_chain_params().await?;
+        let minimum_fee = gas
+            .minimum_fee_in(&chain_params, fee.asset_id())
+            .ok_or_else(|| {
+                anyhow::anyhow!("no minimum fee can be computed in asset {}", fee.asset_id())
+                    .context(TransactionError::UnsupportedFeeAsset)
+            })?;
+
+        if fee.amount() < minimum_fee.amount() {
+            return Err(anyhow::anyhow!(
+                "fee {} is less than the minimum fee {} for {:?}",
+                fee.amount(),
+                minimum_fee.amount(),
+                gas
+            )
+            .context(TransactionError::InsufficientFee));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the fees paid by transactions in the current block so far, by asset.
+    fn collected_fees(&self) -> BTreeMap<asset::Id, Amount> {
+        self.object_get(state_key::collected_fees())
+            .unwrap_or_default()
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+/// Extension trait providing write access to fee data.
+#[async_trait]
+pub trait StateWriteExt: StateWrite + StateReadExt {
+    /// Records a fee paid by a transaction in the current block.
+    fn record_fee(&mut self, fee: &Fee) {
+        if fee.amount() == Amount::zero() {
+            return;
+        }
+
+        let mut collected_fees = self.collected_fees();
+        let total = collected_fees.entry(fee.asset_id()).or_default();
+        *total += fee.amount();
+        self.object_put(state_key::collected_fees(), collected_fees);
+    }
+
+    /// Takes the fees collected in the current block, so that they can be
+    /// converted to the staking token at the end of the block.
+    fn take_collected_fees(&mut self) -> BTreeMap<asset::Id, Amount> {
+        let collected_fees = self.collected_fees();
+        self.object_delete(state_key::collected_fees());
+        collected_fees
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
@@ -0,0 +1,229 @@
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Mul},
+};
+
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::params::{ChainParameters, GasPrices};
+use penumbra_num::Amount;
+
+use crate::Fee;
+
+/// The cost of verifying a zero-knowledge proof.
+pub const PROOF_VERIFICATION_COST: u64 = 1000;
+/// The cost of verifying a signature.
+pub const SIGNATURE_VERIFICATION_COST: u64 = 10;
+/// The cost of executing an action.
+pub const ACTION_EXECUTION_COST: u64 = 10;
+
+/// The quantity of each resource consumed by a transaction or action.
+///
+/// Each kind of gas is priced separately by the chain's [`GasPrices`], and the
+/// minimum fee of a transaction is the total price of the gas it uses.  Adding
+/// and multiplying gas saturates, rather than overflowing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Gas {
+    /// The number of bytes of block space used.
+    pub block_space: u64,
+    /// The number of bytes of compact block space used.
+    pub compact_block_space: u64,
+    /// The cost of verifying proofs and signatures.
+    pub verification: u64,
+    /// The cost of executing state changes.
+    pub execution: u64,
+}
+
+impl Gas {
+    /// Computes the price of this gas, in the staking token.
+    pub fn price(&self, prices: &GasPrices) -> Amount {
+        // Compute in u128 so that no realistic gas usage or price can overflow, and saturate so
+        // that an unrealistic one is priced as high as possible rather than wrapping around.
+        let price = [
+            (self.block_space, prices.block_space_price),
+            (self.compact_block_space, prices.compact_block_space_price),
+            (self.verification, prices.verification_price),
+            (self.execution, prices.execution_price),
+        ]
+        .into_iter()
+        .map(|(gas, price)| u128::from(gas).saturating_mul(u128::from(price)))
+        .fold(0u128, u128::saturating_add);
+
+        price.into()
+    }
+
+    /// Computes the minimum fee for this gas, paid in the staking token.
+    pub fn minimum_fee(&self, prices: &GasPrices) -> Fee {
+        Fee::from_staking_token_amount(self.price(prices))
+    }
+
+    /// Computes the minimum fee for this gas, paid in `asset_id`.
+    ///
+    /// Fees paid in an asset other than the staking token are priced by converting the minimum
+    /// fee in the staking token at the asset's rate.  Returns `None` if the asset has no rate, or
+    /// the conversion overflows.
+    pub fn minimum_fee_in(&self, params: &ChainParameters, asset_id: asset::Id) -> Option<Fee> {
+        let price = self.price(&params.gas_prices);
+        if asset_id == *STAKING_TOKEN_ASSET_ID {
+            return Some(Fee::from_staking_token_amount(price));
+        }
+
+        let amount = params.fee_asset_rate(&asset_id)?.convert(price)?;
+        Some(Fee(Value { amount, asset_id }))
+    }
+}
+
+/// The gas used by an action without a proof, whose encoding is `size` bytes.
+pub fn variable_gas_cost(size: usize) -> Gas {
+    Gas {
+        block_space: size as u64,
+        compact_block_space: 0,
+        verification: 0,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+/// The gas used by an action with a signature, whose encoding is `size` bytes.
+pub fn signed_gas_cost(size: usize) -> Gas {
+    Gas {
+        verification: SIGNATURE_VERIFICATION_COST,
+        ..variable_gas_cost(size)
+    }
+}
+
+impl Add for Gas {
+    type Output = Gas;
+
+    fn add(self, rhs: Gas) -> Gas {
+        Gas {
+            block_space: self.block_space.saturating_add(rhs.block_space),
+            compact_block_space: self
+                .compact_block_space
+                .saturating_add(rhs.compact_block_space),
+            verification: self.verification.saturating_add(rhs.verification),
+            execution: self.execution.saturating_add(rhs.execution),
+        }
+    }
+}
+
+impl AddAssign for Gas {
+    fn add_assign(&mut self, rhs: Gas) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul<u64> for Gas {
+    type Output = Gas;
+
+    fn mul(self, rhs: u64) -> Gas {
+        Gas {
+            block_space: self.block_space.saturating_mul(rhs),
+            compact_block_space: self.compact_block_space.saturating_mul(rhs),
+            verification: self.verification.saturating_mul(rhs),
+            execution: self.execution.saturating_mul(rhs),
+        }
+    }
+}
+
+impl Sum for Gas {
+    fn sum<I: Iterator<Item = Gas>>(iter: I) -> Gas {
+        iter.fold(Gas::default(), Add::add)
+    }
+}
+
+/// Implemented by anything that consumes gas when included in a transaction.
+pub trait GasCost {
+    /// The gas used by `self`.
+    fn gas_cost(&self) -> Gas;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn price_sums_each_kind_of_gas() {
+        let gas = Gas {
+            block_space: 100,
+            compact_block_space: 10,
+            verification: 1000,
+            execution: 5,
+        };
+        let prices = GasPrices {
+            block_space_price: 2,
+            compact_block_space_price: 3,
+            verification_price: 1,
+            execution_price: 7,
+        };
+
+        assert_eq!(gas.price(&prices), Amount::from(200u64 + 30 + 1000 + 35));
+        assert_eq!(gas.price(&GasPrices::default()), Amount::zero());
+        assert_eq!((gas * 2).price(&prices), Amount::from(2 * 1265u64));
+    }
+
+    #[test]
+    fn arithmetic_saturates() {
+        let gas = Gas {
+            block_space: u64::MAX - 1,
+            compact_block_space: 1,
+            verification: u64::MAX,
+            execution: 0,
+        };
+        let prices = GasPrices {
+            block_space_price: u64::MAX,
+            compact_block_space_price: u64::MAX,
+            verification_price: u64::MAX,
+            execution_price: u64::MAX,
+        };
+
+        let doubled = gas + gas;
+        assert_eq!(doubled.block_space, u64::MAX);
+        assert_eq!(doubled.compact_block_space, 2);
+        assert_eq!((gas * u64::MAX).compact_block_space, u64::MAX);
+        assert_eq!((gas * 3).verification, u64::MAX);
+        assert_eq!(gas.price(&prices), Amount::from(u128::MAX));
+    }
+
+    #[test]
+    fn minimum_fee_in_allowed_asset() {
+        use penumbra_chain::params::{FeeAssetRate, Ratio};
+
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .unwrap()
+            .id();
+        let params = ChainParameters {
+            allowed_fee_assets: vec![gm],
+            fee_asset_rates: vec![FeeAssetRate {
+                asset_id: gm,
+                rate: Ratio::new(5, 2),
+            }],
+            gas_prices: GasPrices {
+                block_space_price: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let gas = Gas {
+            block_space: 101,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            gas.minimum_fee_in(&params, *STAKING_TOKEN_ASSET_ID),
+            Some(Fee::from_staking_token_amount(101u64.into()))
+        );
+        // 101 * 5/2 = 252.5, rounded up.
+        assert_eq!(
+            gas.minimum_fee_in(&params, gm),
+            Some(Fee(Value {
+                amount: 253u64.into(),
+                asset_id: gm,
+            }))
+        );
+        assert_eq!(gas.minimum_fee_in(&params, gn), None);
+    }
+}
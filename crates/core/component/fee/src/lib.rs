@@ -8,5 +8,7 @@ pub mod event;
 pub mod state_key;
 
 mod fee;
+pub mod gas;
 
 pub use fee::Fee;
+pub use gas::{Gas, GasCost};
@@ -1 +1,4 @@
-// TODO: fill in when implementing fees
+/// The fees paid by transactions in the current block, by asset.
+pub fn collected_fees() -> &'static str {
+    "fee/collected_fees"
+}
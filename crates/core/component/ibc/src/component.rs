@@ -16,7 +16,13 @@ mod transfer;
 use msg_handler::MsgHandler;
 
 pub use self::metrics::register_metrics;
+pub use action_handler::IbcActionWithHandler;
+pub use app_handler::{
+    AppHandler, AppHandlerCheck, AppHandlerExecute, AppRouter, BoundAppHandler, NoRoute,
+};
 pub use channel::StateReadExt as ChannelStateReadExt;
 pub use client::StateReadExt as ClientStateReadExt;
+pub use client::StateWriteExt as ClientStateWriteExt;
 pub use connection::StateReadExt as ConnectionStateReadExt;
 pub use ibc_component::IBCComponent;
+pub use transfer::Ics20Transfer;
@@ -1,2 +1,4 @@
 mod ibc_action;
 mod ics20_withdrawal;
+
+pub use ibc_action::IbcActionWithHandler;
@@ -1,19 +1,38 @@
-use std::sync::Arc;
+use std::{marker::PhantomData, sync::Arc};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_component::ActionHandler;
 use penumbra_storage::{StateRead, StateWrite};
 
-use crate::{component::MsgHandler as LocalActionHandler, IbcAction};
+use crate::{
+    component::{app_handler::AppHandler, MsgHandler as LocalActionHandler},
+    IbcAction,
+};
+
+/// An [`IbcAction`] whose channel and packet events are handled by the IBC application `AH`.
+pub struct IbcActionWithHandler<'a, AH>(&'a IbcAction, PhantomData<fn() -> AH>);
+
+impl IbcAction {
+    /// Handle this action with the IBC application `AH`, usually an
+    /// [`AppRouter`](crate::component::AppRouter) of all the applications that the chain serves.
+    ///
+    /// The IBC component doesn't know which applications the chain serves, so [`IbcAction`]s
+    /// can only be checked and executed through the handler chosen by the application.
+    pub fn with_handler<AH: AppHandler>(&self) -> IbcActionWithHandler<'_, AH> {
+        IbcActionWithHandler(self, PhantomData)
+    }
+}
 
 #[async_trait]
-impl ActionHandler for IbcAction {
+impl<AH: AppHandler> ActionHandler for IbcActionWithHandler<'_, AH> {
     type CheckStatelessContext = ();
     async fn check_stateless(&self, _context: ()) -> Result<()> {
-        match self {
+        match self.0 {
             IbcAction::CreateClient(msg) => msg.check_stateless().await?,
             IbcAction::UpdateClient(msg) => msg.check_stateless().await?,
+            IbcAction::UpgradeClient(msg) => msg.check_stateless().await?,
+            IbcAction::SubmitMisbehaviour(msg) => msg.check_stateless().await?,
             IbcAction::ConnectionOpenInit(msg) => msg.check_stateless().await?,
             IbcAction::ConnectionOpenTry(msg) => msg.check_stateless().await?,
             IbcAction::ConnectionOpenAck(msg) => msg.check_stateless().await?,
@@ -44,22 +63,24 @@ impl ActionHandler for IbcAction {
     }
 
     async fn execute<S: StateWrite>(&self, state: S) -> Result<()> {
-        match self {
-            IbcAction::CreateClient(msg) => msg.try_execute(state).await?,
-            IbcAction::UpdateClient(msg) => msg.try_execute(state).await?,
-            IbcAction::ConnectionOpenInit(msg) => msg.try_execute(state).await?,
-            IbcAction::ConnectionOpenTry(msg) => msg.try_execute(state).await?,
-            IbcAction::ConnectionOpenAck(msg) => msg.try_execute(state).await?,
-            IbcAction::ConnectionOpenConfirm(msg) => msg.try_execute(state).await?,
-            IbcAction::ChannelOpenInit(msg) => msg.try_execute(state).await?,
-            IbcAction::ChannelOpenTry(msg) => msg.try_execute(state).await?,
-            IbcAction::ChannelOpenAck(msg) => msg.try_execute(state).await?,
-            IbcAction::ChannelOpenConfirm(msg) => msg.try_execute(state).await?,
-            IbcAction::ChannelCloseInit(msg) => msg.try_execute(state).await?,
-            IbcAction::ChannelCloseConfirm(msg) => msg.try_execute(state).await?,
-            IbcAction::RecvPacket(msg) => msg.try_execute(state).await?,
-            IbcAction::Acknowledgement(msg) => msg.try_execute(state).await?,
-            IbcAction::Timeout(msg) => msg.try_execute(state).await?,
+        match self.0 {
+            IbcAction::CreateClient(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::UpdateClient(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::UpgradeClient(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::SubmitMisbehaviour(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ConnectionOpenInit(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ConnectionOpenTry(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ConnectionOpenAck(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ConnectionOpenConfirm(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ChannelOpenInit(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ChannelOpenTry(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ChannelOpenAck(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ChannelOpenConfirm(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ChannelCloseInit(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::ChannelCloseConfirm(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::RecvPacket(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::Acknowledgement(msg) => msg.try_execute::<S, AH>(state).await?,
+            IbcAction::Timeout(msg) => msg.try_execute::<S, AH>(state).await?,
             IbcAction::Unknown(msg) => {
                 return Err(anyhow::anyhow!(
                     "unknown IBC message type: {}",
@@ -5,12 +5,18 @@
 /// that they have subscribed to, and apply application-specific state transition logic.
 ///
 /// The primary IBC application is the Ics20 transfer application, which allows for interchain
-/// token transfers.
+/// token transfers. Multiple applications can be served at once by registering each of them,
+/// keyed by the port they are bound to, with an [`AppRouter`].
+use std::marker::PhantomData;
+
 use anyhow::Result;
 use async_trait::async_trait;
-use ibc_types::core::channel::msgs::{
-    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+use ibc_types::core::channel::{
+    msgs::{
+        MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+        MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    },
+    PortId,
 };
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -46,16 +52,634 @@ pub trait AppHandlerCheck: Send + Sync {
 // once the transaction has been validated using the AppHandlerCheck interface.
 #[async_trait]
 pub trait AppHandlerExecute: Send + Sync {
-    async fn chan_open_init_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenInit);
-    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry);
-    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck);
-    async fn chan_open_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenConfirm);
-    async fn chan_close_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseConfirm);
-    async fn chan_close_init_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseInit);
+    async fn chan_open_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenInit,
+    ) -> Result<()>;
+    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry) -> Result<()>;
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) -> Result<()>;
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()>;
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()>;
+    async fn chan_close_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()>;
 
-    async fn recv_packet_execute<S: StateWrite>(state: S, msg: &MsgRecvPacket);
-    async fn timeout_packet_execute<S: StateWrite>(state: S, msg: &MsgTimeout);
-    async fn acknowledge_packet_execute<S: StateWrite>(state: S, msg: &MsgAcknowledgement);
+    async fn recv_packet_execute<S: StateWrite>(state: S, msg: &MsgRecvPacket) -> Result<()>;
+    async fn timeout_packet_execute<S: StateWrite>(state: S, msg: &MsgTimeout) -> Result<()>;
+    async fn acknowledge_packet_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()>;
 }
 
 pub trait AppHandler: AppHandlerCheck + AppHandlerExecute {}
+
+/// An IBC application bound to a single port, which can be registered with an [`AppRouter`].
+pub trait BoundAppHandler: AppHandler {
+    /// The port that the application is bound to.
+    fn port_id() -> PortId;
+}
+
+/// AppRouter routes IBC channel and packet events to the application bound to the port that they
+/// occur on.
+///
+/// Routers are built by chaining applications: `AppRouter<Ics20Transfer, AppRouter<MyApp>>` routes
+/// events on the `transfer` port to `Ics20Transfer`, events on the port of `MyApp` to `MyApp`, and
+/// rejects events on any other port.
+pub struct AppRouter<H, R = NoRoute>(PhantomData<fn() -> (H, R)>);
+
+/// NoRoute terminates a chain of [`AppRouter`]s, rejecting events on any port.
+pub struct NoRoute;
+
+/// The port on this chain that a channel or packet message is handled on.
+trait LocalPort {
+    fn local_port(&self) -> &PortId;
+}
+
+impl LocalPort for MsgChannelOpenInit {
+    fn local_port(&self) -> &PortId {
+        &self.port_id_on_a
+    }
+}
+
+impl LocalPort for MsgChannelOpenTry {
+    fn local_port(&self) -> &PortId {
+        &self.port_id_on_b
+    }
+}
+
+impl LocalPort for MsgChannelOpenAck {
+    fn local_port(&self) -> &PortId {
+        &self.port_id_on_a
+    }
+}
+
+impl LocalPort for MsgChannelOpenConfirm {
+    fn local_port(&self) -> &PortId {
+        &self.port_id_on_b
+    }
+}
+
+impl LocalPort for MsgChannelCloseInit {
+    fn local_port(&self) -> &PortId {
+        &self.port_id_on_a
+    }
+}
+
+impl LocalPort for MsgChannelCloseConfirm {
+    fn local_port(&self) -> &PortId {
+        &self.port_id_on_b
+    }
+}
+
+impl LocalPort for MsgRecvPacket {
+    fn local_port(&self) -> &PortId {
+        &self.packet.port_on_b
+    }
+}
+
+impl LocalPort for MsgTimeout {
+    fn local_port(&self) -> &PortId {
+        &self.packet.port_on_a
+    }
+}
+
+impl LocalPort for MsgAcknowledgement {
+    fn local_port(&self) -> &PortId {
+        &self.packet.port_on_a
+    }
+}
+
+#[async_trait]
+impl<H: BoundAppHandler, R: AppHandler> AppHandlerCheck for AppRouter<H, R> {
+    async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_init_check(state, msg).await
+        } else {
+            R::chan_open_init_check(state, msg).await
+        }
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_try_check(state, msg).await
+        } else {
+            R::chan_open_try_check(state, msg).await
+        }
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_ack_check(state, msg).await
+        } else {
+            R::chan_open_ack_check(state, msg).await
+        }
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_confirm_check(state, msg).await
+        } else {
+            R::chan_open_confirm_check(state, msg).await
+        }
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_close_confirm_check(state, msg).await
+        } else {
+            R::chan_close_confirm_check(state, msg).await
+        }
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_close_init_check(state, msg).await
+        } else {
+            R::chan_close_init_check(state, msg).await
+        }
+    }
+
+    async fn recv_packet_check<S: StateRead>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::recv_packet_check(state, msg).await
+        } else {
+            R::recv_packet_check(state, msg).await
+        }
+    }
+
+    async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::timeout_packet_check(state, msg).await
+        } else {
+            R::timeout_packet_check(state, msg).await
+        }
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::acknowledge_packet_check(state, msg).await
+        } else {
+            R::acknowledge_packet_check(state, msg).await
+        }
+    }
+}
+
+#[async_trait]
+impl<H: BoundAppHandler, R: AppHandler> AppHandlerExecute for AppRouter<H, R> {
+    async fn chan_open_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_init_execute(state, msg).await
+        } else {
+            R::chan_open_init_execute(state, msg).await
+        }
+    }
+
+    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_try_execute(state, msg).await
+        } else {
+            R::chan_open_try_execute(state, msg).await
+        }
+    }
+
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_ack_execute(state, msg).await
+        } else {
+            R::chan_open_ack_execute(state, msg).await
+        }
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_open_confirm_execute(state, msg).await
+        } else {
+            R::chan_open_confirm_execute(state, msg).await
+        }
+    }
+
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_close_confirm_execute(state, msg).await
+        } else {
+            R::chan_close_confirm_execute(state, msg).await
+        }
+    }
+
+    async fn chan_close_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::chan_close_init_execute(state, msg).await
+        } else {
+            R::chan_close_init_execute(state, msg).await
+        }
+    }
+
+    async fn recv_packet_execute<S: StateWrite>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::recv_packet_execute(state, msg).await
+        } else {
+            R::recv_packet_execute(state, msg).await
+        }
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(state: S, msg: &MsgTimeout) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::timeout_packet_execute(state, msg).await
+        } else {
+            R::timeout_packet_execute(state, msg).await
+        }
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        if msg.local_port() == &H::port_id() {
+            H::acknowledge_packet_execute(state, msg).await
+        } else {
+            R::acknowledge_packet_execute(state, msg).await
+        }
+    }
+}
+
+impl<H: BoundAppHandler, R: AppHandler> AppHandler for AppRouter<H, R> {}
+
+fn no_route(port_id: &PortId) -> anyhow::Error {
+    anyhow::anyhow!("no IBC application is bound to port {port_id}")
+}
+
+#[async_trait]
+impl AppHandlerCheck for NoRoute {
+    async fn chan_open_init_check<S: StateRead>(_state: S, msg: &MsgChannelOpenInit) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_open_try_check<S: StateRead>(_state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(_state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        _state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        _state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        _state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn recv_packet_check<S: StateRead>(_state: S, msg: &MsgRecvPacket) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn timeout_packet_check<S: StateRead>(_state: S, msg: &MsgTimeout) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        _state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+}
+
+// The IBC component always runs the check for an event before executing it, so an unrouted event
+// should be rejected before it can reach any of these, but they fail rather than trusting that.
+#[async_trait]
+impl AppHandlerExecute for NoRoute {
+    async fn chan_open_init_execute<S: StateWrite>(
+        _state: S,
+        msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_open_try_execute<S: StateWrite>(
+        _state: S,
+        msg: &MsgChannelOpenTry,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_open_ack_execute<S: StateWrite>(
+        _state: S,
+        msg: &MsgChannelOpenAck,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        _state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        _state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn chan_close_init_execute<S: StateWrite>(
+        _state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn recv_packet_execute<S: StateWrite>(_state: S, msg: &MsgRecvPacket) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(_state: S, msg: &MsgTimeout) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(
+        _state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        Err(no_route(msg.local_port()))
+    }
+}
+
+impl AppHandler for NoRoute {}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ibc_types::core::{
+        channel::{ChannelId, Packet},
+        client::Height,
+        commitment::MerkleProof,
+    };
+    use penumbra_storage::StateDelta;
+
+    use super::*;
+
+    trait TestPort: Send + Sync + 'static {
+        const PORT: &'static str;
+    }
+
+    struct PortA;
+
+    impl TestPort for PortA {
+        const PORT: &'static str = "port-a";
+    }
+
+    struct PortB;
+
+    impl TestPort for PortB {
+        const PORT: &'static str = "port-b";
+    }
+
+    /// An application bound to the port `P`, which accepts every event and records the packets
+    /// that it receives.
+    struct TestApp<P>(PhantomData<P>);
+
+    type TestRouter = AppRouter<TestApp<PortA>, AppRouter<TestApp<PortB>>>;
+
+    fn received_key(port: &str) -> String {
+        format!("test/{port}/received")
+    }
+
+    #[async_trait]
+    impl<P: TestPort> AppHandlerCheck for TestApp<P> {
+        async fn chan_open_init_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenInit,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_try_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenTry,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_ack_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenAck,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_confirm_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenConfirm,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_close_confirm_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelCloseConfirm,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_close_init_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelCloseInit,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn recv_packet_check<S: StateRead>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
+            Ok(())
+        }
+        async fn timeout_packet_check<S: StateRead>(_state: S, _msg: &MsgTimeout) -> Result<()> {
+            Ok(())
+        }
+        async fn acknowledge_packet_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgAcknowledgement,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl<P: TestPort> AppHandlerExecute for TestApp<P> {
+        async fn chan_open_init_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenInit,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_try_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenTry,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_ack_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenAck,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_confirm_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenConfirm,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_close_confirm_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelCloseConfirm,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_close_init_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelCloseInit,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn recv_packet_execute<S: StateWrite>(
+            mut state: S,
+            _msg: &MsgRecvPacket,
+        ) -> Result<()> {
+            state.put_raw(received_key(P::PORT), vec![]);
+            Ok(())
+        }
+        async fn timeout_packet_execute<S: StateWrite>(_state: S, _msg: &MsgTimeout) -> Result<()> {
+            Ok(())
+        }
+        async fn acknowledge_packet_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgAcknowledgement,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<P: TestPort> AppHandler for TestApp<P> {}
+
+    impl<P: TestPort> BoundAppHandler for TestApp<P> {
+        fn port_id() -> PortId {
+            PortId::from_str(P::PORT).unwrap()
+        }
+    }
+
+    // returns a message receiving a packet on the port `port` of this chain.
+    fn recv_packet(port: &str) -> MsgRecvPacket {
+        MsgRecvPacket {
+            packet: Packet {
+                chan_on_a: ChannelId::new(0),
+                port_on_a: PortId::transfer(),
+                sequence: 1u64.into(),
+                chan_on_b: ChannelId::new(0),
+                port_on_b: PortId::from_str(port).unwrap(),
+                timeout_height_on_b: Height::new(0, 100).unwrap().into(),
+                timeout_timestamp_on_b: ibc_types::timestamp::Timestamp::from_nanoseconds(
+                    1_000_000,
+                )
+                .unwrap(),
+                data: vec![],
+            },
+            proof_commitment_on_a: MerkleProof { proofs: vec![] },
+            proof_height_on_a: Height::new(0, 1).unwrap(),
+            signer: String::new(),
+        }
+    }
+
+    // test that the router hands packets to the application bound to the port they are received
+    // on, and to no other application.
+    #[tokio::test]
+    async fn router_dispatches_packets_by_port() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+
+        let msg = recv_packet(PortB::PORT);
+        TestRouter::recv_packet_check(&state, &msg).await?;
+        TestRouter::recv_packet_execute(&mut state, &msg).await?;
+
+        assert!(state.get_raw(&received_key(PortA::PORT)).await?.is_none());
+        assert!(state.get_raw(&received_key(PortB::PORT)).await?.is_some());
+
+        let msg = recv_packet(PortA::PORT);
+        TestRouter::recv_packet_check(&state, &msg).await?;
+        TestRouter::recv_packet_execute(&mut state, &msg).await?;
+
+        assert!(state.get_raw(&received_key(PortA::PORT)).await?.is_some());
+
+        Ok(())
+    }
+
+    // test that packets received on a port that no application is bound to are rejected, both when
+    // checked and, should the check be skipped, when executed.
+    #[tokio::test]
+    async fn router_rejects_packets_without_route() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+        let msg = recv_packet("port-c");
+
+        let error = TestRouter::recv_packet_check(&state, &msg)
+            .await
+            .expect_err("packets without a route are rejected");
+        assert_eq!(
+            error.to_string(),
+            "no IBC application is bound to port port-c"
+        );
+
+        let error = TestRouter::recv_packet_execute(&mut state, &msg)
+            .await
+            .expect_err("packets without a route can't be executed");
+        assert_eq!(
+            error.to_string(),
+            "no IBC application is bound to port port-c"
+        );
+
+        assert!(state.get_raw(&received_key(PortA::PORT)).await?.is_none());
+        assert!(state.get_raw(&received_key(PortB::PORT)).await?.is_none());
+
+        Ok(())
+    }
+}
@@ -187,6 +187,51 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
 
         Ok(())
     }
+
+    // replaces the state of a frozen or expired client with the latest state of a healthy
+    // substitute client, as authorized by a passed governance proposal. the subject client keeps
+    // its identifier, so the connections and channels that depend on it can be used again.
+    //
+    // see: https://github.com/cosmos/ibc-go/blob/main/docs/architecture/adr-026-ibc-client-recovery-mechanisms.md
+    async fn recover_client(
+        &mut self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+    ) -> Result<()> {
+        self.check_client_recovery(subject_client_id, substitute_client_id)
+            .await?;
+
+        let subject_client_state = self.get_client_state(subject_client_id).await?;
+        let substitute_client_state = self.get_client_state(substitute_client_id).await?;
+        let substitute_consensus_state = self
+            .get_verified_consensus_state(
+                substitute_client_state.latest_height(),
+                substitute_client_id.clone(),
+            )
+            .await?;
+
+        let mut recovered_client_state = subject_client_state;
+        recovered_client_state.chain_id = substitute_client_state.chain_id.clone();
+        recovered_client_state.latest_height = substitute_client_state.latest_height();
+        recovered_client_state.trusting_period = substitute_client_state.trusting_period;
+        recovered_client_state.frozen_height = None;
+
+        self.put_client(subject_client_id, recovered_client_state);
+        self.put_verified_consensus_state(
+            substitute_client_state.latest_height(),
+            subject_client_id.clone(),
+            substitute_consensus_state,
+        )
+        .await?;
+
+        tracing::info!(
+            %subject_client_id,
+            %substitute_client_id,
+            "recovered client from substitute"
+        );
+
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
@@ -212,6 +257,80 @@ pub trait StateReadExt: StateRead {
         client_state.context(format!("could not find client state for {client_id}"))
     }
 
+    // returns whether the client's latest consensus state is older than its trusting period, in
+    // which case the client can no longer be updated or used to verify proofs.
+    async fn client_is_expired(
+        &self,
+        client_id: &ClientId,
+        client_state: &TendermintClientState,
+    ) -> Result<bool> {
+        let latest_consensus_state = self
+            .get_verified_consensus_state(client_state.latest_height(), client_id.clone())
+            .await?;
+
+        let now = self.get_block_timestamp().await?;
+        let time_elapsed = now.duration_since(latest_consensus_state.timestamp)?;
+
+        Ok(client_state.expired(time_elapsed))
+    }
+
+    // checks that the subject client of a client recovery can be replaced by the substitute
+    // client: the subject must be frozen or expired, the substitute must be active and ahead of
+    // the subject, and both must track the same counterparty chain with the same parameters,
+    // except for those that a recovery is allowed to change.
+    async fn check_client_recovery(
+        &self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+    ) -> Result<()> {
+        if subject_client_id == substitute_client_id {
+            anyhow::bail!("subject and substitute clients must be different");
+        }
+
+        let subject_client_state = self.get_client_state(subject_client_id).await?;
+        let substitute_client_state = self.get_client_state(substitute_client_id).await?;
+
+        if !subject_client_state.is_frozen()
+            && !self
+                .client_is_expired(subject_client_id, &subject_client_state)
+                .await?
+        {
+            anyhow::bail!("subject client {subject_client_id} is active and cannot be recovered");
+        }
+
+        if substitute_client_state.is_frozen() {
+            anyhow::bail!("substitute client {substitute_client_id} is frozen");
+        }
+        if self
+            .client_is_expired(substitute_client_id, &substitute_client_state)
+            .await?
+        {
+            anyhow::bail!("substitute client {substitute_client_id} is expired");
+        }
+
+        if substitute_client_state.latest_height() <= subject_client_state.latest_height() {
+            anyhow::bail!(
+                "substitute client latest height {} must be greater than subject client latest height {}",
+                substitute_client_state.latest_height(),
+                subject_client_state.latest_height()
+            );
+        }
+
+        // the chain id, latest height, trusting period and frozen height are allowed to differ;
+        // every other parameter of the two clients must match.
+        let mut expected_client_state = subject_client_state;
+        expected_client_state.chain_id = substitute_client_state.chain_id.clone();
+        expected_client_state.latest_height = substitute_client_state.latest_height();
+        expected_client_state.trusting_period = substitute_client_state.trusting_period;
+        expected_client_state.frozen_height = None;
+
+        if expected_client_state != substitute_client_state {
+            anyhow::bail!("subject and substitute client states do not match");
+        }
+
+        Ok(())
+    }
+
     async fn get_verified_heights(&self, client_id: &ClientId) -> Result<Option<VerifiedHeights>> {
         self.get(&format!(
             // NOTE: this is an implementation detail of the Penumbra ICS2 implementation, so
@@ -352,17 +471,289 @@ impl<T: StateRead + ?Sized> StateReadExt for T {}
 mod tests {
     use std::sync::Arc;
 
+    use super::StateWriteExt as _;
     use super::*;
-    use ibc_types::core::client::msgs::MsgUpdateClient;
+    use ibc_proto::google::protobuf::Any;
+    use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour;
+    use ibc_proto::ibc::lightclients::tendermint::v1::{
+        Header as RawTendermintHeader, Misbehaviour as RawTendermintMisbehaviour,
+    };
+    use ibc_types::core::channel::{msgs::MsgRecvPacket, ChannelId, Packet, PortId};
+    use ibc_types::core::client::msgs::{MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient};
+    use ibc_types::core::commitment::{MerkleProof, MerkleRoot};
+    use ibc_types::core::connection::{
+        ChainId, ConnectionEnd, ConnectionId, Counterparty as ConnectionCounterparty,
+        State as ConnectionState, Version as ConnectionVersion,
+    };
     use ibc_types::{core::client::msgs::MsgCreateClient, DomainType};
-    use penumbra_chain::component::StateWriteExt;
+    use penumbra_chain::component::{
+        AppHashRead, StateWriteExt, PENUMBRA_COMMITMENT_PREFIX, PENUMBRA_PROOF_SPECS,
+    };
     use penumbra_component::ActionHandler;
-    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
+    use prost::Message;
     use std::str::FromStr;
     use tendermint::Time;
 
+    use crate::component::client_counter::ics02_validation;
+    use crate::component::proof_verification::{self, PacketProofVerifier};
+    use crate::component::{AppRouter, Ics20Transfer};
     use crate::IbcAction;
 
+    /// A chain with a single validator, whose headers can be signed in tests.
+    struct TestChain {
+        chain_id: tendermint::chain::Id,
+        signing_key: ed25519_consensus::SigningKey,
+        validator: tendermint::validator::Info,
+        validators: tendermint::validator::Set,
+    }
+
+    impl TestChain {
+        fn new(chain_id: tendermint::chain::Id) -> Self {
+            let signing_key = ed25519_consensus::SigningKey::from([7u8; 32]);
+            let public_key =
+                tendermint::PublicKey::from_raw_ed25519(signing_key.verification_key().as_bytes())
+                    .expect("valid ed25519 key");
+            let validator = tendermint::validator::Info::new(public_key, 10u32.into());
+            let validators =
+                tendermint::validator::Set::new(vec![validator.clone()], Some(validator.clone()));
+
+            Self {
+                chain_id,
+                signing_key,
+                validator,
+                validators,
+            }
+        }
+
+        // returns a header of the chain at `height`, signed by its validator, which commits to
+        // `app_hash` so that different headers of the same height conflict.
+        fn header(
+            &self,
+            height: Height,
+            trusted_height: Height,
+            time: Time,
+            app_hash: u8,
+        ) -> anyhow::Result<TendermintHeader> {
+            use tendermint::block::{self, signed_header::SignedHeader};
+
+            let header = block::Header {
+                version: block::header::Version { block: 11, app: 0 },
+                chain_id: self.chain_id.clone(),
+                height: height.revision_height().try_into()?,
+                time,
+                last_block_id: None,
+                last_commit_hash: None,
+                data_hash: None,
+                validators_hash: self.validators.hash(),
+                next_validators_hash: self.validators.hash(),
+                consensus_hash: tendermint::Hash::Sha256([0; 32]),
+                app_hash: vec![app_hash; 32].try_into()?,
+                last_results_hash: None,
+                evidence_hash: None,
+                proposer_address: self.validator.address,
+            };
+            let block_id = block::Id {
+                hash: header.hash(),
+                part_set_header: block::parts::Header::new(1, tendermint::Hash::Sha256([0; 32]))?,
+            };
+
+            let vote = tendermint::vote::Vote {
+                vote_type: tendermint::vote::Type::Precommit,
+                height: header.height,
+                round: block::Round::default(),
+                block_id: Some(block_id),
+                timestamp: Some(time),
+                validator_address: self.validator.address,
+                validator_index: 0u32.try_into()?,
+                signature: None,
+            };
+            let sign_bytes = vote
+                .to_signable_vec(self.chain_id.clone())
+                .expect("vote can be encoded");
+            let signature = self.signing_key.sign(&sign_bytes);
+
+            let commit = block::Commit {
+                height: header.height,
+                round: block::Round::default(),
+                block_id,
+                signatures: vec![block::CommitSig::BlockIdFlagCommit {
+                    validator_address: self.validator.address,
+                    timestamp: time,
+                    signature: tendermint::Signature::new(signature.to_bytes())?,
+                }],
+            };
+
+            Ok(TendermintHeader {
+                signed_header: SignedHeader::new(header, commit)?,
+                validator_set: self.validators.clone(),
+                trusted_height,
+                trusted_validator_set: self.validators.clone(),
+            })
+        }
+    }
+
+    // sets up the chain state needed to handle client messages, as of the block at `timestamp`.
+    fn put_test_chain_state<S: StateWrite>(mut state: S, timestamp: Time) {
+        state.put_chain_params(Default::default());
+        state.put_block_height(1);
+        state.put_epoch_by_height(
+            1,
+            penumbra_chain::Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+        state.put_block_timestamp(timestamp);
+    }
+
+    // stores a client of the stargaze chain, as created in `test_create_and_update_light_client`,
+    // except that its latest consensus state is from `timestamp` and trusts `chain`'s validators.
+    async fn put_test_client<S: StateWrite>(
+        mut state: S,
+        client_id: &ClientId,
+        chain: &TestChain,
+        timestamp: Time,
+    ) -> anyhow::Result<TendermintClientState> {
+        let msg_create_client_stargaze_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let msg_create_client = MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice())?;
+
+        let client_state =
+            ics02_validation::get_tendermint_client_state(msg_create_client.client_state)?;
+        let mut consensus_state =
+            ics02_validation::get_tendermint_consensus_state(msg_create_client.consensus_state)?;
+        consensus_state.timestamp = timestamp;
+        consensus_state.next_validators_hash = chain.validators.hash();
+
+        state.put_client(client_id, client_state.clone());
+        state
+            .put_verified_consensus_state(
+                client_state.latest_height(),
+                client_id.clone(),
+                consensus_state,
+            )
+            .await?;
+
+        Ok(client_state)
+    }
+
+    // returns a message receiving a packet over a connection using the client `client_id`, whose
+    // proof has to be verified against the client.
+    fn test_recv_packet(
+        client_id: &ClientId,
+        proof_height: Height,
+    ) -> (ConnectionEnd, MsgRecvPacket) {
+        let connection = ConnectionEnd {
+            state: ConnectionState::Open,
+            client_id: client_id.clone(),
+            counterparty: ConnectionCounterparty {
+                client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                connection_id: Some(ConnectionId::new(0)),
+                prefix: PENUMBRA_COMMITMENT_PREFIX.clone(),
+            },
+            versions: vec![ConnectionVersion::default()],
+            delay_period: std::time::Duration::ZERO,
+        };
+        let msg = MsgRecvPacket {
+            packet: Packet {
+                chan_on_a: ChannelId::new(0),
+                port_on_a: PortId::transfer(),
+                sequence: 1u64.into(),
+                chan_on_b: ChannelId::new(0),
+                port_on_b: PortId::transfer(),
+                timeout_height_on_b: Height::new(0, 100).unwrap().into(),
+                timeout_timestamp_on_b: ibc_types::timestamp::Timestamp::from_nanoseconds(
+                    1_000_000,
+                )
+                .unwrap(),
+                data: vec![],
+            },
+            proof_commitment_on_a: MerkleProof { proofs: vec![] },
+            proof_height_on_a: proof_height,
+            signer: String::new(),
+        };
+
+        (connection, msg)
+    }
+
+    // returns the stargaze client state of `put_test_client`, changed to verify proofs of a
+    // counterparty whose state is committed like Penumbra's, along with the client state and
+    // consensus state that the counterparty upgrades to.
+    fn test_upgrade() -> anyhow::Result<(
+        TendermintClientState,
+        TendermintClientState,
+        TendermintConsensusState,
+    )> {
+        let msg_create_client_stargaze_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let msg_create_client = MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice())?;
+
+        let mut client_state =
+            ics02_validation::get_tendermint_client_state(msg_create_client.client_state)?;
+        client_state.proof_specs = PENUMBRA_PROOF_SPECS.clone();
+        client_state.upgrade_path = vec![
+            String::from_utf8(PENUMBRA_COMMITMENT_PREFIX.key_prefix.clone())?,
+            "upgradedIBCState".to_string(),
+        ];
+
+        let mut upgraded_client_state = client_state.clone();
+        upgraded_client_state.chain_id = ChainId::from_string("stargaze-2");
+        upgraded_client_state.latest_height = Height::new(2, 1).unwrap();
+
+        let mut upgraded_consensus_state =
+            ics02_validation::get_tendermint_consensus_state(msg_create_client.consensus_state)?;
+        upgraded_consensus_state.timestamp = Time::parse_from_rfc3339("2022-02-11T17:30:00Z")?;
+
+        Ok((
+            client_state,
+            upgraded_client_state,
+            upgraded_consensus_state,
+        ))
+    }
+
+    // commits the upgraded client and consensus states to the state of a counterparty chain, under
+    // the upgrade path of `client_state` as of its latest height, and returns the root of the
+    // counterparty's state along with proofs of both upgraded states.
+    async fn commit_upgrade(
+        client_state: &TendermintClientState,
+        upgraded_client_state: &Any,
+        upgraded_consensus_state: &Any,
+    ) -> anyhow::Result<(MerkleRoot, MerkleProof, MerkleProof)> {
+        let upgrade_key = |upgraded_key: &str| {
+            format!(
+                "{}/{}/{upgraded_key}",
+                client_state.upgrade_path[1],
+                client_state.latest_height().revision_height()
+            )
+        };
+
+        let storage = TempStorage::new().await?;
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw(
+            upgrade_key("upgradedClient"),
+            upgraded_client_state.encode_to_vec(),
+        );
+        delta.put_raw(
+            upgrade_key("upgradedConsState"),
+            upgraded_consensus_state.encode_to_vec(),
+        );
+        storage.commit(delta).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let (_, proof_upgrade_client) = snapshot
+            .get_with_proof_to_apphash(upgrade_key("upgradedClient").into_bytes())
+            .await?;
+        let (_, proof_upgrade_consensus_state) = snapshot
+            .get_with_proof_to_apphash(upgrade_key("upgradedConsState").into_bytes())
+            .await?;
+        let root = MerkleRoot {
+            hash: snapshot.app_hash().await?.0.to_vec(),
+        };
+
+        Ok((root, proof_upgrade_client, proof_upgrade_consensus_state))
+    }
+
     // test that we can create and update a light client.
     #[tokio::test]
     async fn test_create_and_update_light_client() -> anyhow::Result<()> {
@@ -430,9 +821,12 @@ mod tests {
             MsgUpdateClient::decode(msg_update_client_stargaze_raw.as_slice()).unwrap();
 
         msg_update_stargaze_client.client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let first_update = msg_update_stargaze_client.clone();
 
-        let create_client_action = IbcAction::CreateClient(msg_create_stargaze_client);
-        let update_client_action = IbcAction::UpdateClient(msg_update_stargaze_client);
+        let create_client = IbcAction::CreateClient(msg_create_stargaze_client);
+        let create_client_action = create_client.with_handler::<AppRouter<Ics20Transfer>>();
+        let update_client = IbcAction::UpdateClient(msg_update_stargaze_client);
+        let update_client_action = update_client.with_handler::<AppRouter<Ics20Transfer>>();
 
         create_client_action.check_stateless(()).await?;
         create_client_action.check_stateful(state.clone()).await?;
@@ -457,7 +851,9 @@ mod tests {
 
         let mut second_update = MsgUpdateClient::decode(msg_update_second.as_slice()).unwrap();
         second_update.client_id = ClientId::from_str("07-tendermint-0").unwrap();
-        let second_update_client_action = IbcAction::UpdateClient(second_update);
+        let second_update_client = IbcAction::UpdateClient(second_update.clone());
+        let second_update_client_action =
+            second_update_client.with_handler::<AppRouter<Ics20Transfer>>();
 
         second_update_client_action.check_stateless(()).await?;
         second_update_client_action
@@ -467,6 +863,409 @@ mod tests {
         second_update_client_action.execute(&mut state_tx).await?;
         state_tx.apply();
 
+        // The two updates are consistent with each other, so submitting them as misbehaviour
+        // should be rejected, and leave the client unfrozen.
+        let header_1 = RawTendermintHeader::decode(second_update.header.value.as_slice())?;
+        let header_2 = RawTendermintHeader::decode(first_update.header.value.as_slice())?;
+        let raw_misbehaviour = RawMsgSubmitMisbehaviour {
+            client_id: "07-tendermint-0".to_string(),
+            misbehaviour: Some(ibc_proto::google::protobuf::Any {
+                type_url: ics02_validation::TENDERMINT_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: RawTendermintMisbehaviour {
+                    client_id: "07-tendermint-0".to_string(),
+                    header_1: Some(header_1),
+                    header_2: Some(header_2),
+                }
+                .encode_to_vec(),
+            }),
+            signer: String::new(),
+        };
+        let misbehaviour = IbcAction::SubmitMisbehaviour(MsgSubmitMisbehaviour::decode(
+            raw_misbehaviour.encode_to_vec().as_slice(),
+        )?);
+        let misbehaviour_action = misbehaviour.with_handler::<AppRouter<Ics20Transfer>>();
+
+        assert!(misbehaviour_action.check_stateless(()).await.is_err());
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(misbehaviour_action.execute(&mut state_tx).await.is_err());
+        drop(state_tx);
+
+        let client_state = state
+            .get_client_state(&ClientId::from_str("07-tendermint-0").unwrap())
+            .await?;
+        assert!(!client_state.is_frozen());
+
+        Ok(())
+    }
+
+    // test that a frozen client can be recovered from an active substitute client.
+    #[tokio::test]
+    async fn test_recover_frozen_client() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        {
+            let mut state_tx = state.try_begin_transaction().unwrap();
+            state_tx.put_chain_params(Default::default());
+            state_tx.put_block_height(1);
+            state_tx.put_epoch_by_height(
+                1,
+                penumbra_chain::Epoch {
+                    index: 0,
+                    start_height: 0,
+                },
+            );
+            state_tx
+                .put_block_timestamp(Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?);
+            state_tx.apply();
+        }
+
+        let subject_client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let substitute_client_id = ClientId::from_str("07-tendermint-1").unwrap();
+
+        // create two clients of the same chain, and move the substitute ahead of the subject.
+        let msg_create_client_stargaze_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let create_client = IbcAction::CreateClient(MsgCreateClient::decode(
+            msg_create_client_stargaze_raw.as_slice(),
+        )?);
+        let create_client_action = create_client.with_handler::<AppRouter<Ics20Transfer>>();
+        let msg_update_client_stargaze_raw =
+            base64::decode(include_str!("./test/update_client_1.msg").replace('\n', "")).unwrap();
+        let mut msg_update_stargaze_client =
+            MsgUpdateClient::decode(msg_update_client_stargaze_raw.as_slice())?;
+        msg_update_stargaze_client.client_id = substitute_client_id.clone();
+        let update_client = IbcAction::UpdateClient(msg_update_stargaze_client);
+        let update_client_action = update_client.with_handler::<AppRouter<Ics20Transfer>>();
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        create_client_action.execute(&mut state_tx).await?;
+        create_client_action.execute(&mut state_tx).await?;
+        update_client_action.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        // an active client can't be recovered.
+        assert!(state
+            .check_client_recovery(&subject_client_id, &substitute_client_id)
+            .await
+            .is_err());
+
+        let subject_client_state = state.get_client_state(&subject_client_id).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_client(
+            &subject_client_id,
+            subject_client_state.with_frozen_height(Height::new(0, 1)?),
+        );
+        state_tx.apply();
+
+        // a frozen client can't be used as a substitute.
+        assert!(state
+            .check_client_recovery(&substitute_client_id, &subject_client_id)
+            .await
+            .is_err());
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx
+            .recover_client(&subject_client_id, &substitute_client_id)
+            .await?;
+        state_tx.apply();
+
+        let recovered_client_state = state.get_client_state(&subject_client_id).await?;
+        let substitute_client_state = state.get_client_state(&substitute_client_id).await?;
+        assert!(!recovered_client_state.is_frozen());
+        assert_eq!(
+            recovered_client_state.latest_height(),
+            substitute_client_state.latest_height()
+        );
+        assert_eq!(
+            state
+                .get_verified_consensus_state(
+                    recovered_client_state.latest_height(),
+                    subject_client_id
+                )
+                .await?,
+            state
+                .get_verified_consensus_state(
+                    substitute_client_state.latest_height(),
+                    substitute_client_id
+                )
+                .await?
+        );
+
+        Ok(())
+    }
+
+    // test that misbehaviour made of two conflicting headers, both signed by the validators the
+    // client trusts, freezes the client.
+    #[tokio::test]
+    async fn test_misbehaviour_freezes_client() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let chain = TestChain::new("stargaze-1".parse()?);
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_test_chain_state(
+            &mut state_tx,
+            Time::parse_from_rfc3339("2022-02-11T17:30:50Z")?,
+        );
+        let client_state = put_test_client(
+            &mut state_tx,
+            &client_id,
+            &chain,
+            Time::parse_from_rfc3339("2022-02-11T16:30:00Z")?,
+        )
+        .await?;
+        state_tx.apply();
+
+        // the counterparty signs two different blocks at the same height.
+        let trusted_height = client_state.latest_height();
+        let height = trusted_height.increment();
+        let time = Time::parse_from_rfc3339("2022-02-11T17:00:00Z")?;
+        let header_1 = chain.header(height, trusted_height, time, 1)?;
+        let header_2 = chain.header(height, trusted_height, time, 2)?;
+
+        let raw_misbehaviour = RawMsgSubmitMisbehaviour {
+            client_id: client_id.to_string(),
+            misbehaviour: Some(ibc_proto::google::protobuf::Any {
+                type_url: ics02_validation::TENDERMINT_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: RawTendermintMisbehaviour {
+                    client_id: client_id.to_string(),
+                    header_1: Some(header_1.into()),
+                    header_2: Some(header_2.into()),
+                }
+                .encode_to_vec(),
+            }),
+            signer: String::new(),
+        };
+        let misbehaviour = IbcAction::SubmitMisbehaviour(MsgSubmitMisbehaviour::decode(
+            raw_misbehaviour.encode_to_vec().as_slice(),
+        )?);
+        let misbehaviour_action = misbehaviour.with_handler::<AppRouter<Ics20Transfer>>();
+
+        misbehaviour_action.check_stateless(()).await?;
+        misbehaviour_action.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        misbehaviour_action.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        assert!(state.get_client_state(&client_id).await?.is_frozen());
+
+        // the same misbehaviour can't be submitted again.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(misbehaviour_action.execute(&mut state_tx).await.is_err());
+        drop(state_tx);
+
+        // and the frozen client can no longer verify packet proofs.
+        let (connection, msg) = test_recv_packet(&client_id, trusted_height);
+        let error = state
+            .verify_packet_recv_proof(&connection, &msg)
+            .await
+            .expect_err("frozen clients can't verify proofs");
+        assert_eq!(error.to_string(), "client is frozen");
+
+        Ok(())
+    }
+
+    // test that a client whose latest consensus state is older than its trusting period can no
+    // longer verify packet proofs.
+    #[tokio::test]
+    async fn test_expired_client_rejects_packet_proofs() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_test_chain_state(
+            &mut state_tx,
+            Time::parse_from_rfc3339("2022-02-11T17:30:50Z")?,
+        );
+        let client_state = put_test_client(
+            &mut state_tx,
+            &client_id,
+            &TestChain::new("stargaze-1".parse()?),
+            Time::parse_from_rfc3339("2022-02-11T16:30:00Z")?,
+        )
+        .await?;
+        state_tx.apply();
+
+        assert!(!state.client_is_expired(&client_id, &client_state).await?);
+
+        // long after the trusting period has passed, the client expires.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_timestamp(Time::parse_from_rfc3339("2032-02-11T17:30:50Z")?);
+        state_tx.apply();
+
+        assert!(state.client_is_expired(&client_id, &client_state).await?);
+        let (connection, msg) = test_recv_packet(&client_id, client_state.latest_height());
+        let error = state
+            .verify_packet_recv_proof(&connection, &msg)
+            .await
+            .expect_err("expired clients can't verify proofs");
+        assert_eq!(error.to_string(), "client is expired");
+
+        Ok(())
+    }
+
+    // test that the proofs of upgraded client and consensus states are verified under the upgrade
+    // path of the client, as of its latest height.
+    #[tokio::test]
+    async fn test_verify_upgrade_proof() -> anyhow::Result<()> {
+        let (client_state, upgraded_client_state, upgraded_consensus_state) = test_upgrade()?;
+        let upgraded_client_state = Any::from(upgraded_client_state);
+        let upgraded_consensus_state = Any::from(upgraded_consensus_state);
+
+        let (root, proof_upgrade_client, proof_upgrade_consensus_state) = commit_upgrade(
+            &client_state,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+        )
+        .await?;
+
+        proof_verification::verify_upgraded_client_and_consensus_state(
+            &client_state,
+            &root,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+            &proof_upgrade_client,
+            &proof_upgrade_consensus_state,
+        )?;
+
+        // the proofs don't verify the states under another upgrade height...
+        let mut later_client_state = client_state.clone();
+        later_client_state.latest_height = client_state.latest_height().increment();
+        assert!(
+            proof_verification::verify_upgraded_client_and_consensus_state(
+                &later_client_state,
+                &root,
+                &upgraded_client_state,
+                &upgraded_consensus_state,
+                &proof_upgrade_client,
+                &proof_upgrade_consensus_state,
+            )
+            .is_err()
+        );
+
+        // ...nor another state than the one committed by the counterparty...
+        assert!(
+            proof_verification::verify_upgraded_client_and_consensus_state(
+                &client_state,
+                &root,
+                &upgraded_consensus_state,
+                &upgraded_consensus_state,
+                &proof_upgrade_client,
+                &proof_upgrade_consensus_state,
+            )
+            .is_err()
+        );
+
+        // ...nor with the proofs of client and consensus states swapped.
+        assert!(
+            proof_verification::verify_upgraded_client_and_consensus_state(
+                &client_state,
+                &root,
+                &upgraded_client_state,
+                &upgraded_consensus_state,
+                &proof_upgrade_consensus_state,
+                &proof_upgrade_client,
+            )
+            .is_err()
+        );
+
+        Ok(())
+    }
+
+    // test that a client is upgraded to the client state committed by the counterparty, keeping
+    // its client-chosen parameters, and that an upgrade that wasn't committed is rejected.
+    #[tokio::test]
+    async fn test_upgrade_client() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        let (client_state, upgraded_client_state, upgraded_consensus_state) = test_upgrade()?;
+        let (root, proof_upgrade_client, proof_upgrade_consensus_state) = commit_upgrade(
+            &client_state,
+            &Any::from(upgraded_client_state.clone()),
+            &Any::from(upgraded_consensus_state.clone()),
+        )
+        .await?;
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_test_chain_state(
+            &mut state_tx,
+            Time::parse_from_rfc3339("2022-02-11T17:30:50Z")?,
+        );
+        let msg_create_client_stargaze_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let msg_create_client = MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice())?;
+        let mut consensus_state =
+            ics02_validation::get_tendermint_consensus_state(msg_create_client.consensus_state)?;
+        consensus_state.root = root;
+        consensus_state.timestamp = Time::parse_from_rfc3339("2022-02-11T16:30:00Z")?;
+        state_tx.put_client(&client_id, client_state.clone());
+        state_tx
+            .put_verified_consensus_state(
+                client_state.latest_height(),
+                client_id.clone(),
+                consensus_state,
+            )
+            .await?;
+        state_tx.apply();
+
+        let upgrade_client = |upgraded_client_state: TendermintClientState| {
+            IbcAction::UpgradeClient(MsgUpgradeClient {
+                client_id: client_id.clone(),
+                client_state: upgraded_client_state.into(),
+                consensus_state: upgraded_consensus_state.clone().into(),
+                proof_upgrade_client: proof_upgrade_client.clone().into(),
+                proof_upgrade_consensus_state: proof_upgrade_consensus_state.clone().into(),
+                signer: String::new(),
+            })
+        };
+
+        // an upgrade to another client state than the committed one is rejected.
+        let mut uncommitted_client_state = upgraded_client_state.clone();
+        uncommitted_client_state.chain_id = ChainId::from_string("evil-2");
+        let uncommitted_upgrade = upgrade_client(uncommitted_client_state);
+        let uncommitted_upgrade_action =
+            uncommitted_upgrade.with_handler::<AppRouter<Ics20Transfer>>();
+        uncommitted_upgrade_action.check_stateless(()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(uncommitted_upgrade_action
+            .execute(&mut state_tx)
+            .await
+            .is_err());
+        drop(state_tx);
+
+        let upgrade = upgrade_client(upgraded_client_state.clone());
+        let upgrade_action = upgrade.with_handler::<AppRouter<Ics20Transfer>>();
+        upgrade_action.check_stateless(()).await?;
+        upgrade_action.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        upgrade_action.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        let new_client_state = state.get_client_state(&client_id).await?;
+        assert_eq!(new_client_state.chain_id, upgraded_client_state.chain_id);
+        assert_eq!(
+            new_client_state.latest_height(),
+            upgraded_client_state.latest_height()
+        );
+        assert_eq!(new_client_state.trust_level, client_state.trust_level);
+        assert_eq!(
+            new_client_state.max_clock_drift,
+            client_state.max_clock_drift
+        );
+
+        let new_consensus_state = state
+            .get_verified_consensus_state(new_client_state.latest_height(), client_id.clone())
+            .await?;
+        assert_eq!(
+            new_consensus_state.timestamp,
+            upgraded_consensus_state.timestamp
+        );
+        assert_eq!(new_consensus_state.root.hash, b"sentinel_root".to_vec());
+
+        // the client can't be upgraded again with the same proofs.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(upgrade_action.execute(&mut state_tx).await.is_err());
+
         Ok(())
     }
 }
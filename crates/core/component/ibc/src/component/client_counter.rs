@@ -107,6 +107,7 @@ impl From<ClientConnections> for pb::ClientConnections {
 pub(crate) mod ics02_validation {
     use anyhow::{anyhow, Result};
     use ibc_proto::google::protobuf::Any;
+    use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawTendermintMisbehaviour;
     use ibc_types::lightclients::tendermint::client_state::{
         ClientState as TendermintClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
     };
@@ -116,6 +117,10 @@ pub(crate) mod ics02_validation {
     use ibc_types::lightclients::tendermint::header::{
         Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
     };
+    use prost::Message;
+
+    pub const TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str =
+        "/ibc.lightclients.tendermint.v1.Misbehaviour";
 
     pub fn is_tendermint_header_state(header: &Any) -> bool {
         header.type_url.as_str() == TENDERMINT_HEADER_TYPE_URL
@@ -139,6 +144,42 @@ pub(crate) mod ics02_validation {
         }
     }
 
+    pub fn is_tendermint_misbehaviour(misbehaviour: &Any) -> bool {
+        misbehaviour.type_url.as_str() == TENDERMINT_MISBEHAVIOUR_TYPE_URL
+    }
+
+    // returns the two conflicting headers of a tendermint misbehaviour.
+    pub fn get_tendermint_misbehaviour_headers(
+        misbehaviour: Any,
+    ) -> Result<(TendermintHeader, TendermintHeader)> {
+        if !is_tendermint_misbehaviour(&misbehaviour) {
+            return Err(anyhow!(format!(
+                "expected tendermint misbehaviour, got: {}",
+                misbehaviour.type_url.as_str()
+            )));
+        }
+
+        let raw =
+            RawTendermintMisbehaviour::decode(misbehaviour.value.as_slice()).map_err(|e| {
+                anyhow!(format!(
+                    "failed to deserialize tendermint misbehaviour: {e}"
+                ))
+            })?;
+        let header_1 = raw
+            .header_1
+            .ok_or_else(|| anyhow!("tendermint misbehaviour is missing its first header"))?;
+        let header_2 = raw
+            .header_2
+            .ok_or_else(|| anyhow!("tendermint misbehaviour is missing its second header"))?;
+
+        let header_1 = TendermintHeader::try_from(header_1)
+            .map_err(|e| anyhow!(format!("failed to deserialize tendermint header: {e}")))?;
+        let header_2 = TendermintHeader::try_from(header_2)
+            .map_err(|e| anyhow!(format!("failed to deserialize tendermint header: {e}")))?;
+
+        Ok((header_1, header_2))
+    }
+
     pub fn get_tendermint_consensus_state(
         consensus_state: Any,
     ) -> Result<TendermintConsensusState> {
@@ -10,18 +10,25 @@ mod connection_open_confirm;
 mod connection_open_init;
 mod connection_open_try;
 mod create_client;
+mod misbehaviour;
 mod recv_packet;
 mod timeout;
 mod update_client;
+mod upgrade_client;
 
 use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_storage::StateWrite;
 
+use crate::component::app_handler::AppHandler;
+
 /// Variant of ActionHandler defined locally (so it can be implemented for IBC
 /// message types) and tweaked (removing the separate check_stateless step).
+///
+/// Channel and packet events are passed on to the IBC application `AH`, which is
+/// usually an `AppRouter` that dispatches them based on their port.
 #[async_trait]
 pub(crate) trait MsgHandler {
     async fn check_stateless(&self) -> Result<()>;
-    async fn try_execute<S: StateWrite>(&self, state: S) -> Result<()>;
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, state: S) -> Result<()>;
 }
@@ -1,18 +1,17 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use ibc_types::core::{
-    channel::channel::Order as ChannelOrder, channel::channel::State as ChannelState,
-    channel::events, channel::msgs::MsgAcknowledgement, channel::PortId,
+    channel::channel::State as ChannelState, channel::events, channel::msgs::MsgAcknowledgement,
     connection::State as ConnectionState,
 };
 use penumbra_storage::StateWrite;
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    app_handler::AppHandler,
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
+    packet::{PacketSequenceRead as _, PacketSequenceWrite as _},
     proof_verification::{commit_packet, PacketProofVerifier},
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -24,7 +23,7 @@ impl MsgHandler for MsgAcknowledgement {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let channel = state
             .get_channel(&self.packet.chan_on_a, &self.packet.port_on_a)
@@ -61,32 +60,11 @@ impl MsgHandler for MsgAcknowledgement {
 
         state.verify_packet_ack_proof(&connection, self).await?;
 
-        if channel.ordering == ChannelOrder::Ordered {
-            let next_sequence_ack = state
-                .get_ack_sequence(&self.packet.chan_on_a, &self.packet.port_on_a)
-                .await?;
-            if self.packet.sequence != next_sequence_ack.into() {
-                return Err(anyhow::anyhow!("packet sequence number does not match"));
-            }
-        }
+        state.check_ack_sequence(&channel, &self.packet).await?;
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::acknowledge_packet_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
-        if channel.ordering == ChannelOrder::Ordered {
-            let mut next_sequence_ack = state
-                .get_ack_sequence(&self.packet.chan_on_a, &self.packet.port_on_a)
-                .await?;
-            next_sequence_ack += 1;
-            state.put_ack_sequence(
-                &self.packet.chan_on_a,
-                &self.packet.port_on_a,
-                next_sequence_ack,
-            );
-        }
+        AH::acknowledge_packet_check(&mut state, self).await?;
+
+        state.record_ack_sequence(&channel, &self.packet).await?;
 
         // delete our commitment so we can't ack it again
         state.delete_packet_commitment(
@@ -110,12 +88,7 @@ impl MsgHandler for MsgAcknowledgement {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::acknowledge_packet_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::acknowledge_packet_execute(state, self).await?;
 
         Ok(())
     }
@@ -3,18 +3,17 @@ use async_trait::async_trait;
 use ibc_types::core::{
     channel::{
         channel::State as ChannelState, events, msgs::MsgChannelCloseConfirm, ChannelEnd,
-        Counterparty, PortId,
+        Counterparty,
     },
     connection::State as ConnectionState,
 };
 use penumbra_storage::StateWrite;
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    app_handler::AppHandler,
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
     proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -26,7 +25,7 @@ impl MsgHandler for MsgChannelCloseConfirm {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         // TODO: capability authentication?
         //
@@ -81,12 +80,7 @@ impl MsgHandler for MsgChannelCloseConfirm {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_close_confirm_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_close_confirm_check(&mut state, self).await?;
         channel.set_state(ChannelState::Closed);
         state.put_channel(&self.chan_id_on_b, &self.port_id_on_b, channel.clone());
 
@@ -105,12 +99,7 @@ impl MsgHandler for MsgChannelCloseConfirm {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_close_confirm_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_close_confirm_execute(state, self).await?;
 
         Ok(())
     }
@@ -2,15 +2,14 @@ use anyhow::Result;
 use async_trait::async_trait;
 use ibc_types::core::{
     channel::channel::State as ChannelState, channel::events, channel::msgs::MsgChannelCloseInit,
-    channel::PortId, connection::State as ConnectionState,
+    connection::State as ConnectionState,
 };
 use penumbra_storage::StateWrite;
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    app_handler::AppHandler,
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -22,7 +21,7 @@ impl MsgHandler for MsgChannelCloseInit {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         // TODO: capability authentication?
         //
@@ -44,12 +43,7 @@ impl MsgHandler for MsgChannelCloseInit {
         if !connection.state_matches(&ConnectionState::Open) {
             return Err(anyhow::anyhow!("connection for channel is not open"));
         }
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_close_init_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_close_init_check(&mut state, self).await?;
 
         channel.set_state(ChannelState::Closed);
         state.put_channel(&self.chan_id_on_a, &self.port_id_on_a, channel.clone());
@@ -69,12 +63,7 @@ impl MsgHandler for MsgChannelCloseInit {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_close_init_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_close_init_execute(state, self).await?;
 
         Ok(())
     }
@@ -2,17 +2,16 @@ use anyhow::Result;
 use async_trait::async_trait;
 use ibc_types::core::{
     channel::channel::State as ChannelState, channel::events, channel::msgs::MsgChannelOpenAck,
-    channel::ChannelEnd, channel::Counterparty, channel::PortId, connection::ConnectionEnd,
+    channel::ChannelEnd, channel::Counterparty, connection::ConnectionEnd,
     connection::State as ConnectionState,
 };
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    app_handler::AppHandler,
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
     proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -24,7 +23,7 @@ impl MsgHandler for MsgChannelOpenAck {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let mut channel = state
             .get_channel(&self.chan_id_on_a, &self.port_id_on_a)
@@ -65,12 +64,7 @@ impl MsgHandler for MsgChannelOpenAck {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_ack_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_ack_check(&mut state, self).await?;
 
         channel.set_state(ChannelState::Open);
         channel.set_version(self.version_on_b.clone());
@@ -92,12 +86,7 @@ impl MsgHandler for MsgChannelOpenAck {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_ack_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_ack_execute(state, self).await?;
 
         Ok(())
     }
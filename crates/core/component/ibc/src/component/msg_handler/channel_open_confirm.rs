@@ -2,17 +2,15 @@ use anyhow::Result;
 use async_trait::async_trait;
 use ibc_types::core::{
     channel::channel::State as ChannelState, channel::events, channel::msgs::MsgChannelOpenConfirm,
-    channel::ChannelEnd, channel::Counterparty, channel::PortId,
-    connection::State as ConnectionState,
+    channel::ChannelEnd, channel::Counterparty, connection::State as ConnectionState,
 };
 use penumbra_storage::StateWrite;
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    app_handler::AppHandler,
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
     proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -24,7 +22,7 @@ impl MsgHandler for MsgChannelOpenConfirm {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let mut channel = state
             .get_channel(&self.chan_id_on_b, &self.port_id_on_b)
@@ -76,12 +74,7 @@ impl MsgHandler for MsgChannelOpenConfirm {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_confirm_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_confirm_check(&mut state, self).await?;
 
         channel.set_state(ChannelState::Open);
         state.put_channel(&self.chan_id_on_b, &self.port_id_on_b, channel.clone());
@@ -101,12 +94,7 @@ impl MsgHandler for MsgChannelOpenConfirm {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_confirm_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_confirm_execute(state, self).await?;
 
         Ok(())
     }
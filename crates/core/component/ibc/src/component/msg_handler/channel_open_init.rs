@@ -7,10 +7,9 @@ use ibc_types::core::channel::{
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    app_handler::AppHandler,
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -22,7 +21,7 @@ impl MsgHandler for MsgChannelOpenInit {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let channel_id = get_channel_id(&state).await?;
 
@@ -34,12 +33,7 @@ impl MsgHandler for MsgChannelOpenInit {
 
         // TODO: do we want to do capability authentication?
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_init_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_init_check(&mut state, self).await?;
         let channel_id = state.next_channel_id().await.unwrap();
         let new_channel = ChannelEnd {
             state: State::Init,
@@ -65,12 +59,7 @@ impl MsgHandler for MsgChannelOpenInit {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_init_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_init_execute(state, self).await?;
 
         Ok(())
     }
@@ -3,19 +3,14 @@ use async_trait::async_trait;
 use ibc_types::core::{
     channel::{
         channel::State as ChannelState, events, msgs::MsgChannelOpenTry, ChannelEnd, Counterparty,
-        PortId,
     },
     connection::{ConnectionEnd, State as ConnectionState},
 };
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
-    channel::StateWriteExt,
-    connection::StateReadExt,
-    proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
-    MsgHandler,
+    app_handler::AppHandler, channel::StateWriteExt, connection::StateReadExt,
+    proof_verification::ChannelProofVerifier, MsgHandler,
 };
 
 #[async_trait]
@@ -26,7 +21,7 @@ impl MsgHandler for MsgChannelOpenTry {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let connection_on_b = verify_connections_open(&state, self).await?;
 
@@ -58,12 +53,7 @@ impl MsgHandler for MsgChannelOpenTry {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_try_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_try_check(&mut state, self).await?;
 
         let channel_id = state.next_channel_id().await.unwrap();
         let new_channel = ChannelEnd {
@@ -95,12 +85,7 @@ impl MsgHandler for MsgChannelOpenTry {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_try_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::chan_open_try_execute(state, self).await?;
 
         Ok(())
     }
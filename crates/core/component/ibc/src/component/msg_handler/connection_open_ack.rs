@@ -11,6 +11,7 @@ use penumbra_chain::component::{StateReadExt as _, PENUMBRA_COMMITMENT_PREFIX};
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::component::{
+    app_handler::AppHandler,
     client::StateReadExt as _,
     client_counter::validate_penumbra_client_state,
     connection::{StateReadExt as _, StateWriteExt as _},
@@ -23,7 +24,7 @@ impl MsgHandler for MsgConnectionOpenAck {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         // Validate a ConnectionOpenAck message, which is sent to us by a counterparty chain that
         // has committed a Connection to us expected to be in the TRYOPEN state. Before executing a
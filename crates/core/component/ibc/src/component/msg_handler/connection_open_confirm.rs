@@ -11,6 +11,7 @@ use penumbra_chain::component::PENUMBRA_COMMITMENT_PREFIX;
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::component::{
+    app_handler::AppHandler,
     client::StateReadExt as _,
     connection::{StateReadExt as _, StateWriteExt as _},
     proof_verification, MsgHandler,
@@ -25,7 +26,7 @@ impl MsgHandler for MsgConnectionOpenConfirm {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         // Validate a ConnectionOpenConfirm message, completing the IBC connection handshake.
         //
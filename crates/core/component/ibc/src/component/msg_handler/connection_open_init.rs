@@ -5,6 +5,7 @@ use ibc_types::core::connection::{
 };
 
 use crate::component::{
+    app_handler::AppHandler,
     client::StateReadExt as _,
     connection::{StateReadExt as _, StateWriteExt as _},
     connection_counter::SUPPORTED_VERSIONS,
@@ -22,7 +23,7 @@ impl MsgHandler for MsgConnectionOpenInit {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
 
         // check that the client with the specified ID exists
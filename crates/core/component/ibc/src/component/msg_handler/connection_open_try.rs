@@ -16,6 +16,7 @@ use penumbra_chain::component::{StateReadExt as _, PENUMBRA_COMMITMENT_PREFIX};
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::component::{
+    app_handler::AppHandler,
     client::StateReadExt as _,
     client_counter::validate_penumbra_client_state,
     connection::{StateReadExt as _, StateWriteExt as _},
@@ -30,7 +31,7 @@ impl MsgHandler for MsgConnectionOpenTry {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
 
         // Validate a ConnectionOpenTry message, which is sent to us by a counterparty chain that
@@ -7,6 +7,7 @@ use ibc_types::{
 use penumbra_storage::StateWrite;
 
 use crate::component::{
+    app_handler::AppHandler,
     client::{StateReadExt as _, StateWriteExt as _},
     client_counter::{ics02_validation, ClientCounter},
     MsgHandler,
@@ -28,7 +29,7 @@ impl MsgHandler for MsgCreateClient {
     // - client type
     // - consensus state
     // - processed time and height
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let client_state =
             ics02_validation::get_tendermint_client_state(self.client_state.clone())?;
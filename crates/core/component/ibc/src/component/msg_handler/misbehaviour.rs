@@ -0,0 +1,191 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ibc_types::{
+    core::client::{events::ClientMisbehaviour, msgs::MsgSubmitMisbehaviour, ClientId, Height},
+    lightclients::tendermint::{
+        client_state::ClientState as TendermintClientState, header::Header as TendermintHeader,
+    },
+};
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_storage::{StateRead, StateWrite};
+use tendermint_light_client_verifier::{
+    types::{TrustedBlockState, UntrustedBlockState},
+    ProdVerifier, Verdict, Verifier,
+};
+
+use crate::component::{
+    app_handler::AppHandler,
+    client::{StateReadExt as _, StateWriteExt as _},
+    client_counter::ics02_validation,
+    MsgHandler,
+};
+
+#[async_trait]
+impl MsgHandler for MsgSubmitMisbehaviour {
+    async fn check_stateless(&self) -> Result<()> {
+        misbehaviour_is_tendermint(self)?;
+
+        let (header_1, header_2) =
+            ics02_validation::get_tendermint_misbehaviour_headers(self.misbehaviour.clone())?;
+        headers_are_conflicting(&header_1, &header_2)?;
+
+        Ok(())
+    }
+
+    // execute IBC SubmitMisbehaviour.
+    //
+    // if the two headers in the misbehaviour are both valid updates of the client, the
+    // counterparty chain has signed two conflicting views of its history, and we can no longer
+    // trust the client. we freeze it, which prevents any further updates to the client and the
+    // verification of any proofs against its consensus states.
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(msg = ?self);
+
+        let client_type = state.get_client_type(&self.client_id).await?;
+        let client_state = state.get_client_state(&self.client_id).await?;
+
+        if client_state.is_frozen() {
+            return Err(anyhow::anyhow!("client is already frozen"));
+        }
+
+        let (header_1, header_2) =
+            ics02_validation::get_tendermint_misbehaviour_headers(self.misbehaviour.clone())?;
+
+        verify_misbehaviour_header(&state, &self.client_id, &client_state, &header_1)
+            .await
+            .context("could not verify first misbehaviour header")?;
+        verify_misbehaviour_header(&state, &self.client_id, &client_state, &header_2)
+            .await
+            .context("could not verify second misbehaviour header")?;
+
+        // the frozen height is only used as a flag, and ibc-go uses the same sentinel value.
+        let frozen_client_state = client_state.with_frozen_height(Height::new(0, 1)?);
+        state.put_client(&self.client_id, frozen_client_state);
+
+        state.record(
+            ClientMisbehaviour {
+                client_id: self.client_id.clone(),
+                client_type,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+}
+
+fn misbehaviour_is_tendermint(msg: &MsgSubmitMisbehaviour) -> anyhow::Result<()> {
+    if ics02_validation::is_tendermint_misbehaviour(&msg.misbehaviour) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "MsgSubmitMisbehaviour: not a tendermint misbehaviour"
+        ))
+    }
+}
+
+// check that the two headers are evidence of misbehaviour: either they are for the same height
+// but commit to different blocks, or they violate the monotonicity of block time.
+//
+// see: https://github.com/cosmos/ibc/tree/main/spec/client/ics-007-tendermint-client (checkForMisbehaviour)
+fn headers_are_conflicting(
+    header_1: &TendermintHeader,
+    header_2: &TendermintHeader,
+) -> anyhow::Result<()> {
+    if header_1.signed_header.header().chain_id != header_2.signed_header.header().chain_id {
+        return Err(anyhow::anyhow!(
+            "misbehaviour headers are for different chains"
+        ));
+    }
+
+    if header_1.height() < header_2.height() {
+        return Err(anyhow::anyhow!(
+            "first misbehaviour header must not be lower than the second"
+        ));
+    }
+
+    let conflicting = if header_1.height() == header_2.height() {
+        header_1.signed_header.commit().block_id.hash
+            != header_2.signed_header.commit().block_id.hash
+    } else {
+        header_1.signed_header.header().time <= header_2.signed_header.header().time
+    };
+
+    if conflicting {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "misbehaviour headers do not conflict with each other"
+        ))
+    }
+}
+
+// verify that a misbehaviour header would have been accepted as an update to the client, based
+// on the trusted consensus state it claims to extend.
+async fn verify_misbehaviour_header<S: StateRead>(
+    state: S,
+    client_id: &ClientId,
+    trusted_client_state: &TendermintClientState,
+    untrusted_header: &TendermintHeader,
+) -> anyhow::Result<()> {
+    if untrusted_header.height().revision_number() != trusted_client_state.chain_id.version() {
+        return Err(anyhow::anyhow!(
+            "misbehaviour header revision number does not match client state"
+        ));
+    }
+
+    let trusted_consensus_state = state
+        .get_verified_consensus_state(untrusted_header.trusted_height, client_id.clone())
+        .await?;
+
+    if untrusted_header.trusted_validator_set.hash() != trusted_consensus_state.next_validators_hash
+    {
+        return Err(anyhow::anyhow!(
+            "misbehaviour header validator set hash does not match trusted consensus state"
+        ));
+    }
+
+    // misbehaviour can only be submitted while the consensus state it extends is still trusted.
+    let now = state.get_block_timestamp().await?;
+    let time_elapsed = now.duration_since(trusted_consensus_state.timestamp)?;
+    if trusted_client_state.expired(time_elapsed) {
+        return Err(anyhow::anyhow!(
+            "trusted consensus state for misbehaviour header is expired"
+        ));
+    }
+
+    let trusted_height = untrusted_header
+        .trusted_height
+        .revision_height()
+        .try_into()
+        .context("invalid header height")?;
+
+    let trusted_state = TrustedBlockState {
+        chain_id: &trusted_client_state.chain_id.clone().into(),
+        header_time: trusted_consensus_state.timestamp,
+        height: trusted_height,
+        next_validators: &untrusted_header.trusted_validator_set,
+        next_validators_hash: trusted_consensus_state.next_validators_hash,
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &untrusted_header.signed_header,
+        validators: &untrusted_header.validator_set,
+        next_validators: None,
+    };
+
+    let options = trusted_client_state.as_light_client_options()?;
+    let verifier = ProdVerifier::default();
+
+    match verifier.verify_misbehaviour_header(untrusted_state, trusted_state, &options, now) {
+        Verdict::Success => Ok(()),
+        Verdict::NotEnoughTrust(voting_power_tally) => Err(anyhow::anyhow!(
+            "not enough trust, voting power tally: {:?}",
+            voting_power_tally
+        )),
+        Verdict::Invalid(detail) => Err(anyhow::anyhow!(
+            "could not verify tendermint header: invalid: {:?}",
+            detail
+        )),
+    }
+}
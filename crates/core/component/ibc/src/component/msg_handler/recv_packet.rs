@@ -1,12 +1,7 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use ibc_types::core::{
-    channel::{
-        channel::{Order as ChannelOrder, State as ChannelState},
-        events,
-        msgs::MsgRecvPacket,
-        PortId,
-    },
+    channel::{channel::State as ChannelState, events, msgs::MsgRecvPacket},
     client::Height as IBCHeight,
     connection::State as ConnectionState,
 };
@@ -14,11 +9,11 @@ use penumbra_chain::component::StateReadExt;
 use penumbra_storage::StateWrite;
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
-    channel::{StateReadExt as _, StateWriteExt},
+    app_handler::AppHandler,
+    channel::StateReadExt as _,
     connection::StateReadExt as _,
+    packet::{PacketSequenceRead as _, PacketSequenceWrite as _},
     proof_verification::PacketProofVerifier,
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -30,7 +25,7 @@ impl MsgHandler for MsgRecvPacket {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let channel = state
             .get_channel(&self.packet.chan_on_b, &self.packet.port_on_b)
@@ -84,42 +79,11 @@ impl MsgHandler for MsgRecvPacket {
 
         state.verify_packet_recv_proof(&connection, self).await?;
 
-        if channel.ordering == ChannelOrder::Ordered {
-            let next_sequence_recv = state
-                .get_recv_sequence(&self.packet.chan_on_b, &self.packet.port_on_b)
-                .await?;
+        state.check_recv_sequence(&channel, &self.packet).await?;
 
-            if self.packet.sequence != next_sequence_recv.into() {
-                return Err(anyhow::anyhow!("packet sequence number does not match"));
-            }
-        } else if state.seen_packet(&self.packet).await? {
-            return Err(anyhow::anyhow!("packet has already been processed"));
-        }
-
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::recv_packet_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::recv_packet_check(&mut state, self).await?;
 
-        if channel.ordering == ChannelOrder::Ordered {
-            let mut next_sequence_recv = state
-                .get_recv_sequence(&self.packet.chan_on_b, &self.packet.port_on_b)
-                .await?;
-
-            next_sequence_recv += 1;
-            state.put_recv_sequence(
-                &self.packet.chan_on_b,
-                &self.packet.port_on_b,
-                next_sequence_recv,
-            );
-        } else {
-            // for unordered channels we must set the receipt so it can be verified on the other side
-            // this receipt does not contain any data, since the packet has not yet been processed
-            // it's just a single store key set to an empty string to indicate that the packet has been received
-            state.put_packet_receipt(&self.packet);
-        }
+        state.record_recv_sequence(&channel, &self.packet).await?;
 
         state.record(
             events::packet::ReceivePacket {
@@ -137,12 +101,7 @@ impl MsgHandler for MsgRecvPacket {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::recv_packet_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::recv_packet_execute(state, self).await?;
 
         Ok(())
     }
@@ -4,17 +4,15 @@ use ibc_types::core::channel::{
     channel::{Order as ChannelOrder, State as ChannelState},
     events,
     msgs::MsgTimeout,
-    PortId,
 };
 use penumbra_storage::StateWrite;
 
 use crate::component::{
-    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    app_handler::AppHandler,
     channel::{StateReadExt as _, StateWriteExt},
     client::StateReadExt,
     connection::StateReadExt as _,
     proof_verification::{commit_packet, PacketProofVerifier},
-    transfer::Ics20Transfer,
     MsgHandler,
 };
 
@@ -26,7 +24,7 @@ impl MsgHandler for MsgTimeout {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
         let mut channel = state
             .get_channel(&self.packet.chan_on_a, &self.packet.port_on_a)
@@ -79,9 +77,10 @@ impl MsgHandler for MsgTimeout {
         }
 
         if channel.ordering == ChannelOrder::Ordered {
-            // ordered channel: check that packet has not been received
-            if self.next_seq_recv_on_b != self.packet.sequence {
-                return Err(anyhow::anyhow!("packet sequence number does not match"));
+            // ordered channel: check that packet has not been received. the counterparty may
+            // have moved past this packet only if it received it.
+            if self.packet.sequence < self.next_seq_recv_on_b {
+                return Err(anyhow::anyhow!("packet has already been received"));
             }
 
             // in the case of a timed-out ordered packet, the counterparty should have
@@ -95,12 +94,7 @@ impl MsgHandler for MsgTimeout {
                 .await?;
         }
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::timeout_packet_check(&mut state, self).await?;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::timeout_packet_check(&mut state, self).await?;
 
         state.delete_packet_commitment(
             &self.packet.chan_on_a,
@@ -132,12 +126,7 @@ impl MsgHandler for MsgTimeout {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::timeout_packet_execute(state, self).await;
-        } else {
-            return Err(anyhow::anyhow!("invalid port id"));
-        }
+        AH::timeout_packet_execute(state, self).await?;
 
         Ok(())
     }
@@ -17,6 +17,7 @@ use tendermint_light_client_verifier::{
 };
 
 use crate::component::{
+    app_handler::AppHandler,
     client::{Ics2ClientExt as _, StateReadExt as _, StateWriteExt as _},
     client_counter::ics02_validation,
     MsgHandler,
@@ -30,7 +31,7 @@ impl MsgHandler for MsgUpdateClient {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
         // Optimization: no-op if the update is already committed.  We no-op
         // to Ok(()) rather than erroring to avoid having two "racing" relay
         // transactions fail just because they both contain the same client
@@ -192,18 +193,7 @@ async fn client_is_not_expired<S: StateRead>(
     client_id: &ClientId,
     client_state: &TendermintClientState,
 ) -> anyhow::Result<()> {
-    let latest_consensus_state = state
-        .get_verified_consensus_state(client_state.latest_height(), client_id.clone())
-        .await?;
-
-    // TODO(erwan): for now there is no casting that needs to happen because `get_verified_consensus_state` does not return an
-    // abstracted consensus state.
-    let latest_consensus_state_tm = latest_consensus_state;
-
-    let now = state.get_block_timestamp().await?;
-    let time_elapsed = now.duration_since(latest_consensus_state_tm.timestamp)?;
-
-    if client_state.expired(time_elapsed) {
+    if state.client_is_expired(client_id, client_state).await? {
         Err(anyhow::anyhow!("client is expired"))
     } else {
         Ok(())
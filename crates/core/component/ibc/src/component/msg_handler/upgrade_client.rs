@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ibc_types::{
+    core::{
+        client::{events::UpgradeClient, msgs::MsgUpgradeClient},
+        commitment::{MerkleProof, MerkleRoot},
+    },
+    lightclients::tendermint::{
+        client_state::ClientState as TendermintClientState,
+        consensus_state::ConsensusState as TendermintConsensusState,
+    },
+};
+use penumbra_storage::StateWrite;
+
+use crate::component::{
+    app_handler::AppHandler,
+    client::{StateReadExt as _, StateWriteExt as _},
+    client_counter::ics02_validation,
+    proof_verification, MsgHandler,
+};
+
+// the root of the consensus state of an upgraded client is not known until the client is updated
+// with a header from the upgraded chain, so ibc-go stores this sentinel value in its place.
+static SENTINEL_ROOT: &str = "sentinel_root";
+
+#[async_trait]
+impl MsgHandler for MsgUpgradeClient {
+    async fn check_stateless(&self) -> Result<()> {
+        client_state_is_tendermint(self)?;
+        consensus_state_is_tendermint(self)?;
+
+        Ok(())
+    }
+
+    // execute IBC UpgradeClient.
+    //
+    // when the counterparty chain performs a planned upgrade that changes the parameters of its
+    // light client (for instance, its chain id or unbonding period), it commits the upgraded client
+    // and consensus states under the upgrade path of the client at the last height before the
+    // upgrade. once we verify these against the latest consensus state of the client, we replace
+    // the client state with the upgraded one, keeping the client-chosen parameters.
+    //
+    // see: https://github.com/cosmos/ibc/tree/main/spec/client/ics-007-tendermint-client (upgradeClientState)
+    async fn try_execute<S: StateWrite, AH: AppHandler>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(msg = ?self);
+
+        let client_type = state.get_client_type(&self.client_id).await?;
+        let client_state = state.get_client_state(&self.client_id).await?;
+
+        if client_state.is_frozen() {
+            return Err(anyhow::anyhow!("client is frozen"));
+        }
+        if state
+            .client_is_expired(&self.client_id, &client_state)
+            .await?
+        {
+            return Err(anyhow::anyhow!("client is expired"));
+        }
+
+        let upgraded_client_state =
+            ics02_validation::get_tendermint_client_state(self.client_state.clone())?;
+        let upgraded_consensus_state =
+            ics02_validation::get_tendermint_consensus_state(self.consensus_state.clone())?;
+
+        if upgraded_client_state.latest_height() <= client_state.latest_height() {
+            return Err(anyhow::anyhow!(
+                "upgraded client height {} must be greater than current client height {}",
+                upgraded_client_state.latest_height(),
+                client_state.latest_height()
+            ));
+        }
+
+        // the upgrade must be proven against the latest consensus state, so that we verify it
+        // against the latest upgrade plan of the counterparty.
+        let last_consensus_state = state
+            .get_verified_consensus_state(client_state.latest_height(), self.client_id.clone())
+            .await?;
+
+        let proof_upgrade_client = MerkleProof::try_from(self.proof_upgrade_client.clone())?;
+        let proof_upgrade_consensus_state =
+            MerkleProof::try_from(self.proof_upgrade_consensus_state.clone())?;
+
+        proof_verification::verify_upgraded_client_and_consensus_state(
+            &client_state,
+            &last_consensus_state.root,
+            &self.client_state,
+            &self.consensus_state,
+            &proof_upgrade_client,
+            &proof_upgrade_consensus_state,
+        )?;
+
+        let new_client_state =
+            upgraded_tendermint_client_state(client_state, upgraded_client_state)?;
+        let new_consensus_state = TendermintConsensusState::new(
+            MerkleRoot {
+                hash: SENTINEL_ROOT.as_bytes().to_vec(),
+            },
+            upgraded_consensus_state.timestamp,
+            upgraded_consensus_state.next_validators_hash,
+        );
+
+        let consensus_height = new_client_state.latest_height();
+
+        state.put_client(&self.client_id, new_client_state);
+        state
+            .put_verified_consensus_state(
+                consensus_height,
+                self.client_id.clone(),
+                new_consensus_state,
+            )
+            .await?;
+
+        state.record(
+            UpgradeClient {
+                client_id: self.client_id.clone(),
+                client_type,
+                consensus_height,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+}
+
+fn client_state_is_tendermint(msg: &MsgUpgradeClient) -> anyhow::Result<()> {
+    if ics02_validation::is_tendermint_client_state(&msg.client_state) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "MsgUpgradeClient: not a tendermint client state"
+        ))
+    }
+}
+
+fn consensus_state_is_tendermint(msg: &MsgUpgradeClient) -> anyhow::Result<()> {
+    if ics02_validation::is_tendermint_consensus_state(&msg.consensus_state) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "MsgUpgradeClient: not a tendermint consensus state"
+        ))
+    }
+}
+
+// compute the client state after an upgrade: the chain-specified parameters are taken from the
+// upgraded client state committed by the counterparty, while the client-specified parameters
+// (trust level, clock drift, ...) are kept from the current client state.
+fn upgraded_tendermint_client_state(
+    client_state: TendermintClientState,
+    upgraded_client_state: TendermintClientState,
+) -> anyhow::Result<TendermintClientState> {
+    // if the unbonding period changed, the trusting period is scaled by the same ratio, so that
+    // it remains the same fraction of the unbonding period.
+    let trusting_period = if upgraded_client_state.unbonding_period != client_state.unbonding_period
+    {
+        let trusting_period_nanos = client_state
+            .trusting_period
+            .as_nanos()
+            .checked_mul(upgraded_client_state.unbonding_period.as_nanos())
+            .and_then(|n| n.checked_div(client_state.unbonding_period.as_nanos()))
+            .context("could not scale trusting period to upgraded unbonding period")?;
+        Duration::from_nanos(
+            trusting_period_nanos
+                .try_into()
+                .context("scaled trusting period is too large")?,
+        )
+    } else {
+        client_state.trusting_period
+    };
+
+    let mut new_client_state = client_state;
+    new_client_state.chain_id = upgraded_client_state.chain_id;
+    new_client_state.latest_height = upgraded_client_state.latest_height;
+    new_client_state.unbonding_period = upgraded_client_state.unbonding_period;
+    new_client_state.trusting_period = trusting_period;
+    new_client_state.proof_specs = upgraded_client_state.proof_specs;
+    new_client_state.upgrade_path = upgraded_client_state.upgrade_path;
+    new_client_state.frozen_height = None;
+
+    Ok(new_client_state)
+}
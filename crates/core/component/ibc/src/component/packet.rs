@@ -1,7 +1,10 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use ibc_types::core::{
-    channel::{channel::State as ChannelState, events, ChannelId, Packet, PortId},
+    channel::{
+        channel::{Order as ChannelOrder, State as ChannelState},
+        events, ChannelEnd, ChannelId, Packet, PortId,
+    },
     client::Height,
 };
 use penumbra_storage::{StateRead, StateWrite};
@@ -266,3 +269,193 @@ pub trait WriteAcknowledgement: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> WriteAcknowledgement for T {}
+
+/// Checks on the sequence numbers of packets received and acknowledged on a channel.
+///
+/// On ordered channels, packets must be received and acknowledged in the order of their sequence
+/// numbers, which is tracked by the channel's next receive and acknowledgement sequences. On
+/// unordered channels, packets may be received in any order, but only once, which is tracked by
+/// packet receipts.
+#[async_trait]
+pub trait PacketSequenceRead: StateRead {
+    /// Checks that `packet` can be received on `channel` next.
+    async fn check_recv_sequence(&self, channel: &ChannelEnd, packet: &Packet) -> Result<()> {
+        match channel.ordering {
+            ChannelOrder::Ordered => {
+                let next_sequence_recv = self
+                    .get_recv_sequence(&packet.chan_on_b, &packet.port_on_b)
+                    .await?;
+                if packet.sequence != next_sequence_recv.into() {
+                    return Err(anyhow::anyhow!(
+                        "packet sequence number {} does not match next receive sequence number {}",
+                        packet.sequence,
+                        next_sequence_recv
+                    ));
+                }
+            }
+            ChannelOrder::Unordered => {
+                if self.seen_packet(packet).await? {
+                    return Err(anyhow::anyhow!("packet has already been processed"));
+                }
+            }
+            ChannelOrder::None => return Err(anyhow::anyhow!("channel has no ordering")),
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `packet` can be acknowledged on `channel` next.
+    async fn check_ack_sequence(&self, channel: &ChannelEnd, packet: &Packet) -> Result<()> {
+        if channel.ordering == ChannelOrder::Ordered {
+            let next_sequence_ack = self
+                .get_ack_sequence(&packet.chan_on_a, &packet.port_on_a)
+                .await?;
+            if packet.sequence != next_sequence_ack.into() {
+                return Err(anyhow::anyhow!(
+                    "packet sequence number {} does not match next acknowledgement sequence number {}",
+                    packet.sequence,
+                    next_sequence_ack
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateRead + ?Sized> PacketSequenceRead for T {}
+
+/// Updates to the sequence numbers of packets received and acknowledged on a channel. These
+/// assume that the corresponding checks of [`PacketSequenceRead`] have already been performed.
+#[async_trait]
+pub trait PacketSequenceWrite: StateWrite {
+    /// Records that `packet` was received on `channel`.
+    async fn record_recv_sequence(&mut self, channel: &ChannelEnd, packet: &Packet) -> Result<()> {
+        if channel.ordering == ChannelOrder::Ordered {
+            let next_sequence_recv = self
+                .get_recv_sequence(&packet.chan_on_b, &packet.port_on_b)
+                .await?;
+            self.put_recv_sequence(&packet.chan_on_b, &packet.port_on_b, next_sequence_recv + 1);
+        } else {
+            // for unordered channels we must set the receipt so it can be verified on the other side
+            // this receipt does not contain any data, since the packet has not yet been processed
+            // it's just a single store key set to an empty string to indicate that the packet has been received
+            self.put_packet_receipt(packet);
+        }
+
+        Ok(())
+    }
+
+    /// Records that `packet` was acknowledged on `channel`.
+    async fn record_ack_sequence(&mut self, channel: &ChannelEnd, packet: &Packet) -> Result<()> {
+        if channel.ordering == ChannelOrder::Ordered {
+            let next_sequence_ack = self
+                .get_ack_sequence(&packet.chan_on_a, &packet.port_on_a)
+                .await?;
+            self.put_ack_sequence(&packet.chan_on_a, &packet.port_on_a, next_sequence_ack + 1);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> PacketSequenceWrite for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use ibc_types::core::{
+        channel::{Counterparty, Version},
+        connection::ConnectionId,
+    };
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+
+    use crate::component::channel::StateWriteExt as _;
+
+    fn channel(ordering: ChannelOrder) -> ChannelEnd {
+        ChannelEnd {
+            state: ChannelState::Open,
+            ordering,
+            remote: Counterparty::new(PortId::transfer(), Some(ChannelId::new(0))),
+            connection_hops: vec![ConnectionId::new(0)],
+            version: Version::new("ics20-1".to_string()),
+        }
+    }
+
+    fn packet(sequence: u64) -> Packet {
+        Packet {
+            chan_on_a: ChannelId::new(0),
+            port_on_a: PortId::transfer(),
+            sequence: sequence.into(),
+            chan_on_b: ChannelId::new(1),
+            port_on_b: PortId::transfer(),
+            timeout_height_on_b: Height::new(0, 100).unwrap().into(),
+            timeout_timestamp_on_b: ibc_types::timestamp::Timestamp::from_nanoseconds(1_000_000)
+                .unwrap(),
+            data: vec![],
+        }
+    }
+
+    // test that packets on an ordered channel are only received and acknowledged in sequence.
+    #[tokio::test]
+    async fn ordered_channel_sequencing() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        let channel = channel(ChannelOrder::Ordered);
+        state_tx.put_recv_sequence(&ChannelId::new(1), &PortId::transfer(), 1);
+        state_tx.put_ack_sequence(&ChannelId::new(0), &PortId::transfer(), 1);
+
+        // packets can't skip ahead of the next receive sequence.
+        assert!(state_tx
+            .check_recv_sequence(&channel, &packet(2))
+            .await
+            .is_err());
+
+        state_tx.check_recv_sequence(&channel, &packet(1)).await?;
+        state_tx.record_recv_sequence(&channel, &packet(1)).await?;
+
+        // once received, a packet can't be received again, but the next one can.
+        assert!(state_tx
+            .check_recv_sequence(&channel, &packet(1))
+            .await
+            .is_err());
+        state_tx.check_recv_sequence(&channel, &packet(2)).await?;
+
+        assert!(state_tx
+            .check_ack_sequence(&channel, &packet(2))
+            .await
+            .is_err());
+        state_tx.check_ack_sequence(&channel, &packet(1)).await?;
+        state_tx.record_ack_sequence(&channel, &packet(1)).await?;
+        assert!(state_tx
+            .check_ack_sequence(&channel, &packet(1))
+            .await
+            .is_err());
+        state_tx.check_ack_sequence(&channel, &packet(2)).await?;
+
+        Ok(())
+    }
+
+    // test that packets on an unordered channel are received in any order, but only once.
+    #[tokio::test]
+    async fn unordered_channel_receipts() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        let channel = channel(ChannelOrder::Unordered);
+
+        state_tx.check_recv_sequence(&channel, &packet(2)).await?;
+        state_tx.record_recv_sequence(&channel, &packet(2)).await?;
+        assert!(state_tx
+            .check_recv_sequence(&channel, &packet(2))
+            .await
+            .is_err());
+        state_tx.check_recv_sequence(&channel, &packet(1)).await?;
+
+        // acknowledgements are not sequenced on unordered channels.
+        state_tx.check_ack_sequence(&channel, &packet(2)).await?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use crate::component::client::StateReadExt;
 
+use anyhow::Context;
 use core::time::Duration;
+use ibc_proto::google::protobuf::Any;
 use ibc_proto::protobuf::Protobuf;
 use ibc_types::path::ClientConsensusStatePath;
 use ibc_types::DomainType;
@@ -117,11 +119,16 @@ pub trait ChannelProofVerifier: StateReadExt {
         // get the stored client state for the counterparty
         let trusted_client_state = self.get_client_state(&connection.client_id).await?;
 
-        // check if the client is frozen
-        // TODO: should we also check if the client is expired here?
+        // check if the client is frozen or expired
         if trusted_client_state.is_frozen() {
             return Err(anyhow::anyhow!("client is frozen"));
         }
+        if self
+            .client_is_expired(&connection.client_id, &trusted_client_state)
+            .await?
+        {
+            return Err(anyhow::anyhow!("client is expired"));
+        }
 
         // get the stored consensus state for the counterparty
         let trusted_consensus_state = self
@@ -221,6 +228,74 @@ pub fn verify_client_consensus_state(
     Ok(())
 }
 
+// verify a proof of the upgraded client or consensus state committed by the counterparty chain
+// under the client's upgrade path.
+//
+// the first key of the upgrade path is the store key, and the counterparty commits the upgraded
+// states under the last key of the upgrade path, suffixed with the height of the last block before
+// the upgrade and the kind of state, as ibc-go does:
+// https://github.com/cosmos/ibc-go/blob/main/modules/light-clients/07-tendermint/upgrade.go
+fn verify_upgrade_proof(
+    client_state: &TendermintClientState,
+    root: &MerkleRoot,
+    proof: &MerkleProof,
+    upgraded_key: &str,
+    value: Vec<u8>,
+) -> anyhow::Result<()> {
+    let [store_key, path @ .., last_key] = client_state.upgrade_path.as_slice() else {
+        anyhow::bail!("client upgrade path must contain a store key and a path");
+    };
+
+    let prefix = MerklePrefix {
+        key_prefix: store_key.as_bytes().to_vec(),
+    };
+    let mut keys = path.to_vec();
+    keys.push(format!(
+        "{last_key}/{}/{upgraded_key}",
+        client_state.latest_height().revision_height()
+    ));
+
+    let merkle_path = prefix.apply(keys);
+    proof.verify_membership(
+        &client_state.proof_specs,
+        root.clone().into(),
+        merkle_path,
+        value,
+        0,
+    )?;
+
+    Ok(())
+}
+
+pub fn verify_upgraded_client_and_consensus_state(
+    client_state: &TendermintClientState,
+    root: &MerkleRoot,
+    upgraded_client_state: &Any,
+    upgraded_consensus_state: &Any,
+    proof_upgrade_client: &MerkleProof,
+    proof_upgrade_consensus_state: &MerkleProof,
+) -> anyhow::Result<()> {
+    verify_upgrade_proof(
+        client_state,
+        root,
+        proof_upgrade_client,
+        "upgradedClient",
+        upgraded_client_state.encode_to_vec(),
+    )
+    .context("could not verify proof of upgraded client state")?;
+
+    verify_upgrade_proof(
+        client_state,
+        root,
+        proof_upgrade_consensus_state,
+        "upgradedConsState",
+        upgraded_consensus_state.encode_to_vec(),
+    )
+    .context("could not verify proof of upgraded consensus state")?;
+
+    Ok(())
+}
+
 #[async_trait]
 pub trait PacketProofVerifier: StateReadExt + inner::Inner {
     async fn verify_packet_recv_proof(
@@ -367,10 +442,15 @@ mod inner {
         ) -> anyhow::Result<(TendermintClientState, TendermintConsensusState)> {
             let trusted_client_state = self.get_client_state(client_id).await?;
 
-            // TODO: should we also check if the client is expired here?
             if trusted_client_state.is_frozen() {
                 return Err(anyhow::anyhow!("client is frozen"));
             }
+            if self
+                .client_is_expired(client_id, &trusted_client_state)
+                .await?
+            {
+                return Err(anyhow::anyhow!("client is expired"));
+            }
 
             let trusted_consensus_state = self
                 .get_verified_consensus_state(*height, client_id.clone())
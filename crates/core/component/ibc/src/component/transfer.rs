@@ -10,7 +10,7 @@ use ibc_types::{
             MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
             MsgTimeout,
         },
-        ChannelId, PortId, Version,
+        ChannelId, Packet, PortId, Version,
     },
     transfer::acknowledgement::TokenTransferAcknowledgement,
 };
@@ -23,10 +23,11 @@ use penumbra_proto::{
 use penumbra_shielded_pool::component::{NoteManager, SupplyWrite};
 use penumbra_storage::{StateRead, StateWrite};
 use prost::Message;
+use serde::Deserialize;
 
 use crate::{
     component::{
-        app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
+        app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute, BoundAppHandler},
         packet::{
             IBCPacket, SendPacketRead as _, SendPacketWrite as _, Unchecked,
             WriteAcknowledgement as _,
@@ -187,34 +188,17 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 
     async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
-        let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
-        let denom: asset::DenomMetadata = packet_data.denom.as_str().try_into()?;
-
-        if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
-            // check if we have enough balance to refund tokens to sender
-            let value_balance: Amount = state
-                .get(&state_key::ics20_value_balance(
-                    &msg.packet.chan_on_a,
-                    &denom.id(),
-                ))
-                .await?
-                .unwrap_or_else(Amount::zero);
-
-            let amount_penumbra: Amount = packet_data.amount.try_into()?;
-            if value_balance < amount_penumbra {
-                return Err(anyhow::anyhow!(
-                    "insufficient balance to refund tokens to sender"
-                ));
-            }
-        }
-
-        Ok(())
+        refund_tokens_check(state, &msg.packet).await
     }
 
     async fn acknowledge_packet_check<S: StateRead>(
-        _state: S,
-        _msg: &MsgAcknowledgement,
+        state: S,
+        msg: &MsgAcknowledgement,
     ) -> Result<()> {
+        if !is_successful_ack(&msg.acknowledgement)? {
+            refund_tokens_check(state, &msg.packet).await?;
+        }
+
         Ok(())
     }
 }
@@ -339,33 +323,91 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
     Ok(())
 }
 
+/// The JSON encoding of an ICS-20 acknowledgement, as written by
+/// [`TokenTransferAcknowledgement`].
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Ics20Acknowledgement {
+    Result(String),
+    Error(String),
+}
+
+// returns whether the acknowledgement written by the counterparty indicates that it accepted the
+// transfer.
+fn is_successful_ack(ack: &[u8]) -> Result<bool> {
+    let ack: Ics20Acknowledgement =
+        serde_json::from_slice(ack).context("couldn't decode ics20 acknowledgement")?;
+
+    Ok(match ack {
+        Ics20Acknowledgement::Result(_) => true,
+        Ics20Acknowledgement::Error(e) => {
+            tracing::debug!("counterparty failed to execute transfer: {}", e);
+            false
+        }
+    })
+}
+
+// checks that the tokens sent in a packet can be refunded to the sender, if the packet times out or
+// the counterparty fails to execute it.
+async fn refund_tokens_check<S: StateRead>(state: S, packet: &Packet) -> Result<()> {
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
+    let denom: asset::DenomMetadata = packet_data.denom.as_str().try_into()?;
+
+    if !is_source(&packet.port_on_a, &packet.chan_on_a, &denom) {
+        // we are the source, so the tokens were escrowed. check if we have enough balance to
+        // refund tokens to sender
+        let value_balance: Amount = state
+            .get(&state_key::ics20_value_balance(
+                &packet.chan_on_a,
+                &denom.id(),
+            ))
+            .await?
+            .unwrap_or_else(Amount::zero);
+
+        let amount_penumbra: Amount = packet_data.amount.try_into()?;
+        if value_balance < amount_penumbra {
+            return Err(anyhow::anyhow!(
+                "insufficient balance to refund tokens to sender"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// refunds the tokens sent in a packet to the sender, either by unescrowing them (if we were the
+// source of the denom) or by minting back the vouchers that were burned when the packet was sent.
+//
+// this mirrors `withdrawal_execute`: a denom without the `{port}/{channel}/` prefix of the sending
+// channel is one we are the source of, so it was escrowed rather than burned.
+//
 // see: https://github.com/cosmos/ibc/blob/8326e26e7e1188b95c32481ff00348a705b23700/spec/app/ics-020-fungible-token-transfer/README.md?plain=1#L297
-async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
-    let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
+async fn refund_tokens<S: StateWrite>(mut state: S, packet: &Packet) -> Result<()> {
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
     let denom: asset::DenomMetadata = packet_data // CRITICAL: verify that this denom is validated in upstream timeout handling
         .denom
         .as_str()
         .try_into()
-        .context("couldn't decode denom in ics20 transfer timeout")?;
-    // receiver was source chain, mint vouchers back to sender
+        .context("couldn't decode denom in ics20 transfer refund")?;
     let amount: Amount = packet_data
         .amount
         .try_into()
-        .context("couldn't decode amount in ics20 transfer timeout")?;
+        .context("couldn't decode amount in ics20 transfer refund")?;
 
-    let receiver = Address::from_str(&packet_data.receiver)
-        .context("couldn't decode receiver address in ics20 timeout")?;
+    // the sender is the return address of the withdrawal that sent the packet.
+    let sender = Address::from_str(&packet_data.sender)
+        .context("couldn't decode sender address in ics20 transfer refund")?;
 
     let value: Value = Value {
         amount,
         asset_id: denom.id(),
     };
 
-    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
+    if !is_source(&packet.port_on_a, &packet.chan_on_a, &denom) {
         // sender was source chain, unescrow tokens back to sender
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
+                &packet.chan_on_a,
                 &denom.id(),
             ))
             .await?
@@ -373,50 +415,85 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
 
         if value_balance < amount {
             return Err(anyhow::anyhow!(
-                "couldn't return coins in timeout: not enough value balance"
+                "couldn't return coins in refund: not enough value balance"
             ));
         }
 
         state
-            .mint_note(value, &receiver, penumbra_chain::NoteSource::Ics20Transfer)
+            .mint_note(value, &sender, penumbra_chain::NoteSource::Ics20Transfer)
             .await
-            .context("couldn't mint note in timeout_packet_inner")?;
-
-        // update the value balance
-        let value_balance: Amount = state
-            .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
-                &denom.id(),
-            ))
-            .await?
-            .unwrap_or_else(Amount::zero);
+            .context("couldn't mint note in refund_tokens")?;
 
         // note: this arithmetic was checked above, but we do it again anyway.
         let new_value_balance = value_balance.checked_sub(&amount).unwrap();
         state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_a, &denom.id()),
+            state_key::ics20_value_balance(&packet.chan_on_a, &denom.id()),
             new_value_balance,
         );
     } else {
+        // receiver was source chain, mint vouchers back to sender
         state
-            .mint_note(value, &receiver, penumbra_chain::NoteSource::Ics20Transfer) // NOTE: should this be Ics20TransferTimeout?
+            .mint_note(value, &sender, penumbra_chain::NoteSource::Ics20Transfer) // NOTE: should this be Ics20TransferTimeout?
             .await
-            .context("failed to mint return voucher in ics20 transfer timeout")?;
+            .context("failed to mint return voucher in ics20 transfer refund")?;
+    }
+
+    Ok(())
+}
+
+// handles the acknowledgement of a packet we sent, refunding the sender if the counterparty
+// failed to execute the transfer.
+async fn acknowledge_packet_inner<S: StateWrite>(
+    state: S,
+    packet: &Packet,
+    ack: &[u8],
+) -> Result<()> {
+    if !is_successful_ack(ack)? {
+        refund_tokens(state, packet).await?;
     }
 
     Ok(())
 }
 
-// NOTE: should these be fallible, now that our enclosing state machine is fallible in execution?
 #[async_trait]
 impl AppHandlerExecute for Ics20Transfer {
-    async fn chan_open_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenInit) {}
-    async fn chan_open_try_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenTry) {}
-    async fn chan_open_ack_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenAck) {}
-    async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {}
-    async fn chan_close_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseConfirm) {}
-    async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
-    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) {
+    async fn chan_open_init_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_open_try_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenTry,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_open_ack_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenAck,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_close_init_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
         // recv packet should never fail a transaction, but it should record a failure acknowledgement.
         let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, msg).await {
             Ok(_) => {
@@ -433,19 +510,272 @@ impl AppHandlerExecute for Ics20Transfer {
         state
             .write_acknowledgement(&msg.packet, &ack)
             .await
-            .context("critical: failed to write acknowledgement")
-            .unwrap();
+            .context("failed to write acknowledgement")
     }
 
-    async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) {
-        // timeouts should never fail
-        timeout_packet_inner(&mut state, msg)
+    async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
+        // timeouts were already checked to be refundable, so this should never fail.
+        refund_tokens(&mut state, &msg.packet)
             .await
-            .context("critical: failed to timeout packet")
-            .unwrap();
+            .context("failed to refund timed out packet")
     }
 
-    async fn acknowledge_packet_execute<S: StateWrite>(_state: S, _msg: &MsgAcknowledgement) {}
+    async fn acknowledge_packet_execute<S: StateWrite>(
+        mut state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        // acknowledgements were already checked to be refundable, so this should never fail.
+        acknowledge_packet_inner(&mut state, &msg.packet, &msg.acknowledgement)
+            .await
+            .context("failed to acknowledge packet")
+    }
 }
 
 impl AppHandler for Ics20Transfer {}
+
+impl BoundAppHandler for Ics20Transfer {
+    fn port_id() -> PortId {
+        PortId::transfer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use ibc_types::core::{
+        channel::{channel::State as ChannelState, ChannelEnd, Counterparty},
+        client::Height,
+        connection::ConnectionId,
+    };
+    use penumbra_chain::test_keys;
+    use penumbra_shielded_pool::component::SupplyRead;
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+
+    use crate::component::channel::StateWriteExt as _;
+
+    /// A counterparty chain that rejects every transfer it receives.
+    struct FailingCounterparty;
+
+    impl FailingCounterparty {
+        fn recv_packet(&self, packet: &Packet) -> Vec<u8> {
+            let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice()).unwrap();
+            TokenTransferAcknowledgement::Error(format!(
+                "cannot send {} to {}",
+                packet_data.denom, packet_data.receiver
+            ))
+            .into()
+        }
+    }
+
+    fn withdrawal(denom: &str, amount: u64) -> Ics20Withdrawal {
+        Ics20Withdrawal {
+            amount: amount.into(),
+            denom: denom.try_into().unwrap(),
+            destination_chain_address: "cosmos1receiver".to_string(),
+            return_address: *test_keys::ADDRESS_0,
+            timeout_height: 100,
+            timeout_time: 1_000_000,
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::new(0),
+        }
+    }
+
+    // the packet committed by `withdrawal_execute` as the first packet sent on the channel.
+    fn sent_packet(withdrawal: &Ics20Withdrawal) -> Packet {
+        Packet {
+            chan_on_a: withdrawal.source_channel.clone(),
+            port_on_a: withdrawal.source_port.clone(),
+            sequence: 1u64.into(),
+            chan_on_b: ChannelId::new(7),
+            port_on_b: PortId::transfer(),
+            timeout_height_on_b: Height::new(0, withdrawal.timeout_height).unwrap().into(),
+            timeout_timestamp_on_b: ibc_types::timestamp::Timestamp::from_nanoseconds(
+                withdrawal.timeout_time,
+            )
+            .unwrap(),
+            data: withdrawal.packet_data(),
+        }
+    }
+
+    fn open_transfer_channel<S: StateWrite>(mut state: S) {
+        let channel = ChannelEnd {
+            state: ChannelState::Open,
+            ordering: ChannelOrder::Unordered,
+            remote: Counterparty::new(PortId::transfer(), Some(ChannelId::new(7))),
+            connection_hops: vec![ConnectionId::new(0)],
+            version: Version::new("ics20-1".to_string()),
+        };
+        state.put_channel(&ChannelId::new(0), &PortId::transfer(), channel);
+        state.put_send_sequence(&ChannelId::new(0), &PortId::transfer(), 1);
+    }
+
+    // test that a withdrawal of a native token rejected by the counterparty is unescrowed back to
+    // the sender.
+    #[tokio::test]
+    async fn error_ack_unescrows_native_tokens() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        open_transfer_channel(&mut state_tx);
+
+        let withdrawal = withdrawal("upenumbra", 1000);
+        let balance_key =
+            state_key::ics20_value_balance(&withdrawal.source_channel, &withdrawal.denom.id());
+        state_tx.withdrawal_execute(&withdrawal).await;
+        assert_eq!(
+            state_tx.get::<Amount>(&balance_key).await?,
+            Some(1000u64.into())
+        );
+
+        let packet = sent_packet(&withdrawal);
+        let ack = FailingCounterparty.recv_packet(&packet);
+        assert!(!is_successful_ack(&ack)?);
+
+        refund_tokens_check(&state_tx, &packet).await?;
+        acknowledge_packet_inner(&mut state_tx, &packet, &ack).await?;
+
+        // the escrowed tokens were minted back to the sender.
+        assert_eq!(
+            state_tx.get::<Amount>(&balance_key).await?,
+            Some(Amount::zero())
+        );
+        assert_eq!(
+            state_tx.token_supply(&withdrawal.denom.id()).await?,
+            Some(1000)
+        );
+
+        // the escrow can't be drained by a second refund of the same packet.
+        assert!(refund_tokens_check(&state_tx, &packet).await.is_err());
+
+        Ok(())
+    }
+
+    // test that a withdrawal of a bridged-in token rejected by the counterparty mints the
+    // vouchers back to the sender, without touching any escrow.
+    #[tokio::test]
+    async fn error_ack_remints_vouchers() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        open_transfer_channel(&mut state_tx);
+
+        let withdrawal = withdrawal("transfer/channel-0/uatom", 500);
+        let balance_key =
+            state_key::ics20_value_balance(&withdrawal.source_channel, &withdrawal.denom.id());
+        state_tx.withdrawal_execute(&withdrawal).await;
+        assert_eq!(state_tx.get::<Amount>(&balance_key).await?, None);
+
+        let packet = sent_packet(&withdrawal);
+        let ack = FailingCounterparty.recv_packet(&packet);
+
+        refund_tokens_check(&state_tx, &packet).await?;
+        acknowledge_packet_inner(&mut state_tx, &packet, &ack).await?;
+
+        assert_eq!(state_tx.get::<Amount>(&balance_key).await?, None);
+        assert_eq!(
+            state_tx.token_supply(&withdrawal.denom.id()).await?,
+            Some(500)
+        );
+
+        Ok(())
+    }
+
+    // test that a timed out withdrawal of a native token is unescrowed back to the sender.
+    #[tokio::test]
+    async fn timeout_unescrows_native_tokens() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        open_transfer_channel(&mut state_tx);
+
+        let withdrawal = withdrawal("upenumbra", 1000);
+        let balance_key =
+            state_key::ics20_value_balance(&withdrawal.source_channel, &withdrawal.denom.id());
+        state_tx.withdrawal_execute(&withdrawal).await;
+
+        let packet = sent_packet(&withdrawal);
+        refund_tokens_check(&state_tx, &packet).await?;
+        refund_tokens(&mut state_tx, &packet).await?;
+
+        assert_eq!(
+            state_tx.get::<Amount>(&balance_key).await?,
+            Some(Amount::zero())
+        );
+        assert_eq!(
+            state_tx.token_supply(&withdrawal.denom.id()).await?,
+            Some(1000)
+        );
+        assert!(refund_tokens_check(&state_tx, &packet).await.is_err());
+
+        Ok(())
+    }
+
+    // test that a timed out withdrawal of a bridged-in token mints the vouchers back to the
+    // sender, even though nothing was escrowed.
+    #[tokio::test]
+    async fn timeout_remints_vouchers() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        open_transfer_channel(&mut state_tx);
+
+        let withdrawal = withdrawal("transfer/channel-0/uatom", 500);
+        let balance_key =
+            state_key::ics20_value_balance(&withdrawal.source_channel, &withdrawal.denom.id());
+        state_tx.withdrawal_execute(&withdrawal).await;
+
+        let packet = sent_packet(&withdrawal);
+        refund_tokens_check(&state_tx, &packet).await?;
+        refund_tokens(&mut state_tx, &packet).await?;
+
+        assert_eq!(state_tx.get::<Amount>(&balance_key).await?, None);
+        assert_eq!(
+            state_tx.token_supply(&withdrawal.denom.id()).await?,
+            Some(500)
+        );
+
+        Ok(())
+    }
+
+    // test that a refund of a native token fails if the escrow doesn't cover it, rather than
+    // minting new tokens.
+    #[tokio::test]
+    async fn refund_requires_escrow() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        open_transfer_channel(&mut state_tx);
+
+        let packet = sent_packet(&withdrawal("upenumbra", 1000));
+        assert!(refund_tokens_check(&state_tx, &packet).await.is_err());
+        assert!(refund_tokens(&mut state_tx, &packet).await.is_err());
+
+        Ok(())
+    }
+
+    // test that a successful acknowledgement leaves the escrowed tokens in place.
+    #[tokio::test]
+    async fn success_ack_keeps_escrow() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        open_transfer_channel(&mut state_tx);
+
+        let withdrawal = withdrawal("upenumbra", 1000);
+        state_tx.withdrawal_execute(&withdrawal).await;
+
+        let packet = sent_packet(&withdrawal);
+        let ack: Vec<u8> = TokenTransferAcknowledgement::success().into();
+        assert!(is_successful_ack(&ack)?);
+        acknowledge_packet_inner(&mut state_tx, &packet, &ack).await?;
+
+        assert_eq!(
+            state_tx
+                .get::<Amount>(&state_key::ics20_value_balance(
+                    &withdrawal.source_channel,
+                    &withdrawal.denom.id(),
+                ))
+                .await?,
+            Some(1000u64.into())
+        );
+        assert_eq!(state_tx.token_supply(&withdrawal.denom.id()).await?, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+//! Gas costs of the IBC component's actions, which are charged for the block
+//! space of their encoding.
+
+use penumbra_fee::{gas::variable_gas_cost, Gas, GasCost};
+use penumbra_proto::DomainType;
+
+use crate::{IbcAction, Ics20Withdrawal};
+
+impl GasCost for IbcAction {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
+
+impl GasCost for Ics20Withdrawal {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
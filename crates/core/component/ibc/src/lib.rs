@@ -8,6 +8,8 @@
 #[cfg(feature = "component")]
 pub mod component;
 
+pub mod gas;
+
 mod ibc_action;
 mod ibc_token;
 mod ics20_withdrawal;
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
-use penumbra_component::ActionHandler;
+use penumbra_component::{ActionHandler, TransactionError};
 use penumbra_proof_params::OUTPUT_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -14,11 +14,15 @@ impl ActionHandler for Output {
     async fn check_stateless(&self, _context: ()) -> Result<()> {
         let output = self;
 
-        output.proof.verify(
-            &OUTPUT_PROOF_VERIFICATION_KEY,
-            output.body.balance_commitment,
-            output.body.note_payload.note_commitment,
-        )?;
+        output
+            .proof
+            .verify(
+                &OUTPUT_PROOF_VERIFICATION_KEY,
+                output.body.balance_commitment,
+                output.body.note_payload.note_commitment,
+            )
+            .context("an output proof did not verify")
+            .context(TransactionError::InvalidProof)?;
 
         Ok(())
     }
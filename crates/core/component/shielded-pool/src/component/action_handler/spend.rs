@@ -3,7 +3,7 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::TransactionContext;
-use penumbra_component::ActionHandler;
+use penumbra_component::{ActionHandler, TransactionError};
 use penumbra_proof_params::SPEND_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -22,7 +22,8 @@ impl ActionHandler for Spend {
             .body
             .rk
             .verify(context.effect_hash.as_ref(), &spend.auth_sig)
-            .context("spend auth signature failed to verify")?;
+            .context("spend auth signature failed to verify")
+            .context(TransactionError::InvalidSignature)?;
 
         // 3. Check that the proof verifies.
         spend
@@ -34,7 +35,8 @@ impl ActionHandler for Spend {
                 spend.body.nullifier,
                 spend.body.rk,
             )
-            .context("a spend proof did not verify")?;
+            .context("a spend proof did not verify")
+            .context(TransactionError::InvalidProof)?;
 
         Ok(())
     }
@@ -5,7 +5,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_asset::{asset, Value};
 use penumbra_chain::{genesis, NoteSource, SpendInfo};
-use penumbra_component::Component;
+use penumbra_component::{Component, TransactionError};
 use penumbra_proto::StateReadProto;
 use penumbra_sct::Nullifier;
 use penumbra_storage::StateRead;
@@ -83,7 +83,8 @@ pub trait StateReadExt: StateRead {
                 "nullifier {} was already spent in {:?}",
                 nullifier,
                 info.note_source,
-            ));
+            )
+            .context(TransactionError::NullifierSpent));
         }
         Ok(())
     }
@@ -0,0 +1,62 @@
+//! Gas costs of the shielded pool's actions.
+//!
+//! Spends and outputs carry proofs and have a fixed size, so their costs are
+//! constants shared between each action and its plan.
+
+use penumbra_fee::{
+    gas::{ACTION_EXECUTION_COST, PROOF_VERIFICATION_COST, SIGNATURE_VERIFICATION_COST},
+    Gas, GasCost,
+};
+
+use crate::{Output, OutputPlan, Spend, SpendPlan};
+
+/// The size of a nullifier in the compact block.
+pub const NULLIFIER_SIZE: u64 = 32;
+/// The size of a note commitment in the compact block.
+pub const NOTE_COMMITMENT_SIZE: u64 = 32;
+/// The size of a note payload in the compact block.
+const NOTE_PAYLOAD_SIZE: u64 = 196;
+
+/// The gas used by a spend.
+pub fn spend_gas_cost() -> Gas {
+    Gas {
+        block_space: 370,
+        compact_block_space: NULLIFIER_SIZE,
+        verification: PROOF_VERIFICATION_COST + SIGNATURE_VERIFICATION_COST,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+/// The gas used by an output.
+pub fn output_gas_cost() -> Gas {
+    Gas {
+        block_space: 530,
+        compact_block_space: NOTE_PAYLOAD_SIZE,
+        verification: PROOF_VERIFICATION_COST,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+impl GasCost for Spend {
+    fn gas_cost(&self) -> Gas {
+        spend_gas_cost()
+    }
+}
+
+impl GasCost for SpendPlan {
+    fn gas_cost(&self) -> Gas {
+        spend_gas_cost()
+    }
+}
+
+impl GasCost for Output {
+    fn gas_cost(&self) -> Gas {
+        output_gas_cost()
+    }
+}
+
+impl GasCost for OutputPlan {
+    fn gas_cost(&self) -> Gas {
+        output_gas_cost()
+    }
+}
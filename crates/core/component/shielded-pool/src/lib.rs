@@ -5,6 +5,7 @@
 pub mod component;
 
 pub mod event;
+pub mod gas;
 pub mod state_key;
 
 pub mod note;
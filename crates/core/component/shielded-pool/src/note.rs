@@ -658,4 +658,43 @@ mod tests {
 
         assert!(decryption_result.is_err());
     }
+
+    #[test]
+    fn batch_trial_decryption_finds_own_notes() {
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::generate(rng);
+        let sk = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk = sk.full_viewing_key();
+        let (dest, _dtk_d) = fvk.incoming().payment_address(0u32.into());
+
+        let seed_phrase = SeedPhrase::generate(rng);
+        let sk2 = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk2 = sk2.full_viewing_key();
+        let (dest2, _dtk_d2) = fvk2.incoming().payment_address(0u32.into());
+
+        let value = Value {
+            amount: 10u64.into(),
+            asset_id: asset::Cache::with_known_assets()
+                .get_unit("upenumbra")
+                .unwrap()
+                .id(),
+        };
+        let notes = [
+            Note::generate(&mut rng, &dest, value),
+            Note::generate(&mut rng, &dest2, value),
+            Note::generate(&mut rng, &dest, value),
+        ];
+        let payloads = notes.iter().map(Note::payload).collect::<Vec<_>>();
+
+        let decrypted = NotePayload::trial_decrypt_batch(&payloads, fvk);
+
+        assert_eq!(
+            decrypted,
+            vec![Some(notes[0].clone()), None, Some(notes[2].clone())]
+        );
+        for (payload, note) in payloads.iter().zip(decrypted) {
+            assert_eq!(payload.trial_decrypt(fvk), note);
+        }
+    }
 }
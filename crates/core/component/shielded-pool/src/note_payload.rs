@@ -2,7 +2,7 @@ use anyhow::{Context, Error};
 
 use bytes::Bytes;
 
-use penumbra_keys::keys::FullViewingKey;
+use penumbra_keys::{keys::FullViewingKey, symmetric::PayloadKey};
 use penumbra_num::Amount;
 use penumbra_proto::{core::crypto::v1alpha1 as pb, DomainType, TypeUrl};
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,44 @@ impl NotePayload {
         // Try to decrypt the encrypted note using the ephemeral key and persistent incoming
         // viewing key -- if it doesn't decrypt, it wasn't meant for us.
         let note = Note::decrypt(&self.encrypted_note, fvk.incoming(), &self.ephemeral_key).ok()?;
+        self.check_decrypted(note, fvk)
+    }
+
+    /// Trial-decrypts each of the `payloads` with `fvk`, returning the notes meant for it in the
+    /// same order as the payloads.
+    ///
+    /// This is equivalent to calling [`NotePayload::trial_decrypt`] on each payload, but the key
+    /// agreements for all the payloads, which dominate the cost of trial decryption, are done
+    /// together, in parallel if the `parallel` feature is enabled.
+    pub fn trial_decrypt_batch(
+        payloads: &[NotePayload],
+        fvk: &FullViewingKey,
+    ) -> Vec<Option<Note>> {
+        let ephemeral_keys = payloads
+            .iter()
+            .map(|payload| payload.ephemeral_key)
+            .collect::<Vec<_>>();
+        let shared_secrets = fvk.incoming().key_agreement_with_each(&ephemeral_keys);
+
+        payloads
+            .iter()
+            .zip(shared_secrets)
+            .map(|(payload, shared_secret)| {
+                let shared_secret = shared_secret.ok()?;
+                let key = PayloadKey::derive(&shared_secret, &payload.ephemeral_key);
+                let note = Note::decrypt_with_payload_key(
+                    &payload.encrypted_note,
+                    &key,
+                    &payload.ephemeral_key,
+                )
+                .ok()?;
+                payload.check_decrypted(note, fvk)
+            })
+            .collect()
+    }
+
+    /// Checks that a `note` decrypted from this payload is valid for `fvk`.
+    fn check_decrypted(&self, note: Note, fvk: &FullViewingKey) -> Option<Note> {
         tracing::debug!(note_commitment = ?note.commit(), ?note, "found note while scanning");
 
         // Verification logic (if any fails, return None & log error)
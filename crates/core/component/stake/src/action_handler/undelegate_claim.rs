@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
-use anyhow::{ensure, Result};
+use anyhow::{ensure, Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::component::StateReadExt;
+use penumbra_component::TransactionError;
 use penumbra_proof_params::UNDELEGATECLAIM_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -16,12 +17,15 @@ impl ActionHandler for UndelegateClaim {
         let unbonding_id =
             UnbondingToken::new(self.body.validator_identity, self.body.start_epoch_index).id();
 
-        self.proof.verify(
-            &UNDELEGATECLAIM_PROOF_VERIFICATION_KEY,
-            self.body.balance_commitment,
-            unbonding_id,
-            self.body.penalty,
-        )?;
+        self.proof
+            .verify(
+                &UNDELEGATECLAIM_PROOF_VERIFICATION_KEY,
+                self.body.balance_commitment,
+                unbonding_id,
+                self.body.penalty,
+            )
+            .context("an undelegate claim proof did not verify")
+            .context(TransactionError::InvalidProof)?;
 
         Ok(())
     }
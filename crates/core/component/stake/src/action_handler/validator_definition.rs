@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::component::StateReadExt as _;
+use penumbra_component::TransactionError;
 use penumbra_storage::{StateRead, StateWrite};
 
 use std::sync::Arc;
@@ -22,7 +23,8 @@ impl ActionHandler for validator::Definition {
             .identity_key
             .0
             .verify(&definition_bytes, &self.auth_sig)
-            .context("validator definition signature failed to verify")?;
+            .context("validator definition signature failed to verify")
+            .context(TransactionError::InvalidSignature)?;
 
         // TODO(hdevalence) -- is this duplicated by the check during parsing?
         // Check that the funding streams do not exceed 100% commission (10000bps)
@@ -17,6 +17,7 @@ use penumbra_chain::{
 };
 use penumbra_component::Component;
 use penumbra_dao::component::StateWriteExt as _;
+use penumbra_distributions::component::StateReadExt as _;
 use penumbra_proto::{
     state::future::{DomainFuture, ProtoFuture},
     StateReadProto, StateWriteProto,
@@ -288,6 +289,50 @@ pub(crate) trait StakingImpl: StateWriteExt {
         }
     }
 
+    /// Computes the total amount of the staking token bonded to active validators.
+    async fn bonded_stake(&self) -> Result<u128> {
+        let mut bonded_stake = 0u128;
+        for validator in self.validator_list().await? {
+            if self.validator_state(&validator.identity_key).await?
+                != Some(validator::State::Active)
+            {
+                continue;
+            }
+
+            let rate = self
+                .next_validator_rate(&validator.identity_key)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("validator had ID in validator_list but rate not found in JMT")
+                })?;
+            let delegation_token_supply = self
+                .token_supply(&DelegationToken::from(&validator.identity_key).id())
+                .await?
+                .unwrap_or_default();
+            bonded_stake += rate.unbonded_amount(delegation_token_supply.into());
+        }
+
+        Ok(bonded_stake)
+    }
+
+    /// Computes the base reward rate for the next epoch, so that the staking
+    /// share of the epoch's issuance budget is paid out to the bonded stake.
+    async fn base_reward_rate(&self) -> Result<u64> {
+        let staking_issuance = self.issuance_budget().await?.staking.value();
+        let bonded_stake = self.bonded_stake().await?;
+        tracing::debug!(staking_issuance, bonded_stake);
+
+        if bonded_stake == 0 {
+            return Ok(0);
+        }
+
+        // The rate is capped at 100% per epoch, both to keep the exchange rate
+        // computations from overflowing and because a rate that large could
+        // only come from a vanishingly small amount of bonded stake.
+        let base_reward_rate = (staking_issuance * 1_0000_0000 / bonded_stake).min(1_0000_0000);
+        Ok(base_reward_rate as u64)
+    }
+
     #[instrument(skip(self, epoch_to_end), fields(index = epoch_to_end.index))]
     async fn end_epoch(&mut self, epoch_to_end: Epoch) -> Result<()> {
         // calculate rate data for next rate, move previous next rate to cur rate,
@@ -319,14 +364,13 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 .sum::<usize>(),
         );
 
-        let chain_params = self.get_chain_params().await?;
-
         tracing::debug!("processing base rate");
         // We are transitioning to the next epoch, so set "cur_base_rate" to the previous "next_base_rate", and
         // update "next_base_rate".
         let current_base_rate = self.next_base_rate().await?;
 
-        let next_base_rate = current_base_rate.next(chain_params.base_reward_rate);
+        let base_reward_rate = self.base_reward_rate().await?;
+        let next_base_rate = current_base_rate.next(base_reward_rate);
 
         // rename to curr_rate so it lines up with next_rate (same # chars)
         tracing::debug!(curr_base_rate = ?current_base_rate);
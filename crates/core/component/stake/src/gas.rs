@@ -0,0 +1,52 @@
+//! Gas costs of the staking component's actions.
+
+use penumbra_fee::{
+    gas::{signed_gas_cost, variable_gas_cost, ACTION_EXECUTION_COST, PROOF_VERIFICATION_COST},
+    Gas, GasCost,
+};
+use penumbra_proto::DomainType;
+
+use crate::{validator, Delegate, Undelegate, UndelegateClaim, UndelegateClaimPlan};
+
+/// The gas used by an undelegate claim.
+///
+/// Undelegate claims carry a proof and have a fixed size, so their cost is a
+/// constant shared between the action and its plan.
+pub fn undelegate_claim_gas_cost() -> Gas {
+    Gas {
+        block_space: 320,
+        compact_block_space: 0,
+        verification: PROOF_VERIFICATION_COST,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+impl GasCost for UndelegateClaim {
+    fn gas_cost(&self) -> Gas {
+        undelegate_claim_gas_cost()
+    }
+}
+
+impl GasCost for UndelegateClaimPlan {
+    fn gas_cost(&self) -> Gas {
+        undelegate_claim_gas_cost()
+    }
+}
+
+impl GasCost for Delegate {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
+
+impl GasCost for Undelegate {
+    fn gas_cost(&self) -> Gas {
+        variable_gas_cost(self.encode_to_vec().len())
+    }
+}
+
+impl GasCost for validator::Definition {
+    fn gas_cost(&self) -> Gas {
+        signed_gas_cost(self.encode_to_vec().len())
+    }
+}
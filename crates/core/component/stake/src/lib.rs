@@ -21,6 +21,7 @@ pub mod component;
 pub use component::StateReadExt;
 
 pub mod delegate;
+pub mod gas;
 pub mod rate;
 pub mod state_key;
 pub mod undelegate;
@@ -64,6 +64,15 @@ impl IncomingViewingKey {
         self.ivk.key_agreement_with(pk)
     }
 
+    /// Perform key agreement with each of the given public keys, in parallel if the `parallel`
+    /// feature is enabled.
+    pub fn key_agreement_with_each(
+        &self,
+        pks: &[ka::Public],
+    ) -> Vec<Result<ka::SharedSecret, ka::Error>> {
+        self.ivk.key_agreement_with_each(pks)
+    }
+
     /// Derive a transmission key from the given diversified base.
     pub fn diversified_public(&self, diversified_generator: &decaf377::Element) -> ka::Public {
         self.ivk.diversified_public(diversified_generator)
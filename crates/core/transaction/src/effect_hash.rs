@@ -211,6 +211,14 @@ impl TransactionPlan {
                     .as_bytes(),
             );
         }
+        for position_reward_claim in self.position_reward_claims() {
+            state.update(
+                position_reward_claim
+                    .position_reward_claim()
+                    .effect_hash()
+                    .as_bytes(),
+            );
+        }
         for ics20_withdrawal in self.ics20_withdrawals() {
             state.update(ics20_withdrawal.effect_hash().as_bytes());
         }
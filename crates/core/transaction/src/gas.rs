@@ -0,0 +1,132 @@
+//! Gas costs for transactions and their plans, and for the governance actions.
+//!
+//! Each component defines the gas costs of its own actions, which are reported
+//! by their `ActionHandler` implementations; this module adds the costs of the
+//! parts of a transaction outside of its actions, and of the governance actions
+//! defined in this crate.
+//!
+//! Actions carrying proofs have a fixed size, so their costs are constants that
+//! are shared between an action and its plan.  This lets the planner compute
+//! the exact gas of a transaction before building it, and so its minimum fee.
+//! Other actions are charged for the block space of their encoding, which is
+//! the same in the plan as in the action.
+
+use penumbra_fee::{
+    gas::{
+        signed_gas_cost, variable_gas_cost, ACTION_EXECUTION_COST, PROOF_VERIFICATION_COST,
+        SIGNATURE_VERIFICATION_COST,
+    },
+    Gas, GasCost,
+};
+use penumbra_proto::{core::transaction::v1alpha1 as pb, DomainType, Message};
+
+use crate::{
+    action::{ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote},
+    memo::MEMO_CIPHERTEXT_LEN_BYTES,
+    plan::ActionPlan,
+    TransactionPlan,
+};
+
+/// The size of the parts of a transaction outside of its actions: the binding
+/// signature, the anchor, the transaction parameters, and the fee.
+const TRANSACTION_OVERHEAD_SIZE: u64 = 160;
+/// The size of an FMD clue.
+const CLUE_SIZE: u64 = 68;
+
+/// The gas used by a transaction, outside of its actions, memo, and clues.
+pub fn transaction_base_gas_cost() -> Gas {
+    Gas {
+        block_space: TRANSACTION_OVERHEAD_SIZE,
+        compact_block_space: 0,
+        verification: SIGNATURE_VERIFICATION_COST,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+/// The gas used by a transaction's memo.
+pub fn memo_gas_cost() -> Gas {
+    Gas {
+        block_space: MEMO_CIPHERTEXT_LEN_BYTES as u64,
+        ..Default::default()
+    }
+}
+
+/// The gas used by each of a transaction's FMD clues.
+pub fn clue_gas_cost() -> Gas {
+    Gas {
+        block_space: CLUE_SIZE,
+        ..Default::default()
+    }
+}
+
+/// The gas used by a delegator vote.
+pub fn delegator_vote_gas_cost() -> Gas {
+    Gas {
+        block_space: 450,
+        compact_block_space: 0,
+        verification: PROOF_VERIFICATION_COST + SIGNATURE_VERIFICATION_COST,
+        execution: ACTION_EXECUTION_COST,
+    }
+}
+
+/// The gas used by a validator vote.
+pub fn validator_vote_gas_cost(vote: &ValidatorVote) -> Gas {
+    signed_gas_cost(pb::ValidatorVote::from(vote.clone()).encoded_len())
+}
+
+/// The gas used by a proposal submission.
+pub fn proposal_submit_gas_cost(submit: &ProposalSubmit) -> Gas {
+    variable_gas_cost(submit.encode_to_vec().len())
+}
+
+/// The gas used by a proposal withdrawal.
+pub fn proposal_withdraw_gas_cost(withdraw: &ProposalWithdraw) -> Gas {
+    variable_gas_cost(withdraw.encode_to_vec().len())
+}
+
+/// The gas used by a proposal deposit claim.
+pub fn proposal_deposit_claim_gas_cost(claim: &ProposalDepositClaim) -> Gas {
+    variable_gas_cost(pb::ProposalDepositClaim::from(claim.clone()).encoded_len())
+}
+
+impl GasCost for ActionPlan {
+    fn gas_cost(&self) -> Gas {
+        match self {
+            ActionPlan::Output(x) => x.gas_cost(),
+            ActionPlan::Spend(x) => x.gas_cost(),
+            ActionPlan::Swap(x) => x.gas_cost(),
+            ActionPlan::SwapClaim(x) => x.gas_cost(),
+            ActionPlan::UndelegateClaim(x) => x.gas_cost(),
+            ActionPlan::PositionWithdraw(x) => x.gas_cost(),
+            ActionPlan::PositionRewardClaim(x) => x.gas_cost(),
+            ActionPlan::ValidatorDefinition(x) => x.gas_cost(),
+            ActionPlan::IbcAction(x) => x.gas_cost(),
+            ActionPlan::PositionOpen(x) => x.gas_cost(),
+            ActionPlan::PositionClose(x) => x.gas_cost(),
+            ActionPlan::Delegate(x) => x.gas_cost(),
+            ActionPlan::Undelegate(x) => x.gas_cost(),
+            ActionPlan::Withdrawal(x) => x.gas_cost(),
+            ActionPlan::DaoSpend(x) => x.gas_cost(),
+            ActionPlan::DaoOutput(x) => x.gas_cost(),
+            ActionPlan::DaoDeposit(x) => x.gas_cost(),
+
+            ActionPlan::DelegatorVote(_) => delegator_vote_gas_cost(),
+            ActionPlan::ValidatorVote(x) => validator_vote_gas_cost(x),
+            ActionPlan::ProposalSubmit(x) => proposal_submit_gas_cost(x),
+            ActionPlan::ProposalWithdraw(x) => proposal_withdraw_gas_cost(x),
+            ActionPlan::ProposalDepositClaim(x) => proposal_deposit_claim_gas_cost(x),
+        }
+    }
+}
+
+impl GasCost for TransactionPlan {
+    fn gas_cost(&self) -> Gas {
+        let mut gas = transaction_base_gas_cost();
+        gas += self.actions.iter().map(GasCost::gas_cost).sum();
+        if self.memo_plan.is_some() {
+            gas += memo_gas_cost();
+        }
+        gas += clue_gas_cost() * self.clue_plans.len() as u64;
+        gas
+    }
+}
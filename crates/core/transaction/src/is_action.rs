@@ -221,9 +221,14 @@ impl IsAction for PositionRewardClaim {
             asset_id: LpNft::new(self.position_id, position::State::Withdrawn).asset_id(),
         }
         .commit(Fr::zero());
+        let claimed_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Claimed).asset_id(),
+        }
+        .commit(Fr::zero());
 
-        // The action consumes a closed position and produces the position's reserves.
-        self.rewards_commitment - withdrawn_position_nft
+        // The action consumes a withdrawn position and produces the position's rewards and a claimed position NFT.
+        self.rewards_commitment - withdrawn_position_nft + claimed_position_nft
     }
 
     fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
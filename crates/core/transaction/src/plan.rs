@@ -5,7 +5,7 @@ use anyhow::Result;
 use penumbra_dao::{DaoDeposit, DaoOutput, DaoSpend};
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
-    lp::plan::PositionWithdrawPlan,
+    lp::plan::{PositionRewardClaimPlan, PositionWithdrawPlan},
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
 };
@@ -251,6 +251,16 @@ impl TransactionPlan {
         })
     }
 
+    pub fn position_reward_claims(&self) -> impl Iterator<Item = &PositionRewardClaimPlan> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::PositionRewardClaim(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn ics20_withdrawals(&self) -> impl Iterator<Item = &Ics20Withdrawal> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::Withdrawal(v) = action {
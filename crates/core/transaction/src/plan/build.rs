@@ -169,6 +169,11 @@ impl TransactionPlan {
                 position_withdraw.position_withdraw(),
             ))
         }
+        for position_reward_claim in self.position_reward_claims().cloned() {
+            actions.push(Action::PositionRewardClaim(
+                position_reward_claim.position_reward_claim(),
+            ))
+        }
         // build the transaction's ICS20 withdrawals
         for ics20_withdrawal in self.ics20_withdrawals() {
             actions.push(Action::Ics20Withdrawal(ics20_withdrawal.clone()))
@@ -392,6 +397,11 @@ impl TransactionPlan {
                 position_withdraw.position_withdraw(),
             ))
         }
+        for position_reward_claim in self.position_reward_claims().cloned() {
+            actions.push(Action::PositionRewardClaim(
+                position_reward_claim.position_reward_claim(),
+            ))
+        }
         for ics20_withdrawal in self.ics20_withdrawals().cloned() {
             actions.push(Action::Ics20Withdrawal(ics20_withdrawal))
         }
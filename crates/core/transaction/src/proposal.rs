@@ -3,7 +3,8 @@ use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-use penumbra_chain::params::ChainParameters;
+use ibc_types::core::client::ClientId;
+use penumbra_chain::params::{change::ParameterChange, ChainParameters};
 use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType, TypeUrl};
 
 use crate::plan::TransactionPlan;
@@ -91,6 +92,23 @@ impl From<Proposal> for pb::Proposal {
                     }),
                 });
             }
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                proposal.ibc_client_recovery = Some(pb::proposal::IbcClientRecovery {
+                    subject_client_id: subject_client_id.to_string(),
+                    substitute_client_id: substitute_client_id.to_string(),
+                });
+            }
+            ProposalPayload::UpgradePlan { height, name, info } => {
+                proposal.upgrade_plan = Some(pb::proposal::UpgradePlan { height, name, info });
+            }
+            ProposalPayload::PartialParameterChange { changes } => {
+                proposal.partial_parameter_change = Some(pb::proposal::PartialParameterChange {
+                    changes: changes.into_iter().map(Into::into).collect(),
+                });
+            }
         }
         proposal
     }
@@ -142,6 +160,31 @@ impl TryFrom<pb::Proposal> for Proposal {
                         TransactionPlan::decode(transaction_plan.value)?
                     },
                 }
+            } else if let Some(ibc_client_recovery) = inner.ibc_client_recovery {
+                ProposalPayload::IbcClientRecovery {
+                    subject_client_id: ibc_client_recovery
+                        .subject_client_id
+                        .parse()
+                        .map_err(|e| anyhow::anyhow!("invalid subject client id: {}", e))?,
+                    substitute_client_id: ibc_client_recovery
+                        .substitute_client_id
+                        .parse()
+                        .map_err(|e| anyhow::anyhow!("invalid substitute client id: {}", e))?,
+                }
+            } else if let Some(upgrade_plan) = inner.upgrade_plan {
+                ProposalPayload::UpgradePlan {
+                    height: upgrade_plan.height,
+                    name: upgrade_plan.name,
+                    info: upgrade_plan.info,
+                }
+            } else if let Some(partial_parameter_change) = inner.partial_parameter_change {
+                ProposalPayload::PartialParameterChange {
+                    changes: partial_parameter_change
+                        .changes
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                }
             } else {
                 return Err(anyhow::anyhow!(
                     "missing proposal payload or unknown proposal type"
@@ -175,6 +218,15 @@ pub enum ProposalKind {
     /// A DAO spend proposal.
     #[cfg_attr(feature = "clap", clap(display_order = 400))]
     DaoSpend,
+    /// An IBC client recovery proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 500))]
+    IbcClientRecovery,
+    /// An upgrade plan proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 600))]
+    UpgradePlan,
+    /// A partial parameter change proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 700))]
+    PartialParameterChange,
 }
 
 impl FromStr for ProposalKind {
@@ -186,6 +238,9 @@ impl FromStr for ProposalKind {
             "emergency" => Ok(ProposalKind::Emergency),
             "parameterchange" => Ok(ProposalKind::ParameterChange),
             "daospend" => Ok(ProposalKind::DaoSpend),
+            "ibcclientrecovery" => Ok(ProposalKind::IbcClientRecovery),
+            "upgradeplan" => Ok(ProposalKind::UpgradePlan),
+            "partialparameterchange" => Ok(ProposalKind::PartialParameterChange),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
@@ -199,6 +254,9 @@ impl Proposal {
             ProposalPayload::Emergency { .. } => ProposalKind::Emergency,
             ProposalPayload::ParameterChange { .. } => ProposalKind::ParameterChange,
             ProposalPayload::DaoSpend { .. } => ProposalKind::DaoSpend,
+            ProposalPayload::IbcClientRecovery { .. } => ProposalKind::IbcClientRecovery,
+            ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
+            ProposalPayload::PartialParameterChange { .. } => ProposalKind::PartialParameterChange,
         }
     }
 }
@@ -244,6 +302,47 @@ pub enum ProposalPayload {
         /// action.
         transaction_plan: TransactionPlan,
     },
+    /// An IBC client recovery proposal replaces a frozen or expired IBC client with the state of
+    /// a healthy substitute client tracking the same counterparty chain.
+    IbcClientRecovery {
+        /// The frozen or expired client to be recovered.
+        ///
+        /// Once the proposal passes, the subject client is updated in place, so existing
+        /// connections and channels built on top of it remain usable.
+        subject_client_id: ClientId,
+        /// The active client whose latest state replaces that of the subject client.
+        substitute_client_id: ClientId,
+    },
+    /// An upgrade plan proposal schedules a software upgrade: once it passes, the chain halts after
+    /// committing the block at the planned height, so that it can be restarted with the upgraded
+    /// software.
+    UpgradePlan {
+        /// The height of the last block before the upgrade.
+        ///
+        /// This must be after the end of the voting period, or the upgrade will not be scheduled
+        /// even if the proposal passes.
+        height: u64,
+        /// The name of the upgrade.
+        ///
+        /// When the chain restarts after the upgrade, the upgraded software runs the state
+        /// migrations it registered under this name.
+        name: String,
+        /// Any other information about the upgrade, such as where to find the upgraded software.
+        info: String,
+    },
+    /// A partial parameter change proposal describes changes to individual chain parameters, which
+    /// should take effect when the proposal is passed.
+    ///
+    /// Unlike a [`ProposalPayload::ParameterChange`], it only conflicts with other proposals which
+    /// change the same parameters.
+    PartialParameterChange {
+        /// The changes to individual chain parameters.
+        ///
+        /// Each change specifies the old value of the parameter it changes: even if the proposal
+        /// passes, none of the changes will be applied if any of the changed parameters doesn't
+        /// have its old value at that time.
+        changes: Vec<ParameterChange>,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
@@ -263,6 +362,18 @@ pub enum ProposalPayloadToml {
     DaoSpend {
         transaction: String,
     },
+    IbcClientRecovery {
+        subject_client_id: String,
+        substitute_client_id: String,
+    },
+    UpgradePlan {
+        height: u64,
+        name: String,
+        info: String,
+    },
+    PartialParameterChange {
+        changes: Vec<ParameterChange>,
+    },
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -284,6 +395,23 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
                 ))
                 .context("couldn't decode transaction plan from proto")?,
             },
+            ProposalPayloadToml::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::IbcClientRecovery {
+                subject_client_id: subject_client_id
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid subject client id: {}", e))?,
+                substitute_client_id: substitute_client_id
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid substitute client id: {}", e))?,
+            },
+            ProposalPayloadToml::UpgradePlan { height, name, info } => {
+                ProposalPayload::UpgradePlan { height, name, info }
+            }
+            ProposalPayloadToml::PartialParameterChange { changes } => {
+                ProposalPayload::PartialParameterChange { changes }
+            }
         })
     }
 }
@@ -304,6 +432,19 @@ impl From<ProposalPayload> for ProposalPayloadToml {
                     transaction_plan.encode_to_vec(),
                 ),
             },
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayloadToml::IbcClientRecovery {
+                subject_client_id: subject_client_id.to_string(),
+                substitute_client_id: substitute_client_id.to_string(),
+            },
+            ProposalPayload::UpgradePlan { height, name, info } => {
+                ProposalPayloadToml::UpgradePlan { height, name, info }
+            }
+            ProposalPayload::PartialParameterChange { changes } => {
+                ProposalPayloadToml::PartialParameterChange { changes }
+            }
         }
     }
 }
@@ -324,6 +465,18 @@ impl ProposalPayload {
     pub fn is_dao_spend(&self) -> bool {
         matches!(self, ProposalPayload::DaoSpend { .. })
     }
+
+    pub fn is_ibc_client_recovery(&self) -> bool {
+        matches!(self, ProposalPayload::IbcClientRecovery { .. })
+    }
+
+    pub fn is_upgrade_plan(&self) -> bool {
+        matches!(self, ProposalPayload::UpgradePlan { .. })
+    }
+
+    pub fn is_partial_parameter_change(&self) -> bool {
+        matches!(self, ProposalPayload::PartialParameterChange { .. })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
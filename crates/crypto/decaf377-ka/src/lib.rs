@@ -77,6 +77,33 @@ impl Secret {
         Ok(SharedSecret((self.0 * pk).vartime_compress().into()))
     }
 
+    /// Perform key agreement with each of the provided public keys.
+    ///
+    /// This is exactly [`Secret::key_agreement_with`] called on each public key: no work is shared
+    /// between the key agreements. When the `parallel` feature is enabled, they are spread across
+    /// the rayon thread pool.
+    ///
+    /// Key agreement isn't batched: decoding each public key and encoding each shared secret
+    /// takes an inverse square root, which, unlike an inversion, can't be shared across a batch
+    /// with Montgomery's trick, and the scalar multiplications in between are independent.
+    pub fn key_agreement_with_each(&self, others: &[Public]) -> Vec<Result<SharedSecret, Error>> {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            others
+                .par_iter()
+                .map(|other| self.key_agreement_with(other))
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            others
+                .iter()
+                .map(|other| self.key_agreement_with(other))
+                .collect()
+        }
+    }
+
     /// Convert this shared secret to bytes.
     ///
     /// Convenience wrapper around an [`Into`] impl.
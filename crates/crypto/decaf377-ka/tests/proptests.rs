@@ -60,4 +60,27 @@ proptest! {
         assert_eq!(alice_ss1, bob_ss1);
         assert_eq!(alice_ss2, bob_ss2);
     }
+
+    #[test]
+    fn key_agreement_with_each_matches_key_agreement(
+        alice_sk in fr_strategy(),
+        bob_sks in prop::collection::vec(fr_strategy(), 0..8),
+        invalid in any::<[u8; 32]>(),
+    ) {
+        let alice_sk = ka::Secret::new_from_field(alice_sk);
+
+        let mut others = bob_sks
+            .into_iter()
+            .map(|bob_sk| ka::Secret::new_from_field(bob_sk).public())
+            .collect::<Vec<_>>();
+        // Arbitrary bytes are usually not a valid encoding, so this exercises the error case.
+        others.push(ka::Public(invalid));
+
+        let batch = alice_sk.key_agreement_with_each(&others);
+
+        assert_eq!(batch.len(), others.len());
+        for (other, ss) in others.iter().zip(batch) {
+            assert_eq!(alice_sk.key_agreement_with(other).ok(), ss.ok());
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use std::ops::{Add, AddAssign};
 
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
+
 use crate::{
     decryption_share::Verified, limb, DecryptionShare, DecryptionTable, TableLookupError, Value,
 };
@@ -11,7 +13,7 @@ use crate::{
 pub struct InsufficientSharesError {}
 
 /// A flow encryption ciphertext.
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct Ciphertext {
     pub(crate) c0: limb::Ciphertext,
     pub(crate) c1: limb::Ciphertext,
@@ -20,9 +22,22 @@ pub struct Ciphertext {
 }
 
 impl Ciphertext {
+    /// Encode this ciphertext as the concatenation of its limb ciphertexts.
+    pub fn to_bytes(&self) -> [u8; 256] {
+        let mut bytes = [0u8; 256];
+        bytes[0..64].copy_from_slice(&self.c0.to_bytes());
+        bytes[64..128].copy_from_slice(&self.c1.to_bytes());
+        bytes[128..192].copy_from_slice(&self.c2.to_bytes());
+        bytes[192..256].copy_from_slice(&self.c3.to_bytes());
+        bytes
+    }
+
     /// Use the provided [`DecryptionShare`]s to decrypt the ciphertext,
     /// recovering the value with the given [`DecryptionTable`].
     ///
+    /// The `threshold` is the number of participants required to decrypt,
+    /// chosen when the key shares were generated.
+    ///
     /// # Errors
     ///
     /// - [`InsufficientSharesError`] if insufficiently many decryption shares were supplied;
@@ -30,11 +45,24 @@ impl Ciphertext {
     /// - Underlying I/O errors from the [`DecryptionTable`] implementation.
     pub async fn decrypt(
         &self,
+        threshold: u32,
         shares: Vec<DecryptionShare<Verified>>,
         table: &dyn DecryptionTable,
     ) -> anyhow::Result<Value> {
-        // TODO: how do we know if we have sufficient shares?
-        // How do we return InsufficientSharesError?
+        // Shares from the same participant can't be interpolated, so reject
+        // duplicates rather than counting them towards the threshold.
+        let mut participants = shares
+            .iter()
+            .map(|s| s.participant_index)
+            .collect::<Vec<_>>();
+        participants.sort_unstable();
+        participants.dedup();
+        if participants.len() != shares.len() {
+            anyhow::bail!("multiple decryption shares from the same participant");
+        }
+        if participants.len() < threshold as usize {
+            return Err(InsufficientSharesError {}.into());
+        }
 
         let limb0_shares = shares.iter().map(|s| &s.share0).collect();
         let limb1_shares = shares.iter().map(|s| &s.share1).collect();
@@ -92,3 +120,43 @@ impl AddAssign<&Ciphertext> for Ciphertext {
         self.c3 += &rhs.c3;
     }
 }
+
+impl TryFrom<&[u8]> for Ciphertext {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 256 {
+            anyhow::bail!("ciphertext must be 256 bytes");
+        }
+        Ok(Ciphertext {
+            c0: limb::Ciphertext::from_bytes(bytes[0..64].try_into()?)?,
+            c1: limb::Ciphertext::from_bytes(bytes[64..128].try_into()?)?,
+            c2: limb::Ciphertext::from_bytes(bytes[128..192].try_into()?)?,
+            c3: limb::Ciphertext::from_bytes(bytes[192..256].try_into()?)?,
+        })
+    }
+}
+
+impl TypeUrl for Ciphertext {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.Ciphertext";
+}
+
+impl DomainType for Ciphertext {
+    type Proto = pb::Ciphertext;
+}
+
+impl From<Ciphertext> for pb::Ciphertext {
+    fn from(ciphertext: Ciphertext) -> Self {
+        pb::Ciphertext {
+            inner: ciphertext.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::Ciphertext> for Ciphertext {
+    type Error = anyhow::Error;
+
+    fn try_from(ciphertext: pb::Ciphertext) -> Result<Self, Self::Error> {
+        ciphertext.inner[..].try_into()
+    }
+}
@@ -1,3 +1,4 @@
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
 use rand_core::{CryptoRng, RngCore};
 
 use crate::{limb, Ciphertext, PrivateKeyShare, PublicKeyShare, TranscriptProtocol};
@@ -65,6 +66,55 @@ impl PrivateKeyShare {
     }
 }
 
+impl<S: VerificationStatus> DecryptionShare<S> {
+    /// The index of the participant that produced this decryption share.
+    pub fn participant_index(&self) -> u32 {
+        self.participant_index
+    }
+
+    /// Encode this decryption share as its participant index followed by the
+    /// decryption shares of each limb, along with their proofs.
+    pub fn to_bytes(&self) -> [u8; 388] {
+        let mut bytes = [0u8; 388];
+        bytes[0..4].copy_from_slice(&self.participant_index.to_le_bytes());
+        bytes[4..100].copy_from_slice(&self.share0.to_bytes());
+        bytes[100..196].copy_from_slice(&self.share1.to_bytes());
+        bytes[196..292].copy_from_slice(&self.share2.to_bytes());
+        bytes[292..388].copy_from_slice(&self.share3.to_bytes());
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for DecryptionShare<Unverified> {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 388 {
+            anyhow::bail!("decryption share must be 388 bytes");
+        }
+        let participant_index = u32::from_le_bytes(bytes[0..4].try_into()?);
+        Ok(DecryptionShare {
+            participant_index,
+            share0: limb::DecryptionShare::from_bytes(
+                participant_index,
+                bytes[4..100].try_into()?,
+            )?,
+            share1: limb::DecryptionShare::from_bytes(
+                participant_index,
+                bytes[100..196].try_into()?,
+            )?,
+            share2: limb::DecryptionShare::from_bytes(
+                participant_index,
+                bytes[196..292].try_into()?,
+            )?,
+            share3: limb::DecryptionShare::from_bytes(
+                participant_index,
+                bytes[292..388].try_into()?,
+            )?,
+        })
+    }
+}
+
 impl DecryptionShare<Unverified> {
     /// Verify this decryption share against the [`PublicKeyShare`] that
     /// (supposedly) generated it, so that it can be used in decryption.
@@ -103,3 +153,29 @@ impl DecryptionShare<Unverified> {
         })
     }
 }
+
+impl TypeUrl for DecryptionShare<Unverified> {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.DecryptionShare";
+}
+
+/// Only unverified decryption shares can be decoded, so that decoded shares
+/// must be verified before they are used.
+impl DomainType for DecryptionShare<Unverified> {
+    type Proto = pb::DecryptionShare;
+}
+
+impl<S: VerificationStatus> From<DecryptionShare<S>> for pb::DecryptionShare {
+    fn from(share: DecryptionShare<S>) -> Self {
+        pb::DecryptionShare {
+            inner: share.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::DecryptionShare> for DecryptionShare<Unverified> {
+    type Error = anyhow::Error;
+
+    fn try_from(share: pb::DecryptionShare) -> Result<Self, Self::Error> {
+        share.inner[..].try_into()
+    }
+}
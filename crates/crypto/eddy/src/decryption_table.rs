@@ -1,8 +1,9 @@
 //! Lookup tables for decryption.
 //!
-//! Currently, this module just has a naive, in-memory implementation.  In the
-//! future, it should have a trait that allows users to plug their own storage
-//! system to back the lookup table.
+//! The [`DecryptionTable`] trait allows users to plug their own storage system
+//! to back the lookup table.  This module has a naive, in-memory implementation
+//! for testing, and a [`BabyStepGiantStepTable`] that trades lookup time for a
+//! table small enough to build on demand.
 
 use std::{collections::BTreeMap, future::Future, pin::Pin, sync::Arc};
 
@@ -110,6 +111,79 @@ impl DecryptionTable for MockDecryptionTable {
     }
 }
 
+/// An in-memory decryption table that recovers discrete logarithms with the
+/// baby-step giant-step algorithm.
+///
+/// Rather than storing all `2^k` discrete logarithms, it stores only `2^{k/2}`
+/// "baby steps", and each lookup takes up to `2^{k/2}` "giant steps".  This
+/// makes it cheap enough to build whenever it's needed.  The table is
+/// read-only: its `store` method always fails.
+pub struct BabyStepGiantStepTable {
+    /// The encodings of `i * B` for each baby step `i`.
+    baby_steps: BTreeMap<[u8; 32], u32>,
+    /// The bitsize of the baby steps.
+    baby_bits: usize,
+    /// The number of giant steps, each of `2^baby_bits` baby steps.
+    giant_steps: u32,
+}
+
+impl BabyStepGiantStepTable {
+    /// Build a table of all discrete logarithms up to `2^k`.
+    pub fn new(k: usize) -> Self {
+        assert!(k <= 32, "discrete logarithms must fit in 32 bits");
+        let baby_bits = (k + 1) / 2;
+
+        let mut baby_steps = BTreeMap::new();
+        let mut point = decaf377::Element::default();
+        for i in 0..1u32 << baby_bits {
+            baby_steps.insert(point.vartime_compress().0, i);
+            point += decaf377::basepoint();
+        }
+
+        Self {
+            baby_steps,
+            baby_bits,
+            giant_steps: 1 << (k - baby_bits),
+        }
+    }
+
+    fn discrete_log(&self, key: [u8; 32]) -> anyhow::Result<Option<u32>> {
+        let mut point = decaf377::Encoding(key)
+            .vartime_decompress()
+            .map_err(|_| anyhow::anyhow!("invalid group element encoding"))?;
+        let giant_step = decaf377::Fr::from(1u64 << self.baby_bits) * decaf377::basepoint();
+
+        for j in 0..self.giant_steps {
+            if let Some(i) = self.baby_steps.get(&point.vartime_compress().0) {
+                return Ok(Some((j << self.baby_bits) + i));
+            }
+            point = point - giant_step;
+        }
+
+        Ok(None)
+    }
+}
+
+impl DecryptionTable for BabyStepGiantStepTable {
+    fn lookup(
+        &self,
+        key: [u8; 32],
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<u32>>> + Send + 'static>> {
+        futures::future::ready(self.discrete_log(key)).boxed()
+    }
+
+    fn store(
+        &self,
+        _key: [u8; 32],
+        _value: u32,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'static>> {
+        futures::future::ready(Err(anyhow::anyhow!(
+            "baby-step giant-step tables are read-only"
+        )))
+        .boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,6 +204,28 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn baby_step_giant_step_lookup() {
+        let table = BabyStepGiantStepTable::new(12);
+        for x in [0u32, 1, 63, 64, 65, 2047, 4095] {
+            let point = decaf377::Fr::from(x) * decaf377::basepoint();
+            assert_eq!(
+                table.lookup(point.vartime_compress().0).await.unwrap(),
+                Some(x)
+            );
+        }
+
+        let out_of_range = decaf377::Fr::from(4096u32) * decaf377::basepoint();
+        assert_eq!(
+            table
+                .lookup(out_of_range.vartime_compress().0)
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(table.store([0; 32], 0).await.is_err());
+    }
+
     #[tokio::test]
     #[ignore]
     async fn build_16() {
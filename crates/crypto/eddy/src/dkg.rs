@@ -1,8 +1,337 @@
-//! Types used to perform distributed key generation (WIP).
+//! Types used to perform distributed key generation.
+//!
+//! Key shares are generated using a Feldman-VSS-based protocol with three
+//! rounds, in which every message is broadcast to all participants:
+//!
+//! 1. **Dealing**: each participant samples a random polynomial of degree
+//!    `threshold - 1` and publishes a [`Dealing`], containing commitments to the
+//!    polynomial's coefficients and its evaluation at every participant's index,
+//!    each encrypted to that participant's [`ParticipantPublicKey`].
+//! 2. **Complaint**: each participant decrypts the shares dealt to it, and
+//!    publishes a [`Complaint`] against every dealer whose share is
+//!    inconsistent with the dealer's commitments.
+//! 3. **Finalization**: each dealer answers the complaints against it with
+//!    [`ComplaintResponse`]s, publicly revealing the disputed shares.  Dealers
+//!    that published malformed or multiple dealings, or failed to answer a
+//!    complaint with a valid share, are disqualified.  Each participant's key
+//!    share is the sum of the shares dealt to it by the qualified dealers, and
+//!    the encryption key is the sum of their commitments to their constant
+//!    terms.
+//!
+//! The outcome of the ceremony is determined by the broadcast messages alone,
+//! so anyone can compute the resulting [`Committee`] with [`Committee::new`],
+//! while each participant computes its [`PrivateKeyShare`] with
+//! [`Participant::finalize`].
 
-use crate::PublicKeyShare;
+use std::collections::BTreeMap;
 
+use ark_ff::{UniformRand, Zero};
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{EncryptionKey, PrivateKeyShare, PublicKeyShare};
+
+mod message;
+mod participant;
+
+pub use message::{Complaint, ComplaintResponse, Dealing};
+pub use participant::{Participant, ParticipantKey, ParticipantPublicKey};
+
+#[cfg(test)]
+mod tests;
+
+/// The parameters of a distributed key generation ceremony.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    threshold: u32,
+    participants: Vec<ParticipantPublicKey>,
+}
+
+impl Config {
+    /// Configure a ceremony among the given participants, any `threshold` of
+    /// whom will be able to jointly decrypt.
+    ///
+    /// Participant indices are assigned by position in `participants`,
+    /// starting at 1.
+    pub fn new(threshold: u32, participants: Vec<ParticipantPublicKey>) -> anyhow::Result<Self> {
+        let count = u32::try_from(participants.len())?;
+        if threshold == 0 || threshold > count {
+            anyhow::bail!(
+                "threshold must be between 1 and the number of participants ({}), got {}",
+                count,
+                threshold
+            );
+        }
+        for (i, participant) in participants.iter().enumerate() {
+            if participants[..i].contains(participant) {
+                anyhow::bail!("participant {} appears more than once", i + 1);
+            }
+        }
+
+        Ok(Config {
+            threshold,
+            participants,
+        })
+    }
+
+    /// The number of participants required to decrypt.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// The participants' public keys, in participant index order.
+    pub fn participants(&self) -> &[ParticipantPublicKey] {
+        &self.participants
+    }
+
+    /// The index of the participant with the given public key, if any.
+    pub fn participant_index(&self, key: &ParticipantPublicKey) -> Option<u32> {
+        self.participants
+            .iter()
+            .position(|participant| participant == key)
+            .map(|position| position as u32 + 1)
+    }
+
+    /// The public key of the participant with the given index, if any.
+    pub(crate) fn participant(&self, index: u32) -> Option<&ParticipantPublicKey> {
+        self.participants.get(index.checked_sub(1)? as usize)
+    }
+
+    /// The indices of every participant.
+    pub(crate) fn indices(&self) -> impl Iterator<Item = u32> {
+        1..=self.participants.len() as u32
+    }
+}
+
+/// The committee holding shares of a decryption key, produced by a ceremony.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Committee {
+    pub encryption_key: EncryptionKey,
     pub shares: Vec<PublicKeyShare>,
     pub threshold: u32,
 }
+
+impl Committee {
+    /// Compute the committee resulting from the messages broadcast during a
+    /// ceremony.
+    ///
+    /// # Errors
+    ///
+    /// Fails if fewer than `threshold` dealers qualified, in which case the
+    /// ceremony should be restarted.
+    pub fn new(
+        config: &Config,
+        dealings: &[Dealing],
+        complaints: &[Complaint],
+        responses: &[ComplaintResponse],
+    ) -> anyhow::Result<Self> {
+        let qualified = qualified_dealings(config, dealings, complaints, responses)?;
+
+        let encryption_key = EncryptionKey(
+            qualified
+                .iter()
+                .fold(decaf377::Element::default(), |acc, dealing| {
+                    acc + dealing.commitments[0]
+                }),
+        );
+        let shares = config
+            .indices()
+            .map(|participant_index| PublicKeyShare {
+                participant_index,
+                pub_key_share: qualified
+                    .iter()
+                    .fold(decaf377::Element::default(), |acc, dealing| {
+                        acc + dealing.share_commitment(participant_index)
+                    }),
+            })
+            .collect();
+
+        Ok(Committee {
+            encryption_key,
+            shares,
+            threshold: config.threshold,
+        })
+    }
+
+    /// The public key share of the participant with the given index, if any.
+    pub fn share(&self, participant_index: u32) -> Option<&PublicKeyShare> {
+        self.shares
+            .iter()
+            .find(|share| share.participant_index == participant_index)
+    }
+}
+
+/// Determine the dealings of the dealers that qualified in a ceremony.
+fn qualified_dealings<'a>(
+    config: &Config,
+    dealings: &'a [Dealing],
+    complaints: &[Complaint],
+    responses: &[ComplaintResponse],
+) -> anyhow::Result<Vec<&'a Dealing>> {
+    let mut by_dealer = BTreeMap::<u32, Vec<&Dealing>>::new();
+    for dealing in dealings {
+        by_dealer
+            .entry(dealing.dealer_index)
+            .or_default()
+            .push(dealing);
+    }
+
+    let mut qualified = Vec::new();
+    for (dealer_index, dealings) in by_dealer {
+        // A dealer that published more than one dealing is equivocating.
+        let &[dealing] = dealings.as_slice() else {
+            continue;
+        };
+        if dealing.check_well_formed(config).is_err() {
+            continue;
+        }
+
+        // Every complaint against the dealer must be answered with a valid share.
+        let unanswered = complaints
+            .iter()
+            .filter(|complaint| complaint.dealer_index == dealer_index)
+            .filter(|complaint| config.participant(complaint.complainer_index).is_some())
+            .any(|complaint| {
+                revealed_share(dealing, complaint.complainer_index, responses).is_none()
+            });
+        if !unanswered {
+            qualified.push(dealing);
+        }
+    }
+
+    if qualified.len() < config.threshold as usize {
+        anyhow::bail!(
+            "only {} dealers qualified, but at least {} are required",
+            qualified.len(),
+            config.threshold
+        );
+    }
+
+    Ok(qualified)
+}
+
+/// The share dealt to `participant_index` in `dealing`, if it was validly
+/// revealed in response to a complaint.
+fn revealed_share(
+    dealing: &Dealing,
+    participant_index: u32,
+    responses: &[ComplaintResponse],
+) -> Option<decaf377::Fr> {
+    responses
+        .iter()
+        .find(|response| {
+            response.dealer_index == dealing.dealer_index
+                && response.complainer_index == participant_index
+                && dealing.verify_share(participant_index, &response.share)
+        })
+        .map(|response| response.share)
+}
+
+/// Evaluate the polynomial with the given coefficients at a participant's index.
+fn evaluate(coefficients: &[decaf377::Fr], participant_index: u32) -> decaf377::Fr {
+    let x = decaf377::Fr::from(participant_index);
+    coefficients
+        .iter()
+        .rev()
+        .fold(decaf377::Fr::zero(), |acc, coefficient| {
+            acc * x + coefficient
+        })
+}
+
+/// Generate key shares for `participants` decryptors using a trusted dealer,
+/// so that any `threshold` of them can jointly decrypt.
+///
+/// The dealer learns the decryption key, so this is only suitable for testing;
+/// otherwise, key shares should be generated by a ceremony among the
+/// [`Participant`]s.
+pub fn deal<R: RngCore + CryptoRng>(
+    threshold: u32,
+    participants: u32,
+    mut rng: R,
+) -> (EncryptionKey, Vec<PrivateKeyShare>) {
+    assert!(threshold > 0 && threshold <= participants);
+
+    // The key shares are evaluations of a random polynomial of degree
+    // `threshold - 1`, whose constant term is the decryption key.
+    let coefficients = (0..threshold)
+        .map(|_| decaf377::Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+
+    let shares = (1..=participants)
+        .map(|index| PrivateKeyShare::new(index, evaluate(&coefficients, index)))
+        .collect();
+
+    (
+        EncryptionKey(coefficients[0] * decaf377::basepoint()),
+        shares,
+    )
+}
+
+impl TypeUrl for Config {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.DkgConfig";
+}
+
+impl DomainType for Config {
+    type Proto = pb::DkgConfig;
+}
+
+impl From<Config> for pb::DkgConfig {
+    fn from(config: Config) -> Self {
+        pb::DkgConfig {
+            threshold: config.threshold,
+            participants: config.participants.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::DkgConfig> for Config {
+    type Error = anyhow::Error;
+
+    fn try_from(config: pb::DkgConfig) -> Result<Self, Self::Error> {
+        Config::new(
+            config.threshold,
+            config
+                .participants
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        )
+    }
+}
+
+impl TypeUrl for Committee {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.Committee";
+}
+
+impl DomainType for Committee {
+    type Proto = pb::Committee;
+}
+
+impl From<Committee> for pb::Committee {
+    fn from(committee: Committee) -> Self {
+        pb::Committee {
+            encryption_key: Some(committee.encryption_key.into()),
+            shares: committee.shares.into_iter().map(Into::into).collect(),
+            threshold: committee.threshold,
+        }
+    }
+}
+
+impl TryFrom<pb::Committee> for Committee {
+    type Error = anyhow::Error;
+
+    fn try_from(committee: pb::Committee) -> Result<Self, Self::Error> {
+        Ok(Committee {
+            encryption_key: committee
+                .encryption_key
+                .ok_or_else(|| anyhow::anyhow!("missing encryption key"))?
+                .try_into()?,
+            shares: committee
+                .shares
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            threshold: committee.threshold,
+        })
+    }
+}
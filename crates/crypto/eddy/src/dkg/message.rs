@@ -0,0 +1,209 @@
+use decaf377::FieldExt;
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
+
+use super::Config;
+
+/// A dealer's broadcast in the first round of a ceremony: commitments to the
+/// coefficients of its secret polynomial, and its shares for each participant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dealing {
+    pub(crate) dealer_index: u32,
+    /// Commitments to the coefficients of the dealer's secret polynomial, in
+    /// increasing order of degree.
+    pub(crate) commitments: Vec<decaf377::Element>,
+    /// The share for each participant, in participant index order, encrypted
+    /// to that participant.
+    pub(crate) encrypted_shares: Vec<decaf377::Fr>,
+}
+
+/// A participant's broadcast in the second round of a ceremony, claiming that
+/// the share dealt to it by a dealer is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Complaint {
+    pub complainer_index: u32,
+    pub dealer_index: u32,
+}
+
+/// A dealer's broadcast in the final round of a ceremony, publicly revealing
+/// the share it dealt to a complainer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplaintResponse {
+    pub dealer_index: u32,
+    pub complainer_index: u32,
+    pub(crate) share: decaf377::Fr,
+}
+
+impl Dealing {
+    /// The index of the participant that produced this dealing.
+    pub fn dealer_index(&self) -> u32 {
+        self.dealer_index
+    }
+
+    /// Check that this dealing has the shape required by the ceremony.
+    pub(crate) fn check_well_formed(&self, config: &Config) -> anyhow::Result<()> {
+        if config.participant(self.dealer_index).is_none() {
+            anyhow::bail!("dealer {} is not a participant", self.dealer_index);
+        }
+        if self.commitments.len() != config.threshold as usize {
+            anyhow::bail!(
+                "dealing has {} commitments, but the threshold is {}",
+                self.commitments.len(),
+                config.threshold
+            );
+        }
+        if self.encrypted_shares.len() != config.participants.len() {
+            anyhow::bail!(
+                "dealing has {} shares, but there are {} participants",
+                self.encrypted_shares.len(),
+                config.participants.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// The commitment to the share for the given participant, computed from
+    /// the commitments to the coefficients.
+    pub(crate) fn share_commitment(&self, participant_index: u32) -> decaf377::Element {
+        let x = decaf377::Fr::from(participant_index);
+        self.commitments
+            .iter()
+            .rev()
+            .fold(decaf377::Element::default(), |acc, commitment| {
+                x * acc + *commitment
+            })
+    }
+
+    /// Check that `share` is the share for the given participant committed
+    /// to by this dealing.
+    pub(crate) fn verify_share(&self, participant_index: u32, share: &decaf377::Fr) -> bool {
+        *share * decaf377::basepoint() == self.share_commitment(participant_index)
+    }
+}
+
+impl ComplaintResponse {
+    /// The share revealed by the dealer.
+    pub fn share(&self) -> decaf377::Fr {
+        self.share
+    }
+}
+
+impl TypeUrl for Dealing {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.Dealing";
+}
+
+impl DomainType for Dealing {
+    type Proto = pb::Dealing;
+}
+
+impl From<Dealing> for pb::Dealing {
+    fn from(dealing: Dealing) -> Self {
+        pb::Dealing {
+            dealer_index: dealing.dealer_index,
+            commitments: dealing
+                .commitments
+                .iter()
+                .map(|commitment| commitment.vartime_compress().0.to_vec())
+                .collect(),
+            encrypted_shares: dealing
+                .encrypted_shares
+                .iter()
+                .map(|share| share.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::Dealing> for Dealing {
+    type Error = anyhow::Error;
+
+    fn try_from(dealing: pb::Dealing) -> Result<Self, Self::Error> {
+        Ok(Dealing {
+            dealer_index: dealing.dealer_index,
+            commitments: dealing
+                .commitments
+                .iter()
+                .map(|bytes| {
+                    decaf377::Encoding(
+                        bytes[..]
+                            .try_into()
+                            .map_err(|_| anyhow::anyhow!("commitment must be 32 bytes"))?,
+                    )
+                    .vartime_decompress()
+                    .map_err(|_| anyhow::anyhow!("invalid commitment"))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            encrypted_shares: dealing
+                .encrypted_shares
+                .iter()
+                .map(|bytes| scalar_from_bytes(bytes, "encrypted share"))
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+impl TypeUrl for Complaint {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.Complaint";
+}
+
+impl DomainType for Complaint {
+    type Proto = pb::Complaint;
+}
+
+impl From<Complaint> for pb::Complaint {
+    fn from(complaint: Complaint) -> Self {
+        pb::Complaint {
+            complainer_index: complaint.complainer_index,
+            dealer_index: complaint.dealer_index,
+        }
+    }
+}
+
+impl TryFrom<pb::Complaint> for Complaint {
+    type Error = anyhow::Error;
+
+    fn try_from(complaint: pb::Complaint) -> Result<Self, Self::Error> {
+        Ok(Complaint {
+            complainer_index: complaint.complainer_index,
+            dealer_index: complaint.dealer_index,
+        })
+    }
+}
+
+impl TypeUrl for ComplaintResponse {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.ComplaintResponse";
+}
+
+impl DomainType for ComplaintResponse {
+    type Proto = pb::ComplaintResponse;
+}
+
+impl From<ComplaintResponse> for pb::ComplaintResponse {
+    fn from(response: ComplaintResponse) -> Self {
+        pb::ComplaintResponse {
+            dealer_index: response.dealer_index,
+            complainer_index: response.complainer_index,
+            share: response.share.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ComplaintResponse> for ComplaintResponse {
+    type Error = anyhow::Error;
+
+    fn try_from(response: pb::ComplaintResponse) -> Result<Self, Self::Error> {
+        Ok(ComplaintResponse {
+            dealer_index: response.dealer_index,
+            complainer_index: response.complainer_index,
+            share: scalar_from_bytes(&response.share, "revealed share")?,
+        })
+    }
+}
+
+fn scalar_from_bytes(bytes: &[u8], what: &str) -> anyhow::Result<decaf377::Fr> {
+    decaf377::Fr::from_bytes(
+        bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("{} must be 32 bytes", what))?,
+    )
+    .map_err(|_| anyhow::anyhow!("invalid {}", what))
+}
@@ -0,0 +1,306 @@
+use ark_ff::{UniformRand, Zero};
+use decaf377::FieldExt;
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
+use rand_core::{CryptoRng, RngCore};
+
+use super::{
+    evaluate, qualified_dealings, revealed_share, Complaint, ComplaintResponse, Config, Dealing,
+};
+use crate::{PrivateKeyShare, TranscriptProtocol};
+
+/// A participant's long-term key, used to receive the key shares dealt to it
+/// during a ceremony.
+#[derive(Clone)]
+pub struct ParticipantKey {
+    key: decaf377::Fr,
+    public_key: ParticipantPublicKey,
+}
+
+/// The public counterpart of a [`ParticipantKey`], identifying a participant
+/// in a ceremony.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticipantPublicKey(pub(crate) decaf377::Element);
+
+impl Eq for ParticipantPublicKey {}
+
+/// A participant in a distributed key generation ceremony.
+///
+/// Each participant deals shares of a secret polynomial to every participant
+/// (including itself), and finalizes its key share from the shares dealt to it
+/// by every qualified dealer.
+pub struct Participant {
+    config: Config,
+    index: u32,
+    key: ParticipantKey,
+    /// The coefficients of this participant's secret polynomial.
+    coefficients: Vec<decaf377::Fr>,
+}
+
+impl ParticipantKey {
+    /// Generate a new participant key.
+    pub fn new<R: RngCore + CryptoRng>(mut rng: R) -> Self {
+        Self::from_scalar(decaf377::Fr::rand(&mut rng))
+    }
+
+    fn from_scalar(key: decaf377::Fr) -> Self {
+        ParticipantKey {
+            key,
+            public_key: ParticipantPublicKey(key * decaf377::basepoint()),
+        }
+    }
+
+    /// The public key identifying this participant.
+    pub fn public_key(&self) -> ParticipantPublicKey {
+        self.public_key
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.key.to_bytes()
+    }
+
+    /// The one-time pad encrypting the share dealt in `dealing` to `recipient_index`.
+    ///
+    /// The pad is derived from the Diffie-Hellman shared secret between the
+    /// dealer and the recipient, so either of them can compute it.
+    fn share_pad(
+        &self,
+        counterparty: &ParticipantPublicKey,
+        dealer_index: u32,
+        commitments: &[decaf377::Element],
+        recipient_index: u32,
+    ) -> decaf377::Fr {
+        let shared_secret = self.key * counterparty.0;
+
+        let mut transcript = merlin::Transcript::new(b"eddy-dkg-share");
+        transcript.append_message(b"dealer", &dealer_index.to_le_bytes());
+        for commitment in commitments {
+            transcript.append_message(b"commitment", &commitment.vartime_compress().0);
+        }
+        transcript.append_message(b"recipient", &recipient_index.to_le_bytes());
+        transcript.append_message(b"shared-secret", &shared_secret.vartime_compress().0);
+        transcript.challenge_scalar(b"pad")
+    }
+}
+
+impl TryFrom<&[u8]> for ParticipantKey {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("participant key must be 32 bytes"))?;
+        let key = decaf377::Fr::from_bytes(bytes)
+            .map_err(|_| anyhow::anyhow!("invalid participant key"))?;
+        Ok(ParticipantKey::from_scalar(key))
+    }
+}
+
+impl ParticipantPublicKey {
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.vartime_compress().0
+    }
+}
+
+impl TryFrom<&[u8]> for ParticipantPublicKey {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("participant public key must be 32 bytes"))?;
+        let element = decaf377::Encoding(bytes)
+            .vartime_decompress()
+            .map_err(|_| anyhow::anyhow!("invalid participant public key"))?;
+        Ok(ParticipantPublicKey(element))
+    }
+}
+
+impl Participant {
+    /// Join the ceremony configured by `config` as the participant holding
+    /// `key`, sampling the secret polynomial to deal.
+    pub fn new<R: RngCore + CryptoRng>(
+        config: Config,
+        key: ParticipantKey,
+        mut rng: R,
+    ) -> anyhow::Result<Self> {
+        let index = config
+            .participant_index(&key.public_key())
+            .ok_or_else(|| anyhow::anyhow!("key is not one of the ceremony's participants"))?;
+        let coefficients = (0..config.threshold)
+            .map(|_| decaf377::Fr::rand(&mut rng))
+            .collect();
+
+        Ok(Participant {
+            config,
+            index,
+            key,
+            coefficients,
+        })
+    }
+
+    /// The index of this participant in the ceremony.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Produce this participant's dealing, to broadcast in the first round.
+    pub fn dealing(&self) -> Dealing {
+        let commitments = self
+            .coefficients
+            .iter()
+            .map(|coefficient| *coefficient * decaf377::basepoint())
+            .collect::<Vec<_>>();
+
+        let encrypted_shares = self
+            .config
+            .participants
+            .iter()
+            .zip(self.config.indices())
+            .map(|(recipient, recipient_index)| {
+                evaluate(&self.coefficients, recipient_index)
+                    + self
+                        .key
+                        .share_pad(recipient, self.index, &commitments, recipient_index)
+            })
+            .collect();
+
+        Dealing {
+            dealer_index: self.index,
+            commitments,
+            encrypted_shares,
+        }
+    }
+
+    /// Check the shares dealt to this participant, producing complaints
+    /// against every dealer whose share is invalid, to broadcast in the
+    /// second round.
+    ///
+    /// Malformed dealings are publicly disqualified, so they need no complaint.
+    pub fn complaints(&self, dealings: &[Dealing]) -> Vec<Complaint> {
+        dealings
+            .iter()
+            .filter(|dealing| dealing.dealer_index != self.index)
+            .filter(|dealing| dealing.check_well_formed(&self.config).is_ok())
+            .filter(|dealing| self.decrypt_share(dealing).is_none())
+            .map(|dealing| Complaint {
+                complainer_index: self.index,
+                dealer_index: dealing.dealer_index,
+            })
+            .collect()
+    }
+
+    /// Answer the complaints against this participant's dealing by revealing
+    /// the disputed shares, to broadcast in the final round.
+    pub fn responses(&self, complaints: &[Complaint]) -> Vec<ComplaintResponse> {
+        complaints
+            .iter()
+            .filter(|complaint| complaint.dealer_index == self.index)
+            .filter(|complaint| {
+                self.config
+                    .participant(complaint.complainer_index)
+                    .is_some()
+            })
+            .map(|complaint| ComplaintResponse {
+                dealer_index: self.index,
+                complainer_index: complaint.complainer_index,
+                share: evaluate(&self.coefficients, complaint.complainer_index),
+            })
+            .collect()
+    }
+
+    /// Compute this participant's key share from the messages broadcast
+    /// during the ceremony.
+    ///
+    /// # Errors
+    ///
+    /// Fails if fewer than `threshold` dealers qualified, or if a qualified
+    /// dealer's share for this participant is invalid, which can only happen if
+    /// this participant failed to complain about it.
+    pub fn finalize(
+        &self,
+        dealings: &[Dealing],
+        complaints: &[Complaint],
+        responses: &[ComplaintResponse],
+    ) -> anyhow::Result<PrivateKeyShare> {
+        let qualified = qualified_dealings(&self.config, dealings, complaints, responses)?;
+
+        let mut key_share = decaf377::Fr::zero();
+        for dealing in qualified {
+            let share = revealed_share(dealing, self.index, responses)
+                .or_else(|| self.decrypt_share(dealing))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "dealer {} dealt an invalid share without a complaint",
+                        dealing.dealer_index
+                    )
+                })?;
+            key_share += share;
+        }
+
+        Ok(PrivateKeyShare::new(self.index, key_share))
+    }
+
+    /// Decrypt the share dealt to this participant in a well-formed dealing,
+    /// if it is valid.
+    fn decrypt_share(&self, dealing: &Dealing) -> Option<decaf377::Fr> {
+        let dealer = self.config.participant(dealing.dealer_index)?;
+        let encrypted_share = dealing.encrypted_shares.get(self.index as usize - 1)?;
+        let share = *encrypted_share
+            - self.key.share_pad(
+                dealer,
+                dealing.dealer_index,
+                &dealing.commitments,
+                self.index,
+            );
+
+        dealing.verify_share(self.index, &share).then_some(share)
+    }
+}
+
+impl TypeUrl for ParticipantKey {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.ParticipantKey";
+}
+
+impl DomainType for ParticipantKey {
+    type Proto = pb::ParticipantKey;
+}
+
+impl From<ParticipantKey> for pb::ParticipantKey {
+    fn from(key: ParticipantKey) -> Self {
+        pb::ParticipantKey {
+            inner: key.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ParticipantKey> for ParticipantKey {
+    type Error = anyhow::Error;
+
+    fn try_from(key: pb::ParticipantKey) -> Result<Self, Self::Error> {
+        key.inner[..].try_into()
+    }
+}
+
+impl TypeUrl for ParticipantPublicKey {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.ParticipantPublicKey";
+}
+
+impl DomainType for ParticipantPublicKey {
+    type Proto = pb::ParticipantPublicKey;
+}
+
+impl From<ParticipantPublicKey> for pb::ParticipantPublicKey {
+    fn from(key: ParticipantPublicKey) -> Self {
+        pb::ParticipantPublicKey {
+            inner: key.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ParticipantPublicKey> for ParticipantPublicKey {
+    type Error = anyhow::Error;
+
+    fn try_from(key: pb::ParticipantPublicKey) -> Result<Self, Self::Error> {
+        key.inner[..].try_into()
+    }
+}
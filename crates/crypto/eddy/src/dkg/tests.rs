@@ -0,0 +1,372 @@
+//! An in-process harness running ceremonies among simulated participants.
+
+use ark_ff::One;
+use penumbra_proto::DomainType;
+
+use super::*;
+use crate::{Ciphertext, DecryptionShare, DecryptionTable, MockDecryptionTable, Value, Verified};
+
+/// A simulated ceremony, where every participant sees every broadcast message.
+///
+/// The harness can interfere with each round, to simulate misbehaving participants.
+struct Harness {
+    config: Config,
+    participants: Vec<Participant>,
+}
+
+/// The outcome of a ceremony, as seen by every participant.
+struct Outcome {
+    committee: Committee,
+    private_shares: Vec<PrivateKeyShare>,
+}
+
+impl Harness {
+    fn new(threshold: u32, participants: u32) -> Self {
+        let mut rng = rand::thread_rng();
+        let keys = (0..participants)
+            .map(|_| ParticipantKey::new(&mut rng))
+            .collect::<Vec<_>>();
+        let config =
+            Config::new(threshold, keys.iter().map(|key| key.public_key()).collect()).unwrap();
+        let participants = keys
+            .into_iter()
+            .map(|key| Participant::new(config.clone(), key, &mut rng).unwrap())
+            .collect();
+
+        Harness {
+            config,
+            participants,
+        }
+    }
+
+    /// Run a ceremony among every participant, letting `tamper` modify the
+    /// dealings and `withhold` suppress the responses of any dealer before
+    /// they're broadcast.
+    fn run(
+        &self,
+        tamper: impl FnOnce(&mut Vec<Dealing>),
+        withhold: impl Fn(u32) -> bool,
+    ) -> anyhow::Result<Outcome> {
+        let mut dealings = self
+            .participants
+            .iter()
+            .map(|participant| participant.dealing())
+            .collect::<Vec<_>>();
+        tamper(&mut dealings);
+
+        let complaints = self
+            .participants
+            .iter()
+            .flat_map(|participant| participant.complaints(&dealings))
+            .collect::<Vec<_>>();
+
+        let responses = self
+            .participants
+            .iter()
+            .filter(|participant| !withhold(participant.index()))
+            .flat_map(|participant| participant.responses(&complaints))
+            .collect::<Vec<_>>();
+
+        // Every message goes over the wire.
+        let dealings = roundtrip(dealings);
+        let complaints = roundtrip(complaints);
+        let responses = roundtrip(responses);
+
+        let committee = Committee::new(&self.config, &dealings, &complaints, &responses)?;
+        let private_shares = self
+            .participants
+            .iter()
+            .map(|participant| participant.finalize(&dealings, &complaints, &responses))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Outcome {
+            committee,
+            private_shares,
+        })
+    }
+
+    fn run_honestly(&self) -> anyhow::Result<Outcome> {
+        self.run(|_| {}, |_| false)
+    }
+}
+
+fn roundtrip<T: DomainType>(messages: Vec<T>) -> Vec<T>
+where
+    anyhow::Error: From<<T as TryFrom<T::Proto>>::Error>,
+{
+    messages
+        .into_iter()
+        .map(|message| T::decode(message.encode_to_vec().as_slice()).unwrap())
+        .collect()
+}
+
+impl Outcome {
+    /// Check that each participant's key share matches its public key share.
+    fn check_key_shares(&self) {
+        for private_share in &self.private_shares {
+            assert_eq!(
+                Some(&private_share.public_key_share()),
+                self.committee.share(private_share.participant_index())
+            );
+        }
+    }
+
+    /// Decrypt `ciphertext` using the decryption shares of the given participants.
+    async fn decrypt(
+        &self,
+        ciphertext: &Ciphertext,
+        participants: &[u32],
+        table: &MockDecryptionTable,
+    ) -> anyhow::Result<Value> {
+        let mut rng = rand::thread_rng();
+        let shares = participants
+            .iter()
+            .map(|index| {
+                let private_share = &self.private_shares[*index as usize - 1];
+                private_share
+                    .decryption_share(ciphertext, &mut merlin::Transcript::new(b"test"), &mut rng)
+                    .verify(
+                        ciphertext,
+                        self.committee.share(*index).unwrap(),
+                        &mut merlin::Transcript::new(b"test"),
+                    )
+            })
+            .collect::<anyhow::Result<Vec<DecryptionShare<Verified>>>>()?;
+
+        ciphertext
+            .decrypt(self.committee.threshold, shares, table)
+            .await
+    }
+}
+
+async fn table() -> MockDecryptionTable {
+    let table = MockDecryptionTable::default();
+    table.initialize(16).await.unwrap();
+    table
+}
+
+#[tokio::test]
+async fn threshold_decryption_of_aggregate() {
+    let mut rng = rand::thread_rng();
+    let (encryption_key, private_shares) = deal(3, 4, &mut rng);
+
+    let (ciphertext_1, _) = Value::from(1000u64)
+        .transparent_encrypt(&encryption_key, &mut rng)
+        .unwrap();
+    let (ciphertext_2, _) = Value::from(2345u64)
+        .transparent_encrypt(&encryption_key, &mut rng)
+        .unwrap();
+    let aggregate = &ciphertext_1 + &ciphertext_2;
+
+    // Any three of the four participants can decrypt.
+    let shares = private_shares[1..]
+        .iter()
+        .map(|private_share| {
+            private_share
+                .decryption_share(&aggregate, &mut merlin::Transcript::new(b"test"), &mut rng)
+                .verify(
+                    &aggregate,
+                    &private_share.public_key_share(),
+                    &mut merlin::Transcript::new(b"test"),
+                )
+                .unwrap()
+        })
+        .collect();
+
+    let table = MockDecryptionTable::default();
+    table.initialize(13).await.unwrap();
+    let value = aggregate.decrypt(3, shares, &table).await.unwrap();
+    assert_eq!(value.0, 3345);
+}
+
+#[tokio::test]
+async fn honest_ceremony_allows_threshold_decryption() {
+    let mut rng = rand::thread_rng();
+    let harness = Harness::new(3, 5);
+    let outcome = harness.run_honestly().unwrap();
+    outcome.check_key_shares();
+
+    // The outcome can be serialized, e.g., to persist it.
+    assert_eq!(
+        roundtrip(vec![outcome.committee.clone()]),
+        vec![outcome.committee.clone()]
+    );
+    for private_share in roundtrip(outcome.private_shares.clone()) {
+        assert_eq!(
+            Some(&private_share.public_key_share()),
+            outcome.committee.share(private_share.participant_index())
+        );
+    }
+    assert_eq!(
+        roundtrip(vec![harness.config.clone()]),
+        vec![harness.config.clone()]
+    );
+
+    let (ciphertext, proof) = Value::from(31337u64)
+        .encrypt(
+            &outcome.committee.encryption_key,
+            &mut merlin::Transcript::new(b"test"),
+            &mut rng,
+        )
+        .unwrap();
+    proof
+        .verify(
+            &ciphertext,
+            &outcome.committee.encryption_key,
+            &mut merlin::Transcript::new(b"test"),
+        )
+        .unwrap();
+
+    // Any three of the five participants can decrypt...
+    let table = table().await;
+    for participants in [[1, 2, 3], [1, 3, 5], [2, 4, 5]] {
+        let value = outcome
+            .decrypt(&ciphertext, &participants, &table)
+            .await
+            .unwrap();
+        assert_eq!(value.0, 31337);
+    }
+
+    // ...but two can't.
+    let error = outcome
+        .decrypt(&ciphertext, &[2, 4], &table)
+        .await
+        .err()
+        .expect("two shares are below the threshold");
+    assert!(error.is::<crate::InsufficientSharesError>());
+}
+
+#[tokio::test]
+async fn dealer_answering_complaint_stays_qualified() {
+    let harness = Harness::new(2, 4);
+    let outcome = harness
+        .run(
+            // Dealer 2 deals a bad share to participant 3...
+            |dealings| dealings[1].encrypted_shares[2] += decaf377::Fr::one(),
+            // ...but reveals the correct share when participant 3 complains.
+            |_| false,
+        )
+        .unwrap();
+    outcome.check_key_shares();
+
+    // Dealer 2's dealing still contributes to the encryption key.
+    let dealings = harness
+        .participants
+        .iter()
+        .map(|participant| participant.dealing())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        Committee::new(&harness.config, &dealings, &[], &[])
+            .unwrap()
+            .encryption_key,
+        outcome.committee.encryption_key
+    );
+
+    let mut rng = rand::thread_rng();
+    let (ciphertext, _) = Value::from(4242u64)
+        .transparent_encrypt(&outcome.committee.encryption_key, &mut rng)
+        .unwrap();
+    let value = outcome
+        .decrypt(&ciphertext, &[3, 4], &table().await)
+        .await
+        .unwrap();
+    assert_eq!(value.0, 4242);
+}
+
+#[tokio::test]
+async fn dealer_ignoring_complaint_is_disqualified() {
+    let harness = Harness::new(2, 4);
+    let outcome = harness
+        .run(
+            // Dealer 2 deals a bad share to participant 3, and never answers
+            // the complaint.
+            |dealings| dealings[1].encrypted_shares[2] += decaf377::Fr::one(),
+            |dealer_index| dealer_index == 2,
+        )
+        .unwrap();
+    outcome.check_key_shares();
+
+    // Only the other dealers contribute to the encryption key.
+    let dealings = harness
+        .participants
+        .iter()
+        .map(|participant| participant.dealing())
+        .collect::<Vec<_>>();
+    let without_dealer_2 = [
+        dealings[0].clone(),
+        dealings[2].clone(),
+        dealings[3].clone(),
+    ];
+    assert_eq!(
+        Committee::new(&harness.config, &without_dealer_2, &[], &[])
+            .unwrap()
+            .encryption_key,
+        outcome.committee.encryption_key
+    );
+
+    let mut rng = rand::thread_rng();
+    let (ciphertext, _) = Value::from(4242u64)
+        .transparent_encrypt(&outcome.committee.encryption_key, &mut rng)
+        .unwrap();
+    let value = outcome
+        .decrypt(&ciphertext, &[2, 3], &table().await)
+        .await
+        .unwrap();
+    assert_eq!(value.0, 4242);
+}
+
+#[test]
+fn malformed_and_equivocating_dealers_are_disqualified() {
+    let harness = Harness::new(2, 4);
+    let outcome = harness
+        .run(
+            |dealings| {
+                // Dealer 1 commits to a polynomial of the wrong degree...
+                dealings[0].commitments.pop();
+                // ...and dealer 4 publishes two different dealings.
+                let mut second = dealings[3].clone();
+                second.encrypted_shares[0] += decaf377::Fr::one();
+                dealings.push(second);
+            },
+            |_| false,
+        )
+        .unwrap();
+    outcome.check_key_shares();
+
+    let dealings = harness
+        .participants
+        .iter()
+        .map(|participant| participant.dealing())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        Committee::new(&harness.config, &dealings[1..3], &[], &[])
+            .unwrap()
+            .encryption_key,
+        outcome.committee.encryption_key
+    );
+}
+
+#[test]
+fn ceremony_fails_without_enough_qualified_dealers() {
+    let harness = Harness::new(3, 4);
+    let result = harness.run(
+        |dealings| {
+            dealings[0].commitments.pop();
+            dealings[1].commitments.pop();
+        },
+        |_| false,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn config_rejects_bad_thresholds_and_duplicate_participants() {
+    let mut rng = rand::thread_rng();
+    let key = ParticipantKey::new(&mut rng).public_key();
+    let other = ParticipantKey::new(&mut rng).public_key();
+
+    assert!(Config::new(0, vec![key, other]).is_err());
+    assert!(Config::new(3, vec![key, other]).is_err());
+    assert!(Config::new(2, vec![key, key]).is_err());
+    assert!(Config::new(2, vec![key, other]).is_ok());
+}
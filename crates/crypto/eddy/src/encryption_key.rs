@@ -1,3 +1,52 @@
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
+
 /// The key used to encrypt ciphertexts (the public key of the encryption
 /// scheme).
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct EncryptionKey(pub(crate) decaf377::Element);
+
+impl Eq for EncryptionKey {}
+
+impl EncryptionKey {
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.vartime_compress().0
+    }
+}
+
+impl TryFrom<&[u8]> for EncryptionKey {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("encryption key must be 32 bytes"))?;
+        let element = decaf377::Encoding(bytes)
+            .vartime_decompress()
+            .map_err(|_| anyhow::anyhow!("invalid encryption key"))?;
+        Ok(EncryptionKey(element))
+    }
+}
+
+impl TypeUrl for EncryptionKey {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.EncryptionKey";
+}
+
+impl DomainType for EncryptionKey {
+    type Proto = pb::EncryptionKey;
+}
+
+impl From<EncryptionKey> for pb::EncryptionKey {
+    fn from(key: EncryptionKey) -> Self {
+        pb::EncryptionKey {
+            inner: key.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::EncryptionKey> for EncryptionKey {
+    type Error = anyhow::Error;
+
+    fn try_from(key: pb::EncryptionKey) -> Result<Self, Self::Error> {
+        key.inner[..].try_into()
+    }
+}
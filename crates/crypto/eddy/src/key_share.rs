@@ -1,4 +1,8 @@
+use decaf377::FieldExt;
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
+
 /// A decryptor's private key share.
+#[derive(Clone)]
 pub struct PrivateKeyShare {
     pub(crate) participant_index: u32,
     pub(crate) key_share: decaf377::Fr,
@@ -6,7 +10,153 @@ pub struct PrivateKeyShare {
 }
 
 /// A decryptor's public key share.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PublicKeyShare {
     pub(crate) participant_index: u32,
     pub(crate) pub_key_share: decaf377::Element,
 }
+
+impl PrivateKeyShare {
+    /// Construct the private key share of the participant with the given index.
+    pub fn new(participant_index: u32, key_share: decaf377::Fr) -> Self {
+        PrivateKeyShare {
+            participant_index,
+            key_share,
+            cached_pub: PublicKeyShare {
+                participant_index,
+                pub_key_share: key_share * decaf377::basepoint(),
+            },
+        }
+    }
+
+    /// The index of the participant holding this key share.
+    pub fn participant_index(&self) -> u32 {
+        self.participant_index
+    }
+
+    /// The public key share corresponding to this private key share.
+    pub fn public_key_share(&self) -> PublicKeyShare {
+        self.cached_pub
+    }
+
+    /// Encode this key share as its participant index followed by the key share scalar.
+    pub fn to_bytes(&self) -> [u8; 36] {
+        let mut bytes = [0u8; 36];
+        bytes[0..4].copy_from_slice(&self.participant_index.to_le_bytes());
+        bytes[4..36].copy_from_slice(&self.key_share.to_bytes());
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for PrivateKeyShare {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 36] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("private key share must be 36 bytes"))?;
+        let participant_index = u32::from_le_bytes(bytes[0..4].try_into()?);
+        let key_share = decaf377::Fr::from_bytes(bytes[4..36].try_into()?)
+            .map_err(|_| anyhow::anyhow!("invalid private key share"))?;
+        Ok(PrivateKeyShare::new(participant_index, key_share))
+    }
+}
+
+impl PublicKeyShare {
+    /// The index of the participant holding the corresponding private key share.
+    pub fn participant_index(&self) -> u32 {
+        self.participant_index
+    }
+
+    /// Encode this key share as its participant index followed by the key share point.
+    pub fn to_bytes(&self) -> [u8; 36] {
+        let mut bytes = [0u8; 36];
+        bytes[0..4].copy_from_slice(&self.participant_index.to_le_bytes());
+        bytes[4..36].copy_from_slice(&self.pub_key_share.vartime_compress().0);
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKeyShare {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 36] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("public key share must be 36 bytes"))?;
+        let participant_index = u32::from_le_bytes(bytes[0..4].try_into()?);
+        let pub_key_share = decaf377::Encoding(bytes[4..36].try_into()?)
+            .vartime_decompress()
+            .map_err(|_| anyhow::anyhow!("invalid public key share"))?;
+        Ok(PublicKeyShare {
+            participant_index,
+            pub_key_share,
+        })
+    }
+}
+
+impl TypeUrl for PrivateKeyShare {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.PrivateKeyShare";
+}
+
+impl DomainType for PrivateKeyShare {
+    type Proto = pb::PrivateKeyShare;
+}
+
+impl From<PrivateKeyShare> for pb::PrivateKeyShare {
+    fn from(share: PrivateKeyShare) -> Self {
+        pb::PrivateKeyShare {
+            participant_index: share.participant_index,
+            key_share: share.key_share.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::PrivateKeyShare> for PrivateKeyShare {
+    type Error = anyhow::Error;
+
+    fn try_from(share: pb::PrivateKeyShare) -> Result<Self, Self::Error> {
+        let key_share = decaf377::Fr::from_bytes(
+            share.key_share[..]
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("private key share must be 32 bytes"))?,
+        )
+        .map_err(|_| anyhow::anyhow!("invalid private key share"))?;
+        Ok(PrivateKeyShare::new(share.participant_index, key_share))
+    }
+}
+
+impl TypeUrl for PublicKeyShare {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.PublicKeyShare";
+}
+
+impl DomainType for PublicKeyShare {
+    type Proto = pb::PublicKeyShare;
+}
+
+impl From<PublicKeyShare> for pb::PublicKeyShare {
+    fn from(share: PublicKeyShare) -> Self {
+        pb::PublicKeyShare {
+            participant_index: share.participant_index,
+            pub_key_share: share.pub_key_share.vartime_compress().0.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::PublicKeyShare> for PublicKeyShare {
+    type Error = anyhow::Error;
+
+    fn try_from(share: pb::PublicKeyShare) -> Result<Self, Self::Error> {
+        let pub_key_share = decaf377::Encoding(
+            share.pub_key_share[..]
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("public key share must be 32 bytes"))?,
+        )
+        .vartime_decompress()
+        .map_err(|_| anyhow::anyhow!("invalid public key share"))?;
+        Ok(PublicKeyShare {
+            participant_index: share.participant_index,
+            pub_key_share,
+        })
+    }
+}
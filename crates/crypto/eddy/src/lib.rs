@@ -5,10 +5,13 @@
 //! - [x] Decryption
 //! - [x] Decryption Proofs
 //! - [x] Lookup table interface
-//! - [ ] Error on insufficient shares
-//! - [ ] Distributed key generation
-//! - [ ] Serialization
-//! - [ ] Encryption Proofs
+//! - [x] Error on insufficient shares
+//! - [x] Distributed key generation
+//! - [x] Serialization
+//! - [x] Encryption Proofs
+//! - [ ] Use by the DEX, which needs a swap circuit that accepts blinded input
+//!   commitments, a committee keyed by validators at genesis, and refunds for
+//!   batches that are never decrypted
 //!
 //! [protocol-batching]: https://protocol.penumbra.zone/main/concepts/batching_flows.html
 
@@ -28,7 +31,9 @@ pub mod proofs;
 
 pub use ciphertext::{Ciphertext, InsufficientSharesError};
 pub use decryption_share::{DecryptionShare, Unverified, VerificationStatus, Verified};
-pub use decryption_table::{DecryptionTable, MockDecryptionTable, TableLookupError};
+pub use decryption_table::{
+    BabyStepGiantStepTable, DecryptionTable, MockDecryptionTable, TableLookupError,
+};
 pub use encryption_key::EncryptionKey;
 pub use key_share::{PrivateKeyShare, PublicKeyShare};
 pub use value::Value;
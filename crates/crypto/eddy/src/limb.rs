@@ -1,7 +1,10 @@
 mod ciphertext;
 mod decryption_share;
+mod encryption_proof;
 mod value;
 
 pub use ciphertext::Ciphertext;
 pub use decryption_share::DecryptionShare;
+pub use encryption_proof::EncryptionProof;
+pub(crate) use encryption_proof::LIMB_BITS;
 pub use value::Value;
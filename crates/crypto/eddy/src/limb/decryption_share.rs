@@ -84,7 +84,34 @@ impl PrivateKeyShare {
     }
 }
 
+impl<S: VerificationStatus> DecryptionShare<S> {
+    pub(crate) fn to_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        bytes[0..32].copy_from_slice(&self.decryption_share.vartime_compress().0);
+        bytes[32..64].copy_from_slice(&self.proof.c.to_bytes());
+        bytes[64..96].copy_from_slice(&self.proof.r.to_bytes());
+        bytes
+    }
+}
+
 impl DecryptionShare<Unverified> {
+    pub(crate) fn from_bytes(participant_index: u32, bytes: &[u8; 96]) -> anyhow::Result<Self> {
+        let decryption_share = decaf377::Encoding(bytes[0..32].try_into()?)
+            .vartime_decompress()
+            .map_err(|_| anyhow::anyhow!("invalid decryption share point"))?;
+        let c = Fr::from_bytes(bytes[32..64].try_into()?)
+            .map_err(|_| anyhow::anyhow!("invalid decryption share challenge"))?;
+        let r = Fr::from_bytes(bytes[64..96].try_into()?)
+            .map_err(|_| anyhow::anyhow!("invalid decryption share response"))?;
+
+        Ok(DecryptionShare {
+            decryption_share,
+            proof: DecryptionShareProof { c, r },
+            participant_index,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
     #[allow(non_snake_case)]
     pub fn verify(
         &self,
@@ -0,0 +1,254 @@
+use ark_ff::Zero;
+use ark_std::UniformRand;
+use decaf377::{FieldExt, Fr};
+use rand_core::{CryptoRng, RngCore};
+
+use super::{Ciphertext, Value};
+use crate::{EncryptionKey, TranscriptProtocol};
+
+/// The number of bits in an encrypted limb.
+pub(crate) const LIMB_BITS: usize = 16;
+
+/// The transcript labels of the commitments for the statements that a bit
+/// ciphertext encrypts 0 and 1, respectively.
+const COMMITMENT_LABELS: [(&[u8], &[u8]); 2] = [(b"A_0", b"B_0"), (b"A_1", b"B_1")];
+
+/// A proof that a limb ciphertext encrypts a 16-bit value.
+///
+/// The limb ciphertext is decomposed into encryptions of each of the bits of
+/// the limb, which sum (with the appropriate powers of two) to the limb
+/// ciphertext, and each of which is proven to encrypt either 0 or 1.
+#[derive(Debug, Clone)]
+pub struct EncryptionProof {
+    bits: [BitProof; LIMB_BITS],
+}
+
+/// A disjunctive Chaum-Pedersen proof that a ciphertext encrypts either 0 or 1.
+///
+/// For each bit `b`, this is a proof that `(c1, c2 - b * B)` has the same
+/// discrete logarithm with respect to `(B, D)`, where `D` is the encryption
+/// key; the prover simulates the proof for the bit it didn't encrypt.
+#[derive(Debug, Clone, Copy)]
+struct BitProof {
+    ciphertext: Ciphertext,
+    challenges: [Fr; 2],
+    responses: [Fr; 2],
+}
+
+impl Value {
+    /// Encrypt this (16-bit) limb, proving that the ciphertext is well-formed.
+    ///
+    /// Also returns the blinding factor of the limb ciphertext, so that further
+    /// statements about it can be proven.
+    pub(crate) fn encrypt_with_proof<R: RngCore + CryptoRng>(
+        &self,
+        encryption_key: &EncryptionKey,
+        transcript: &mut merlin::Transcript,
+        mut rng: R,
+    ) -> (Ciphertext, EncryptionProof, Fr) {
+        debug_assert!(self.0 < 1 << LIMB_BITS);
+
+        // The limb's blinding factor is the sum of the blinding factors of its
+        // bits, so that the bit ciphertexts sum to the limb ciphertext.
+        let bit_blindings = [(); LIMB_BITS].map(|_| Fr::rand(&mut rng));
+        let blinding = bit_blindings
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, bit_blinding| acc + acc + bit_blinding);
+        let ciphertext = self.encrypt_with_blinding(encryption_key, blinding);
+
+        transcript.begin_limb_encryption();
+        transcript.append_limb_ciphertext(&ciphertext);
+
+        let bits = bit_blindings
+            .iter()
+            .enumerate()
+            .map(|(i, bit_blinding)| {
+                let bit = (self.0 >> i) & 1;
+                BitProof::new(bit, *bit_blinding, encryption_key, transcript, &mut rng)
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("there is one proof for each bit");
+
+        (ciphertext, EncryptionProof { bits }, blinding)
+    }
+}
+
+impl EncryptionProof {
+    /// Verify that the given limb ciphertext encrypts a 16-bit value.
+    pub(crate) fn verify(
+        &self,
+        ciphertext: &Ciphertext,
+        encryption_key: &EncryptionKey,
+        transcript: &mut merlin::Transcript,
+    ) -> anyhow::Result<()> {
+        transcript.begin_limb_encryption();
+        transcript.append_limb_ciphertext(ciphertext);
+
+        let sum = self
+            .bits
+            .iter()
+            .rev()
+            .fold(Ciphertext::default(), |acc, bit| {
+                let double = &acc + &acc;
+                &double + &bit.ciphertext
+            });
+        if &sum != ciphertext {
+            anyhow::bail!("bit ciphertexts do not sum to the limb ciphertext");
+        }
+
+        for bit in &self.bits {
+            bit.verify(encryption_key, transcript)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn to_bytes(&self) -> [u8; 192 * LIMB_BITS] {
+        let mut bytes = [0u8; 192 * LIMB_BITS];
+        for (i, bit) in self.bits.iter().enumerate() {
+            bytes[192 * i..192 * (i + 1)].copy_from_slice(&bit.to_bytes());
+        }
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8; 192 * LIMB_BITS]) -> anyhow::Result<Self> {
+        let bits = bytes
+            .chunks_exact(192)
+            .map(|chunk| BitProof::from_bytes(chunk.try_into()?))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .try_into()
+            .expect("there is one proof for each bit");
+        Ok(EncryptionProof { bits })
+    }
+}
+
+impl BitProof {
+    fn new<R: RngCore + CryptoRng>(
+        bit: u32,
+        blinding: Fr,
+        encryption_key: &EncryptionKey,
+        transcript: &mut merlin::Transcript,
+        mut rng: R,
+    ) -> Self {
+        let ciphertext = Value(bit).encrypt_with_blinding(encryption_key, blinding);
+        transcript.append_bit_ciphertext(&ciphertext);
+
+        let real = bit as usize;
+        let simulated = 1 - real;
+
+        // Bind the prover's randomness to the public context, the witness, and
+        // fresh randomness from the provided RNG.
+        let mut rng = transcript
+            .build_rng()
+            .rekey_with_witness_bytes(b"blinding", &blinding.to_bytes())
+            .finalize(&mut rng);
+
+        // Simulate the proof for the statement that doesn't hold, by picking
+        // its challenge and response first...
+        let mut challenges = [Fr::zero(); 2];
+        let mut responses = [Fr::zero(); 2];
+        challenges[simulated] = Fr::rand(&mut rng);
+        responses[simulated] = Fr::rand(&mut rng);
+
+        // ...and commit to a blinding factor for the one that does.
+        let k = Fr::rand(&mut rng);
+        let mut commitments = [(decaf377::Element::default(), decaf377::Element::default()); 2];
+        commitments[real] = (k * decaf377::basepoint(), k * encryption_key.0);
+        commitments[simulated] = commitments_for(
+            &ciphertext,
+            encryption_key,
+            simulated,
+            challenges[simulated],
+            responses[simulated],
+        );
+
+        for ((label_a, label_b), (a, b)) in COMMITMENT_LABELS.into_iter().zip(commitments.iter()) {
+            transcript.append_blinding_commitment(label_a, a);
+            transcript.append_blinding_commitment(label_b, b);
+        }
+
+        // The challenges of the two statements must sum to the challenge
+        // scalar, so only one of them can be chosen in advance.
+        let challenge = transcript.challenge_scalar(b"e");
+        challenges[real] = challenge - challenges[simulated];
+        responses[real] = k + challenges[real] * blinding;
+
+        BitProof {
+            ciphertext,
+            challenges,
+            responses,
+        }
+    }
+
+    fn verify(
+        &self,
+        encryption_key: &EncryptionKey,
+        transcript: &mut merlin::Transcript,
+    ) -> anyhow::Result<()> {
+        transcript.append_bit_ciphertext(&self.ciphertext);
+
+        for (bit, (label_a, label_b)) in COMMITMENT_LABELS.into_iter().enumerate() {
+            let (a, b) = commitments_for(
+                &self.ciphertext,
+                encryption_key,
+                bit,
+                self.challenges[bit],
+                self.responses[bit],
+            );
+            transcript.append_blinding_commitment(label_a, &a);
+            transcript.append_blinding_commitment(label_b, &b);
+        }
+
+        let challenge = transcript.challenge_scalar(b"e");
+        if self.challenges[0] + self.challenges[1] != challenge {
+            anyhow::bail!("bit encryption proof did not verify");
+        }
+
+        Ok(())
+    }
+
+    fn to_bytes(&self) -> [u8; 192] {
+        let mut bytes = [0u8; 192];
+        bytes[0..64].copy_from_slice(&self.ciphertext.to_bytes());
+        bytes[64..96].copy_from_slice(&self.challenges[0].to_bytes());
+        bytes[96..128].copy_from_slice(&self.challenges[1].to_bytes());
+        bytes[128..160].copy_from_slice(&self.responses[0].to_bytes());
+        bytes[160..192].copy_from_slice(&self.responses[1].to_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 192]) -> anyhow::Result<Self> {
+        let scalar = |range: std::ops::Range<usize>| {
+            Fr::from_bytes(bytes[range].try_into()?)
+                .map_err(|_| anyhow::anyhow!("invalid bit encryption proof scalar"))
+        };
+        Ok(BitProof {
+            ciphertext: Ciphertext::from_bytes(bytes[0..64].try_into()?)?,
+            challenges: [scalar(64..96)?, scalar(96..128)?],
+            responses: [scalar(128..160)?, scalar(160..192)?],
+        })
+    }
+}
+
+/// Compute the commitments of a proof that `ciphertext` encrypts `bit` from
+/// its challenge and response.
+fn commitments_for(
+    ciphertext: &Ciphertext,
+    encryption_key: &EncryptionKey,
+    bit: usize,
+    challenge: Fr,
+    response: Fr,
+) -> (decaf377::Element, decaf377::Element) {
+    let c2 = if bit == 1 {
+        ciphertext.c2 - decaf377::basepoint()
+    } else {
+        ciphertext.c2
+    };
+
+    (
+        response * decaf377::basepoint() - challenge * ciphertext.c1,
+        response * encryption_key.0 - challenge * c2,
+    )
+}
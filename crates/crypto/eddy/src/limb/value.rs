@@ -26,10 +26,21 @@ impl Value {
         mut rng: R,
     ) -> (Ciphertext, Blinding) {
         let elgamal_blind = decaf377::Fr::rand(&mut rng);
+        (
+            self.encrypt_with_blinding(encryption_key, elgamal_blind),
+            elgamal_blind,
+        )
+    }
+
+    pub fn encrypt_with_blinding(
+        &self,
+        encryption_key: &EncryptionKey,
+        elgamal_blind: Blinding,
+    ) -> Ciphertext {
         let c1 = elgamal_blind * decaf377::basepoint();
         let c2 =
             elgamal_blind * encryption_key.0 + decaf377::Fr::from(self.0) * decaf377::basepoint();
 
-        (Ciphertext { c1, c2 }, elgamal_blind)
+        Ciphertext { c1, c2 }
     }
 }
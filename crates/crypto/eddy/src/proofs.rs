@@ -1,11 +1,326 @@
-//! Encryption correctness proofs (WIP: currently, only placeholder "transparent
-//! proofs").
+//! Encryption correctness proofs.
+//!
+//! An [`EncryptionProof`] proves in zero knowledge that a [`Ciphertext`] is a
+//! well-formed encryption of a 64-bit value, and a [`CommitmentProof`] that it
+//! encrypts the value of a commitment, while a [`TransparentEncryptionProof`]
+//! reveals the encrypted value.
 
-use crate::{Ciphertext, EncryptionKey, Value};
+use ark_ff::{UniformRand, Zero};
+use decaf377::{Element, FieldExt, Fr};
+use penumbra_proto::{core::eddy::v1alpha1 as pb, DomainType, TypeUrl};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{limb, Ciphertext, EncryptionKey, TranscriptProtocol, Value};
+
+/// A zero-knowledge proof that a [`Ciphertext`] is a well-formed encryption of
+/// a 64-bit value.
+///
+/// Each of the four 16-bit limbs of the value is proven to be in range, which
+/// ensures that sums of ciphertexts remain decryptable.
+#[derive(Debug, Clone)]
+pub struct EncryptionProof {
+    pub(crate) limbs: [limb::EncryptionProof; 4],
+}
+
+/// The length of the encoding of an [`EncryptionProof`].
+const ENCRYPTION_PROOF_LEN: usize = 4 * 192 * limb::LIMB_BITS;
+
+impl EncryptionProof {
+    /// Verify that `ctxt` is a well-formed encryption to `encryption_key`.
+    ///
+    /// The `transcript` must be in the same state as the one used to produce
+    /// the proof, so that proofs can be bound to the context they were made in.
+    pub fn verify(
+        &self,
+        ctxt: &Ciphertext,
+        encryption_key: &EncryptionKey,
+        transcript: &mut merlin::Transcript,
+    ) -> anyhow::Result<()> {
+        transcript.begin_encryption();
+        transcript.append_encryption_key(encryption_key);
+
+        self.limbs[0].verify(&ctxt.c0, encryption_key, transcript)?;
+        self.limbs[1].verify(&ctxt.c1, encryption_key, transcript)?;
+        self.limbs[2].verify(&ctxt.c2, encryption_key, transcript)?;
+        self.limbs[3].verify(&ctxt.c3, encryption_key, transcript)?;
+
+        Ok(())
+    }
+
+    /// Encode this proof as the concatenation of the proofs of each limb.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.limbs.iter().flat_map(|limb| limb.to_bytes()).collect()
+    }
+}
+
+impl TryFrom<&[u8]> for EncryptionProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != ENCRYPTION_PROOF_LEN {
+            anyhow::bail!("encryption proof must be {} bytes", ENCRYPTION_PROOF_LEN);
+        }
+        let limbs = bytes
+            .chunks_exact(ENCRYPTION_PROOF_LEN / 4)
+            .map(|chunk| limb::EncryptionProof::from_bytes(chunk.try_into()?))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .try_into()
+            .expect("there is one proof for each limb");
+        Ok(EncryptionProof { limbs })
+    }
+}
+
+impl TypeUrl for EncryptionProof {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.EncryptionProof";
+}
+
+impl DomainType for EncryptionProof {
+    type Proto = pb::EncryptionProof;
+}
+
+impl From<EncryptionProof> for pb::EncryptionProof {
+    fn from(proof: EncryptionProof) -> Self {
+        pb::EncryptionProof {
+            inner: proof.to_bytes(),
+        }
+    }
+}
+
+impl TryFrom<pb::EncryptionProof> for EncryptionProof {
+    type Error = anyhow::Error;
+
+    fn try_from(proof: pb::EncryptionProof) -> Result<Self, Self::Error> {
+        proof.inner[..].try_into()
+    }
+}
+
+/// A zero-knowledge proof that a [`Ciphertext`] encrypts the value `v`
+/// committed to by `V = v * G`, for a generator `G`.
+///
+/// This is a Schnorr proof of knowledge of the value and blinding factor of
+/// each limb ciphertext, whose limb values are tied to the committed value.
+/// Together with an [`EncryptionProof`] bounding each limb to 16 bits, it
+/// proves that the ciphertext encrypts exactly the committed 64-bit value.
+#[derive(Debug, Clone)]
+pub struct CommitmentProof {
+    /// For each limb, the commitments `(s B, s D + k B)` to its nonces.
+    limb_commitments: [(Element, Element); 4],
+    /// The commitment `(sum 2^{16i} k_i) G` to the nonces of the limb values.
+    value_commitment: Element,
+    /// For each limb, the responses `(k + e v, s + e r)`.
+    responses: [(Fr, Fr); 4],
+}
+
+/// The length of the encoding of a [`CommitmentProof`].
+const COMMITMENT_PROOF_LEN: usize = 4 * 64 + 32 + 4 * 64;
+
+/// Combine the limbs `x_i` of a value into `sum 2^{16i} x_i`.
+fn combine_limbs(limbs: [Fr; 4]) -> Fr {
+    let shift = Fr::from(1u64 << limb::LIMB_BITS);
+    limbs
+        .iter()
+        .rev()
+        .fold(Fr::zero(), |acc, limb| acc * shift + limb)
+}
+
+impl CommitmentProof {
+    pub(crate) fn new<R: RngCore + CryptoRng>(
+        limbs: &[limb::Value; 4],
+        blindings: &[Fr; 4],
+        ctxt: &Ciphertext,
+        encryption_key: &EncryptionKey,
+        generator: &Element,
+        transcript: &mut merlin::Transcript,
+        mut rng: R,
+    ) -> Self {
+        let values: [Fr; 4] = std::array::from_fn(|i| Fr::from(limbs[i].0));
+        let commitment = combine_limbs(values) * *generator;
+        transcript.begin_commitment();
+        transcript.append_commitment(generator, &commitment);
+
+        // Bind the prover's randomness to the public context, the witness, and
+        // fresh randomness from the provided RNG.
+        let mut rng = blindings
+            .iter()
+            .fold(transcript.build_rng(), |builder, blinding| {
+                builder.rekey_with_witness_bytes(b"blinding", &blinding.to_bytes())
+            })
+            .finalize(&mut rng);
+
+        let nonces = [(); 4].map(|_| (Fr::rand(&mut rng), Fr::rand(&mut rng)));
+        let limb_commitments = nonces.map(|(k, s)| {
+            (
+                s * decaf377::basepoint(),
+                s * encryption_key.0 + k * decaf377::basepoint(),
+            )
+        });
+        let value_commitment = combine_limbs(nonces.map(|(k, _)| k)) * *generator;
+
+        let challenge =
+            commitment_challenge(ctxt, &limb_commitments, &value_commitment, transcript);
+        let responses = std::array::from_fn(|i| {
+            let (k, s) = nonces[i];
+            (k + challenge * values[i], s + challenge * blindings[i])
+        });
+
+        CommitmentProof {
+            limb_commitments,
+            value_commitment,
+            responses,
+        }
+    }
+
+    /// Verify that `ctxt`, encrypted to `encryption_key`, encrypts the value
+    /// committed to by `commitment` with respect to `generator`.
+    ///
+    /// The `transcript` must be in the same state as the one used to produce
+    /// the proof, so that proofs can be bound to the context they were made in.
+    pub fn verify(
+        &self,
+        ctxt: &Ciphertext,
+        encryption_key: &EncryptionKey,
+        generator: &Element,
+        commitment: &Element,
+        transcript: &mut merlin::Transcript,
+    ) -> anyhow::Result<()> {
+        transcript.begin_commitment();
+        transcript.append_commitment(generator, commitment);
+
+        let challenge = commitment_challenge(
+            ctxt,
+            &self.limb_commitments,
+            &self.value_commitment,
+            transcript,
+        );
+
+        let ctxts = [ctxt.c0, ctxt.c1, ctxt.c2, ctxt.c3];
+        for (((a, t), (z, w)), limb) in self
+            .limb_commitments
+            .iter()
+            .zip(self.responses.iter())
+            .zip(ctxts.iter())
+        {
+            if *w * decaf377::basepoint() != *a + challenge * limb.c1
+                || *w * encryption_key.0 + *z * decaf377::basepoint() != *t + challenge * limb.c2
+            {
+                anyhow::bail!("commitment proof did not verify");
+            }
+        }
+
+        let values = self.responses.map(|(z, _)| z);
+        if combine_limbs(values) * *generator != self.value_commitment + challenge * *commitment {
+            anyhow::bail!("commitment proof did not verify");
+        }
+
+        Ok(())
+    }
+
+    /// Encode this proof as the limb commitments, the value commitment, and
+    /// the responses for each limb.
+    pub fn to_bytes(&self) -> [u8; COMMITMENT_PROOF_LEN] {
+        let mut bytes = [0u8; COMMITMENT_PROOF_LEN];
+        for (i, (a, t)) in self.limb_commitments.iter().enumerate() {
+            bytes[64 * i..64 * i + 32].copy_from_slice(&a.vartime_compress().0);
+            bytes[64 * i + 32..64 * (i + 1)].copy_from_slice(&t.vartime_compress().0);
+        }
+        bytes[256..288].copy_from_slice(&self.value_commitment.vartime_compress().0);
+        for (i, (z, w)) in self.responses.iter().enumerate() {
+            bytes[288 + 64 * i..288 + 64 * i + 32].copy_from_slice(&z.to_bytes());
+            bytes[288 + 64 * i + 32..288 + 64 * (i + 1)].copy_from_slice(&w.to_bytes());
+        }
+        bytes
+    }
+}
+
+/// Append the public parts of a [`CommitmentProof`] to the transcript, and
+/// derive its challenge.
+fn commitment_challenge(
+    ctxt: &Ciphertext,
+    limb_commitments: &[(Element, Element); 4],
+    value_commitment: &Element,
+    transcript: &mut merlin::Transcript,
+) -> Fr {
+    for limb in [&ctxt.c0, &ctxt.c1, &ctxt.c2, &ctxt.c3] {
+        transcript.append_limb_ciphertext(limb);
+    }
+    for (a, t) in limb_commitments {
+        transcript.append_blinding_commitment(b"A", a);
+        transcript.append_blinding_commitment(b"T", t);
+    }
+    transcript.append_blinding_commitment(b"V", value_commitment);
+    transcript.challenge_scalar(b"e")
+}
+
+impl TryFrom<&[u8]> for CommitmentProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != COMMITMENT_PROOF_LEN {
+            anyhow::bail!("commitment proof must be {} bytes", COMMITMENT_PROOF_LEN);
+        }
+        let element = |range: std::ops::Range<usize>| {
+            decaf377::Encoding(bytes[range].try_into()?)
+                .vartime_decompress()
+                .map_err(|_| anyhow::anyhow!("invalid commitment proof element"))
+        };
+        let scalar = |range: std::ops::Range<usize>| {
+            Fr::from_bytes(bytes[range].try_into()?)
+                .map_err(|_| anyhow::anyhow!("invalid commitment proof scalar"))
+        };
+
+        let mut limb_commitments = [(Element::default(), Element::default()); 4];
+        let mut responses = [(Fr::zero(), Fr::zero()); 4];
+        for (i, (limb_commitment, response)) in limb_commitments
+            .iter_mut()
+            .zip(responses.iter_mut())
+            .enumerate()
+        {
+            *limb_commitment = (
+                element(64 * i..64 * i + 32)?,
+                element(64 * i + 32..64 * (i + 1))?,
+            );
+            *response = (
+                scalar(288 + 64 * i..288 + 64 * i + 32)?,
+                scalar(288 + 64 * i + 32..288 + 64 * (i + 1))?,
+            );
+        }
+
+        Ok(CommitmentProof {
+            limb_commitments,
+            value_commitment: element(256..288)?,
+            responses,
+        })
+    }
+}
+
+impl TypeUrl for CommitmentProof {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.CommitmentProof";
+}
+
+impl DomainType for CommitmentProof {
+    type Proto = pb::CommitmentProof;
+}
+
+impl From<CommitmentProof> for pb::CommitmentProof {
+    fn from(proof: CommitmentProof) -> Self {
+        pb::CommitmentProof {
+            inner: proof.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::CommitmentProof> for CommitmentProof {
+    type Error = anyhow::Error;
+
+    fn try_from(proof: pb::CommitmentProof) -> Result<Self, Self::Error> {
+        proof.inner[..].try_into()
+    }
+}
 
 /// Placeholder for a zk-SNARK proof that the encryption is well-formed.
 ///
 /// Note: this proof reveals the ciphertext!!!
+#[derive(Debug, Clone)]
 pub struct TransparentEncryptionProof {
     value: u64,
     blindings: [decaf377::Fr; 4],
@@ -16,6 +331,21 @@ impl TransparentEncryptionProof {
         TransparentEncryptionProof { value, blindings }
     }
 
+    /// The encrypted value, which this proof reveals.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Encode this proof as the value followed by the blinding factor of each limb.
+    pub fn to_bytes(&self) -> [u8; 136] {
+        let mut bytes = [0u8; 136];
+        bytes[0..8].copy_from_slice(&self.value.to_le_bytes());
+        for (i, blinding) in self.blindings.iter().enumerate() {
+            bytes[8 + 32 * i..8 + 32 * (i + 1)].copy_from_slice(&blinding.to_bytes());
+        }
+        bytes
+    }
+
     pub fn verify(&self, ctxt: &Ciphertext, encryption_key: &EncryptionKey) -> anyhow::Result<()> {
         let limbs = Value::from(self.value).to_limbs()?;
         let ctxts = [ctxt.c0, ctxt.c1, ctxt.c2, ctxt.c3];
@@ -39,3 +369,44 @@ impl TransparentEncryptionProof {
         Ok(())
     }
 }
+
+impl TryFrom<&[u8]> for TransparentEncryptionProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 136 {
+            anyhow::bail!("transparent encryption proof must be 136 bytes");
+        }
+        let value = u64::from_le_bytes(bytes[0..8].try_into()?);
+        let mut blindings: [decaf377::Fr; 4] = Default::default();
+        for (i, blinding) in blindings.iter_mut().enumerate() {
+            *blinding = decaf377::Fr::from_bytes(bytes[8 + 32 * i..8 + 32 * (i + 1)].try_into()?)
+                .map_err(|_| anyhow::anyhow!("invalid blinding factor"))?;
+        }
+        Ok(TransparentEncryptionProof { value, blindings })
+    }
+}
+
+impl TypeUrl for TransparentEncryptionProof {
+    const TYPE_URL: &'static str = "/penumbra.core.eddy.v1alpha1.TransparentEncryptionProof";
+}
+
+impl DomainType for TransparentEncryptionProof {
+    type Proto = pb::TransparentEncryptionProof;
+}
+
+impl From<TransparentEncryptionProof> for pb::TransparentEncryptionProof {
+    fn from(proof: TransparentEncryptionProof) -> Self {
+        pb::TransparentEncryptionProof {
+            inner: proof.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::TransparentEncryptionProof> for TransparentEncryptionProof {
+    type Error = anyhow::Error;
+
+    fn try_from(proof: pb::TransparentEncryptionProof) -> Result<Self, Self::Error> {
+        proof.inner[..].try_into()
+    }
+}
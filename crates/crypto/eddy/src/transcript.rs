@@ -1,4 +1,4 @@
-use crate::{limb, PublicKeyShare};
+use crate::{limb, EncryptionKey, PublicKeyShare};
 
 pub trait TranscriptProtocol {
     fn begin_decryption(&mut self);
@@ -7,6 +7,12 @@ pub trait TranscriptProtocol {
     fn append_limb_ciphertext(&mut self, ciphertext: &limb::Ciphertext);
     fn append_decryption_share_point(&mut self, point: &decaf377::Element);
     fn append_blinding_commitment(&mut self, label: &'static [u8], point: &decaf377::Element);
+    fn begin_encryption(&mut self);
+    fn begin_limb_encryption(&mut self);
+    fn append_encryption_key(&mut self, key: &EncryptionKey);
+    fn append_bit_ciphertext(&mut self, ciphertext: &limb::Ciphertext);
+    fn begin_commitment(&mut self);
+    fn append_commitment(&mut self, generator: &decaf377::Element, commitment: &decaf377::Element);
 
     fn challenge_scalar(&mut self, label: &'static [u8]) -> decaf377::Fr;
 }
@@ -38,6 +44,27 @@ impl TranscriptProtocol for merlin::Transcript {
         self.append_message(b"dom-sep", label);
         self.append_message(b"blinding-commitment", &point.vartime_compress().0);
     }
+    fn begin_encryption(&mut self) {
+        self.append_message(b"dom-sep", b"eddy-decaf377-encrypt");
+    }
+    fn begin_limb_encryption(&mut self) {
+        self.append_message(b"dom-sep", b"begin-limb-encryption");
+    }
+    fn append_encryption_key(&mut self, key: &EncryptionKey) {
+        self.append_message(b"encryption-key", &key.to_bytes());
+    }
+    fn append_bit_ciphertext(&mut self, ciphertext: &limb::Ciphertext) {
+        self.append_message(b"dom-sep", b"bit-ciphertext");
+        self.append_message(b"c1", &ciphertext.c1.vartime_compress().0);
+        self.append_message(b"c2", &ciphertext.c2.vartime_compress().0);
+    }
+    fn begin_commitment(&mut self) {
+        self.append_message(b"dom-sep", b"eddy-decaf377-commitment");
+    }
+    fn append_commitment(&mut self, generator: &decaf377::Element, commitment: &decaf377::Element) {
+        self.append_message(b"generator", &generator.vartime_compress().0);
+        self.append_message(b"commitment", &commitment.vartime_compress().0);
+    }
 
     fn challenge_scalar(&mut self, label: &'static [u8]) -> decaf377::Fr {
         use ark_ff::fields::PrimeField;
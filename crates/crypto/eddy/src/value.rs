@@ -1,3 +1,4 @@
+use ark_ff::UniformRand;
 use rand_core::{CryptoRng, RngCore};
 
 use crate::{limb, proofs, Ciphertext, EncryptionKey};
@@ -40,6 +41,78 @@ impl Value {
         Value(x0 + (x1 << 16) + (x2 << 32) + (x3 << 48))
     }
 
+    /// Encrypt this value to the given [`EncryptionKey`], producing a
+    /// [`Ciphertext`] and a zero-knowledge proof that it is well-formed.
+    ///
+    /// The proof is bound to the state of the `transcript`, which must be
+    /// reproduced to verify it.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        &self,
+        encryption_key: &EncryptionKey,
+        transcript: &mut merlin::Transcript,
+        rng: R,
+    ) -> anyhow::Result<(Ciphertext, proofs::EncryptionProof)> {
+        let (ciphertext, proof, _) = self.encrypt_limbs(encryption_key, transcript, rng)?;
+        Ok((ciphertext, proof))
+    }
+
+    /// Encrypt this value to the given [`EncryptionKey`], like
+    /// [`Value::encrypt`], additionally proving that the ciphertext encrypts
+    /// the value committed to by `value * generator`.
+    ///
+    /// The `generator` must have no known discrete logarithm relation to the
+    /// basepoint.  Both proofs are bound to the state of the `transcript`.
+    pub fn encrypt_committed<R: RngCore + CryptoRng>(
+        &self,
+        encryption_key: &EncryptionKey,
+        generator: &decaf377::Element,
+        transcript: &mut merlin::Transcript,
+        mut rng: R,
+    ) -> anyhow::Result<(Ciphertext, proofs::EncryptionProof, proofs::CommitmentProof)> {
+        let (ciphertext, encryption_proof, blindings) =
+            self.encrypt_limbs(encryption_key, transcript, &mut rng)?;
+        let commitment_proof = proofs::CommitmentProof::new(
+            &self.to_limbs()?,
+            &blindings,
+            &ciphertext,
+            encryption_key,
+            generator,
+            transcript,
+            &mut rng,
+        );
+
+        Ok((ciphertext, encryption_proof, commitment_proof))
+    }
+
+    /// Encrypt each limb of this value with a proof that it is well-formed,
+    /// returning the blinding factors of the limb ciphertexts.
+    fn encrypt_limbs<R: RngCore + CryptoRng>(
+        &self,
+        encryption_key: &EncryptionKey,
+        transcript: &mut merlin::Transcript,
+        mut rng: R,
+    ) -> anyhow::Result<(Ciphertext, proofs::EncryptionProof, [decaf377::Fr; 4])> {
+        use crate::TranscriptProtocol;
+
+        let limbs = self.to_limbs()?;
+
+        transcript.begin_encryption();
+        transcript.append_encryption_key(encryption_key);
+
+        let (c0, proof0, r0) = limbs[0].encrypt_with_proof(encryption_key, transcript, &mut rng);
+        let (c1, proof1, r1) = limbs[1].encrypt_with_proof(encryption_key, transcript, &mut rng);
+        let (c2, proof2, r2) = limbs[2].encrypt_with_proof(encryption_key, transcript, &mut rng);
+        let (c3, proof3, r3) = limbs[3].encrypt_with_proof(encryption_key, transcript, &mut rng);
+
+        Ok((
+            Ciphertext { c0, c1, c2, c3 },
+            proofs::EncryptionProof {
+                limbs: [proof0, proof1, proof2, proof3],
+            },
+            [r0, r1, r2, r3],
+        ))
+    }
+
     /// Encrypt this value to the given [`EncryptionKey`], producing a
     /// [`Ciphertext`] and a (transparent) encryption proof.
     ///
@@ -51,22 +124,33 @@ impl Value {
         encryption_key: &EncryptionKey,
         mut rng: R,
     ) -> anyhow::Result<(Ciphertext, proofs::TransparentEncryptionProof)> {
-        let encrypted_limbs = self
-            .to_limbs()?
-            .iter()
-            .map(|limb| limb.transparent_encrypt(encryption_key, &mut rng))
-            .collect::<Vec<_>>();
-
-        let mut blindings: [decaf377::Fr; 4] = Default::default();
-        for (i, (_, blinding)) in encrypted_limbs.iter().enumerate() {
-            blindings[i] = *blinding;
-        }
+        let blindings = [
+            decaf377::Fr::rand(&mut rng),
+            decaf377::Fr::rand(&mut rng),
+            decaf377::Fr::rand(&mut rng),
+            decaf377::Fr::rand(&mut rng),
+        ];
+        self.transparent_encrypt_with_blindings(encryption_key, blindings)
+    }
+
+    /// Encrypt this value to the given [`EncryptionKey`] using the given
+    /// blinding factor for each limb, producing a [`Ciphertext`] and a
+    /// (transparent) encryption proof.
+    ///
+    /// This allows a ciphertext to be deterministically recomputed from a
+    /// previously sampled set of blinding factors.
+    pub fn transparent_encrypt_with_blindings(
+        &self,
+        encryption_key: &EncryptionKey,
+        blindings: [decaf377::Fr; 4],
+    ) -> anyhow::Result<(Ciphertext, proofs::TransparentEncryptionProof)> {
+        let limbs = self.to_limbs()?;
 
         let ciphertext = Ciphertext {
-            c0: encrypted_limbs[0].0,
-            c1: encrypted_limbs[1].0,
-            c2: encrypted_limbs[2].0,
-            c3: encrypted_limbs[3].0,
+            c0: limbs[0].encrypt_with_blinding(encryption_key, blindings[0]),
+            c1: limbs[1].encrypt_with_blinding(encryption_key, blindings[1]),
+            c2: limbs[2].encrypt_with_blinding(encryption_key, blindings[2]),
+            c3: limbs[3].encrypt_with_blinding(encryption_key, blindings[3]),
         };
 
         let proof = proofs::TransparentEncryptionProof::new(self.0 as u64, blindings);
@@ -79,7 +163,6 @@ impl Value {
 mod tests {
     use super::*;
 
-    use ark_ff::UniformRand;
     use proptest::prelude::*;
 
     proptest! {
@@ -119,4 +202,62 @@ mod tests {
             assert!(proof.verify(&ciphertext, &encryption_key).is_ok());
         }
     }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(8))]
+        #[test]
+        fn encrypt_verify_zk_roundtrip(value: u64) {
+            let mut rng = rand::thread_rng();
+            let encryption_key = EncryptionKey(decaf377::basepoint() * decaf377::Fr::rand(&mut rng));
+            let (ciphertext, proof) = Value::from(value)
+                .encrypt(&encryption_key, &mut merlin::Transcript::new(b"test"), &mut rng)
+                .unwrap();
+
+            assert!(proof
+                .verify(&ciphertext, &encryption_key, &mut merlin::Transcript::new(b"test"))
+                .is_ok());
+            // The proof is bound to its context.
+            assert!(proof
+                .verify(&ciphertext, &encryption_key, &mut merlin::Transcript::new(b"other"))
+                .is_err());
+
+            let proof2 = proofs::EncryptionProof::try_from(&proof.to_bytes()[..]).unwrap();
+            assert!(proof2
+                .verify(&ciphertext, &encryption_key, &mut merlin::Transcript::new(b"test"))
+                .is_ok());
+        }
+
+        #[test]
+        fn encrypt_committed_verify_roundtrip(value: u64) {
+            let mut rng = rand::thread_rng();
+            let encryption_key = EncryptionKey(decaf377::basepoint() * decaf377::Fr::rand(&mut rng));
+            let generator = decaf377::basepoint() * decaf377::Fr::rand(&mut rng);
+            let commitment = generator * decaf377::Fr::from(value);
+            let (ciphertext, encryption_proof, commitment_proof) = Value::from(value)
+                .encrypt_committed(&encryption_key, &generator, &mut merlin::Transcript::new(b"test"), &mut rng)
+                .unwrap();
+
+            let mut transcript = merlin::Transcript::new(b"test");
+            assert!(encryption_proof.verify(&ciphertext, &encryption_key, &mut transcript).is_ok());
+            assert!(commitment_proof
+                .verify(&ciphertext, &encryption_key, &generator, &commitment, &mut transcript)
+                .is_ok());
+
+            // The proof doesn't hold for a commitment to a different value.
+            let other = generator * decaf377::Fr::from(value.wrapping_add(1));
+            let mut transcript = merlin::Transcript::new(b"test");
+            encryption_proof.verify(&ciphertext, &encryption_key, &mut transcript).unwrap();
+            assert!(commitment_proof
+                .verify(&ciphertext, &encryption_key, &generator, &other, &mut transcript)
+                .is_err());
+
+            let commitment_proof2 =
+                proofs::CommitmentProof::try_from(&commitment_proof.to_bytes()[..]).unwrap();
+            let mut transcript = merlin::Transcript::new(b"test");
+            encryption_proof.verify(&ciphertext, &encryption_key, &mut transcript).unwrap();
+            assert!(commitment_proof2
+                .verify(&ciphertext, &encryption_key, &generator, &commitment, &mut transcript)
+                .is_ok());
+        }
+    }
 }
@@ -1,20 +1,33 @@
 #[macro_use]
 extern crate tracing;
 
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    time::Instant,
+};
+
 use clap::Parser;
+use rand_core::OsRng;
 use tracing::Instrument;
 use tracing_subscriber::EnvFilter;
 
 use penumbra_chain::params::ChainParameters;
 use penumbra_compact_block::CompactBlock;
+use penumbra_keys::{
+    keys::{SeedPhrase, SpendKey},
+    FullViewingKey,
+};
 use penumbra_proto::{
     client::v1alpha1::{
         oblivious_query_service_client::ObliviousQueryServiceClient,
         tendermint_proxy_service_client::TendermintProxyServiceClient, ChainParametersRequest,
-        CompactBlockRangeRequest, GetStatusRequest,
+        CompactBlockRangeRequest, CompactBlockRangeResponse, GetStatusRequest,
     },
     Message,
 };
+use penumbra_view::{scan_and_record_block, trial_decrypt_blocks, Storage};
 
 use tonic::transport::{Channel, ClientTlsConfig};
 use url::Url;
@@ -57,6 +70,9 @@ pub enum Command {
         /// If set, skip downloading the genesis compact block.
         #[clap(long)]
         skip_genesis: bool,
+        /// If set, record the downloaded compact blocks to this file, for use with `scan-blocks`.
+        #[clap(long)]
+        output: Option<PathBuf>,
     },
     /// Load-test `pd` by holding open many connections subscribing to compact block updates.
     OpenConnections {
@@ -68,6 +84,18 @@ pub enum Command {
         #[clap(long)]
         full_sync: bool,
     },
+    /// Measure the throughput of scanning compact blocks as a view service does when syncing,
+    /// using the blocks recorded from genesis by `stream-blocks --output`.
+    ScanBlocks {
+        /// The file of recorded compact blocks to scan.
+        input: PathBuf,
+        /// The full viewing key to scan with; by default, a fresh one is generated.
+        #[clap(long)]
+        full_viewing_key: Option<String>,
+        /// The number of compact blocks to trial-decrypt together.
+        #[clap(long, default_value = "100")]
+        batch_size: usize,
+    },
 }
 
 impl Opt {
@@ -119,7 +147,10 @@ impl Opt {
                     res?;
                 }
             }
-            Command::StreamBlocks { skip_genesis } => {
+            Command::StreamBlocks {
+                skip_genesis,
+                ref output,
+            } => {
                 let mut client =
                     ObliviousQueryServiceClient::connect(self.node.to_string()).await?;
 
@@ -152,6 +183,11 @@ impl Opt {
                         ));
                 progress_bar.set_position(0);
 
+                let mut output = match output {
+                    Some(path) => Some(BufWriter::new(File::create(path)?)),
+                    None => None,
+                };
+
                 let mut bytes = 0;
                 let mut cb_count = 0;
                 let mut nf_count = 0;
@@ -164,6 +200,9 @@ impl Opt {
                 while let Some(block_rsp) = stream.message().await? {
                     cb_count += 1;
                     bytes += block_rsp.encoded_len();
+                    if let Some(output) = &mut output {
+                        output.write_all(&block_rsp.encode_length_delimited_to_vec())?;
+                    }
                     let block: CompactBlock = block_rsp.try_into()?;
                     nf_count += block.nullifiers.len();
                     sp_rolled_up_count += block
@@ -184,6 +223,9 @@ impl Opt {
                     progress_bar.set_position(block.height);
                 }
                 progress_bar.finish();
+                if let Some(output) = &mut output {
+                    output.flush()?;
+                }
 
                 let sp_count = sp_note_count + sp_swap_count + sp_rolled_up_count;
                 println!(
@@ -197,6 +239,86 @@ impl Opt {
                 println!("\t\t{sp_swap_count} swap payloads");
                 println!("\t\t{sp_rolled_up_count} rolled up payloads");
             }
+            Command::ScanBlocks {
+                ref input,
+                ref full_viewing_key,
+                batch_size,
+            } => {
+                let fvk: FullViewingKey = match full_viewing_key {
+                    Some(fvk) => fvk.parse()?,
+                    None => SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+                        .full_viewing_key()
+                        .clone(),
+                };
+
+                // Read all the blocks up front, so that reading the file isn't measured.
+                let file = std::fs::read(input)?;
+                let mut buf = file.as_slice();
+                let mut blocks = Vec::new();
+                while !buf.is_empty() {
+                    let block_rsp = CompactBlockRangeResponse::decode_length_delimited(&mut buf)?;
+                    let block: CompactBlock = block_rsp.try_into()?;
+                    blocks.push(block);
+                }
+
+                // The view storage can only record blocks in order from genesis.
+                if blocks.first().map(|block| block.height) != Some(0) {
+                    anyhow::bail!(
+                        "{} must hold compact blocks from genesis, recorded without --skip-genesis",
+                        input.display()
+                    );
+                }
+
+                // Scan into an in-memory view database, like a freshly initialized view service.
+                let params = blocks[0].chain_parameters.clone().unwrap_or_default();
+                let chain_id = params.chain_id.clone();
+                let storage = Storage::initialize(None::<&str>, fvk.clone(), params).await?;
+                let mut sct = penumbra_tct::Tree::new();
+
+                use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+                let progress_bar = ProgressBar::with_draw_target(
+                    blocks.len() as u64,
+                    ProgressDrawTarget::stderr(),
+                )
+                .with_style(ProgressStyle::default_bar().template(
+                    "[{elapsed}] {bar:50.cyan/blue} {pos:>7}/{len:7} {per_sec} ETA: {eta}",
+                ));
+                progress_bar.set_position(0);
+
+                let sp_count: usize = blocks.iter().map(|block| block.state_payloads.len()).sum();
+                let mut note_count = 0;
+                let mut swap_count = 0;
+
+                let start = Instant::now();
+                for batch in blocks.chunks(batch_size.max(1)) {
+                    let decrypted_blocks =
+                        trial_decrypt_blocks(&fvk, batch.iter().map(|block| (block, None)));
+
+                    for (block, decrypted) in batch.iter().cloned().zip(decrypted_blocks) {
+                        let height = block.height;
+                        // There's no node to fetch transactions or asset metadata from.
+                        let filtered_block = scan_and_record_block(
+                            &fvk, &chain_id, &mut sct, block, decrypted, &storage, None,
+                        )
+                        .await?;
+                        if let Some(filtered_block) = filtered_block {
+                            note_count += filtered_block.new_notes.len();
+                            swap_count += filtered_block.new_swaps.len();
+                        }
+                        progress_bar.set_position(height + 1);
+                    }
+                }
+                let elapsed = start.elapsed().as_secs_f64();
+                progress_bar.finish();
+
+                let cb_count = blocks.len();
+                println!("Scanned {cb_count} compact blocks in {elapsed:.2}s, containing:");
+                println!("\t{sp_count} state payloads");
+                println!("Throughput:");
+                println!("\t{:.2} compact blocks/s", cb_count as f64 / elapsed);
+                println!("\t{:.2} state payloads/s", sp_count as f64 / elapsed);
+                println!("Found {note_count} notes and {swap_count} swaps");
+            }
         }
 
         Ok(())
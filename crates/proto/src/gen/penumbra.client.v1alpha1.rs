@@ -137,6 +137,9 @@ pub struct ValidatorStatusRequest {
     pub identity_key: ::core::option::Option<
         super::super::core::crypto::v1alpha1::IdentityKey,
     >,
+    /// The height of the state to query, or 0 to query the latest state.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -329,6 +332,9 @@ pub struct LiquidityPositionsRequest {
     /// If true, include closed and withdrawn positions.
     #[prost(bool, tag = "4")]
     pub include_closed: bool,
+    /// The height of the state to query, or 0 to query the latest state.
+    #[prost(uint64, tag = "5")]
+    pub height: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -351,6 +357,11 @@ pub struct LiquidityPositionsByPriceRequest {
     /// The maximum number of positions to return.
     #[prost(uint64, tag = "5")]
     pub limit: u64,
+    /// The height of the state to query, or 0 to query the latest state.
+    /// Only the latest state can be queried, since the DEX's liquidity indexes
+    /// aren't versioned.
+    #[prost(uint64, tag = "6")]
+    pub height: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -369,6 +380,9 @@ pub struct LiquidityPositionByIdRequest {
     pub position_id: ::core::option::Option<
         super::super::core::dex::v1alpha1::PositionId,
     >,
+    /// The height of the state to query, or 0 to query the latest state.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -376,6 +390,28 @@ pub struct LiquidityPositionByIdResponse {
     #[prost(message, optional, tag = "1")]
     pub data: ::core::option::Option<super::super::core::dex::v1alpha1::Position>,
 }
+/// Requests the rewards accumulated by a liquidity position.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub position_id: ::core::option::Option<
+        super::super::core::dex::v1alpha1::PositionId,
+    >,
+    /// The height of the state to query, or 0 to query the latest state.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsResponse {
+    /// The rewards accumulated by the position, paid in the staking token.
+    #[prost(message, optional, tag = "1")]
+    pub rewards: ::core::option::Option<super::super::core::crypto::v1alpha1::Amount>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LiquidityPositionsByIdRequest {
@@ -386,6 +422,9 @@ pub struct LiquidityPositionsByIdRequest {
     pub position_id: ::prost::alloc::vec::Vec<
         super::super::core::dex::v1alpha1::PositionId,
     >,
+    /// The height of the state to query, or 0 to query the latest state.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -403,6 +442,11 @@ pub struct SpreadRequest {
     pub trading_pair: ::core::option::Option<
         super::super::core::dex::v1alpha1::TradingPair,
     >,
+    /// The height of the state to query, or 0 to query the latest state.
+    /// Only the latest state can be queried, since the DEX's liquidity indexes
+    /// aren't versioned.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -493,6 +537,11 @@ pub struct SimulateTradeRequest {
     pub output: ::core::option::Option<super::super::core::crypto::v1alpha1::AssetId>,
     #[prost(message, optional, tag = "3")]
     pub routing: ::core::option::Option<simulate_trade_request::Routing>,
+    /// The height of the state to query, or 0 to query the latest state.
+    /// Only the latest state can be queried, since the DEX's liquidity indexes
+    /// aren't versioned.
+    #[prost(uint64, tag = "4")]
+    pub height: u64,
 }
 /// Nested message and enum types in `SimulateTradeRequest`.
 pub mod simulate_trade_request {
@@ -541,6 +590,9 @@ pub struct KeyValueRequest {
     /// whether to return a proof
     #[prost(bool, tag = "3")]
     pub proof: bool,
+    /// The height of the state to query, or 0 to query the latest state.
+    #[prost(uint64, tag = "4")]
+    pub height: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -573,6 +625,16 @@ pub struct PrefixValueRequest {
     /// The prefix to fetch subkeys from storage.
     #[prost(string, tag = "2")]
     pub prefix: ::prost::alloc::string::String,
+    /// The height of the state to query, or 0 to query the latest state.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+    /// Whether to return a proof of each key-value pair.
+    ///
+    /// Each proof only proves that its key-value pair is present: the keys are
+    /// hashed in the JMT, so there is no proof that the results are complete, and
+    /// a server can omit keys without detection.
+    #[prost(bool, tag = "4")]
+    pub proof: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -581,6 +643,74 @@ pub struct PrefixValueResponse {
     pub key: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "2")]
     pub value: ::prost::alloc::vec::Vec<u8>,
+    /// A proof of existence of the key-value pair, if requested.
+    #[prost(message, optional, tag = "3")]
+    pub proof: ::core::option::Option<
+        ::ibc_proto::ibc::core::commitment::v1::MerkleProof,
+    >,
+}
+/// Subscribes to the changes made to the state by each subsequent block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateChangesRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// The changes made to the state by a single block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateChangesResponse {
+    /// The height of the block.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The root hash of the verifiable state after the block.
+    #[prost(bytes = "vec", tag = "2")]
+    pub root_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The keys written to or deleted from the verifiable store.
+    #[prost(message, repeated, tag = "3")]
+    pub verifiable_changes: ::prost::alloc::vec::Vec<StateChange>,
+    /// The keys written to or deleted from the nonverifiable store.
+    #[prost(message, repeated, tag = "4")]
+    pub nonverifiable_changes: ::prost::alloc::vec::Vec<StateChange>,
+    /// The ABCI events emitted by the block.
+    #[prost(message, repeated, tag = "5")]
+    pub events: ::prost::alloc::vec::Vec<AbciEvent>,
+}
+/// A write to a single key in the state.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateChange {
+    /// The key, which is UTF-8 for keys in the verifiable store.
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+    /// The new value of the key, if it was not deleted.
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    /// Whether the key was deleted.
+    #[prost(bool, tag = "3")]
+    pub deleted: bool,
+}
+/// An ABCI event.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AbciEvent {
+    #[prost(string, tag = "1")]
+    pub r#type: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub attributes: ::prost::alloc::vec::Vec<AbciEventAttribute>,
+}
+/// An attribute of an ABCI event.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AbciEventAttribute {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+    /// Whether the attribute should be indexed by Tendermint.
+    #[prost(bool, tag = "3")]
+    pub index: bool,
 }
 /// GetTxRequest is the request type for the GetTx RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -674,6 +804,9 @@ pub struct BroadcastTxSyncResponse {
     pub log: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "4")]
     pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// The codespace of the `code`, if the transaction was rejected.
+    #[prost(string, tag = "5")]
+    pub codespace: ::prost::alloc::string::String,
 }
 /// GetStatusRequest is the request type for the Query/GetStatus RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1276,6 +1409,29 @@ pub mod specific_query_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// Returns the rewards accumulated by a liquidity position.
+        pub async fn position_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionRewardsRequest>,
+        ) -> Result<
+            tonic::Response<super::PositionRewardsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/PositionRewards",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         /// Returns a stream of `LiquidityPositionsByIdResponse`s.
         pub async fn liquidity_positions_by_id(
             &mut self,
@@ -1471,6 +1627,30 @@ pub mod specific_query_service_client {
             );
             self.inner.server_streaming(request.into_request(), path, codec).await
         }
+        /// Subscribes to the changes made to the state by each subsequent block,
+        /// including the keys written and the ABCI events emitted.
+        pub async fn state_changes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StateChangesRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::StateChangesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/StateChanges",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -2098,6 +2278,14 @@ pub mod specific_query_service_server {
             tonic::Response<super::LiquidityPositionByIdResponse>,
             tonic::Status,
         >;
+        /// Returns the rewards accumulated by a liquidity position.
+        async fn position_rewards(
+            &self,
+            request: tonic::Request<super::PositionRewardsRequest>,
+        ) -> Result<
+            tonic::Response<super::PositionRewardsResponse>,
+            tonic::Status,
+        >;
         /// Server streaming response type for the LiquidityPositionsById method.
         type LiquidityPositionsByIdStream: futures_core::Stream<
                 Item = Result<super::LiquidityPositionsByIdResponse, tonic::Status>,
@@ -2169,6 +2357,18 @@ pub mod specific_query_service_server {
             &self,
             request: tonic::Request<super::PrefixValueRequest>,
         ) -> Result<tonic::Response<Self::PrefixValueStream>, tonic::Status>;
+        /// Server streaming response type for the StateChanges method.
+        type StateChangesStream: futures_core::Stream<
+                Item = Result<super::StateChangesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Subscribes to the changes made to the state by each subsequent block,
+        /// including the keys written and the ABCI events emitted.
+        async fn state_changes(
+            &self,
+            request: tonic::Request<super::StateChangesRequest>,
+        ) -> Result<tonic::Response<Self::StateChangesStream>, tonic::Status>;
     }
     /// Methods for accessing chain state that are "specific" in the sense that they
     /// request specific portions of the chain state that could reveal private
@@ -2719,6 +2919,46 @@ pub mod specific_query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.client.v1alpha1.SpecificQueryService/PositionRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionRewardsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::PositionRewardsRequest>
+                    for PositionRewardsSvc<T> {
+                        type Response = super::PositionRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PositionRewardsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).position_rewards(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PositionRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.client.v1alpha1.SpecificQueryService/LiquidityPositionsById" => {
                     #[allow(non_camel_case_types)]
                     struct LiquidityPositionsByIdSvc<T: SpecificQueryService>(
@@ -3088,6 +3328,47 @@ pub mod specific_query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.client.v1alpha1.SpecificQueryService/StateChanges" => {
+                    #[allow(non_camel_case_types)]
+                    struct StateChangesSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<super::StateChangesRequest>
+                    for StateChangesSvc<T> {
+                        type Response = super::StateChangesResponse;
+                        type ResponseStream = T::StateChangesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StateChangesRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).state_changes(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StateChangesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
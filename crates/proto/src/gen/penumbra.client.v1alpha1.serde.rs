@@ -1,3 +1,236 @@
+impl serde::Serialize for AbciEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        if !self.attributes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.AbciEvent", len)?;
+        if !self.r#type.is_empty() {
+            struct_ser.serialize_field("type", &self.r#type)?;
+        }
+        if !self.attributes.is_empty() {
+            struct_ser.serialize_field("attributes", &self.attributes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AbciEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "type",
+            "attributes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Type,
+            Attributes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "type" => Ok(GeneratedField::Type),
+                            "attributes" => Ok(GeneratedField::Attributes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AbciEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.AbciEvent")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AbciEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r#type__ = None;
+                let mut attributes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Attributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attributes"));
+                            }
+                            attributes__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(AbciEvent {
+                    r#type: r#type__.unwrap_or_default(),
+                    attributes: attributes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.AbciEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AbciEventAttribute {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        if self.index {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.AbciEventAttribute", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", &self.key)?;
+        }
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", &self.value)?;
+        }
+        if self.index {
+            struct_ser.serialize_field("index", &self.index)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AbciEventAttribute {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "value",
+            "index",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            Value,
+            Index,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "value" => Ok(GeneratedField::Value),
+                            "index" => Ok(GeneratedField::Index),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AbciEventAttribute;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.AbciEventAttribute")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AbciEventAttribute, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut value__ = None;
+                let mut index__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Index => {
+                            if index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("index"));
+                            }
+                            index__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(AbciEventAttribute {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                    index: index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.AbciEventAttribute", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AbciQueryRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1223,6 +1456,9 @@ impl serde::Serialize for BroadcastTxSyncResponse {
         if !self.hash.is_empty() {
             len += 1;
         }
+        if !self.codespace.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.BroadcastTxSyncResponse", len)?;
         if self.code != 0 {
             struct_ser.serialize_field("code", ToString::to_string(&self.code).as_str())?;
@@ -1236,6 +1472,9 @@ impl serde::Serialize for BroadcastTxSyncResponse {
         if !self.hash.is_empty() {
             struct_ser.serialize_field("hash", pbjson::private::base64::encode(&self.hash).as_str())?;
         }
+        if !self.codespace.is_empty() {
+            struct_ser.serialize_field("codespace", &self.codespace)?;
+        }
         struct_ser.end()
     }
 }
@@ -1250,6 +1489,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTxSyncResponse {
             "data",
             "log",
             "hash",
+            "codespace",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1258,6 +1498,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTxSyncResponse {
             Data,
             Log,
             Hash,
+            Codespace,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1283,6 +1524,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTxSyncResponse {
                             "data" => Ok(GeneratedField::Data),
                             "log" => Ok(GeneratedField::Log),
                             "hash" => Ok(GeneratedField::Hash),
+                            "codespace" => Ok(GeneratedField::Codespace),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1306,6 +1548,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTxSyncResponse {
                 let mut data__ = None;
                 let mut log__ = None;
                 let mut hash__ = None;
+                let mut codespace__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Code => {
@@ -1338,6 +1581,12 @@ impl<'de> serde::Deserialize<'de> for BroadcastTxSyncResponse {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Codespace => {
+                            if codespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("codespace"));
+                            }
+                            codespace__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(BroadcastTxSyncResponse {
@@ -1345,6 +1594,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTxSyncResponse {
                     data: data__.unwrap_or_default(),
                     log: log__.unwrap_or_default(),
                     hash: hash__.unwrap_or_default(),
+                    codespace: codespace__.unwrap_or_default(),
                 })
             }
         }
@@ -3142,6 +3392,9 @@ impl serde::Serialize for KeyValueRequest {
         if self.proof {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.KeyValueRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -3152,6 +3405,9 @@ impl serde::Serialize for KeyValueRequest {
         if self.proof {
             struct_ser.serialize_field("proof", &self.proof)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -3166,6 +3422,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
             "chainId",
             "key",
             "proof",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3173,6 +3430,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
             ChainId,
             Key,
             Proof,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3197,6 +3455,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "key" => Ok(GeneratedField::Key),
                             "proof" => Ok(GeneratedField::Proof),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3219,6 +3478,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                 let mut chain_id__ = None;
                 let mut key__ = None;
                 let mut proof__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -3237,21 +3497,579 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                             if proof__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            proof__ = Some(map.next_value()?);
+                            proof__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(KeyValueRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    key: key__.unwrap_or_default(),
+                    proof: proof__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for KeyValueResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.KeyValueResponse", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for KeyValueResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "proof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Proof,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "proof" => Ok(GeneratedField::Proof),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = KeyValueResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.KeyValueResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<KeyValueResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut proof__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map.next_value()?;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(KeyValueResponse {
+                    value: value__,
+                    proof: proof__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PositionRewardsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRewardsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "position_id",
+            "positionId",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            PositionId,
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.PositionRewardsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PositionRewardsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut position_id__ = None;
+                let mut height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PositionRewardsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    position_id: position_id__,
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.PositionRewardsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PositionRewardsResponse", len)?;
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRewardsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rewards,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.PositionRewardsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PositionRewardsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rewards__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(PositionRewardsResponse {
+                    rewards: rewards__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.PositionRewardsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StateChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        if self.deleted {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StateChange", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", pbjson::private::base64::encode(&self.key).as_str())?;
+        }
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
+        }
+        if self.deleted {
+            struct_ser.serialize_field("deleted", &self.deleted)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StateChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "value",
+            "deleted",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            Value,
+            Deleted,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "value" => Ok(GeneratedField::Value),
+                            "deleted" => Ok(GeneratedField::Deleted),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StateChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.StateChange")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StateChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut value__ = None;
+                let mut deleted__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Deleted => {
+                            if deleted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deleted"));
+                            }
+                            deleted__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(StateChange {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                    deleted: deleted__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.StateChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StateChangesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StateChangesRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StateChangesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StateChangesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.StateChangesRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StateChangesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(KeyValueRequest {
+                Ok(StateChangesRequest {
                     chain_id: chain_id__.unwrap_or_default(),
-                    key: key__.unwrap_or_default(),
-                    proof: proof__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.StateChangesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for KeyValueResponse {
+impl serde::Serialize for StateChangesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3259,37 +4077,64 @@ impl serde::Serialize for KeyValueResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.proof.is_some() {
+        if !self.root_hash.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.KeyValueResponse", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        if !self.verifiable_changes.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.proof.as_ref() {
-            struct_ser.serialize_field("proof", v)?;
+        if !self.nonverifiable_changes.is_empty() {
+            len += 1;
+        }
+        if !self.events.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StateChangesResponse", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.root_hash.is_empty() {
+            struct_ser.serialize_field("rootHash", pbjson::private::base64::encode(&self.root_hash).as_str())?;
+        }
+        if !self.verifiable_changes.is_empty() {
+            struct_ser.serialize_field("verifiableChanges", &self.verifiable_changes)?;
+        }
+        if !self.nonverifiable_changes.is_empty() {
+            struct_ser.serialize_field("nonverifiableChanges", &self.nonverifiable_changes)?;
+        }
+        if !self.events.is_empty() {
+            struct_ser.serialize_field("events", &self.events)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for KeyValueResponse {
+impl<'de> serde::Deserialize<'de> for StateChangesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "proof",
+            "height",
+            "root_hash",
+            "rootHash",
+            "verifiable_changes",
+            "verifiableChanges",
+            "nonverifiable_changes",
+            "nonverifiableChanges",
+            "events",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            Proof,
+            Height,
+            RootHash,
+            VerifiableChanges,
+            NonverifiableChanges,
+            Events,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3311,8 +4156,11 @@ impl<'de> serde::Deserialize<'de> for KeyValueResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "proof" => Ok(GeneratedField::Proof),
+                            "height" => Ok(GeneratedField::Height),
+                            "rootHash" | "root_hash" => Ok(GeneratedField::RootHash),
+                            "verifiableChanges" | "verifiable_changes" => Ok(GeneratedField::VerifiableChanges),
+                            "nonverifiableChanges" | "nonverifiable_changes" => Ok(GeneratedField::NonverifiableChanges),
+                            "events" => Ok(GeneratedField::Events),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3322,41 +4170,69 @@ impl<'de> serde::Deserialize<'de> for KeyValueResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = KeyValueResponse;
+            type Value = StateChangesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.KeyValueResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.StateChangesResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<KeyValueResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StateChangesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut proof__ = None;
+                let mut height__ = None;
+                let mut root_hash__ = None;
+                let mut verifiable_changes__ = None;
+                let mut nonverifiable_changes__ = None;
+                let mut events__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            value__ = map.next_value()?;
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::RootHash => {
+                            if root_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rootHash"));
                             }
-                            proof__ = map.next_value()?;
+                            root_hash__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VerifiableChanges => {
+                            if verifiable_changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verifiableChanges"));
+                            }
+                            verifiable_changes__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NonverifiableChanges => {
+                            if nonverifiable_changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonverifiableChanges"));
+                            }
+                            nonverifiable_changes__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Events => {
+                            if events__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("events"));
+                            }
+                            events__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(KeyValueResponse {
-                    value: value__,
-                    proof: proof__,
+                Ok(StateChangesResponse {
+                    height: height__.unwrap_or_default(),
+                    root_hash: root_hash__.unwrap_or_default(),
+                    verifiable_changes: verifiable_changes__.unwrap_or_default(),
+                    nonverifiable_changes: nonverifiable_changes__.unwrap_or_default(),
+                    events: events__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.KeyValueResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.StateChangesResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for key_value_response::Value {
@@ -3466,6 +4342,9 @@ impl serde::Serialize for LiquidityPositionByIdRequest {
         if self.position_id.is_some() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.LiquidityPositionByIdRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -3473,6 +4352,9 @@ impl serde::Serialize for LiquidityPositionByIdRequest {
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -3487,12 +4369,14 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
             "chainId",
             "position_id",
             "positionId",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             PositionId,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3516,6 +4400,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3537,6 +4422,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
             {
                 let mut chain_id__ = None;
                 let mut position_id__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -3551,11 +4437,20 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                             }
                             position_id__ = map.next_value()?;
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(LiquidityPositionByIdRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     position_id: position_id__,
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
@@ -3667,6 +4562,9 @@ impl serde::Serialize for LiquidityPositionsByIdRequest {
         if !self.position_id.is_empty() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.LiquidityPositionsByIdRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -3674,6 +4572,9 @@ impl serde::Serialize for LiquidityPositionsByIdRequest {
         if !self.position_id.is_empty() {
             struct_ser.serialize_field("positionId", &self.position_id)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -3688,12 +4589,14 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
             "chainId",
             "position_id",
             "positionId",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             PositionId,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3717,6 +4620,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3738,6 +4642,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
             {
                 let mut chain_id__ = None;
                 let mut position_id__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -3752,11 +4657,20 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
                             }
                             position_id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(LiquidityPositionsByIdRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     position_id: position_id__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
@@ -3871,6 +4785,9 @@ impl serde::Serialize for LiquidityPositionsByPriceRequest {
         if self.limit != 0 {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.LiquidityPositionsByPriceRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -3881,6 +4798,9 @@ impl serde::Serialize for LiquidityPositionsByPriceRequest {
         if self.limit != 0 {
             struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -3896,6 +4816,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
             "trading_pair",
             "tradingPair",
             "limit",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3903,6 +4824,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
             ChainId,
             TradingPair,
             Limit,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3927,6 +4849,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             "limit" => Ok(GeneratedField::Limit),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3949,6 +4872,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                 let mut chain_id__ = None;
                 let mut trading_pair__ = None;
                 let mut limit__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -3971,12 +4895,21 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(LiquidityPositionsByPriceRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     trading_pair: trading_pair__,
                     limit: limit__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
@@ -4088,6 +5021,9 @@ impl serde::Serialize for LiquidityPositionsRequest {
         if self.include_closed {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.LiquidityPositionsRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -4095,6 +5031,9 @@ impl serde::Serialize for LiquidityPositionsRequest {
         if self.include_closed {
             struct_ser.serialize_field("includeClosed", &self.include_closed)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -4109,12 +5048,14 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
             "chainId",
             "include_closed",
             "includeClosed",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             IncludeClosed,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4138,6 +5079,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4159,6 +5101,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
             {
                 let mut chain_id__ = None;
                 let mut include_closed__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -4173,11 +5116,20 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
                             }
                             include_closed__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(LiquidityPositionsRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     include_closed: include_closed__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
@@ -4490,6 +5442,12 @@ impl serde::Serialize for PrefixValueRequest {
         if !self.prefix.is_empty() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.proof {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PrefixValueRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -4497,6 +5455,12 @@ impl serde::Serialize for PrefixValueRequest {
         if !self.prefix.is_empty() {
             struct_ser.serialize_field("prefix", &self.prefix)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if self.proof {
+            struct_ser.serialize_field("proof", &self.proof)?;
+        }
         struct_ser.end()
     }
 }
@@ -4510,12 +5474,16 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
             "chain_id",
             "chainId",
             "prefix",
+            "height",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             Prefix,
+            Height,
+            Proof,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4539,6 +5507,8 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "prefix" => Ok(GeneratedField::Prefix),
+                            "height" => Ok(GeneratedField::Height),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4560,6 +5530,8 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
             {
                 let mut chain_id__ = None;
                 let mut prefix__ = None;
+                let mut height__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -4574,11 +5546,27 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
                             }
                             prefix__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(PrefixValueRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     prefix: prefix__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    proof: proof__.unwrap_or_default(),
                 })
             }
         }
@@ -4599,6 +5587,9 @@ impl serde::Serialize for PrefixValueResponse {
         if !self.value.is_empty() {
             len += 1;
         }
+        if self.proof.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.PrefixValueResponse", len)?;
         if !self.key.is_empty() {
             struct_ser.serialize_field("key", &self.key)?;
@@ -4606,6 +5597,9 @@ impl serde::Serialize for PrefixValueResponse {
         if !self.value.is_empty() {
             struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
         }
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -4618,12 +5612,14 @@ impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
         const FIELDS: &[&str] = &[
             "key",
             "value",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Key,
             Value,
+            Proof,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4647,6 +5643,7 @@ impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
                         match value {
                             "key" => Ok(GeneratedField::Key),
                             "value" => Ok(GeneratedField::Value),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4668,6 +5665,7 @@ impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
             {
                 let mut key__ = None;
                 let mut value__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Key => {
@@ -4684,11 +5682,18 @@ impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(PrefixValueResponse {
                     key: key__.unwrap_or_default(),
                     value: value__.unwrap_or_default(),
+                    proof: proof__,
                 })
             }
         }
@@ -5142,6 +6147,9 @@ impl serde::Serialize for SimulateTradeRequest {
         if self.routing.is_some() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SimulateTradeRequest", len)?;
         if let Some(v) = self.input.as_ref() {
             struct_ser.serialize_field("input", v)?;
@@ -5152,6 +6160,9 @@ impl serde::Serialize for SimulateTradeRequest {
         if let Some(v) = self.routing.as_ref() {
             struct_ser.serialize_field("routing", v)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -5165,6 +6176,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             "input",
             "output",
             "routing",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5172,6 +6184,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             Input,
             Output,
             Routing,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5196,6 +6209,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             "input" => Ok(GeneratedField::Input),
                             "output" => Ok(GeneratedField::Output),
                             "routing" => Ok(GeneratedField::Routing),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5218,6 +6232,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                 let mut input__ = None;
                 let mut output__ = None;
                 let mut routing__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Input => {
@@ -5238,12 +6253,21 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             }
                             routing__ = map.next_value()?;
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(SimulateTradeRequest {
                     input: input__,
                     output: output__,
                     routing: routing__,
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
@@ -5607,6 +6631,9 @@ impl serde::Serialize for SpreadRequest {
         if self.trading_pair.is_some() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SpreadRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -5614,6 +6641,9 @@ impl serde::Serialize for SpreadRequest {
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -5628,12 +6658,14 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
             "chainId",
             "trading_pair",
             "tradingPair",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             TradingPair,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5657,6 +6689,7 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5678,6 +6711,7 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
             {
                 let mut chain_id__ = None;
                 let mut trading_pair__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -5692,11 +6726,20 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
                             }
                             trading_pair__ = map.next_value()?;
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(SpreadRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     trading_pair: trading_pair__,
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
@@ -7459,6 +8502,9 @@ impl serde::Serialize for ValidatorStatusRequest {
         if self.identity_key.is_some() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ValidatorStatusRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -7466,6 +8512,9 @@ impl serde::Serialize for ValidatorStatusRequest {
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -7480,12 +8529,14 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
             "chainId",
             "identity_key",
             "identityKey",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             IdentityKey,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -7509,6 +8560,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -7530,6 +8582,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
             {
                 let mut chain_id__ = None;
                 let mut identity_key__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -7544,11 +8597,20 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
                             }
                             identity_key__ = map.next_value()?;
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(ValidatorStatusRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     identity_key: identity_key__,
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
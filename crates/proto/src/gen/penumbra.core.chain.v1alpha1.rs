@@ -14,9 +14,6 @@ pub struct ChainParameters {
     /// The maximum number of validators in the consensus set.
     #[prost(uint64, tag = "4")]
     pub active_validator_limit: u64,
-    /// The base reward rate, expressed in basis points of basis points
-    #[prost(uint64, tag = "9")]
-    pub base_reward_rate: u64,
     /// The penalty for slashing due to misbehavior.
     #[prost(uint64, tag = "5")]
     pub slashing_penalty_misbehavior: u64,
@@ -60,6 +57,72 @@ pub struct ChainParameters {
     /// Whether DAO spend proposals are enabled.
     #[prost(bool, tag = "25")]
     pub dao_spend_proposals_enabled: bool,
+    /// The assets, other than the staking token, that transaction fees may be paid in.
+    ///
+    /// Fees paid in these assets are swapped to the staking token through the DEX at the end
+    /// of the block in which they were collected.
+    #[prost(message, repeated, tag = "26")]
+    pub allowed_fee_assets: ::prost::alloc::vec::Vec<super::super::crypto::v1alpha1::AssetId>,
+    /// The prices used to compute the minimum fee of a transaction from its gas cost.
+    #[prost(message, optional, tag = "27")]
+    pub gas_prices: ::core::option::Option<GasPrices>,
+    /// The assets always considered as intermediate hops when routing trades through the DEX.
+    #[prost(message, repeated, tag = "28")]
+    pub dex_fixed_candidates: ::prost::alloc::vec::Vec<super::super::crypto::v1alpha1::AssetId>,
+    /// The maximum number of hops in a route through the DEX.
+    #[prost(uint32, tag = "29")]
+    pub dex_max_hops: u32,
+    /// The assets considered as intermediate hops when searching for arbitrage opportunities.
+    #[prost(message, repeated, tag = "30")]
+    pub dex_arb_candidates: ::prost::alloc::vec::Vec<super::super::crypto::v1alpha1::AssetId>,
+    /// The number of the most liquid assets, by their liquidity against the staking token, to
+    /// consider as intermediate hops in addition to the fixed candidates.
+    #[prost(uint32, tag = "31")]
+    pub dex_auto_candidates: u32,
+    /// The amount of the staking token issued each epoch.
+    #[prost(uint64, tag = "32")]
+    pub issuance_per_epoch: u64,
+    /// The share of each epoch's issuance deposited into the DAO, in basis points.
+    #[prost(uint32, tag = "33")]
+    pub dao_issuance_bps: u32,
+    /// The share of each epoch's issuance paid as rewards to liquidity providers, in basis points.
+    ///
+    /// The remainder of the issuance is paid as staking rewards.
+    #[prost(uint32, tag = "34")]
+    pub lp_issuance_bps: u32,
+    /// The rates at which the minimum fee is converted into each of the allowed fee assets.
+    ///
+    /// Every allowed fee asset must have exactly one rate.
+    #[prost(message, repeated, tag = "36")]
+    pub fee_asset_rates: ::prost::alloc::vec::Vec<FeeAssetRate>,
+}
+/// The prices of each kind of gas, denominated in the staking token.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GasPrices {
+    /// The price per unit of block space, in bytes.
+    #[prost(uint64, tag = "1")]
+    pub block_space_price: u64,
+    /// The price per unit of compact block space, in bytes.
+    #[prost(uint64, tag = "2")]
+    pub compact_block_space_price: u64,
+    /// The price per unit of verification cost.
+    #[prost(uint64, tag = "3")]
+    pub verification_price: u64,
+    /// The price per unit of execution cost.
+    #[prost(uint64, tag = "4")]
+    pub execution_price: u64,
+}
+/// The rate at which fees paid in an asset other than the staking token are priced.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeAssetRate {
+    /// The asset fees are paid in.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::crypto::v1alpha1::AssetId>,
+    /// The number of units of the asset charged per unit of the staking token.
+    #[prost(message, optional, tag = "2")]
+    pub rate: ::core::option::Option<Ratio>,
 }
 /// The ratio between two numbers, used in governance to describe vote thresholds and quorums.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -72,6 +135,21 @@ pub struct Ratio {
     #[prost(uint64, tag = "2")]
     pub denominator: u64,
 }
+/// A change to a single chain parameter, as part of a partial parameter change proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParameterChange {
+    /// The name of the parameter to change, as in the fields of the domain type for `ChainParameters`
+    /// (nested fields are separated by dots, as in `gas_prices.block_space_price`).
+    #[prost(string, tag = "1")]
+    pub parameter: ::prost::alloc::string::String,
+    /// The value the parameter must have for the change to be applied.
+    #[prost(string, tag = "2")]
+    pub old_value: ::prost::alloc::string::String,
+    /// The value the parameter is changed to.
+    #[prost(string, tag = "3")]
+    pub new_value: ::prost::alloc::string::String,
+}
 /// Parameters for Fuzzy Message Detection
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -18,9 +18,6 @@ impl serde::Serialize for ChainParameters {
         if self.active_validator_limit != 0 {
             len += 1;
         }
-        if self.base_reward_rate != 0 {
-            len += 1;
-        }
         if self.slashing_penalty_misbehavior != 0 {
             len += 1;
         }
@@ -60,6 +57,36 @@ impl serde::Serialize for ChainParameters {
         if self.dao_spend_proposals_enabled {
             len += 1;
         }
+        if !self.allowed_fee_assets.is_empty() {
+            len += 1;
+        }
+        if self.gas_prices.is_some() {
+            len += 1;
+        }
+        if !self.dex_fixed_candidates.is_empty() {
+            len += 1;
+        }
+        if self.dex_max_hops != 0 {
+            len += 1;
+        }
+        if !self.dex_arb_candidates.is_empty() {
+            len += 1;
+        }
+        if self.dex_auto_candidates != 0 {
+            len += 1;
+        }
+        if self.issuance_per_epoch != 0 {
+            len += 1;
+        }
+        if self.dao_issuance_bps != 0 {
+            len += 1;
+        }
+        if self.lp_issuance_bps != 0 {
+            len += 1;
+        }
+        if !self.fee_asset_rates.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.ChainParameters", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -73,9 +100,6 @@ impl serde::Serialize for ChainParameters {
         if self.active_validator_limit != 0 {
             struct_ser.serialize_field("activeValidatorLimit", ToString::to_string(&self.active_validator_limit).as_str())?;
         }
-        if self.base_reward_rate != 0 {
-            struct_ser.serialize_field("baseRewardRate", ToString::to_string(&self.base_reward_rate).as_str())?;
-        }
         if self.slashing_penalty_misbehavior != 0 {
             struct_ser.serialize_field("slashingPenaltyMisbehavior", ToString::to_string(&self.slashing_penalty_misbehavior).as_str())?;
         }
@@ -115,6 +139,36 @@ impl serde::Serialize for ChainParameters {
         if self.dao_spend_proposals_enabled {
             struct_ser.serialize_field("daoSpendProposalsEnabled", &self.dao_spend_proposals_enabled)?;
         }
+        if !self.allowed_fee_assets.is_empty() {
+            struct_ser.serialize_field("allowedFeeAssets", &self.allowed_fee_assets)?;
+        }
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
+        }
+        if !self.dex_fixed_candidates.is_empty() {
+            struct_ser.serialize_field("dexFixedCandidates", &self.dex_fixed_candidates)?;
+        }
+        if self.dex_max_hops != 0 {
+            struct_ser.serialize_field("dexMaxHops", &self.dex_max_hops)?;
+        }
+        if !self.dex_arb_candidates.is_empty() {
+            struct_ser.serialize_field("dexArbCandidates", &self.dex_arb_candidates)?;
+        }
+        if self.dex_auto_candidates != 0 {
+            struct_ser.serialize_field("dexAutoCandidates", &self.dex_auto_candidates)?;
+        }
+        if self.issuance_per_epoch != 0 {
+            struct_ser.serialize_field("issuancePerEpoch", ToString::to_string(&self.issuance_per_epoch).as_str())?;
+        }
+        if self.dao_issuance_bps != 0 {
+            struct_ser.serialize_field("daoIssuanceBps", &self.dao_issuance_bps)?;
+        }
+        if self.lp_issuance_bps != 0 {
+            struct_ser.serialize_field("lpIssuanceBps", &self.lp_issuance_bps)?;
+        }
+        if !self.fee_asset_rates.is_empty() {
+            struct_ser.serialize_field("feeAssetRates", &self.fee_asset_rates)?;
+        }
         struct_ser.end()
     }
 }
@@ -133,8 +187,6 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             "unbondingEpochs",
             "active_validator_limit",
             "activeValidatorLimit",
-            "base_reward_rate",
-            "baseRewardRate",
             "slashing_penalty_misbehavior",
             "slashingPenaltyMisbehavior",
             "slashing_penalty_downtime",
@@ -161,6 +213,26 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             "proposalSlashThreshold",
             "dao_spend_proposals_enabled",
             "daoSpendProposalsEnabled",
+            "allowed_fee_assets",
+            "allowedFeeAssets",
+            "gas_prices",
+            "gasPrices",
+            "dex_fixed_candidates",
+            "dexFixedCandidates",
+            "dex_max_hops",
+            "dexMaxHops",
+            "dex_arb_candidates",
+            "dexArbCandidates",
+            "dex_auto_candidates",
+            "dexAutoCandidates",
+            "issuance_per_epoch",
+            "issuancePerEpoch",
+            "dao_issuance_bps",
+            "daoIssuanceBps",
+            "lp_issuance_bps",
+            "lpIssuanceBps",
+            "fee_asset_rates",
+            "feeAssetRates",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -169,7 +241,6 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             EpochDuration,
             UnbondingEpochs,
             ActiveValidatorLimit,
-            BaseRewardRate,
             SlashingPenaltyMisbehavior,
             SlashingPenaltyDowntime,
             SignedBlocksWindowLen,
@@ -183,6 +254,16 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             ProposalPassThreshold,
             ProposalSlashThreshold,
             DaoSpendProposalsEnabled,
+            AllowedFeeAssets,
+            GasPrices,
+            DexFixedCandidates,
+            DexMaxHops,
+            DexArbCandidates,
+            DexAutoCandidates,
+            IssuancePerEpoch,
+            DaoIssuanceBps,
+            LpIssuanceBps,
+            FeeAssetRates,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -208,7 +289,6 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                             "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
                             "unbondingEpochs" | "unbonding_epochs" => Ok(GeneratedField::UnbondingEpochs),
                             "activeValidatorLimit" | "active_validator_limit" => Ok(GeneratedField::ActiveValidatorLimit),
-                            "baseRewardRate" | "base_reward_rate" => Ok(GeneratedField::BaseRewardRate),
                             "slashingPenaltyMisbehavior" | "slashing_penalty_misbehavior" => Ok(GeneratedField::SlashingPenaltyMisbehavior),
                             "slashingPenaltyDowntime" | "slashing_penalty_downtime" => Ok(GeneratedField::SlashingPenaltyDowntime),
                             "signedBlocksWindowLen" | "signed_blocks_window_len" => Ok(GeneratedField::SignedBlocksWindowLen),
@@ -222,6 +302,16 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                             "proposalPassThreshold" | "proposal_pass_threshold" => Ok(GeneratedField::ProposalPassThreshold),
                             "proposalSlashThreshold" | "proposal_slash_threshold" => Ok(GeneratedField::ProposalSlashThreshold),
                             "daoSpendProposalsEnabled" | "dao_spend_proposals_enabled" => Ok(GeneratedField::DaoSpendProposalsEnabled),
+                            "allowedFeeAssets" | "allowed_fee_assets" => Ok(GeneratedField::AllowedFeeAssets),
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "dexFixedCandidates" | "dex_fixed_candidates" => Ok(GeneratedField::DexFixedCandidates),
+                            "dexMaxHops" | "dex_max_hops" => Ok(GeneratedField::DexMaxHops),
+                            "dexArbCandidates" | "dex_arb_candidates" => Ok(GeneratedField::DexArbCandidates),
+                            "dexAutoCandidates" | "dex_auto_candidates" => Ok(GeneratedField::DexAutoCandidates),
+                            "issuancePerEpoch" | "issuance_per_epoch" => Ok(GeneratedField::IssuancePerEpoch),
+                            "daoIssuanceBps" | "dao_issuance_bps" => Ok(GeneratedField::DaoIssuanceBps),
+                            "lpIssuanceBps" | "lp_issuance_bps" => Ok(GeneratedField::LpIssuanceBps),
+                            "feeAssetRates" | "fee_asset_rates" => Ok(GeneratedField::FeeAssetRates),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -245,7 +335,6 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                 let mut epoch_duration__ = None;
                 let mut unbonding_epochs__ = None;
                 let mut active_validator_limit__ = None;
-                let mut base_reward_rate__ = None;
                 let mut slashing_penalty_misbehavior__ = None;
                 let mut slashing_penalty_downtime__ = None;
                 let mut signed_blocks_window_len__ = None;
@@ -259,6 +348,16 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                 let mut proposal_pass_threshold__ = None;
                 let mut proposal_slash_threshold__ = None;
                 let mut dao_spend_proposals_enabled__ = None;
+                let mut allowed_fee_assets__ = None;
+                let mut gas_prices__ = None;
+                let mut dex_fixed_candidates__ = None;
+                let mut dex_max_hops__ = None;
+                let mut dex_arb_candidates__ = None;
+                let mut dex_auto_candidates__ = None;
+                let mut issuance_per_epoch__ = None;
+                let mut dao_issuance_bps__ = None;
+                let mut lp_issuance_bps__ = None;
+                let mut fee_asset_rates__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -291,14 +390,6 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::BaseRewardRate => {
-                            if base_reward_rate__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("baseRewardRate"));
-                            }
-                            base_reward_rate__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
                         GeneratedField::SlashingPenaltyMisbehavior => {
                             if slashing_penalty_misbehavior__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("slashingPenaltyMisbehavior"));
@@ -387,6 +478,76 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                             }
                             dao_spend_proposals_enabled__ = Some(map.next_value()?);
                         }
+                        GeneratedField::AllowedFeeAssets => {
+                            if allowed_fee_assets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowedFeeAssets"));
+                            }
+                            allowed_fee_assets__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                            }
+                            gas_prices__ = map.next_value()?;
+                        }
+                        GeneratedField::DexFixedCandidates => {
+                            if dex_fixed_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexFixedCandidates"));
+                            }
+                            dex_fixed_candidates__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::DexMaxHops => {
+                            if dex_max_hops__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexMaxHops"));
+                            }
+                            dex_max_hops__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DexArbCandidates => {
+                            if dex_arb_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexArbCandidates"));
+                            }
+                            dex_arb_candidates__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::DexAutoCandidates => {
+                            if dex_auto_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexAutoCandidates"));
+                            }
+                            dex_auto_candidates__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IssuancePerEpoch => {
+                            if issuance_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("issuancePerEpoch"));
+                            }
+                            issuance_per_epoch__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DaoIssuanceBps => {
+                            if dao_issuance_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("daoIssuanceBps"));
+                            }
+                            dao_issuance_bps__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LpIssuanceBps => {
+                            if lp_issuance_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpIssuanceBps"));
+                            }
+                            lp_issuance_bps__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAssetRates => {
+                            if fee_asset_rates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetRates"));
+                            }
+                            fee_asset_rates__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ChainParameters {
@@ -394,7 +555,6 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                     epoch_duration: epoch_duration__.unwrap_or_default(),
                     unbonding_epochs: unbonding_epochs__.unwrap_or_default(),
                     active_validator_limit: active_validator_limit__.unwrap_or_default(),
-                    base_reward_rate: base_reward_rate__.unwrap_or_default(),
                     slashing_penalty_misbehavior: slashing_penalty_misbehavior__.unwrap_or_default(),
                     slashing_penalty_downtime: slashing_penalty_downtime__.unwrap_or_default(),
                     signed_blocks_window_len: signed_blocks_window_len__.unwrap_or_default(),
@@ -408,6 +568,16 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                     proposal_pass_threshold: proposal_pass_threshold__.unwrap_or_default(),
                     proposal_slash_threshold: proposal_slash_threshold__.unwrap_or_default(),
                     dao_spend_proposals_enabled: dao_spend_proposals_enabled__.unwrap_or_default(),
+                    allowed_fee_assets: allowed_fee_assets__.unwrap_or_default(),
+                    gas_prices: gas_prices__,
+                    dex_fixed_candidates: dex_fixed_candidates__.unwrap_or_default(),
+                    dex_max_hops: dex_max_hops__.unwrap_or_default(),
+                    dex_arb_candidates: dex_arb_candidates__.unwrap_or_default(),
+                    dex_auto_candidates: dex_auto_candidates__.unwrap_or_default(),
+                    issuance_per_epoch: issuance_per_epoch__.unwrap_or_default(),
+                    dao_issuance_bps: dao_issuance_bps__.unwrap_or_default(),
+                    lp_issuance_bps: lp_issuance_bps__.unwrap_or_default(),
+                    fee_asset_rates: fee_asset_rates__.unwrap_or_default(),
                 })
             }
         }
@@ -763,6 +933,115 @@ impl<'de> serde::Deserialize<'de> for Epoch {
         deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.Epoch", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for FeeAssetRate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.rate.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.FeeAssetRate", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.rate.as_ref() {
+            struct_ser.serialize_field("rate", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeAssetRate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "rate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            Rate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "rate" => Ok(GeneratedField::Rate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeAssetRate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.chain.v1alpha1.FeeAssetRate")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FeeAssetRate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut rate__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Rate => {
+                            if rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rate"));
+                            }
+                            rate__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeAssetRate {
+                    asset_id: asset_id__,
+                    rate: rate__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.FeeAssetRate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for FmdParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -877,6 +1156,160 @@ impl<'de> serde::Deserialize<'de> for FmdParameters {
         deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.FmdParameters", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GasPrices {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.block_space_price != 0 {
+            len += 1;
+        }
+        if self.compact_block_space_price != 0 {
+            len += 1;
+        }
+        if self.verification_price != 0 {
+            len += 1;
+        }
+        if self.execution_price != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.GasPrices", len)?;
+        if self.block_space_price != 0 {
+            struct_ser.serialize_field("blockSpacePrice", ToString::to_string(&self.block_space_price).as_str())?;
+        }
+        if self.compact_block_space_price != 0 {
+            struct_ser.serialize_field("compactBlockSpacePrice", ToString::to_string(&self.compact_block_space_price).as_str())?;
+        }
+        if self.verification_price != 0 {
+            struct_ser.serialize_field("verificationPrice", ToString::to_string(&self.verification_price).as_str())?;
+        }
+        if self.execution_price != 0 {
+            struct_ser.serialize_field("executionPrice", ToString::to_string(&self.execution_price).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GasPrices {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_space_price",
+            "blockSpacePrice",
+            "compact_block_space_price",
+            "compactBlockSpacePrice",
+            "verification_price",
+            "verificationPrice",
+            "execution_price",
+            "executionPrice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockSpacePrice,
+            CompactBlockSpacePrice,
+            VerificationPrice,
+            ExecutionPrice,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockSpacePrice" | "block_space_price" => Ok(GeneratedField::BlockSpacePrice),
+                            "compactBlockSpacePrice" | "compact_block_space_price" => Ok(GeneratedField::CompactBlockSpacePrice),
+                            "verificationPrice" | "verification_price" => Ok(GeneratedField::VerificationPrice),
+                            "executionPrice" | "execution_price" => Ok(GeneratedField::ExecutionPrice),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GasPrices;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.chain.v1alpha1.GasPrices")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GasPrices, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_space_price__ = None;
+                let mut compact_block_space_price__ = None;
+                let mut verification_price__ = None;
+                let mut execution_price__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::BlockSpacePrice => {
+                            if block_space_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockSpacePrice"));
+                            }
+                            block_space_price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CompactBlockSpacePrice => {
+                            if compact_block_space_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("compactBlockSpacePrice"));
+                            }
+                            compact_block_space_price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VerificationPrice => {
+                            if verification_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verificationPrice"));
+                            }
+                            verification_price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExecutionPrice => {
+                            if execution_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionPrice"));
+                            }
+                            execution_price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GasPrices {
+                    block_space_price: block_space_price__.unwrap_or_default(),
+                    compact_block_space_price: compact_block_space_price__.unwrap_or_default(),
+                    verification_price: verification_price__.unwrap_or_default(),
+                    execution_price: execution_price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.GasPrices", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GenesisAppState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1003,6 +1436,133 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
         deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.GenesisAppState", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ParameterChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.parameter.is_empty() {
+            len += 1;
+        }
+        if !self.old_value.is_empty() {
+            len += 1;
+        }
+        if !self.new_value.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.ParameterChange", len)?;
+        if !self.parameter.is_empty() {
+            struct_ser.serialize_field("parameter", &self.parameter)?;
+        }
+        if !self.old_value.is_empty() {
+            struct_ser.serialize_field("oldValue", &self.old_value)?;
+        }
+        if !self.new_value.is_empty() {
+            struct_ser.serialize_field("newValue", &self.new_value)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ParameterChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parameter",
+            "old_value",
+            "oldValue",
+            "new_value",
+            "newValue",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Parameter,
+            OldValue,
+            NewValue,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "parameter" => Ok(GeneratedField::Parameter),
+                            "oldValue" | "old_value" => Ok(GeneratedField::OldValue),
+                            "newValue" | "new_value" => Ok(GeneratedField::NewValue),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.chain.v1alpha1.ParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parameter__ = None;
+                let mut old_value__ = None;
+                let mut new_value__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Parameter => {
+                            if parameter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameter"));
+                            }
+                            parameter__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::OldValue => {
+                            if old_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldValue"));
+                            }
+                            old_value__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NewValue => {
+                            if new_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newValue"));
+                            }
+                            new_value__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ParameterChange {
+                    parameter: parameter__.unwrap_or_default(),
+                    old_value: old_value__.unwrap_or_default(),
+                    new_value: new_value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.ParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for genesis_app_state::Allocation {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
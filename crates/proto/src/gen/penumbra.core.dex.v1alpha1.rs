@@ -547,6 +547,9 @@ pub struct PositionWithdrawPlan {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PositionRewardClaimPlan {
-    #[prost(message, optional, tag = "1")]
-    pub reserves: ::core::option::Option<Reserves>,
+    #[prost(message, optional, tag = "2")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The rewards accumulated by the position, paid in the staking token.
+    #[prost(message, optional, tag = "3")]
+    pub rewards: ::core::option::Option<super::super::crypto::v1alpha1::Amount>,
 }
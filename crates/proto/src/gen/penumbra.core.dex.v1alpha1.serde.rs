@@ -1155,12 +1155,18 @@ impl serde::Serialize for PositionRewardClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.rewards.is_some() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.dex.v1alpha1.PositionRewardClaimPlan", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
         }
         struct_ser.end()
     }
@@ -1172,12 +1178,15 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
+            "position_id",
+            "positionId",
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
+            PositionId,
+            Rewards,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1199,7 +1208,8 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1219,19 +1229,27 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
+                let mut position_id__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            reserves__ = map.next_value()?;
+                            position_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map.next_value()?;
                         }
                     }
                 }
                 Ok(PositionRewardClaimPlan {
-                    reserves: reserves__,
+                    position_id: position_id__,
+                    rewards: rewards__,
                 })
             }
         }
@@ -0,0 +1,129 @@
+/// The public key that flow ciphertexts are encrypted to.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EncryptionKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// A flow encryption ciphertext, the encryptions of the four 16-bit limbs of a value.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ciphertext {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// A decryptor's share of the decryption key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrivateKeyShare {
+    #[prost(uint32, tag = "1")]
+    pub participant_index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub key_share: ::prost::alloc::vec::Vec<u8>,
+}
+/// The public counterpart of a decryptor's share of the decryption key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublicKeyShare {
+    #[prost(uint32, tag = "1")]
+    pub participant_index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub pub_key_share: ::prost::alloc::vec::Vec<u8>,
+}
+/// A decryptor's share of the decryption of a ciphertext, with proofs of its correctness.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecryptionShare {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// A proof that a ciphertext encrypts a 64-bit value, which reveals the value.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransparentEncryptionProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// A zero-knowledge proof that a ciphertext encrypts a 64-bit value.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EncryptionProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// A zero-knowledge proof that a ciphertext encrypts the value of a commitment.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitmentProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// A participant's long-term key, used to receive key shares during distributed key generation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParticipantKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// The public counterpart of a participant's long-term key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParticipantPublicKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+/// The parameters of a distributed key generation ceremony.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DkgConfig {
+    /// The number of participants required to decrypt.
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+    /// The participants, whose participant indices are their positions in this list, starting at 1.
+    #[prost(message, repeated, tag = "2")]
+    pub participants: ::prost::alloc::vec::Vec<ParticipantPublicKey>,
+}
+/// A participant's dealing of key shares to every other participant.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Dealing {
+    #[prost(uint32, tag = "1")]
+    pub dealer_index: u32,
+    /// Commitments to the coefficients of the dealer's secret polynomial.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub commitments: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The share for each participant, in participant index order, encrypted to that participant.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub encrypted_shares: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// A complaint that a dealer's share for the complainer is invalid.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Complaint {
+    #[prost(uint32, tag = "1")]
+    pub complainer_index: u32,
+    #[prost(uint32, tag = "2")]
+    pub dealer_index: u32,
+}
+/// A dealer's response to a complaint, publicly revealing the complainer's share.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComplaintResponse {
+    #[prost(uint32, tag = "1")]
+    pub dealer_index: u32,
+    #[prost(uint32, tag = "2")]
+    pub complainer_index: u32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub share: ::prost::alloc::vec::Vec<u8>,
+}
+/// The outcome of distributed key generation: the committee that can jointly decrypt.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Committee {
+    #[prost(message, optional, tag = "1")]
+    pub encryption_key: ::core::option::Option<EncryptionKey>,
+    #[prost(message, repeated, tag = "2")]
+    pub shares: ::prost::alloc::vec::Vec<PublicKeyShare>,
+    #[prost(uint32, tag = "3")]
+    pub threshold: u32,
+}
@@ -344,6 +344,12 @@ pub struct Proposal {
     pub parameter_change: ::core::option::Option<proposal::ParameterChange>,
     #[prost(message, optional, tag = "8")]
     pub dao_spend: ::core::option::Option<proposal::DaoSpend>,
+    #[prost(message, optional, tag = "9")]
+    pub ibc_client_recovery: ::core::option::Option<proposal::IbcClientRecovery>,
+    #[prost(message, optional, tag = "10")]
+    pub upgrade_plan: ::core::option::Option<proposal::UpgradePlan>,
+    #[prost(message, optional, tag = "11")]
+    pub partial_parameter_change: ::core::option::Option<proposal::PartialParameterChange>,
 }
 /// Nested message and enum types in `Proposal`.
 pub mod proposal {
@@ -389,6 +395,21 @@ pub mod proposal {
             super::super::super::chain::v1alpha1::ChainParameters,
         >,
     }
+    /// A partial parameter change proposal describes changes to individual chain parameters, which
+    /// should take effect when the proposal is passed.
+    ///
+    /// Unlike a `ParameterChange` proposal, it only conflicts with other proposals changing the same
+    /// parameters: each change is applied only if the parameter still has the old value specified in
+    /// the change, and the whole proposal fails if any of the changes can't be applied.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PartialParameterChange {
+        /// The changes to individual chain parameters.
+        #[prost(message, repeated, tag = "1")]
+        pub changes: ::prost::alloc::vec::Vec<
+            super::super::super::chain::v1alpha1::ParameterChange,
+        >,
+    }
     /// A DAO spend proposal describes zero or more transactions to execute on behalf of the DAO, with
     /// access to its funds, and zero or more scheduled transactions from previous passed proposals to
     /// cancel.
@@ -401,4 +422,32 @@ pub mod proposal {
         #[prost(message, optional, tag = "2")]
         pub transaction_plan: ::core::option::Option<::pbjson_types::Any>,
     }
+    /// An IBC client recovery proposal substitutes the state of a healthy client for that of a frozen
+    /// or expired one, so that the connections and channels built on the subject client can keep being
+    /// used.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct IbcClientRecovery {
+        /// The identifier of the frozen or expired client to recover.
+        #[prost(string, tag = "1")]
+        pub subject_client_id: ::prost::alloc::string::String,
+        /// The identifier of the healthy client whose state replaces that of the subject client.
+        #[prost(string, tag = "2")]
+        pub substitute_client_id: ::prost::alloc::string::String,
+    }
+    /// An upgrade plan proposal schedules a software upgrade, halting the chain at the planned height so
+    /// that it can be restarted with the upgraded software.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UpgradePlan {
+        /// The height after which the chain halts for the upgrade.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+        /// The name of the upgrade, identifying the state migrations to run once the chain restarts.
+        #[prost(string, tag = "2")]
+        pub name: ::prost::alloc::string::String,
+        /// Any other information about the upgrade, such as where to find the upgraded software.
+        #[prost(string, tag = "3")]
+        pub info: ::prost::alloc::string::String,
+    }
 }
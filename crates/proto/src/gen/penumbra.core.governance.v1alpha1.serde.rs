@@ -891,6 +891,15 @@ impl serde::Serialize for Proposal {
         if self.dao_spend.is_some() {
             len += 1;
         }
+        if self.ibc_client_recovery.is_some() {
+            len += 1;
+        }
+        if self.upgrade_plan.is_some() {
+            len += 1;
+        }
+        if self.partial_parameter_change.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal", len)?;
         if self.id != 0 {
             struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
@@ -913,6 +922,15 @@ impl serde::Serialize for Proposal {
         if let Some(v) = self.dao_spend.as_ref() {
             struct_ser.serialize_field("daoSpend", v)?;
         }
+        if let Some(v) = self.ibc_client_recovery.as_ref() {
+            struct_ser.serialize_field("ibcClientRecovery", v)?;
+        }
+        if let Some(v) = self.upgrade_plan.as_ref() {
+            struct_ser.serialize_field("upgradePlan", v)?;
+        }
+        if let Some(v) = self.partial_parameter_change.as_ref() {
+            struct_ser.serialize_field("partialParameterChange", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -932,6 +950,12 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "parameterChange",
             "dao_spend",
             "daoSpend",
+            "ibc_client_recovery",
+            "ibcClientRecovery",
+            "upgrade_plan",
+            "upgradePlan",
+            "partial_parameter_change",
+            "partialParameterChange",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -943,6 +967,9 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             Emergency,
             ParameterChange,
             DaoSpend,
+            IbcClientRecovery,
+            UpgradePlan,
+            PartialParameterChange,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -971,6 +998,9 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "emergency" => Ok(GeneratedField::Emergency),
                             "parameterChange" | "parameter_change" => Ok(GeneratedField::ParameterChange),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
+                            "ibcClientRecovery" | "ibc_client_recovery" => Ok(GeneratedField::IbcClientRecovery),
+                            "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            "partialParameterChange" | "partial_parameter_change" => Ok(GeneratedField::PartialParameterChange),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -997,6 +1027,9 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                 let mut emergency__ = None;
                 let mut parameter_change__ = None;
                 let mut dao_spend__ = None;
+                let mut ibc_client_recovery__ = None;
+                let mut upgrade_plan__ = None;
+                let mut partial_parameter_change__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1043,6 +1076,24 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             }
                             dao_spend__ = map.next_value()?;
                         }
+                        GeneratedField::IbcClientRecovery => {
+                            if ibc_client_recovery__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcClientRecovery"));
+                            }
+                            ibc_client_recovery__ = map.next_value()?;
+                        }
+                        GeneratedField::UpgradePlan => {
+                            if upgrade_plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradePlan"));
+                            }
+                            upgrade_plan__ = map.next_value()?;
+                        }
+                        GeneratedField::PartialParameterChange => {
+                            if partial_parameter_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialParameterChange"));
+                            }
+                            partial_parameter_change__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(Proposal {
@@ -1053,6 +1104,9 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                     emergency: emergency__,
                     parameter_change: parameter_change__,
                     dao_spend: dao_spend__,
+                    ibc_client_recovery: ibc_client_recovery__,
+                    upgrade_plan: upgrade_plan__,
+                    partial_parameter_change: partial_parameter_change__,
                 })
             }
         }
@@ -1243,6 +1297,116 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.Emergency", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::IbcClientRecovery {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.subject_client_id.is_empty() {
+            len += 1;
+        }
+        if !self.substitute_client_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.IbcClientRecovery", len)?;
+        if !self.subject_client_id.is_empty() {
+            struct_ser.serialize_field("subjectClientId", &self.subject_client_id)?;
+        }
+        if !self.substitute_client_id.is_empty() {
+            struct_ser.serialize_field("substituteClientId", &self.substitute_client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::IbcClientRecovery {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "subject_client_id",
+            "subjectClientId",
+            "substitute_client_id",
+            "substituteClientId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SubjectClientId,
+            SubstituteClientId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "subjectClientId" | "subject_client_id" => Ok(GeneratedField::SubjectClientId),
+                            "substituteClientId" | "substitute_client_id" => Ok(GeneratedField::SubstituteClientId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::IbcClientRecovery;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.IbcClientRecovery")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::IbcClientRecovery, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut subject_client_id__ = None;
+                let mut substitute_client_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::SubjectClientId => {
+                            if subject_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subjectClientId"));
+                            }
+                            subject_client_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SubstituteClientId => {
+                            if substitute_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("substituteClientId"));
+                            }
+                            substitute_client_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::IbcClientRecovery {
+                    subject_client_id: subject_client_id__.unwrap_or_default(),
+                    substitute_client_id: substitute_client_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.IbcClientRecovery", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::ParameterChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1353,6 +1517,97 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::PartialParameterChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.changes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange", len)?;
+        if !self.changes.is_empty() {
+            struct_ser.serialize_field("changes", &self.changes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::PartialParameterChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "changes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Changes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "changes" => Ok(GeneratedField::Changes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::PartialParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::PartialParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut changes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Changes => {
+                            if changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("changes"));
+                            }
+                            changes__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::PartialParameterChange {
+                    changes: changes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::Signaling {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1444,6 +1699,133 @@ impl<'de> serde::Deserialize<'de> for proposal::Signaling {
         deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.Signaling", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::UpgradePlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.info.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.UpgradePlan", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.info.is_empty() {
+            struct_ser.serialize_field("info", &self.info)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "name",
+            "info",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Name,
+            Info,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "name" => Ok(GeneratedField::Name),
+                            "info" => Ok(GeneratedField::Info),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::UpgradePlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.UpgradePlan")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::UpgradePlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut name__ = None;
+                let mut info__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Info => {
+                            if info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("info"));
+                            }
+                            info__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::UpgradePlan {
+                    height: height__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    info: info__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.UpgradePlan", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ProposalDepositClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
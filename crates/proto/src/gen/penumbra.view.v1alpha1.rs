@@ -11,6 +11,11 @@ pub struct AuthorizeAndBuildRequest {
     pub authorization_data: ::core::option::Option<
         super::super::core::transaction::v1alpha1::AuthorizationData,
     >,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -32,6 +37,11 @@ pub struct BroadcastTransactionRequest {
     /// If true, wait for the view service to detect the transaction during sync.
     #[prost(bool, tag = "2")]
     pub await_detection: bool,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -148,6 +158,11 @@ pub struct AddressByIndexRequest {
     >,
     #[prost(bool, tag = "2")]
     pub display_confirm: bool,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -160,6 +175,11 @@ pub struct AddressByIndexResponse {
 pub struct IndexByAddressRequest {
     #[prost(message, optional, tag = "1")]
     pub address: ::core::option::Option<super::super::core::crypto::v1alpha1::Address>,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -179,6 +199,11 @@ pub struct EphemeralAddressRequest {
     >,
     #[prost(bool, tag = "2")]
     pub display_confirm: bool,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -199,6 +224,11 @@ pub struct BalancesRequest {
     pub asset_id_filter: ::core::option::Option<
         super::super::core::crypto::v1alpha1::AssetId,
     >,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -231,6 +261,85 @@ pub struct ViewAuthResponse {
     #[prost(message, optional, tag = "1")]
     pub token: ::core::option::Option<ViewAuthToken>,
 }
+/// Requests the candidate transactions detected with a set of detection keys.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandidateTransactionsRequest {
+    /// The detection keys to examine the clues of each transaction with, one for each address the
+    /// client wants to detect transactions for.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub detection_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The height of the first block to detect candidate transactions in.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// If set, keep the stream open, detecting candidate transactions in new blocks as they're
+    /// produced.
+    #[prost(bool, tag = "3")]
+    pub keep_alive: bool,
+}
+/// The candidate transactions detected in a single block.
+///
+/// A response is sent for every block, even if it has no candidate transactions, so that the client
+/// can tell which blocks have been examined.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandidateTransactionsResponse {
+    /// The height of the block.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The transactions in the block with a clue detected by any of the detection keys.
+    ///
+    /// Since detection has false positives, some of these may not be relevant to the client.
+    #[prost(message, repeated, tag = "2")]
+    pub transaction_ids: ::prost::alloc::vec::Vec<
+        super::super::core::transaction::v1alpha1::Id,
+    >,
+}
+/// Requests that a wallet be added to the view service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddWalletRequest {
+    /// The full viewing key of the wallet.
+    #[prost(message, optional, tag = "1")]
+    pub full_viewing_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::FullViewingKey,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddWalletResponse {
+    /// The account group ID of the added wallet, used to identify it in queries.
+    #[prost(message, optional, tag = "1")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
+}
+/// Requests that a wallet be removed from the view service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveWalletRequest {
+    /// The account group ID of the wallet to remove.
+    #[prost(message, optional, tag = "1")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveWalletResponse {}
+/// Requests the list of wallets synced by the view service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletsRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletsResponse {
+    /// The account group IDs of the wallets.
+    #[prost(message, repeated, tag = "1")]
+    pub account_group_ids: ::prost::alloc::vec::Vec<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
+}
 /// Requests sync status of the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -358,6 +467,11 @@ pub struct WitnessAndBuildRequest {
     pub authorization_data: ::core::option::Option<
         super::super::core::transaction::v1alpha1::AuthorizationData,
     >,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -500,6 +614,11 @@ pub struct TransactionInfoByHashRequest {
     /// The transaction hash to query for.
     #[prost(message, optional, tag = "2")]
     pub id: ::core::option::Option<super::super::core::transaction::v1alpha1::Id>,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -510,6 +629,11 @@ pub struct TransactionInfoRequest {
     /// If present, return only transactions before this height.
     #[prost(uint64, optional, tag = "2")]
     pub end_height: ::core::option::Option<u64>,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -636,6 +760,11 @@ pub struct OwnedPositionIdsRequest {
     pub trading_pair: ::core::option::Option<
         super::super::core::dex::v1alpha1::TradingPair,
     >,
+    /// Identifies the account group to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_group_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountGroupId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1291,127 +1420,357 @@ pub mod view_auth_service_client {
         }
     }
 }
-/// Generated server implementations.
+/// Generated client implementations.
 #[cfg(feature = "rpc")]
-pub mod view_protocol_service_server {
+pub mod detection_service_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ViewProtocolServiceServer.
-    #[async_trait]
-    pub trait ViewProtocolService: Send + Sync + 'static {
-        /// Get current status of chain sync
-        async fn status(
-            &self,
-            request: tonic::Request<super::StatusRequest>,
-        ) -> Result<tonic::Response<super::StatusResponse>, tonic::Status>;
-        /// Server streaming response type for the StatusStream method.
-        type StatusStreamStream: futures_core::Stream<
-                Item = Result<super::StatusStreamResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Stream sync status updates until the view service has caught up with the core.chain.v1alpha1.
-        /// Returns a stream of `StatusStreamResponse`s.
-        async fn status_stream(
-            &self,
-            request: tonic::Request<super::StatusStreamRequest>,
-        ) -> Result<tonic::Response<Self::StatusStreamStream>, tonic::Status>;
-        /// Server streaming response type for the Notes method.
-        type NotesStream: futures_core::Stream<
-                Item = Result<super::NotesResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Queries for notes that have been accepted by the core.chain.v1alpha1.
-        /// Returns a stream of `NotesResponse`s.
-        async fn notes(
-            &self,
-            request: tonic::Request<super::NotesRequest>,
-        ) -> Result<tonic::Response<Self::NotesStream>, tonic::Status>;
-        /// Server streaming response type for the NotesForVoting method.
-        type NotesForVotingStream: futures_core::Stream<
-                Item = Result<super::NotesForVotingResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Returns a stream of `NotesForVotingResponse`s.
-        async fn notes_for_voting(
-            &self,
-            request: tonic::Request<super::NotesForVotingRequest>,
-        ) -> Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status>;
-        /// Returns authentication paths for the given note commitments.
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct DetectionServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DetectionServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DetectionServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> DetectionServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            DetectionServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
         ///
-        /// This method takes a batch of input commitments, rather than just one, so
-        /// that the client can get a consistent set of authentication paths to a
-        /// common root.  (Otherwise, if a client made multiple requests, the wallet
-        /// service could have advanced the state commitment tree state between queries).
-        async fn witness(
-            &self,
-            request: tonic::Request<super::WitnessRequest>,
-        ) -> Result<tonic::Response<super::WitnessResponse>, tonic::Status>;
-        async fn witness_and_build(
-            &self,
-            request: tonic::Request<super::WitnessAndBuildRequest>,
-        ) -> Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status>;
-        /// Server streaming response type for the Assets method.
-        type AssetsStream: futures_core::Stream<
-                Item = Result<super::AssetsResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Queries for assets.
-        /// Returns a stream of `AssetsResponse`s.
-        async fn assets(
-            &self,
-            request: tonic::Request<super::AssetsRequest>,
-        ) -> Result<tonic::Response<Self::AssetsStream>, tonic::Status>;
-        /// Query for the current chain parameters.
-        async fn chain_parameters(
-            &self,
-            request: tonic::Request<super::ChainParametersRequest>,
-        ) -> Result<tonic::Response<super::ChainParametersResponse>, tonic::Status>;
-        /// Query for the current FMD parameters.
-        async fn fmd_parameters(
-            &self,
-            request: tonic::Request<super::FmdParametersRequest>,
-        ) -> Result<tonic::Response<super::FmdParametersResponse>, tonic::Status>;
-        /// Query for an address given an address index
-        async fn address_by_index(
-            &self,
-            request: tonic::Request<super::AddressByIndexRequest>,
-        ) -> Result<tonic::Response<super::AddressByIndexResponse>, tonic::Status>;
-        /// Query for an address given an address index
-        async fn index_by_address(
-            &self,
-            request: tonic::Request<super::IndexByAddressRequest>,
-        ) -> Result<tonic::Response<super::IndexByAddressResponse>, tonic::Status>;
-        /// Query for an ephemeral address
-        async fn ephemeral_address(
-            &self,
-            request: tonic::Request<super::EphemeralAddressRequest>,
-        ) -> Result<tonic::Response<super::EphemeralAddressResponse>, tonic::Status>;
-        /// Server streaming response type for the Balances method.
-        type BalancesStream: futures_core::Stream<
-                Item = Result<super::BalancesResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Query for balance of a given address.
-        /// Returns a stream of `BalancesResponses`.
-        async fn balances(
-            &self,
-            request: tonic::Request<super::BalancesRequest>,
-        ) -> Result<tonic::Response<Self::BalancesStream>, tonic::Status>;
-        /// Query for a note by its note commitment, optionally waiting until the note is detected.
-        async fn note_by_commitment(
-            &self,
-            request: tonic::Request<super::NoteByCommitmentRequest>,
-        ) -> Result<tonic::Response<super::NoteByCommitmentResponse>, tonic::Status>;
-        /// Query for a swap by its swap commitment, optionally waiting until the swap is detected.
-        async fn swap_by_commitment(
-            &self,
-            request: tonic::Request<super::SwapByCommitmentRequest>,
-        ) -> Result<tonic::Response<super::SwapByCommitmentResponse>, tonic::Status>;
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Registers detection keys with the service for the lifetime of the stream, and streams the
+        /// candidate transactions detected with any of them in each block.
+        pub async fn candidate_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CandidateTransactionsRequest>,
+        ) -> Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::CandidateTransactionsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.DetectionService/CandidateTransactions",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+pub mod wallet_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Manages the wallets synced by a view service serving several full viewing keys.
+    #[derive(Debug, Clone)]
+    pub struct WalletServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl WalletServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> WalletServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> WalletServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            WalletServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Adds a wallet, starting to sync it with the other wallets of the view service.
+        pub async fn add_wallet(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddWalletRequest>,
+        ) -> Result<tonic::Response<super::AddWalletResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.WalletService/AddWallet",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Removes a wallet, stopping its sync and deleting its local state.
+        pub async fn remove_wallet(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveWalletRequest>,
+        ) -> Result<tonic::Response<super::RemoveWalletResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.WalletService/RemoveWallet",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Lists the wallets synced by the view service.
+        pub async fn wallets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WalletsRequest>,
+        ) -> Result<tonic::Response<super::WalletsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.WalletService/Wallets",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod view_protocol_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ViewProtocolServiceServer.
+    #[async_trait]
+    pub trait ViewProtocolService: Send + Sync + 'static {
+        /// Get current status of chain sync
+        async fn status(
+            &self,
+            request: tonic::Request<super::StatusRequest>,
+        ) -> Result<tonic::Response<super::StatusResponse>, tonic::Status>;
+        /// Server streaming response type for the StatusStream method.
+        type StatusStreamStream: futures_core::Stream<
+                Item = Result<super::StatusStreamResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Stream sync status updates until the view service has caught up with the core.chain.v1alpha1.
+        /// Returns a stream of `StatusStreamResponse`s.
+        async fn status_stream(
+            &self,
+            request: tonic::Request<super::StatusStreamRequest>,
+        ) -> Result<tonic::Response<Self::StatusStreamStream>, tonic::Status>;
+        /// Server streaming response type for the Notes method.
+        type NotesStream: futures_core::Stream<
+                Item = Result<super::NotesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Queries for notes that have been accepted by the core.chain.v1alpha1.
+        /// Returns a stream of `NotesResponse`s.
+        async fn notes(
+            &self,
+            request: tonic::Request<super::NotesRequest>,
+        ) -> Result<tonic::Response<Self::NotesStream>, tonic::Status>;
+        /// Server streaming response type for the NotesForVoting method.
+        type NotesForVotingStream: futures_core::Stream<
+                Item = Result<super::NotesForVotingResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Returns a stream of `NotesForVotingResponse`s.
+        async fn notes_for_voting(
+            &self,
+            request: tonic::Request<super::NotesForVotingRequest>,
+        ) -> Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status>;
+        /// Returns authentication paths for the given note commitments.
+        ///
+        /// This method takes a batch of input commitments, rather than just one, so
+        /// that the client can get a consistent set of authentication paths to a
+        /// common root.  (Otherwise, if a client made multiple requests, the wallet
+        /// service could have advanced the state commitment tree state between queries).
+        async fn witness(
+            &self,
+            request: tonic::Request<super::WitnessRequest>,
+        ) -> Result<tonic::Response<super::WitnessResponse>, tonic::Status>;
+        async fn witness_and_build(
+            &self,
+            request: tonic::Request<super::WitnessAndBuildRequest>,
+        ) -> Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status>;
+        /// Server streaming response type for the Assets method.
+        type AssetsStream: futures_core::Stream<
+                Item = Result<super::AssetsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Queries for assets.
+        /// Returns a stream of `AssetsResponse`s.
+        async fn assets(
+            &self,
+            request: tonic::Request<super::AssetsRequest>,
+        ) -> Result<tonic::Response<Self::AssetsStream>, tonic::Status>;
+        /// Query for the current chain parameters.
+        async fn chain_parameters(
+            &self,
+            request: tonic::Request<super::ChainParametersRequest>,
+        ) -> Result<tonic::Response<super::ChainParametersResponse>, tonic::Status>;
+        /// Query for the current FMD parameters.
+        async fn fmd_parameters(
+            &self,
+            request: tonic::Request<super::FmdParametersRequest>,
+        ) -> Result<tonic::Response<super::FmdParametersResponse>, tonic::Status>;
+        /// Query for an address given an address index
+        async fn address_by_index(
+            &self,
+            request: tonic::Request<super::AddressByIndexRequest>,
+        ) -> Result<tonic::Response<super::AddressByIndexResponse>, tonic::Status>;
+        /// Query for an address given an address index
+        async fn index_by_address(
+            &self,
+            request: tonic::Request<super::IndexByAddressRequest>,
+        ) -> Result<tonic::Response<super::IndexByAddressResponse>, tonic::Status>;
+        /// Query for an ephemeral address
+        async fn ephemeral_address(
+            &self,
+            request: tonic::Request<super::EphemeralAddressRequest>,
+        ) -> Result<tonic::Response<super::EphemeralAddressResponse>, tonic::Status>;
+        /// Server streaming response type for the Balances method.
+        type BalancesStream: futures_core::Stream<
+                Item = Result<super::BalancesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Query for balance of a given address.
+        /// Returns a stream of `BalancesResponses`.
+        async fn balances(
+            &self,
+            request: tonic::Request<super::BalancesRequest>,
+        ) -> Result<tonic::Response<Self::BalancesStream>, tonic::Status>;
+        /// Query for a note by its note commitment, optionally waiting until the note is detected.
+        async fn note_by_commitment(
+            &self,
+            request: tonic::Request<super::NoteByCommitmentRequest>,
+        ) -> Result<tonic::Response<super::NoteByCommitmentResponse>, tonic::Status>;
+        /// Query for a swap by its swap commitment, optionally waiting until the swap is detected.
+        async fn swap_by_commitment(
+            &self,
+            request: tonic::Request<super::SwapByCommitmentRequest>,
+        ) -> Result<tonic::Response<super::SwapByCommitmentResponse>, tonic::Status>;
         /// Query for whether a nullifier has been spent, optionally waiting until it is spent.
         async fn nullifier_status(
             &self,
@@ -2301,12 +2660,244 @@ pub mod view_protocol_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BroadcastTransactionRequest>,
+                            request: tonic::Request<super::BroadcastTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).broadcast_transaction(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BroadcastTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.ViewProtocolService/OwnedPositionIds" => {
+                    #[allow(non_camel_case_types)]
+                    struct OwnedPositionIdsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<
+                        super::OwnedPositionIdsRequest,
+                    > for OwnedPositionIdsSvc<T> {
+                        type Response = super::OwnedPositionIdsResponse;
+                        type ResponseStream = T::OwnedPositionIdsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::OwnedPositionIdsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).owned_position_ids(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = OwnedPositionIdsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.ViewProtocolService/AuthorizeAndBuild" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuthorizeAndBuildSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::UnaryService<super::AuthorizeAndBuildRequest>
+                    for AuthorizeAndBuildSvc<T> {
+                        type Response = super::AuthorizeAndBuildResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AuthorizeAndBuildRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).authorize_and_build(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AuthorizeAndBuildSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ViewProtocolService> Clone for ViewProtocolServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: ViewProtocolService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ViewProtocolService> tonic::server::NamedService
+    for ViewProtocolServiceServer<T> {
+        const NAME: &'static str = "penumbra.view.v1alpha1.ViewProtocolService";
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod view_auth_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ViewAuthServiceServer.
+    #[async_trait]
+    pub trait ViewAuthService: Send + Sync + 'static {
+        async fn view_auth(
+            &self,
+            request: tonic::Request<super::ViewAuthRequest>,
+        ) -> Result<tonic::Response<super::ViewAuthResponse>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ViewAuthServiceServer<T: ViewAuthService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ViewAuthService> ViewAuthServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ViewAuthServiceServer<T>
+    where
+        T: ViewAuthService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.view.v1alpha1.ViewAuthService/ViewAuth" => {
+                    #[allow(non_camel_case_types)]
+                    struct ViewAuthSvc<T: ViewAuthService>(pub Arc<T>);
+                    impl<
+                        T: ViewAuthService,
+                    > tonic::server::UnaryService<super::ViewAuthRequest>
+                    for ViewAuthSvc<T> {
+                        type Response = super::ViewAuthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ViewAuthRequest>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move {
-                                (*inner).broadcast_transaction(request).await
-                            };
+                            let fut = async move { (*inner).view_auth(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2315,7 +2906,7 @@ pub mod view_protocol_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = BroadcastTransactionSvc(inner);
+                        let method = ViewAuthSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2327,27 +2918,149 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1alpha1.ViewProtocolService/OwnedPositionIds" => {
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ViewAuthService> Clone for ViewAuthServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: ViewAuthService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ViewAuthService> tonic::server::NamedService for ViewAuthServiceServer<T> {
+        const NAME: &'static str = "penumbra.view.v1alpha1.ViewAuthService";
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod detection_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DetectionServiceServer.
+    #[async_trait]
+    pub trait DetectionService: Send + Sync + 'static {
+        /// Server streaming response type for the CandidateTransactions method.
+        type CandidateTransactionsStream: futures_core::Stream<
+                Item = Result<super::CandidateTransactionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Registers detection keys with the service for the lifetime of the stream, and streams the
+        /// candidate transactions detected with any of them in each block.
+        async fn candidate_transactions(
+            &self,
+            request: tonic::Request<super::CandidateTransactionsRequest>,
+        ) -> Result<
+            tonic::Response<Self::CandidateTransactionsStream>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct DetectionServiceServer<T: DetectionService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: DetectionService> DetectionServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DetectionServiceServer<T>
+    where
+        T: DetectionService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.view.v1alpha1.DetectionService/CandidateTransactions" => {
                     #[allow(non_camel_case_types)]
-                    struct OwnedPositionIdsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    struct CandidateTransactionsSvc<T: DetectionService>(pub Arc<T>);
                     impl<
-                        T: ViewProtocolService,
+                        T: DetectionService,
                     > tonic::server::ServerStreamingService<
-                        super::OwnedPositionIdsRequest,
-                    > for OwnedPositionIdsSvc<T> {
-                        type Response = super::OwnedPositionIdsResponse;
-                        type ResponseStream = T::OwnedPositionIdsStream;
+                        super::CandidateTransactionsRequest,
+                    > for CandidateTransactionsSvc<T> {
+                        type Response = super::CandidateTransactionsResponse;
+                        type ResponseStream = T::CandidateTransactionsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::OwnedPositionIdsRequest>,
+                            request: tonic::Request<super::CandidateTransactionsRequest>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
                             let fut = async move {
-                                (*inner).owned_position_ids(request).await
+                                (*inner).candidate_transactions(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2357,7 +3070,7 @@ pub mod view_protocol_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = OwnedPositionIdsSvc(inner);
+                        let method = CandidateTransactionsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2369,46 +3082,6 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1alpha1.ViewProtocolService/AuthorizeAndBuild" => {
-                    #[allow(non_camel_case_types)]
-                    struct AuthorizeAndBuildSvc<T: ViewProtocolService>(pub Arc<T>);
-                    impl<
-                        T: ViewProtocolService,
-                    > tonic::server::UnaryService<super::AuthorizeAndBuildRequest>
-                    for AuthorizeAndBuildSvc<T> {
-                        type Response = super::AuthorizeAndBuildResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::AuthorizeAndBuildRequest>,
-                        ) -> Self::Future {
-                            let inner = self.0.clone();
-                            let fut = async move {
-                                (*inner).authorize_and_build(request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = AuthorizeAndBuildSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -2424,7 +3097,7 @@ pub mod view_protocol_service_server {
             }
         }
     }
-    impl<T: ViewProtocolService> Clone for ViewProtocolServiceServer<T> {
+    impl<T: DetectionService> Clone for DetectionServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2434,7 +3107,7 @@ pub mod view_protocol_service_server {
             }
         }
     }
-    impl<T: ViewProtocolService> Clone for _Inner<T> {
+    impl<T: DetectionService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -2444,32 +3117,42 @@ pub mod view_protocol_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: ViewProtocolService> tonic::server::NamedService
-    for ViewProtocolServiceServer<T> {
-        const NAME: &'static str = "penumbra.view.v1alpha1.ViewProtocolService";
+    impl<T: DetectionService> tonic::server::NamedService for DetectionServiceServer<T> {
+        const NAME: &'static str = "penumbra.view.v1alpha1.DetectionService";
     }
 }
 /// Generated server implementations.
-#[cfg(feature = "rpc")]
-pub mod view_auth_service_server {
+pub mod wallet_service_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ViewAuthServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with WalletServiceServer.
     #[async_trait]
-    pub trait ViewAuthService: Send + Sync + 'static {
-        async fn view_auth(
+    pub trait WalletService: Send + Sync + 'static {
+        /// Adds a wallet, starting to sync it with the other wallets of the view service.
+        async fn add_wallet(
             &self,
-            request: tonic::Request<super::ViewAuthRequest>,
-        ) -> Result<tonic::Response<super::ViewAuthResponse>, tonic::Status>;
+            request: tonic::Request<super::AddWalletRequest>,
+        ) -> Result<tonic::Response<super::AddWalletResponse>, tonic::Status>;
+        /// Removes a wallet, stopping its sync and deleting its local state.
+        async fn remove_wallet(
+            &self,
+            request: tonic::Request<super::RemoveWalletRequest>,
+        ) -> Result<tonic::Response<super::RemoveWalletResponse>, tonic::Status>;
+        /// Lists the wallets synced by the view service.
+        async fn wallets(
+            &self,
+            request: tonic::Request<super::WalletsRequest>,
+        ) -> Result<tonic::Response<super::WalletsResponse>, tonic::Status>;
     }
+    /// Manages the wallets synced by a view service serving several full viewing keys.
     #[derive(Debug)]
-    pub struct ViewAuthServiceServer<T: ViewAuthService> {
+    pub struct WalletServiceServer<T: WalletService> {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: ViewAuthService> ViewAuthServiceServer<T> {
+    impl<T: WalletService> WalletServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -2503,9 +3186,9 @@ pub mod view_auth_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for ViewAuthServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for WalletServiceServer<T>
     where
-        T: ViewAuthService,
+        T: WalletService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -2521,24 +3204,24 @@ pub mod view_auth_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/penumbra.view.v1alpha1.ViewAuthService/ViewAuth" => {
+                "/penumbra.view.v1alpha1.WalletService/AddWallet" => {
                     #[allow(non_camel_case_types)]
-                    struct ViewAuthSvc<T: ViewAuthService>(pub Arc<T>);
+                    struct AddWalletSvc<T: WalletService>(pub Arc<T>);
                     impl<
-                        T: ViewAuthService,
-                    > tonic::server::UnaryService<super::ViewAuthRequest>
-                    for ViewAuthSvc<T> {
-                        type Response = super::ViewAuthResponse;
+                        T: WalletService,
+                    > tonic::server::UnaryService<super::AddWalletRequest>
+                    for AddWalletSvc<T> {
+                        type Response = super::AddWalletResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ViewAuthRequest>,
+                            request: tonic::Request<super::AddWalletRequest>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).view_auth(request).await };
+                            let fut = async move { (*inner).add_wallet(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2547,7 +3230,83 @@ pub mod view_auth_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ViewAuthSvc(inner);
+                        let method = AddWalletSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.WalletService/RemoveWallet" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveWalletSvc<T: WalletService>(pub Arc<T>);
+                    impl<
+                        T: WalletService,
+                    > tonic::server::UnaryService<super::RemoveWalletRequest>
+                    for RemoveWalletSvc<T> {
+                        type Response = super::RemoveWalletResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveWalletRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).remove_wallet(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveWalletSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.WalletService/Wallets" => {
+                    #[allow(non_camel_case_types)]
+                    struct WalletsSvc<T: WalletService>(pub Arc<T>);
+                    impl<
+                        T: WalletService,
+                    > tonic::server::UnaryService<super::WalletsRequest>
+                    for WalletsSvc<T> {
+                        type Response = super::WalletsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WalletsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).wallets(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WalletsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2574,7 +3333,7 @@ pub mod view_auth_service_server {
             }
         }
     }
-    impl<T: ViewAuthService> Clone for ViewAuthServiceServer<T> {
+    impl<T: WalletService> Clone for WalletServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2584,7 +3343,7 @@ pub mod view_auth_service_server {
             }
         }
     }
-    impl<T: ViewAuthService> Clone for _Inner<T> {
+    impl<T: WalletService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -2594,7 +3353,7 @@ pub mod view_auth_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: ViewAuthService> tonic::server::NamedService for ViewAuthServiceServer<T> {
-        const NAME: &'static str = "penumbra.view.v1alpha1.ViewAuthService";
+    impl<T: WalletService> tonic::server::NamedService for WalletServiceServer<T> {
+        const NAME: &'static str = "penumbra.view.v1alpha1.WalletService";
     }
 }
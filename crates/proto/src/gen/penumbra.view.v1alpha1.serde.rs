@@ -1,3 +1,187 @@
+impl serde::Serialize for AddWalletRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.full_viewing_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AddWalletRequest", len)?;
+        if let Some(v) = self.full_viewing_key.as_ref() {
+            struct_ser.serialize_field("fullViewingKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddWalletRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_viewing_key",
+            "fullViewingKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullViewingKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullViewingKey" | "full_viewing_key" => Ok(GeneratedField::FullViewingKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddWalletRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.AddWalletRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AddWalletRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_viewing_key__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::FullViewingKey => {
+                            if full_viewing_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullViewingKey"));
+                            }
+                            full_viewing_key__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(AddWalletRequest {
+                    full_viewing_key: full_viewing_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.AddWalletRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AddWalletResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AddWalletResponse", len)?;
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddWalletResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account_group_id",
+            "accountGroupId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AccountGroupId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddWalletResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.AddWalletResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AddWalletResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account_group_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(AddWalletResponse {
+                    account_group_id: account_group_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.AddWalletResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AddressByIndexRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -12,6 +196,9 @@ impl serde::Serialize for AddressByIndexRequest {
         if self.display_confirm {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AddressByIndexRequest", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
@@ -19,6 +206,9 @@ impl serde::Serialize for AddressByIndexRequest {
         if self.display_confirm {
             struct_ser.serialize_field("displayConfirm", &self.display_confirm)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -33,12 +223,15 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
             "addressIndex",
             "display_confirm",
             "displayConfirm",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AddressIndex,
             DisplayConfirm,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -62,6 +255,7 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                         match value {
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             "displayConfirm" | "display_confirm" => Ok(GeneratedField::DisplayConfirm),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -83,6 +277,7 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
             {
                 let mut address_index__ = None;
                 let mut display_confirm__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::AddressIndex => {
@@ -97,11 +292,18 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                             }
                             display_confirm__ = Some(map.next_value()?);
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(AddressByIndexRequest {
                     address_index: address_index__,
                     display_confirm: display_confirm__.unwrap_or_default(),
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -504,6 +706,9 @@ impl serde::Serialize for AuthorizeAndBuildRequest {
         if self.authorization_data.is_some() {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AuthorizeAndBuildRequest", len)?;
         if let Some(v) = self.transaction_plan.as_ref() {
             struct_ser.serialize_field("transactionPlan", v)?;
@@ -511,6 +716,9 @@ impl serde::Serialize for AuthorizeAndBuildRequest {
         if let Some(v) = self.authorization_data.as_ref() {
             struct_ser.serialize_field("authorizationData", v)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -525,12 +733,15 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
             "transactionPlan",
             "authorization_data",
             "authorizationData",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TransactionPlan,
             AuthorizationData,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -554,6 +765,7 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
                         match value {
                             "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
                             "authorizationData" | "authorization_data" => Ok(GeneratedField::AuthorizationData),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -575,6 +787,7 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
             {
                 let mut transaction_plan__ = None;
                 let mut authorization_data__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TransactionPlan => {
@@ -589,11 +802,18 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
                             }
                             authorization_data__ = map.next_value()?;
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(AuthorizeAndBuildRequest {
                     transaction_plan: transaction_plan__,
                     authorization_data: authorization_data__,
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -705,6 +925,9 @@ impl serde::Serialize for BalancesRequest {
         if self.asset_id_filter.is_some() {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.BalancesRequest", len)?;
         if let Some(v) = self.account_filter.as_ref() {
             struct_ser.serialize_field("accountFilter", v)?;
@@ -712,6 +935,9 @@ impl serde::Serialize for BalancesRequest {
         if let Some(v) = self.asset_id_filter.as_ref() {
             struct_ser.serialize_field("assetIdFilter", v)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -726,12 +952,15 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
             "accountFilter",
             "asset_id_filter",
             "assetIdFilter",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AccountFilter,
             AssetIdFilter,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -755,6 +984,7 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                         match value {
                             "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
                             "assetIdFilter" | "asset_id_filter" => Ok(GeneratedField::AssetIdFilter),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -776,6 +1006,7 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
             {
                 let mut account_filter__ = None;
                 let mut asset_id_filter__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::AccountFilter => {
@@ -790,11 +1021,18 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                             }
                             asset_id_filter__ = map.next_value()?;
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(BalancesRequest {
                     account_filter: account_filter__,
                     asset_id_filter: asset_id_filter__,
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -923,6 +1161,9 @@ impl serde::Serialize for BroadcastTransactionRequest {
         if self.await_detection {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.BroadcastTransactionRequest", len)?;
         if let Some(v) = self.transaction.as_ref() {
             struct_ser.serialize_field("transaction", v)?;
@@ -930,6 +1171,9 @@ impl serde::Serialize for BroadcastTransactionRequest {
         if self.await_detection {
             struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -943,12 +1187,15 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
             "transaction",
             "await_detection",
             "awaitDetection",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Transaction,
             AwaitDetection,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -972,6 +1219,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
                         match value {
                             "transaction" => Ok(GeneratedField::Transaction),
                             "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -993,6 +1241,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
             {
                 let mut transaction__ = None;
                 let mut await_detection__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Transaction => {
@@ -1007,11 +1256,18 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
                             }
                             await_detection__ = Some(map.next_value()?);
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(BroadcastTransactionRequest {
                     transaction: transaction__,
                     await_detection: await_detection__.unwrap_or_default(),
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -1114,19 +1370,263 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionResponse {
                             if detection_height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("detectionHeight"));
                             }
-                            detection_height__ = 
+                            detection_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(BroadcastTransactionResponse {
+                    id: id__,
+                    detection_height: detection_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.BroadcastTransactionResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CandidateTransactionsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.detection_keys.is_empty() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.keep_alive {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.CandidateTransactionsRequest", len)?;
+        if !self.detection_keys.is_empty() {
+            struct_ser.serialize_field("detectionKeys", &self.detection_keys.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.keep_alive {
+            struct_ser.serialize_field("keepAlive", &self.keep_alive)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CandidateTransactionsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "detection_keys",
+            "detectionKeys",
+            "start_height",
+            "startHeight",
+            "keep_alive",
+            "keepAlive",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DetectionKeys,
+            StartHeight,
+            KeepAlive,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "detectionKeys" | "detection_keys" => Ok(GeneratedField::DetectionKeys),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "keepAlive" | "keep_alive" => Ok(GeneratedField::KeepAlive),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CandidateTransactionsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.CandidateTransactionsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CandidateTransactionsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut detection_keys__ = None;
+                let mut start_height__ = None;
+                let mut keep_alive__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::DetectionKeys => {
+                            if detection_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("detectionKeys"));
+                            }
+                            detection_keys__ = 
+                                Some(map.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::KeepAlive => {
+                            if keep_alive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keepAlive"));
+                            }
+                            keep_alive__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(CandidateTransactionsRequest {
+                    detection_keys: detection_keys__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    keep_alive: keep_alive__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.CandidateTransactionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CandidateTransactionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.transaction_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.CandidateTransactionsResponse", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.transaction_ids.is_empty() {
+            struct_ser.serialize_field("transactionIds", &self.transaction_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CandidateTransactionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "transaction_ids",
+            "transactionIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            TransactionIds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "transactionIds" | "transaction_ids" => Ok(GeneratedField::TransactionIds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CandidateTransactionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.CandidateTransactionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CandidateTransactionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut transaction_ids__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::TransactionIds => {
+                            if transaction_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionIds"));
+                            }
+                            transaction_ids__ = Some(map.next_value()?);
+                        }
                     }
                 }
-                Ok(BroadcastTransactionResponse {
-                    id: id__,
-                    detection_height: detection_height__.unwrap_or_default(),
+                Ok(CandidateTransactionsResponse {
+                    height: height__.unwrap_or_default(),
+                    transaction_ids: transaction_ids__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.BroadcastTransactionResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.CandidateTransactionsResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ChainParametersRequest {
@@ -1305,6 +1805,9 @@ impl serde::Serialize for EphemeralAddressRequest {
         if self.display_confirm {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.EphemeralAddressRequest", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
@@ -1312,6 +1815,9 @@ impl serde::Serialize for EphemeralAddressRequest {
         if self.display_confirm {
             struct_ser.serialize_field("displayConfirm", &self.display_confirm)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1326,12 +1832,15 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
             "addressIndex",
             "display_confirm",
             "displayConfirm",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AddressIndex,
             DisplayConfirm,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1355,6 +1864,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                         match value {
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             "displayConfirm" | "display_confirm" => Ok(GeneratedField::DisplayConfirm),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1376,6 +1886,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
             {
                 let mut address_index__ = None;
                 let mut display_confirm__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::AddressIndex => {
@@ -1390,11 +1901,18 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                             }
                             display_confirm__ = Some(map.next_value()?);
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(EphemeralAddressRequest {
                     address_index: address_index__,
                     display_confirm: display_confirm__.unwrap_or_default(),
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -1665,10 +2183,16 @@ impl serde::Serialize for IndexByAddressRequest {
         if self.address.is_some() {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.IndexByAddressRequest", len)?;
         if let Some(v) = self.address.as_ref() {
             struct_ser.serialize_field("address", v)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1680,11 +2204,14 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
     {
         const FIELDS: &[&str] = &[
             "address",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Address,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1707,6 +2234,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                     {
                         match value {
                             "address" => Ok(GeneratedField::Address),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1727,6 +2255,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut address__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Address => {
@@ -1735,10 +2264,17 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                             }
                             address__ = map.next_value()?;
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(IndexByAddressRequest {
                     address: address__,
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -2785,6 +3321,9 @@ impl serde::Serialize for OwnedPositionIdsRequest {
         if self.trading_pair.is_some() {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", len)?;
         if let Some(v) = self.position_state.as_ref() {
             struct_ser.serialize_field("positionState", v)?;
@@ -2792,6 +3331,9 @@ impl serde::Serialize for OwnedPositionIdsRequest {
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2806,12 +3348,217 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
             "positionState",
             "trading_pair",
             "tradingPair",
+            "account_group_id",
+            "accountGroupId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionState,
+            TradingPair,
+            AccountGroupId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_state__ = None;
+                let mut trading_pair__ = None;
+                let mut account_group_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
+                            }
+                            position_state__ = map.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map.next_value()?;
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsRequest {
+                    position_state: position_state__,
+                    trading_pair: trading_pair__,
+                    account_group_id: account_group_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsResponse {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RemoveWalletRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.RemoveWalletRequest", len)?;
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RemoveWalletRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionState,
-            TradingPair,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2833,8 +3580,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2844,75 +3590,58 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsRequest;
+            type Value = RemoveWalletRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.RemoveWalletRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RemoveWalletRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_state__ = None;
-                let mut trading_pair__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::PositionState => {
-                            if position_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionState"));
-                            }
-                            position_state__ = map.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
                             }
-                            trading_pair__ = map.next_value()?;
+                            account_group_id__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(OwnedPositionIdsRequest {
-                    position_state: position_state__,
-                    trading_pair: trading_pair__,
+                Ok(RemoveWalletRequest {
+                    account_group_id: account_group_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.RemoveWalletRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsResponse {
+impl serde::Serialize for RemoveWalletResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.RemoveWalletResponse", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+impl<'de> serde::Deserialize<'de> for RemoveWalletResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2933,10 +3662,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
-                        }
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2944,33 +3670,24 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsResponse;
+            type Value = RemoveWalletResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.RemoveWalletResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RemoveWalletResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                while let Some(k) = map.next_key()? {
-                    match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map.next_value()?;
-                        }
-                    }
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(OwnedPositionIdsResponse {
-                    position_id: position_id__,
+                Ok(RemoveWalletResponse {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.RemoveWalletResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SpendableNoteRecord {
@@ -4194,10 +4911,16 @@ impl serde::Serialize for TransactionInfoByHashRequest {
         if self.id.is_some() {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionInfoByHashRequest", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -4209,11 +4932,14 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4236,6 +4962,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4256,6 +4983,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -4264,10 +4992,17 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                             }
                             id__ = map.next_value()?;
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(TransactionInfoByHashRequest {
                     id: id__,
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -4380,6 +5115,9 @@ impl serde::Serialize for TransactionInfoRequest {
         if self.end_height.is_some() {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionInfoRequest", len)?;
         if let Some(v) = self.start_height.as_ref() {
             struct_ser.serialize_field("startHeight", ToString::to_string(&v).as_str())?;
@@ -4387,6 +5125,9 @@ impl serde::Serialize for TransactionInfoRequest {
         if let Some(v) = self.end_height.as_ref() {
             struct_ser.serialize_field("endHeight", ToString::to_string(&v).as_str())?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -4401,12 +5142,15 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
             "startHeight",
             "end_height",
             "endHeight",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             StartHeight,
             EndHeight,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4430,6 +5174,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                         match value {
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4451,6 +5196,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
             {
                 let mut start_height__ = None;
                 let mut end_height__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::StartHeight => {
@@ -4469,11 +5215,18 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                                 map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
                             ;
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(TransactionInfoRequest {
                     start_height: start_height__,
                     end_height: end_height__,
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -4804,6 +5557,169 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for WalletsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WalletsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WalletsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WalletsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.WalletsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<WalletsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(WalletsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.WalletsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WalletsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.account_group_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WalletsResponse", len)?;
+        if !self.account_group_ids.is_empty() {
+            struct_ser.serialize_field("accountGroupIds", &self.account_group_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WalletsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account_group_ids",
+            "accountGroupIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AccountGroupIds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accountGroupIds" | "account_group_ids" => Ok(GeneratedField::AccountGroupIds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WalletsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.WalletsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<WalletsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account_group_ids__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AccountGroupIds => {
+                            if account_group_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupIds"));
+                            }
+                            account_group_ids__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(WalletsResponse {
+                    account_group_ids: account_group_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.WalletsResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for transaction_planner_request::Delegate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -5636,6 +6552,9 @@ impl serde::Serialize for WitnessAndBuildRequest {
         if self.authorization_data.is_some() {
             len += 1;
         }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WitnessAndBuildRequest", len)?;
         if let Some(v) = self.transaction_plan.as_ref() {
             struct_ser.serialize_field("transactionPlan", v)?;
@@ -5643,6 +6562,9 @@ impl serde::Serialize for WitnessAndBuildRequest {
         if let Some(v) = self.authorization_data.as_ref() {
             struct_ser.serialize_field("authorizationData", v)?;
         }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5657,12 +6579,15 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
             "transactionPlan",
             "authorization_data",
             "authorizationData",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TransactionPlan,
             AuthorizationData,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5686,6 +6611,7 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
                         match value {
                             "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
                             "authorizationData" | "authorization_data" => Ok(GeneratedField::AuthorizationData),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5707,6 +6633,7 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
             {
                 let mut transaction_plan__ = None;
                 let mut authorization_data__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TransactionPlan => {
@@ -5721,11 +6648,18 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
                             }
                             authorization_data__ = map.next_value()?;
                         }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(WitnessAndBuildRequest {
                     transaction_plan: transaction_plan__,
                     authorization_data: authorization_data__,
+                    account_group_id: account_group_id__,
                 })
             }
         }
@@ -64,6 +64,14 @@ pub mod penumbra {
             }
         }
 
+        /// Flow encryption structures.
+        pub mod eddy {
+            pub mod v1alpha1 {
+                include!("gen/penumbra.core.eddy.v1alpha1.rs");
+                include!("gen/penumbra.core.eddy.v1alpha1.serde.rs");
+            }
+        }
+
         /// Governance structures.
         pub mod governance {
             pub mod v1alpha1 {
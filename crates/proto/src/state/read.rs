@@ -5,7 +5,7 @@ use std::{fmt::Debug, pin::Pin};
 
 use async_trait::async_trait;
 use futures::{Stream, StreamExt};
-use penumbra_storage::StateRead;
+use penumbra_storage::{KeyRange, StateRead};
 
 use super::future::{DomainFuture, ProtoFuture};
 
@@ -84,5 +84,44 @@ pub trait StateReadProto: StateRead + Send + Sync {
         });
         Box::pin(o)
     }
+
+    /// Retrieve all values for keys in a range from consensus-critical state, as domain types.
+    #[allow(clippy::type_complexity)]
+    fn range<D>(
+        &self,
+        range: KeyRange,
+    ) -> Pin<Box<dyn Stream<Item = Result<(String, D)>> + Send + 'static>>
+    where
+        D: DomainType,
+        anyhow::Error: From<<D as TryFrom<D::Proto>>::Error>,
+    {
+        Box::pin(self.range_proto(range).map(|p| match p {
+            Ok(p) => match D::try_from(p.1) {
+                Ok(d) => Ok((p.0, d)),
+                Err(e) => Err(e.into()),
+            },
+            Err(e) => Err(e),
+        }))
+    }
+
+    /// Retrieve all values for keys in a range from the verifiable key-value store, as proto types.
+    #[allow(clippy::type_complexity)]
+    fn range_proto<P>(
+        &self,
+        range: KeyRange,
+    ) -> Pin<Box<dyn Stream<Item = Result<(String, P)>> + Send + 'static>>
+    where
+        P: Message + Default,
+    {
+        let o = self.range_raw(range).map(|r| {
+            r.and_then(|(key, bytes)| {
+                Ok((
+                    key,
+                    Message::decode(&*bytes).map_err(|e| anyhow::anyhow!(e))?,
+                ))
+            })
+        });
+        Box::pin(o)
+    }
 }
 impl<T: StateRead + ?Sized> StateReadProto for T {}
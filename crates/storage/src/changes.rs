@@ -0,0 +1,23 @@
+use std::collections::BTreeMap;
+
+use tendermint::abci;
+
+use crate::RootHash;
+
+/// The changes made to the state by a single commit, as delivered by
+/// [`Storage::subscribe_changes`](crate::Storage::subscribe_changes).
+#[derive(Clone, Debug)]
+pub struct StateChanges {
+    /// The version of the state produced by the commit.
+    pub version: jmt::Version,
+    /// The root hash of the verifiable state at that version.
+    pub root_hash: RootHash,
+    /// The keys written to the verifiable store, with their new values, or
+    /// `None` if they were deleted.
+    pub verifiable: BTreeMap<String, Option<Vec<u8>>>,
+    /// The keys written to the nonverifiable store, with their new values, or
+    /// `None` if they were deleted.
+    pub nonverifiable: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    /// The ABCI events recorded in the committed state.
+    pub events: Vec<abci::Event>,
+}
@@ -9,7 +9,7 @@ use crate::{
         CacheFuture, StateDeltaNonconsensusPrefixRawStream, StateDeltaPrefixKeysStream,
         StateDeltaPrefixRawStream,
     },
-    Cache, EscapedByteSlice, StateRead, StateWrite,
+    Cache, EscapedByteSlice, KeyRange, StateRead, StateWrite,
 };
 
 /// An arbitrarily-deeply nested stack of delta updates to an underlying state.
@@ -323,54 +323,54 @@ impl<S: StateRead> StateRead for StateDelta<S> {
             .object_get(key)
     }
 
-    fn prefix_raw(&self, prefix: &str) -> Self::PrefixRawStream {
+    fn range_raw(&self, range: KeyRange) -> Self::PrefixRawStream {
         let underlying = self
             .state
             .read()
             .as_ref()
             .expect("delta must not have been applied")
-            .prefix_raw(prefix)
+            .range_raw(range.clone())
             .peekable();
         StateDeltaPrefixRawStream {
             underlying,
             layers: self.layers.clone(),
             leaf_cache: self.leaf_cache.clone(),
             last_key: None,
-            prefix: prefix.to_owned(),
+            range,
         }
     }
 
-    fn prefix_keys(&self, prefix: &str) -> Self::PrefixKeysStream {
+    fn range_keys(&self, range: KeyRange) -> Self::PrefixKeysStream {
         let underlying = self
             .state
             .read()
             .as_ref()
             .expect("delta must not have been applied")
-            .prefix_keys(prefix)
+            .range_keys(range.clone())
             .peekable();
         StateDeltaPrefixKeysStream {
             underlying,
             layers: self.layers.clone(),
             leaf_cache: self.leaf_cache.clone(),
             last_key: None,
-            prefix: prefix.to_owned(),
+            range,
         }
     }
 
-    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
+    fn nonverifiable_range_raw(&self, range: KeyRange) -> Self::NonconsensusPrefixRawStream {
         let underlying = self
             .state
             .read()
             .as_ref()
             .expect("delta must not have been applied")
-            .nonverifiable_prefix_raw(prefix)
+            .nonverifiable_range_raw(range.clone())
             .peekable();
         StateDeltaNonconsensusPrefixRawStream {
             underlying,
             layers: self.layers.clone(),
             leaf_cache: self.leaf_cache.clone(),
             last_key: None,
-            prefix: prefix.to_vec(),
+            range,
         }
     }
 }
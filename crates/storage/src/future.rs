@@ -10,14 +10,74 @@ use parking_lot::RwLock;
 use pin_project::pin_project;
 use smallvec::SmallVec;
 use std::{
+    collections::BTreeMap,
     future::Future,
-    ops::Bound,
+    ops::{Bound, RangeBounds},
     pin::Pin,
     sync::Arc,
     task::{ready, Context, Poll},
 };
 
-use crate::Cache;
+use crate::{Cache, KeyRange};
+
+/// The range of keys in cache layers that could affect whether to yield the
+/// next item in an underlying stream over `range`.
+///
+/// This lies between the last key yielded (exclusive, so we make progress on
+/// the stream) and the next key in the underlying stream (inclusive, because
+/// we need to find out whether or not there was a covering deletion), in
+/// iteration order.
+fn search_range<'a>(
+    range: &'a KeyRange,
+    last_key: Option<&'a [u8]>,
+    peeked_key: Option<&'a [u8]>,
+) -> (Bound<&'a [u8]>, Bound<&'a [u8]>) {
+    let (start, end) = range.bounds();
+    let last = last_key.map(Bound::Excluded);
+    let peeked = peeked_key.map(Bound::Included);
+    if range.reverse {
+        (peeked.unwrap_or(start), last.unwrap_or(end))
+    } else {
+        (last.unwrap_or(start), peeked.unwrap_or(end))
+    }
+}
+
+/// Finds the first entry of a cache layer within `bounds`, in iteration order.
+fn first_bytes_entry<'a, V>(
+    changes: &'a BTreeMap<Vec<u8>, V>,
+    bounds: (Bound<&[u8]>, Bound<&[u8]>),
+    reverse: bool,
+) -> Option<(&'a Vec<u8>, &'a V)> {
+    let mut entries = changes.range::<[u8], _>(bounds);
+    if reverse {
+        entries.next_back()
+    } else {
+        entries.next()
+    }
+}
+
+/// Finds the first entry of a cache layer within `bounds`, in iteration order.
+fn first_string_entry<'a, 'b, V>(
+    changes: &'a BTreeMap<String, V>,
+    bounds: (Bound<&'b [u8]>, Bound<&'b [u8]>),
+    reverse: bool,
+) -> Option<(&'a String, &'a V)> {
+    // The bounds may not be valid UTF-8 (e.g., the end of a prefix range), in
+    // which case we search without them, and filter out the keys outside them.
+    let as_str = |bound: Bound<&'b [u8]>| match bound {
+        Bound::Included(k) => std::str::from_utf8(k).map_or(Bound::Unbounded, Bound::Included),
+        Bound::Excluded(k) => std::str::from_utf8(k).map_or(Bound::Unbounded, Bound::Excluded),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    let mut entries = changes
+        .range::<str, _>((as_str(bounds.0), as_str(bounds.1)))
+        .filter(|(k, _)| RangeBounds::<[u8]>::contains(&bounds, k.as_bytes()));
+    if reverse {
+        entries.next_back()
+    } else {
+        entries.next()
+    }
+}
 
 /// Future representing a read from a state snapshot.
 #[pin_project]
@@ -76,7 +136,7 @@ where
     pub(crate) layers: Vec<Arc<RwLock<Option<Cache>>>>,
     pub(crate) leaf_cache: Arc<RwLock<Option<Cache>>>,
     pub(crate) last_key: Option<Vec<u8>>,
-    pub(crate) prefix: Vec<u8>,
+    pub(crate) range: KeyRange,
 }
 
 impl<St> Stream for StateDeltaNonconsensusPrefixRawStream<St>
@@ -110,6 +170,8 @@ where
         //          │
         //          │search range of key-value pairs in cache layers that could
         //          │affect whether to yield the next item in the underlying stream
+        //
+        // When iterating in reverse, the same process runs from right to left.
 
         // Optimization: ensure we have a peekable item in the underlying stream before continuing.
         let mut this = self.project();
@@ -141,14 +203,10 @@ where
             // key we returned (exclusive, so we make forward progress on the
             // stream) and the peeked key (inclusive, because we need to find out
             // whether or not there was a covering deletion).
-            let search_range = (
-                this.last_key
-                    .as_ref()
-                    .map(Bound::Excluded)
-                    .unwrap_or(Bound::Included(this.prefix)),
-                peeked
-                    .map(|(k, _)| Bound::Included(k))
-                    .unwrap_or(Bound::Unbounded),
+            let search_range = search_range(
+                this.range,
+                this.last_key.as_deref(),
+                peeked.map(|(k, _)| k.as_slice()),
             );
 
             // It'd be slightly cleaner to initialize `leftmost_pair` with the
@@ -156,22 +214,28 @@ where
             // `peeked` borrow, and we may need to mutate the underlying stream
             // later.  Instead, initialize it with `None` to only search the
             // cache layers, and compare at the end.
+            //
+            // Here, "leftmost" means first in iteration order.
             let mut leftmost_pair = None;
             for layer in layer_guards.iter() {
                 // Find this layer's leftmost key-value pair in the search range.
-                let found_pair = layer
-                    .as_ref()
-                    .unwrap()
-                    .nonverifiable_changes
-                    .range::<Vec<u8>, _>(search_range)
-                    .take_while(|(k, _v)| k.starts_with(this.prefix))
-                    .next();
+                let found_pair = first_bytes_entry(
+                    &layer.as_ref().unwrap().nonverifiable_changes,
+                    search_range,
+                    this.range.reverse,
+                );
 
                 // Check whether the new pair, if any, is the new leftmost pair.
                 match (leftmost_pair, found_pair) {
                     // We want to replace the pair even when the key is equal,
                     // so that we always prefer a newer value over an older value.
-                    (Some((leftmost_k, _)), Some((k, v))) if k <= leftmost_k => {
+                    (Some((leftmost_k, _)), Some((k, v)))
+                        if (if this.range.reverse {
+                            k >= leftmost_k
+                        } else {
+                            k <= leftmost_k
+                        }) =>
+                    {
                         leftmost_pair = Some((k, v));
                     }
                     (None, Some((k, v))) => {
@@ -193,9 +257,9 @@ where
 
             match (leftmost_pair, peeked) {
                 (Some((k, v)), peeked) => {
-                    // Since we searched for cached keys less than or equal to
-                    // the peeked key, we know that the cached pair takes
-                    // priority over the peeked pair.
+                    // Since we searched for cached keys up to and including
+                    // the peeked key in iteration order, we know that the
+                    // cached pair takes priority over the peeked pair.
                     //
                     // If the keys are exactly equal, we advance the underlying stream.
                     if peeked.map(|(kp, _)| kp) == Some(k) {
@@ -243,7 +307,7 @@ where
     pub(crate) layers: Vec<Arc<RwLock<Option<Cache>>>>,
     pub(crate) leaf_cache: Arc<RwLock<Option<Cache>>>,
     pub(crate) last_key: Option<String>,
-    pub(crate) prefix: String,
+    pub(crate) range: KeyRange,
 }
 
 impl<St> Stream for StateDeltaPrefixRawStream<St>
@@ -277,6 +341,8 @@ where
         //          │
         //          │search range of key-value pairs in cache layers that could
         //          │affect whether to yield the next item in the underlying stream
+        //
+        // When iterating in reverse, the same process runs from right to left.
 
         // Optimization: ensure we have a peekable item in the underlying stream before continuing.
         let mut this = self.project();
@@ -308,14 +374,10 @@ where
             // key we returned (exclusive, so we make forward progress on the
             // stream) and the peeked key (inclusive, because we need to find out
             // whether or not there was a covering deletion).
-            let search_range = (
-                this.last_key
-                    .as_ref()
-                    .map(Bound::Excluded)
-                    .unwrap_or(Bound::Included(this.prefix)),
-                peeked
-                    .map(|(k, _)| Bound::Included(k))
-                    .unwrap_or(Bound::Unbounded),
+            let search_range = search_range(
+                this.range,
+                this.last_key.as_deref().map(str::as_bytes),
+                peeked.map(|(k, _)| k.as_bytes()),
             );
 
             // It'd be slightly cleaner to initialize `leftmost_pair` with the
@@ -323,22 +385,28 @@ where
             // `peeked` borrow, and we may need to mutate the underlying stream
             // later.  Instead, initialize it with `None` to only search the
             // cache layers, and compare at the end.
+            //
+            // Here, "leftmost" means first in iteration order.
             let mut leftmost_pair = None;
             for layer in layer_guards.iter() {
                 // Find this layer's leftmost key-value pair in the search range.
-                let found_pair = layer
-                    .as_ref()
-                    .unwrap()
-                    .unwritten_changes
-                    .range::<String, _>(search_range)
-                    .take_while(|(k, _v)| k.starts_with(this.prefix.as_str()))
-                    .next();
+                let found_pair = first_string_entry(
+                    &layer.as_ref().unwrap().unwritten_changes,
+                    search_range,
+                    this.range.reverse,
+                );
 
                 // Check whether the new pair, if any, is the new leftmost pair.
                 match (leftmost_pair, found_pair) {
                     // We want to replace the pair even when the key is equal,
                     // so that we always prefer a newer value over an older value.
-                    (Some((leftmost_k, _)), Some((k, v))) if k <= leftmost_k => {
+                    (Some((leftmost_k, _)), Some((k, v)))
+                        if (if this.range.reverse {
+                            k >= leftmost_k
+                        } else {
+                            k <= leftmost_k
+                        }) =>
+                    {
                         leftmost_pair = Some((k, v));
                     }
                     (None, Some((k, v))) => {
@@ -360,9 +428,9 @@ where
 
             match (leftmost_pair, peeked) {
                 (Some((k, v)), peeked) => {
-                    // Since we searched for cached keys less than or equal to
-                    // the peeked key, we know that the cached pair takes
-                    // priority over the peeked pair.
+                    // Since we searched for cached keys up to and including
+                    // the peeked key in iteration order, we know that the
+                    // cached pair takes priority over the peeked pair.
                     //
                     // If the keys are exactly equal, we advance the underlying stream.
                     if peeked.map(|(kp, _)| kp) == Some(k) {
@@ -410,7 +478,7 @@ where
     pub(crate) layers: Vec<Arc<RwLock<Option<Cache>>>>,
     pub(crate) leaf_cache: Arc<RwLock<Option<Cache>>>,
     pub(crate) last_key: Option<String>,
-    pub(crate) prefix: String,
+    pub(crate) range: KeyRange,
 }
 
 impl<St> Stream for StateDeltaPrefixKeysStream<St>
@@ -444,6 +512,8 @@ where
         //          │
         //          │search range of key-value pairs in cache layers that could
         //          │affect whether to yield the next item in the underlying stream
+        //
+        // When iterating in reverse, the same process runs from right to left.
 
         // Optimization: ensure we have a peekable item in the underlying stream before continuing.
         let mut this = self.project();
@@ -475,12 +545,10 @@ where
             // key we returned (exclusive, so we make forward progress on the
             // stream) and the peeked key (inclusive, because we need to find out
             // whether or not there was a covering deletion).
-            let search_range = (
-                this.last_key
-                    .as_ref()
-                    .map(Bound::Excluded)
-                    .unwrap_or(Bound::Included(this.prefix)),
-                peeked.map(Bound::Included).unwrap_or(Bound::Unbounded),
+            let search_range = search_range(
+                this.range,
+                this.last_key.as_deref().map(str::as_bytes),
+                peeked.map(|k| k.as_bytes()),
             );
 
             // It'd be slightly cleaner to initialize `leftmost_pair` with the
@@ -488,22 +556,28 @@ where
             // `peeked` borrow, and we may need to mutate the underlying stream
             // later.  Instead, initialize it with `None` to only search the
             // cache layers, and compare at the end.
+            //
+            // Here, "leftmost" means first in iteration order.
             let mut leftmost_pair = None;
             for layer in layer_guards.iter() {
                 // Find this layer's leftmost key-value pair in the search range.
-                let found_pair = layer
-                    .as_ref()
-                    .unwrap()
-                    .unwritten_changes
-                    .range::<String, _>(search_range)
-                    .take_while(|(k, _v)| k.starts_with(this.prefix.as_str()))
-                    .next();
+                let found_pair = first_string_entry(
+                    &layer.as_ref().unwrap().unwritten_changes,
+                    search_range,
+                    this.range.reverse,
+                );
 
                 // Check whether the new pair, if any, is the new leftmost pair.
                 match (leftmost_pair, found_pair) {
                     // We want to replace the pair even when the key is equal,
                     // so that we always prefer a newer value over an older value.
-                    (Some((leftmost_k, _)), Some((k, v))) if k <= leftmost_k => {
+                    (Some((leftmost_k, _)), Some((k, v)))
+                        if (if this.range.reverse {
+                            k >= leftmost_k
+                        } else {
+                            k <= leftmost_k
+                        }) =>
+                    {
                         leftmost_pair = Some((k, v));
                     }
                     (None, Some((k, v))) => {
@@ -525,9 +599,9 @@ where
 
             match (leftmost_pair, peeked) {
                 (Some((k, v)), peeked) => {
-                    // Since we searched for cached keys less than or equal to
-                    // the peeked key, we know that the cached pair takes
-                    // priority over the peeked pair.
+                    // Since we searched for cached keys up to and including
+                    // the peeked key in iteration order, we know that the
+                    // cached pair takes priority over the peeked pair.
                     //
                     // If the keys are exactly equal, we advance the underlying stream.
                     if peeked == Some(k) {
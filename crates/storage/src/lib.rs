@@ -37,23 +37,31 @@
 //! or domain types, allowing its use as an object store.
 
 mod cache;
+mod changes;
 mod delta;
 mod escaped_byte_slice;
 mod metrics;
+mod proof;
+mod range;
 mod read;
 mod snapshot;
 mod snapshot_cache;
+mod state_sync;
 mod storage;
 mod write;
 
 pub use crate::metrics::register_metrics;
 pub use cache::Cache;
+pub use changes::StateChanges;
 pub use delta::{ArcStateDeltaExt, StateDelta};
 pub use escaped_byte_slice::EscapedByteSlice;
 pub use jmt::{ics23_spec, RootHash};
+pub use proof::{verify_batch_proof, verify_proof};
+pub use range::KeyRange;
 pub use read::StateRead;
 pub use snapshot::Snapshot;
-pub use storage::{Storage, TempStorage};
+pub use state_sync::{ExportedSnapshot, Restore, SnapshotManifest};
+pub use storage::{RetentionPolicy, Storage, TempStorage};
 pub use write::StateWrite;
 
 pub mod future;
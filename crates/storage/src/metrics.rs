@@ -27,8 +27,15 @@ pub fn register_metrics() {
         Unit::Seconds,
         "The duration of a nonverifiable_get_raw request"
     );
+    register_counter!(STORAGE_PRUNED_ENTRIES_TOTAL);
+    describe_counter!(
+        STORAGE_PRUNED_ENTRIES_TOTAL,
+        Unit::Count,
+        "The number of stale JMT nodes and values deleted by pruning"
+    );
 }
 
 pub const STORAGE_GET_RAW_DURATION: &str = "penumbra_storage_get_raw_duration_seconds";
 pub const STORAGE_NONCONSENSUS_GET_RAW_DURATION: &str =
     "penumbra_storage_nonverifiable_get_raw_duration_seconds";
+pub const STORAGE_PRUNED_ENTRIES_TOTAL: &str = "penumbra_storage_pruned_entries_total";
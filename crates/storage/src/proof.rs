@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use ics23::{batch_entry, commitment_proof, CommitmentProof, HostFunctionsManager};
+
+use crate::RootHash;
+
+/// Verifies an ICS23 proof produced by [`Snapshot::get_with_proof`](crate::Snapshot::get_with_proof)
+/// against the JMT root hash of the snapshot.
+///
+/// If `value` is `Some`, the proof must show that `key` maps to `value`;
+/// otherwise, it must show that `key` is absent.
+pub fn verify_proof(
+    root: &RootHash,
+    key: &[u8],
+    value: Option<&[u8]>,
+    proof: &CommitmentProof,
+) -> Result<()> {
+    let spec = jmt::ics23_spec();
+    let root = root.0.to_vec();
+    let verified = match value {
+        Some(value) => {
+            ics23::verify_membership::<HostFunctionsManager>(proof, &spec, &root, key, value)
+        }
+        None => ics23::verify_non_membership::<HostFunctionsManager>(proof, &spec, &root, key),
+    };
+    anyhow::ensure!(
+        verified,
+        "proof for key {} does not verify against root hash {}",
+        hex::encode(key),
+        hex::encode(root)
+    );
+    Ok(())
+}
+
+/// Verifies a batch proof produced by [`Snapshot::get_with_proofs`](crate::Snapshot::get_with_proofs)
+/// against the JMT root hash of the snapshot.
+///
+/// Each entry pairs a key with its expected value, or `None` if the key is
+/// expected to be absent.
+pub fn verify_batch_proof(
+    root: &RootHash,
+    entries: &[(Vec<u8>, Option<Vec<u8>>)],
+    proof: &CommitmentProof,
+) -> Result<()> {
+    let spec = jmt::ics23_spec();
+    let root = root.0.to_vec();
+
+    let present = entries
+        .iter()
+        .filter_map(|(key, value)| Some((key.as_slice(), value.as_deref()?)))
+        .collect::<BTreeMap<_, _>>();
+    let absent = entries
+        .iter()
+        .filter(|(_, value)| value.is_none())
+        .map(|(key, _)| key.as_slice())
+        .collect::<Vec<_>>();
+
+    anyhow::ensure!(
+        present.is_empty()
+            || ics23::verify_batch_membership::<HostFunctionsManager>(proof, &spec, &root, present),
+        "batch proof of membership does not verify against root hash {}",
+        hex::encode(&root)
+    );
+    anyhow::ensure!(
+        absent.is_empty()
+            || ics23::verify_batch_non_membership::<HostFunctionsManager>(
+                proof, &spec, &root, &absent
+            ),
+        "batch proof of non-membership does not verify against root hash {}",
+        hex::encode(&root)
+    );
+    Ok(())
+}
+
+/// Combines single-key proofs into one batch proof.
+pub(crate) fn batch(proofs: Vec<CommitmentProof>) -> Result<CommitmentProof> {
+    let entries = proofs
+        .into_iter()
+        .map(|proof| {
+            let proof = match proof.proof {
+                Some(commitment_proof::Proof::Exist(proof)) => batch_entry::Proof::Exist(proof),
+                Some(commitment_proof::Proof::Nonexist(proof)) => {
+                    batch_entry::Proof::Nonexist(proof)
+                }
+                _ => anyhow::bail!("expected an existence or non-existence proof"),
+            };
+            Ok(ics23::BatchEntry { proof: Some(proof) })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(CommitmentProof {
+        proof: Some(commitment_proof::Proof::Batch(ics23::BatchProof {
+            entries,
+        })),
+    })
+}
@@ -0,0 +1,234 @@
+use std::ops::{Bound, RangeBounds};
+
+/// A contiguous range of keys to iterate over, in either ascending or
+/// descending order.
+///
+/// Keys are ordered as byte strings, which for the (UTF-8) keys of the
+/// verifiable store coincides with their ordering as strings.
+///
+/// # Example
+///
+/// ```
+/// # use penumbra_storage::KeyRange;
+/// // Keys with the prefix `a/`, from `a/m` (inclusive) downwards.
+/// let range = KeyRange::prefix("a/").rev().seek("a/m");
+/// assert!(range.contains(b"a/m"));
+/// assert!(range.contains(b"a/b"));
+/// assert!(!range.contains(b"a/n"));
+/// assert!(!range.contains(b"b/a"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyRange {
+    /// The inclusive lower bound of the range, if any.
+    pub(crate) start: Option<Vec<u8>>,
+    /// The exclusive upper bound of the range, if any.
+    pub(crate) end: Option<Vec<u8>>,
+    /// Whether to iterate over the range in descending order.
+    pub(crate) reverse: bool,
+}
+
+impl KeyRange {
+    /// The range of all keys, in ascending order.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// The range of keys with the given prefix, in ascending order.
+    pub fn prefix(prefix: impl AsRef<[u8]>) -> Self {
+        let prefix = prefix.as_ref();
+        Self {
+            start: Some(prefix.to_vec()),
+            end: prefix_end(prefix),
+            reverse: false,
+        }
+    }
+
+    /// The range of keys within the given bounds, in ascending order.
+    pub fn new<K: AsRef<[u8]>>(bounds: impl RangeBounds<K>) -> Self {
+        let start = match bounds.start_bound() {
+            Bound::Included(key) => Some(key.as_ref().to_vec()),
+            Bound::Excluded(key) => Some(successor(key.as_ref())),
+            Bound::Unbounded => None,
+        };
+        let end = match bounds.end_bound() {
+            Bound::Included(key) => Some(successor(key.as_ref())),
+            Bound::Excluded(key) => Some(key.as_ref().to_vec()),
+            Bound::Unbounded => None,
+        };
+        Self {
+            start,
+            end,
+            reverse: false,
+        }
+        .normalize()
+    }
+
+    /// Restricts this range to the keys that also lie within `bounds`,
+    /// keeping this range's iteration order.
+    pub fn within<K: AsRef<[u8]>>(self, bounds: impl RangeBounds<K>) -> Self {
+        self.intersect(KeyRange::new(bounds))
+    }
+
+    /// Restricts this range to start iterating at `key` (inclusive), skipping
+    /// the keys that precede it in iteration order.
+    pub fn seek(self, key: impl AsRef<[u8]>) -> Self {
+        if self.reverse {
+            self.within(..=key.as_ref())
+        } else {
+            self.within(key.as_ref()..)
+        }
+    }
+
+    /// Reverses the order of iteration over this range.
+    pub fn rev(mut self) -> Self {
+        self.reverse = !self.reverse;
+        self
+    }
+
+    /// Returns `true` if this range is iterated in descending order.
+    pub fn is_reverse(&self) -> bool {
+        self.reverse
+    }
+
+    /// Returns `true` if `key` lies within this range.
+    pub fn contains(&self, key: &[u8]) -> bool {
+        self.start.as_deref().map_or(true, |start| start <= key)
+            && self.end.as_deref().map_or(true, |end| key < end)
+    }
+
+    /// Returns the range of keys lying in both `self` and `other`, in the
+    /// iteration order of `self`.
+    fn intersect(self, other: KeyRange) -> Self {
+        let start = match (self.start, other.start) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        let end = match (self.end, other.end) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        Self {
+            start,
+            end,
+            reverse: self.reverse,
+        }
+        .normalize()
+    }
+
+    /// Ensures that the end of an empty range isn't before its start, so
+    /// that the range can be searched without special cases.
+    fn normalize(mut self) -> Self {
+        if let (Some(start), Some(end)) = (&self.start, &mut self.end) {
+            if *end < *start {
+                *end = start.clone();
+            }
+        }
+        self
+    }
+
+    /// The bounds of this range, for searching the keys of an in-memory cache.
+    pub(crate) fn bounds(&self) -> (Bound<&[u8]>, Bound<&[u8]>) {
+        (
+            self.start
+                .as_deref()
+                .map_or(Bound::Unbounded, Bound::Included),
+            self.end
+                .as_deref()
+                .map_or(Bound::Unbounded, Bound::Excluded),
+        )
+    }
+
+    /// Read options bounding a RocksDB iterator to this range.
+    pub(crate) fn read_options(&self) -> rocksdb::ReadOptions {
+        let mut options = rocksdb::ReadOptions::default();
+        if let Some(start) = &self.start {
+            options.set_iterate_lower_bound(start.clone());
+        }
+        if let Some(end) = &self.end {
+            options.set_iterate_upper_bound(end.clone());
+        }
+        options
+    }
+
+    /// The mode starting a RocksDB iterator at the first key of this range in
+    /// iteration order, when bounded by [`KeyRange::read_options`].
+    pub(crate) fn iterator_mode(&self) -> rocksdb::IteratorMode<'static> {
+        if self.reverse {
+            rocksdb::IteratorMode::End
+        } else {
+            rocksdb::IteratorMode::Start
+        }
+    }
+}
+
+/// The least key greater than `key`.
+fn successor(key: &[u8]) -> Vec<u8> {
+    let mut successor = key.to_vec();
+    successor.push(0);
+    successor
+}
+
+/// The least key greater than every key with the given prefix, if any.
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last < u8::MAX {
+            end.push(last + 1);
+            return Some(end);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_ranges() {
+        let range = KeyRange::prefix("a/");
+        assert!(range.contains(b"a/"));
+        assert!(range.contains(b"a/\xff\xff"));
+        assert!(!range.contains(b"a"));
+        assert!(!range.contains(b"a0"));
+
+        assert_eq!(prefix_end(b"a\xff\xff"), Some(b"b".to_vec()));
+        assert_eq!(prefix_end(b"\xff"), None);
+        assert!(KeyRange::prefix(b"\xff").contains(b"\xff\xff"));
+    }
+
+    #[test]
+    fn bounds_and_seek() {
+        let range = KeyRange::new("b".."d");
+        assert!(range.contains(b"b"));
+        assert!(range.contains(b"c\xff"));
+        assert!(!range.contains(b"d"));
+
+        let range = KeyRange::new("b"..="d");
+        assert!(range.contains(b"d"));
+        assert!(!range.contains(b"d\0"));
+
+        let range = KeyRange::new((Bound::Excluded("b"), Bound::Unbounded));
+        assert!(!range.contains(b"b"));
+        assert!(range.contains(b"b\0"));
+
+        // Empty ranges are empty, regardless of the order of their bounds.
+        let range = KeyRange::new("d".."b");
+        assert_eq!(
+            range.bounds(),
+            (Bound::Included(&b"d"[..]), Bound::Excluded(&b"d"[..]))
+        );
+        assert!(!range.contains(b"c"));
+
+        let forward = KeyRange::prefix("a/").seek("a/m");
+        assert!(forward.contains(b"a/m"));
+        assert!(forward.contains(b"a/z"));
+        assert!(!forward.contains(b"a/b"));
+
+        let reverse = KeyRange::prefix("a/").rev().seek("a/m");
+        assert!(reverse.is_reverse());
+        assert!(reverse.contains(b"a/m"));
+        assert!(reverse.contains(b"a/b"));
+        assert!(!reverse.contains(b"a/m\0"));
+    }
+}
@@ -3,6 +3,8 @@ use std::{any::Any, future::Future, sync::Arc};
 use anyhow::Result;
 use futures::Stream;
 
+use crate::KeyRange;
+
 /// Read access to chain state.
 pub trait StateRead: Send + Sync {
     type GetRawFut: Future<Output = Result<Option<Vec<u8>>>> + Send + 'static;
@@ -43,18 +45,40 @@ pub trait StateRead: Send + Sync {
     /// is present.
     fn object_type(&self, key: &'static str) -> Option<std::any::TypeId>;
 
+    /// Retrieve all values for keys in a range from the verifiable key-value store, as raw bytes,
+    /// in the iteration order of the range.
+    ///
+    /// Users should generally prefer to use `range` or `range_proto` from an extension trait.
+    fn range_raw(&self, range: KeyRange) -> Self::PrefixRawStream;
+
+    /// Retrieve all keys (but not values) in a range from the verifiable key-value store, in the
+    /// iteration order of the range.
+    fn range_keys(&self, range: KeyRange) -> Self::PrefixKeysStream;
+
+    /// Retrieve all values for keys in a range from the non-verifiable key-value store, as raw
+    /// bytes, in the iteration order of the range.
+    ///
+    /// Users should generally prefer to use wrapper methods in an extension trait.
+    fn nonverifiable_range_raw(&self, range: KeyRange) -> Self::NonconsensusPrefixRawStream;
+
     /// Retrieve all values for keys matching a prefix from the verifiable key-value store, as raw bytes.
     ///
     /// Users should generally prefer to use `prefix` or `prefix_proto` from an extension trait.
-    fn prefix_raw(&self, prefix: &str) -> Self::PrefixRawStream;
+    fn prefix_raw(&self, prefix: &str) -> Self::PrefixRawStream {
+        self.range_raw(KeyRange::prefix(prefix))
+    }
 
     /// Retrieve all keys (but not values) matching a prefix from the verifiable key-value store.
-    fn prefix_keys(&self, prefix: &str) -> Self::PrefixKeysStream;
+    fn prefix_keys(&self, prefix: &str) -> Self::PrefixKeysStream {
+        self.range_keys(KeyRange::prefix(prefix))
+    }
 
     /// Retrieve all values for keys matching a prefix from the non-verifiable key-value store, as raw bytes.
     ///
     /// Users should generally prefer to use wrapper methods in an extension trait.
-    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream;
+    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
+        self.nonverifiable_range_raw(KeyRange::prefix(prefix))
+    }
 }
 
 impl<'a, S: StateRead + Send + Sync> StateRead for &'a S {
@@ -67,16 +91,16 @@ impl<'a, S: StateRead + Send + Sync> StateRead for &'a S {
         (**self).get_raw(key)
     }
 
-    fn prefix_raw(&self, prefix: &str) -> S::PrefixRawStream {
-        (**self).prefix_raw(prefix)
+    fn range_raw(&self, range: KeyRange) -> S::PrefixRawStream {
+        (**self).range_raw(range)
     }
 
-    fn prefix_keys(&self, prefix: &str) -> S::PrefixKeysStream {
-        (**self).prefix_keys(prefix)
+    fn range_keys(&self, range: KeyRange) -> S::PrefixKeysStream {
+        (**self).range_keys(range)
     }
 
-    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> S::NonconsensusPrefixRawStream {
-        (**self).nonverifiable_prefix_raw(prefix)
+    fn nonverifiable_range_raw(&self, range: KeyRange) -> S::NonconsensusPrefixRawStream {
+        (**self).nonverifiable_range_raw(range)
     }
 
     fn nonverifiable_get_raw(&self, key: &[u8]) -> Self::GetRawFut {
@@ -102,16 +126,16 @@ impl<'a, S: StateRead + Send + Sync> StateRead for &'a mut S {
         (**self).get_raw(key)
     }
 
-    fn prefix_raw(&self, prefix: &str) -> S::PrefixRawStream {
-        (**self).prefix_raw(prefix)
+    fn range_raw(&self, range: KeyRange) -> S::PrefixRawStream {
+        (**self).range_raw(range)
     }
 
-    fn prefix_keys(&self, prefix: &str) -> S::PrefixKeysStream {
-        (**self).prefix_keys(prefix)
+    fn range_keys(&self, range: KeyRange) -> S::PrefixKeysStream {
+        (**self).range_keys(range)
     }
 
-    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> S::NonconsensusPrefixRawStream {
-        (**self).nonverifiable_prefix_raw(prefix)
+    fn nonverifiable_range_raw(&self, range: KeyRange) -> S::NonconsensusPrefixRawStream {
+        (**self).nonverifiable_range_raw(range)
     }
 
     fn nonverifiable_get_raw(&self, key: &[u8]) -> Self::GetRawFut {
@@ -137,16 +161,16 @@ impl<S: StateRead + Send + Sync> StateRead for Arc<S> {
         (**self).get_raw(key)
     }
 
-    fn prefix_raw(&self, prefix: &str) -> S::PrefixRawStream {
-        (**self).prefix_raw(prefix)
+    fn range_raw(&self, range: KeyRange) -> S::PrefixRawStream {
+        (**self).range_raw(range)
     }
 
-    fn prefix_keys(&self, prefix: &str) -> S::PrefixKeysStream {
-        (**self).prefix_keys(prefix)
+    fn range_keys(&self, range: KeyRange) -> S::PrefixKeysStream {
+        (**self).range_keys(range)
     }
 
-    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> S::NonconsensusPrefixRawStream {
-        (**self).nonverifiable_prefix_raw(prefix)
+    fn nonverifiable_range_raw(&self, range: KeyRange) -> S::NonconsensusPrefixRawStream {
+        (**self).nonverifiable_range_raw(range)
     }
 
     fn nonverifiable_get_raw(&self, key: &[u8]) -> Self::GetRawFut {
@@ -188,15 +212,15 @@ impl StateRead for () {
         None
     }
 
-    fn prefix_raw(&self, _prefix: &str) -> Self::PrefixRawStream {
+    fn range_raw(&self, _range: KeyRange) -> Self::PrefixRawStream {
         futures::stream::iter(std::iter::empty())
     }
 
-    fn prefix_keys(&self, _prefix: &str) -> Self::PrefixKeysStream {
+    fn range_keys(&self, _range: KeyRange) -> Self::PrefixKeysStream {
         futures::stream::iter(std::iter::empty())
     }
 
-    fn nonverifiable_prefix_raw(&self, _prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
+    fn nonverifiable_range_raw(&self, _range: KeyRange) -> Self::NonconsensusPrefixRawStream {
         futures::stream::iter(std::iter::empty())
     }
 }
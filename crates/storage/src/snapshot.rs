@@ -3,6 +3,7 @@ use std::{any::Any, sync::Arc};
 use anyhow::Result;
 use async_trait::async_trait;
 use borsh::BorshDeserialize;
+use futures::{Stream, TryStreamExt};
 use jmt::{
     storage::{HasPreimage, LeafNode, Node, NodeKey, TreeReader},
     KeyHash, Sha256Jmt,
@@ -13,8 +14,9 @@ use tracing::Span;
 
 use crate::{
     metrics,
+    state_sync::{Chunker, COLUMN_FAMILIES},
     storage::{DbNodeKey, VersionedKeyHash},
-    StateRead,
+    KeyRange, StateRead,
 };
 
 mod rocks_wrapper;
@@ -43,6 +45,9 @@ impl std::fmt::Debug for Snapshot {
 pub(crate) struct Inner {
     snapshot: RocksDbSnapshot,
     version: jmt::Version,
+    // Whether the RocksDB snapshot was taken after `version` was committed,
+    // in which case the key index may include keys written in later versions.
+    historical: bool,
     // Used to retrieve column family handles.
     db: Arc<rocksdb::DB>,
 }
@@ -52,10 +57,35 @@ impl Snapshot {
         Self(Arc::new(Inner {
             snapshot: RocksDbSnapshot::new(db.clone()),
             version,
+            historical: false,
             db,
         }))
     }
 
+    /// Reconstructs a snapshot of an older version of the tree from the
+    /// backing store, returning `None` if that version has been pruned.
+    ///
+    /// Reads of the verifiable state reflect the state as of `version`, but
+    /// the nonverifiable state is not versioned, so reads of it fail rather
+    /// than silently returning the latest state.
+    pub(crate) fn historical(db: Arc<rocksdb::DB>, version: jmt::Version) -> Result<Option<Self>> {
+        let snapshot = Self(Arc::new(Inner {
+            snapshot: RocksDbSnapshot::new(db.clone()),
+            version,
+            historical: true,
+            db,
+        }));
+
+        // Pruning a version deletes its root node, and the RocksDB snapshot
+        // pins the root node if it's still present.
+        let tree = Sha256Jmt::new(&*snapshot.0);
+        if tree.get_root_hash_option(version)?.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(snapshot))
+    }
+
     pub fn version(&self) -> jmt::Version {
         self.0.version
     }
@@ -81,6 +111,75 @@ impl Snapshot {
             .await?
     }
 
+    /// Returns the values corresponding to each of the keys, along with a
+    /// single ICS23 batch proof of their existence (or non-existence) up to
+    /// the current JMT root hash.
+    ///
+    /// The proof can be checked with [`verify_batch_proof`](crate::verify_batch_proof).
+    pub async fn get_with_proofs(
+        &self,
+        keys: Vec<Vec<u8>>,
+    ) -> Result<(Vec<Option<Vec<u8>>>, ics23::CommitmentProof)> {
+        let span = Span::current();
+        let snapshot = self.clone();
+
+        tokio::task::Builder::new()
+            .name("State::get_with_proofs")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    let tree = jmt::Sha256Jmt::new(&*snapshot.0);
+                    let (values, proofs): (Vec<_>, Vec<_>) = keys
+                        .into_iter()
+                        .map(|key| tree.get_with_ics23_proof(key, snapshot.version()))
+                        .collect::<Result<Vec<_>>>()?
+                        .into_iter()
+                        .unzip();
+                    Ok((values, crate::proof::batch(proofs)?))
+                })
+            })?
+            .await?
+    }
+
+    /// Returns a stream of all the key-value pairs in `range`, each along with
+    /// an ICS23 existence proof up to the current JMT root hash.
+    ///
+    /// Since all the proofs are against the same root hash, they can be used to
+    /// verify every entry in the range with [`verify_proof`](crate::verify_proof).
+    ///
+    /// The proofs don't show that the range is complete: the JMT is keyed by
+    /// key hash, so its leaves aren't ordered by key, and there's no proof that
+    /// no other keys fall in the range. A verifier can't tell if entries were
+    /// left out.
+    pub fn range_with_proofs(
+        &self,
+        range: KeyRange,
+    ) -> impl Stream<Item = Result<(String, Vec<u8>, ics23::CommitmentProof)>> + Send + 'static
+    {
+        let snapshot = self.clone();
+        self.range_keys(range).and_then(move |key| {
+            let snapshot = snapshot.clone();
+            async move {
+                let (value, proof) = snapshot.get_with_proof(key.as_bytes().to_vec()).await?;
+                let value = value.ok_or_else(|| {
+                    anyhow::anyhow!("key {key} is indexed but missing from the tree")
+                })?;
+                Ok((key, value, proof))
+            }
+        })
+    }
+
+    /// Returns a stream of all the key-value pairs with the given prefix, each
+    /// along with an ICS23 existence proof up to the current JMT root hash.
+    ///
+    /// As with [`Snapshot::range_with_proofs`], the results aren't proven complete.
+    pub fn prefix_with_proofs(
+        &self,
+        prefix: &str,
+    ) -> impl Stream<Item = Result<(String, Vec<u8>, ics23::CommitmentProof)>> + Send + 'static
+    {
+        self.range_with_proofs(KeyRange::prefix(prefix))
+    }
+
     /// Returns the root hash of this `State`.
     ///
     /// If the `State` is empty, the all-zeros hash will be returned as a placeholder value.
@@ -105,7 +204,107 @@ impl Snapshot {
             .await?
     }
 
-    /// Internal helper function used by `get_raw` and `prefix_raw`.
+    /// Exports the full contents of this snapshot as a sequence of chunks of
+    /// approximately `chunk_size` bytes, suitable for restoring into an empty
+    /// [`Storage`](crate::Storage) with [`Storage::restore`](crate::Storage::restore).
+    pub async fn export(&self, chunk_size: usize) -> Result<crate::ExportedSnapshot> {
+        let span = Span::current();
+        let snapshot = self.clone();
+
+        tokio::task::Builder::new()
+            .name("Snapshot::export")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    anyhow::ensure!(
+                        snapshot.version() != u64::MAX,
+                        "cannot export a pre-genesis snapshot"
+                    );
+                    let tree = jmt::Sha256Jmt::new(&*snapshot.0);
+                    let root_hash = tree
+                        .get_root_hash_option(snapshot.version())?
+                        .unwrap_or(crate::RootHash([0; 32]));
+
+                    let mut chunker = Chunker::new(chunk_size);
+                    for (cf_id, cf_name) in COLUMN_FAMILIES.iter().enumerate() {
+                        let cf = snapshot
+                            .0
+                            .db
+                            .cf_handle(cf_name)
+                            .unwrap_or_else(|| panic!("{cf_name} column family not found"));
+                        for tuple in snapshot.0.snapshot.iterator_cf(cf, IteratorMode::Start) {
+                            let (key, value) = tuple?;
+                            chunker.push(cf_id as u8, key.into(), value.into())?;
+                        }
+                    }
+
+                    let exported = chunker.finish(snapshot.version(), root_hash)?;
+                    tracing::debug!(
+                        version = snapshot.version(),
+                        chunks = exported.chunks.len(),
+                        "exported snapshot"
+                    );
+                    Ok(exported)
+                })
+            })?
+            .await?
+    }
+
+    /// Returns an iterator over the preimages of the JMT keys in `range`, in
+    /// the range's iteration order.
+    ///
+    /// The `jmt_keys` index only holds the keys present in the latest version,
+    /// so for historical snapshots, this merges in the keys deleted since,
+    /// some of which may not be present at this snapshot's version either.
+    fn key_preimages(&self, range: &KeyRange) -> Box<dyn Iterator<Item = Result<Box<[u8]>>> + '_> {
+        let keys_cf = self
+            .0
+            .db
+            .cf_handle("jmt_keys")
+            .expect("jmt_keys column family not found");
+        let keys = self
+            .0
+            .snapshot
+            .iterator_cf_opt(keys_cf, range.read_options(), range.iterator_mode())
+            .map(|tuple| -> Result<Box<[u8]>> { Ok(tuple?.0) });
+        if !self.0.historical {
+            return Box::new(keys);
+        }
+
+        let deleted_keys_cf = self
+            .0
+            .db
+            .cf_handle("jmt_deleted_keys")
+            .expect("jmt_deleted_keys column family not found");
+        let deleted_keys = self
+            .0
+            .snapshot
+            .iterator_cf_opt(deleted_keys_cf, range.read_options(), range.iterator_mode())
+            .map(|tuple| -> Result<Box<[u8]>> { Ok(tuple?.0) });
+
+        let reverse = range.is_reverse();
+        let mut keys = keys.peekable();
+        let mut deleted_keys = deleted_keys.peekable();
+        Box::new(std::iter::from_fn(move || {
+            // A key that was deleted and then written again is in both indexes.
+            let order = match (keys.peek(), deleted_keys.peek()) {
+                (Some(Ok(key)), Some(Ok(deleted_key))) if reverse => deleted_key.cmp(key),
+                (Some(Ok(key)), Some(Ok(deleted_key))) => key.cmp(deleted_key),
+                (Some(Err(_)), _) | (Some(_), None) => std::cmp::Ordering::Less,
+                (None, None) => return None,
+                (_, _) => std::cmp::Ordering::Greater,
+            };
+            match order {
+                std::cmp::Ordering::Less => keys.next(),
+                std::cmp::Ordering::Greater => deleted_keys.next(),
+                std::cmp::Ordering::Equal => {
+                    deleted_keys.next();
+                    keys.next()
+                }
+            }
+        }))
+    }
+
+    /// Internal helper function used by `get_raw` and `range_raw`.
     ///
     /// Reads from the JMT will fail if the root is missing; this method
     /// special-cases the empty tree case so that reads on an empty tree just
@@ -176,6 +375,9 @@ impl StateRead for Snapshot {
                 .name("Snapshot::nonverifiable_get_raw")
                 .spawn_blocking(move || {
                     span.in_scope(|| {
+                        if inner.historical {
+                            return Err(nonverifiable_unversioned(inner.version));
+                        }
                         let start = std::time::Instant::now();
                         let nonverifiable_cf = inner
                             .db
@@ -196,35 +398,22 @@ impl StateRead for Snapshot {
         )
     }
 
-    fn prefix_raw(&self, prefix: &str) -> Self::PrefixRawStream {
+    fn range_raw(&self, range: KeyRange) -> Self::PrefixRawStream {
         let span = Span::current();
         let self2 = self.clone();
 
-        let mut options = rocksdb::ReadOptions::default();
-        options.set_iterate_range(rocksdb::PrefixRange(prefix.as_bytes()));
-        let mode = rocksdb::IteratorMode::Start;
-
         let (tx, rx) = mpsc::channel(10);
 
-        // Since the JMT keys are hashed, we can't use a prefix iterator directly.
-        // We need to first prefix range the key preimages column family, then use the hashed matches to fetch the values
+        // Since the JMT keys are hashed, we can't use a range iterator directly.
+        // We need to first range over the key preimages column family, then use the hashed matches to fetch the values
         // from the JMT column family.
         tokio::task::Builder::new()
-            .name("Snapshot::prefix_raw")
+            .name("Snapshot::range_raw")
             .spawn_blocking(move || {
                 span.in_scope(|| {
-                    let keys_cf = self2
-                        .0
-                        .db
-                        .cf_handle("jmt_keys")
-                        .expect("jmt_keys column family not found");
-
-                    let jmt_keys_iterator =
-                        self2.0.snapshot.iterator_cf_opt(keys_cf, options, mode);
-
-                    for tuple in jmt_keys_iterator {
-                        // For each key that matches the prefix, fetch the value from the JMT column family.
-                        let (key_preimage, _) = tuple?;
+                    for key_preimage in self2.key_preimages(&range) {
+                        // For each key in the range, fetch the value from the JMT column family.
+                        let key_preimage = key_preimage?;
 
                         let k = std::str::from_utf8(key_preimage.as_ref())
                             .expect("saved jmt keys are utf-8 strings")
@@ -232,10 +421,15 @@ impl StateRead for Snapshot {
 
                         let key_hash = jmt::KeyHash::with::<sha2::Sha256>(k.as_bytes());
 
-                        let v = self2
-                            .get_jmt(key_hash)?
-                            .expect("keys in jmt_keys should have a corresponding value in jmt");
-                        tracing::debug!(%k, "prefix_raw");
+                        let v = match self2.get_jmt(key_hash)? {
+                            Some(v) => v,
+                            // Historical snapshots see keys absent at their version.
+                            None if self2.0.historical => continue,
+                            None => {
+                                panic!("keys in jmt_keys should have a corresponding value in jmt")
+                            }
+                        };
+                        tracing::debug!(%k, "range_raw");
 
                         tx.blocking_send(Ok((k, v)))?;
                     }
@@ -250,31 +444,28 @@ impl StateRead for Snapshot {
     // NOTE: this implementation is almost the same as the above, but without
     // fetching the values. not totally clear if this could be combined, or if that would
     // be better overall.
-    fn prefix_keys(&self, prefix: &str) -> Self::PrefixKeysStream {
+    fn range_keys(&self, range: KeyRange) -> Self::PrefixKeysStream {
         let span = Span::current();
         let self2 = self.clone();
 
-        let mut options = rocksdb::ReadOptions::default();
-        options.set_iterate_range(rocksdb::PrefixRange(prefix.as_bytes()));
-        let mode = rocksdb::IteratorMode::Start;
-
         let (tx, rx) = mpsc::channel(10);
         tokio::task::Builder::new()
-            .name("Snapshot::prefix_keys")
+            .name("Snapshot::range_keys")
             .spawn_blocking(move || {
                 span.in_scope(|| {
-                    let keys_cf = self2
-                        .0
-                        .db
-                        .cf_handle("jmt_keys")
-                        .expect("jmt_keys column family not found");
-                    let iter = self2.0.snapshot.iterator_cf_opt(keys_cf, options, mode);
-                    for i in iter {
-                        // For each key that matches the prefix, fetch the value from the JMT column family.
-                        let (key_preimage, _key_hash) = i?;
+                    for key_preimage in self2.key_preimages(&range) {
+                        let key_preimage = key_preimage?;
                         let k = std::str::from_utf8(key_preimage.as_ref())
                             .expect("saved jmt keys are utf-8 strings")
                             .to_string();
+                        // Historical snapshots see keys absent at their version.
+                        if self2.0.historical
+                            && self2
+                                .get_jmt(jmt::KeyHash::with::<sha2::Sha256>(k.as_bytes()))?
+                                .is_none()
+                        {
+                            continue;
+                        }
                         tx.blocking_send(Ok(k))?;
                     }
                     Ok::<(), anyhow::Error>(())
@@ -285,22 +476,25 @@ impl StateRead for Snapshot {
         tokio_stream::wrappers::ReceiverStream::new(rx)
     }
 
-    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
+    fn nonverifiable_range_raw(&self, range: KeyRange) -> Self::NonconsensusPrefixRawStream {
         let span = Span::current();
         let self2 = self.clone();
 
-        let mut options = rocksdb::ReadOptions::default();
-        options.set_iterate_range(rocksdb::PrefixRange(prefix));
-        let mode = rocksdb::IteratorMode::Start;
+        let options = range.read_options();
+        let mode = range.iterator_mode();
 
         let (tx, rx) = mpsc::channel(10);
 
         // Here we're operating on the nonverifiable data, which is a raw k/v store,
         // so we just iterate over the keys.
         tokio::task::Builder::new()
-            .name("Snapshot::nonverifiable_prefix_raw")
+            .name("Snapshot::nonverifiable_range_raw")
             .spawn_blocking(move || {
                 span.in_scope(|| {
+                    if self2.0.historical {
+                        tx.blocking_send(Err(nonverifiable_unversioned(self2.0.version)))?;
+                        return Ok(());
+                    }
                     let keys_cf = self2
                         .0
                         .db
@@ -330,6 +524,14 @@ impl StateRead for Snapshot {
     }
 }
 
+/// The error for reads of the nonverifiable state from a historical snapshot,
+/// which would otherwise see the latest state rather than the state at `version`.
+fn nonverifiable_unversioned(version: jmt::Version) -> anyhow::Error {
+    anyhow::anyhow!(
+        "the nonverifiable state is not versioned, so it can't be read from the historical snapshot at version {version}"
+    )
+}
+
 /// A reader interface for rocksdb. NOTE: it is up to the caller to ensure consistency between the
 /// rocksdb::DB handle and any write batches that may be applied through the writer interface.
 impl TreeReader for Inner {
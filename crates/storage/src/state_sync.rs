@@ -0,0 +1,282 @@
+//! Export and restore of full [`Snapshot`]s, used to bootstrap new nodes via
+//! state sync rather than by replaying the chain from genesis.
+//!
+//! An exported snapshot is a sequence of chunks, each containing a batch of
+//! raw key-value pairs from the backing RocksDB column families, together
+//! with a [`SnapshotManifest`] that commits to the hash of every chunk and to
+//! the JMT root hash at the exported version.  Chunks are verified against
+//! the manifest as they are applied, and the restored root hash is checked
+//! against the manifest once the last chunk has been applied.
+
+use std::collections::BTreeSet;
+
+use anyhow::{Context, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use sha2::{Digest, Sha256};
+use tracing::Span;
+
+use crate::{Snapshot, Storage};
+
+/// The RocksDB column families included in an exported snapshot.
+///
+/// The index of each column family in this list is used as its identifier in
+/// the encoded chunks, so the list must only ever be appended to.
+pub(crate) const COLUMN_FAMILIES: [&str; 7] = [
+    "jmt",
+    "jmt_values",
+    "jmt_keys",
+    "jmt_keys_by_keyhash",
+    "nonverifiable",
+    "jmt_stale",
+    "jmt_deleted_keys",
+];
+
+/// A description of an exported [`Snapshot`], committing to its contents.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SnapshotManifest {
+    /// The JMT version of the exported snapshot.
+    pub version: jmt::Version,
+    /// The JMT root hash at `version`.
+    pub root_hash: [u8; 32],
+    /// The SHA256 hash of each chunk, in order.
+    pub chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl SnapshotManifest {
+    /// Encodes the manifest to bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        self.try_to_vec()
+            .expect("serializing a manifest to a vec is infallible")
+    }
+
+    /// Decodes a manifest previously produced by [`SnapshotManifest::encode`].
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        Self::try_from_slice(bytes).context("could not decode snapshot manifest")
+    }
+
+    /// Returns the SHA256 hash of the encoded manifest, which identifies the snapshot.
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.encode()).into()
+    }
+
+    /// Returns the number of chunks in the snapshot.
+    pub fn chunk_count(&self) -> u32 {
+        self.chunk_hashes.len() as u32
+    }
+}
+
+/// A [`Snapshot`] exported as a list of chunks, along with its manifest.
+#[derive(Clone, Debug)]
+pub struct ExportedSnapshot {
+    pub manifest: SnapshotManifest,
+    pub chunks: Vec<Vec<u8>>,
+}
+
+/// A single raw key-value pair in an exported chunk.
+#[derive(BorshSerialize, BorshDeserialize)]
+struct ChunkEntry {
+    column_family: u8,
+    key: Vec<u8>,
+    value: Vec<u8>,
+}
+
+/// Accumulates key-value pairs into chunks of approximately `chunk_size` bytes.
+pub(crate) struct Chunker {
+    chunk_size: usize,
+    current: Vec<ChunkEntry>,
+    current_size: usize,
+    chunks: Vec<Vec<u8>>,
+}
+
+impl Chunker {
+    pub(crate) fn new(chunk_size: usize) -> Self {
+        Self {
+            chunk_size: chunk_size.max(1),
+            current: Vec::new(),
+            current_size: 0,
+            chunks: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, column_family: u8, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        self.current_size += key.len() + value.len();
+        self.current.push(ChunkEntry {
+            column_family,
+            key,
+            value,
+        });
+        if self.current_size >= self.chunk_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let entries = std::mem::take(&mut self.current);
+        self.chunks.push(entries.try_to_vec()?);
+        self.current_size = 0;
+        Ok(())
+    }
+
+    pub(crate) fn finish(
+        mut self,
+        version: jmt::Version,
+        root_hash: crate::RootHash,
+    ) -> Result<ExportedSnapshot> {
+        if !self.current.is_empty() {
+            self.flush()?;
+        }
+        let chunk_hashes = self
+            .chunks
+            .iter()
+            .map(|chunk| Sha256::digest(chunk).into())
+            .collect::<Vec<[u8; 32]>>();
+
+        Ok(ExportedSnapshot {
+            manifest: SnapshotManifest {
+                version,
+                root_hash: root_hash.0,
+                chunk_hashes,
+            },
+            chunks: self.chunks,
+        })
+    }
+}
+
+impl Storage {
+    /// Begins restoring the snapshot described by `manifest` into this
+    /// `Storage`, which must be empty (i.e., at the pre-genesis version).
+    pub fn restore(&self, manifest: SnapshotManifest) -> Result<Restore> {
+        anyhow::ensure!(
+            self.latest_version() == u64::MAX,
+            "can only restore a snapshot into empty storage, but storage is at version {}",
+            self.latest_version()
+        );
+        anyhow::ensure!(
+            manifest.version != u64::MAX,
+            "cannot restore a pre-genesis snapshot"
+        );
+
+        Ok(Restore {
+            storage: self.clone(),
+            pending: (0..manifest.chunk_count()).collect(),
+            manifest,
+        })
+    }
+}
+
+/// An in-progress restoration of an exported snapshot into a [`Storage`].
+///
+/// Chunks may be applied in any order.  Once the last chunk has been applied,
+/// the [`Storage`] is reloaded at the restored version and its root hash is
+/// checked against the manifest.
+#[derive(Debug)]
+pub struct Restore {
+    storage: Storage,
+    manifest: SnapshotManifest,
+    /// Indices of the chunks that have not been applied yet.
+    pending: BTreeSet<u32>,
+}
+
+impl Restore {
+    /// Returns the manifest of the snapshot being restored.
+    pub fn manifest(&self) -> &SnapshotManifest {
+        &self.manifest
+    }
+
+    /// Returns `true` once every chunk has been applied and the restored state verified.
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Checks whether `chunk` matches the hash recorded in the manifest for chunk `index`.
+    pub fn chunk_is_valid(&self, index: u32, chunk: &[u8]) -> bool {
+        self.manifest
+            .chunk_hashes
+            .get(index as usize)
+            .map(|expected| {
+                let hash: [u8; 32] = Sha256::digest(chunk).into();
+                *expected == hash
+            })
+            .unwrap_or(false)
+    }
+
+    /// Verifies and writes the chunk at `index` to the backing store.
+    ///
+    /// If this was the last pending chunk, the storage is reloaded and its root
+    /// hash checked against the manifest; on a mismatch, all restored data is
+    /// discarded and an error is returned.
+    pub async fn apply_chunk(&mut self, index: u32, chunk: Vec<u8>) -> Result<()> {
+        anyhow::ensure!(
+            self.chunk_is_valid(index, &chunk),
+            "chunk {} does not match the snapshot manifest",
+            index
+        );
+        if !self.pending.contains(&index) {
+            tracing::debug!(index, "chunk was already applied, skipping");
+            return Ok(());
+        }
+
+        let span = Span::current();
+        let db = self.storage.db();
+        tokio::task::Builder::new()
+            .name("Restore::apply_chunk")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    let entries = Vec::<ChunkEntry>::try_from_slice(&chunk)?;
+                    let mut batch = rocksdb::WriteBatch::default();
+                    for entry in entries {
+                        let cf_name = COLUMN_FAMILIES
+                            .get(entry.column_family as usize)
+                            .ok_or_else(|| {
+                                anyhow::anyhow!("unknown column family {}", entry.column_family)
+                            })?;
+                        let cf = db
+                            .cf_handle(cf_name)
+                            .unwrap_or_else(|| panic!("{cf_name} column family not found"));
+                        batch.put_cf(cf, entry.key, entry.value);
+                    }
+                    db.write(batch)?;
+                    Ok::<(), anyhow::Error>(())
+                })
+            })?
+            .await??;
+
+        self.pending.remove(&index);
+        tracing::debug!(
+            index,
+            remaining = self.pending.len(),
+            "applied snapshot chunk"
+        );
+
+        if self.pending.is_empty() {
+            self.finish().await?;
+        }
+        Ok(())
+    }
+
+    async fn finish(&mut self) -> Result<()> {
+        self.storage.reload()?;
+        let snapshot: Snapshot = self.storage.latest_snapshot();
+        let root_hash = snapshot.root_hash().await?;
+
+        if snapshot.version() != self.manifest.version || root_hash.0 != self.manifest.root_hash {
+            tracing::warn!(
+                version = snapshot.version(),
+                expected_version = self.manifest.version,
+                root_hash = ?root_hash,
+                "restored state does not match snapshot manifest, discarding it"
+            );
+            std::mem::drop(snapshot);
+            self.storage.clear()?;
+            anyhow::bail!("restored state does not match the snapshot manifest");
+        }
+
+        tracing::info!(
+            version = snapshot.version(),
+            ?root_hash,
+            "restored snapshot"
+        );
+        Ok(())
+    }
+}
@@ -2,21 +2,31 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use std::{path::PathBuf, sync::Arc};
 
 use anyhow::Result;
+use futures::{Stream, StreamExt};
 use jmt::{
     storage::{LeafNode, Node, NodeBatch, NodeKey, TreeWriter},
     KeyHash, Sha256Jmt,
 };
 use parking_lot::RwLock;
-use rocksdb::{Options, DB};
-use tokio::sync::watch;
+use rocksdb::{Options, WriteBatch, DB};
+use tokio::sync::{broadcast, watch};
+use tokio_stream::wrappers::BroadcastStream;
 use tracing::Span;
 
-use crate::{cache::Cache, snapshot::Snapshot, EscapedByteSlice};
+use crate::{cache::Cache, snapshot::Snapshot, EscapedByteSlice, StateChanges};
 use crate::{snapshot_cache::SnapshotCache, StateDelta};
 
+mod pruning;
 mod temp;
+pub use pruning::RetentionPolicy;
 pub use temp::TempStorage;
 
+/// The number of recent [`Snapshot`]s kept in memory.
+const SNAPSHOT_CACHE_SIZE: usize = 10;
+
+/// The number of commits buffered for each subscriber to the change feed.
+const CHANGE_FEED_CAPACITY: usize = 16;
+
 /// A handle for a storage instance, backed by RocksDB.
 ///
 /// The handle is cheaply clonable; all clones share the same backing data store.
@@ -35,6 +45,8 @@ struct Inner {
     snapshots: RwLock<SnapshotCache>,
     db: Arc<DB>,
     state_tx: watch::Sender<Snapshot>,
+    changes_tx: broadcast::Sender<Arc<StateChanges>>,
+    pruning: pruning::PruningState,
 }
 
 impl Storage {
@@ -64,6 +76,14 @@ impl Storage {
                        --> jmt_keys_by_keyhash: index JMT keys by their hash.
 
                        --> jmt_values: maps KeyHash || BE(version) to an `Option<Vec<u8>>`
+
+                       --> jmt_stale: index of the JMT nodes and values that became stale at
+                                      each version, keyed by BE(stale_since_version), used
+                                      for pruning historical versions.
+
+                       --> jmt_deleted_keys: maps the JMT keys (i.e. keyhash preimages) deleted
+                                             from the tree to BE(version) of their deletion, so
+                                             that historical versions can still range over them.
                     */
 
                     let db = Arc::new(DB::open_cf(
@@ -75,6 +95,8 @@ impl Storage {
                             "jmt_keys",
                             "jmt_keys_by_keyhash",
                             "jmt_values",
+                            "jmt_stale",
+                            "jmt_deleted_keys",
                         ],
                     )?);
 
@@ -86,13 +108,17 @@ impl Storage {
 
                     // We discard the receiver here, because we'll construct new ones in subscribe()
                     let (snapshot_tx, _) = watch::channel(latest_snapshot.clone());
+                    let (changes_tx, _) = broadcast::channel(CHANGE_FEED_CAPACITY);
 
-                    let snapshots = RwLock::new(SnapshotCache::new(latest_snapshot, 10));
+                    let snapshots =
+                        RwLock::new(SnapshotCache::new(latest_snapshot, SNAPSHOT_CACHE_SIZE));
 
                     Ok(Self(Arc::new(Inner {
                         snapshots,
                         db,
                         state_tx: snapshot_tx,
+                        changes_tx,
+                        pruning: Default::default(),
                     })))
                 })
             })?
@@ -115,15 +141,51 @@ impl Storage {
         self.0.state_tx.subscribe()
     }
 
+    /// Returns a stream of the [`StateChanges`] made by each subsequent commit.
+    ///
+    /// Only a limited number of commits are buffered for each subscriber; if a
+    /// subscriber falls further behind than that, the stream yields an error
+    /// and resumes from the oldest buffered commit.  Writes made directly to
+    /// the backing store, e.g., when restoring a snapshot, are not reported.
+    pub fn subscribe_changes(
+        &self,
+    ) -> impl Stream<Item = Result<Arc<StateChanges>>> + Send + 'static {
+        BroadcastStream::new(self.0.changes_tx.subscribe())
+            .map(|changes| changes.map_err(|e| anyhow::anyhow!("state change feed: {e}")))
+    }
+
     /// Returns a new [`State`] on top of the latest version of the tree.
     pub fn latest_snapshot(&self) -> Snapshot {
         self.0.snapshots.read().latest()
     }
 
-    /// Fetches the [`State`] snapshot corresponding to the supplied `jmt::Version`
-    /// from [`SnapshotCache`], or returns `None` if no match was found (cache-miss).
+    /// Fetches the [`State`] snapshot corresponding to the supplied `jmt::Version`.
+    ///
+    /// Recent snapshots are served from the [`SnapshotCache`]; older versions are
+    /// reconstructed from the backing store, provided they haven't been pruned.
+    /// Returns `None` if the version is newer than the latest version, or is no
+    /// longer retained.
+    ///
+    /// Reconstructed snapshots read the verifiable state as of `version`, but
+    /// reads of their nonverifiable state fail, since only the verifiable state
+    /// is versioned.
     pub fn snapshot(&self, version: jmt::Version) -> Option<Snapshot> {
-        self.0.snapshots.read().get(version)
+        if let Some(snapshot) = self.0.snapshots.read().get(version) {
+            return Some(snapshot);
+        }
+
+        let latest_version = self.latest_version();
+        if latest_version == u64::MAX || version > latest_version {
+            return None;
+        }
+
+        match Snapshot::historical(self.0.db.clone(), version) {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                tracing::warn!(?error, version, "failed to reconstruct historical snapshot");
+                None
+            }
+        }
     }
 
     async fn commit_inner(
@@ -141,6 +203,14 @@ impl Storage {
                     let snap = inner.snapshots.read().latest();
                     let jmt = Sha256Jmt::new(&*snap.0);
 
+                    // Only copy the changes if someone is following the change feed.
+                    let feed = (inner.changes_tx.receiver_count() > 0).then(|| {
+                        (
+                            cache.unwritten_changes.clone(),
+                            cache.nonverifiable_changes.clone(),
+                        )
+                    });
+
                     let unwritten_changes: Vec<_> = cache
                         .unwritten_changes
                         .into_iter()
@@ -162,6 +232,12 @@ impl Storage {
                         .cf_handle("jmt_keys_by_keyhash")
                         .expect("jmt_keys_by_keyhash family not found");
 
+                    let jmt_deleted_keys_cf = inner
+                        .db
+                        .cf_handle("jmt_deleted_keys")
+                        .expect("jmt_deleted_keys column family not found");
+
+                    let mut deleted_keys = Vec::new();
                     for (keyhash, key_preimage, v) in unwritten_changes.iter() {
                         match v {
                             // Key still exists so update the key preimage and keyhash index.
@@ -171,10 +247,17 @@ impl Storage {
                                     .db
                                     .put_cf(jmt_keys_by_keyhash_cf, keyhash.0, key_preimage)?
                             }
-                            // Key was deleted, so delete the key preimage, and its keyhash index.
+                            // Key was deleted, so delete the key preimage, and its keyhash index,
+                            // but remember the preimage for range queries on earlier versions.
                             None => {
                                 inner.db.delete_cf(jmt_keys_cf, key_preimage)?;
                                 inner.db.delete_cf(jmt_keys_by_keyhash_cf, &keyhash.0)?;
+                                inner.db.put_cf(
+                                    jmt_deleted_keys_cf,
+                                    key_preimage,
+                                    new_version.to_be_bytes(),
+                                )?;
+                                deleted_keys.push((*keyhash, key_preimage.clone()));
                             }
                         };
                     }
@@ -186,7 +269,26 @@ impl Storage {
                     )?;
 
                     // Apply the JMT changes to the DB.
-                    inner.write_node_batch(&batch.node_batch)?;
+                    let mut write_batch = WriteBatch::default();
+                    inner.stage_node_batch(&batch.node_batch, &mut write_batch)?;
+
+                    // If pruning is enabled, record which nodes and values became stale at this
+                    // version, in the same write as the nodes themselves, so that they can be
+                    // pruned once no retained version refers to them.
+                    if inner.pruning.is_enabled() {
+                        inner.stage_stale_index(
+                            &batch.node_batch,
+                            batch
+                                .stale_node_index_batch
+                                .iter()
+                                .map(|index| (index.stale_since_version, &index.node_key)),
+                            deleted_keys.iter().map(|(keyhash, key)| (*keyhash, key.as_str())),
+                            new_version,
+                            &mut write_batch,
+                        )?;
+                    }
+
+                    inner.db.write(write_batch)?;
                     tracing::trace!(?root_hash, "wrote node batch to backing store");
 
                     // Write the unwritten changes from the nonverifiable to RocksDB.
@@ -220,6 +322,17 @@ impl Storage {
                     // in this case, we should ignore the error, we have no one to notify.
                     let _ = inner.state_tx.send(latest_snapshot);
 
+                    if let Some((verifiable, nonverifiable)) = feed {
+                        // As above, send fails only if there are no receivers.
+                        let _ = inner.changes_tx.send(Arc::new(StateChanges {
+                            version: new_version,
+                            root_hash,
+                            verifiable,
+                            nonverifiable,
+                            events: cache.events,
+                        }));
+                    }
+
                     Ok(root_hash)
                 })
             })?
@@ -244,8 +357,43 @@ impl Storage {
         self.commit_inner(changes, new_version).await
     }
 
-    /// Returns the internal handle to RocksDB, this is useful to test adjacent storage crates.
-    #[cfg(test)]
+    /// Reloads the latest version of the tree from the backing store, discarding
+    /// all cached snapshots.
+    ///
+    /// This is used after the backing store has been written to directly, e.g.,
+    /// when restoring an exported snapshot.
+    pub(crate) fn reload(&self) -> Result<()> {
+        let jmt_version = latest_version(self.0.db.as_ref())?.unwrap_or(u64::MAX);
+        let latest_snapshot = Snapshot::new(self.0.db.clone(), jmt_version);
+
+        *self.0.snapshots.write() =
+            SnapshotCache::new(latest_snapshot.clone(), SNAPSHOT_CACHE_SIZE);
+        // Send fails if the channel is closed (i.e., if there are no receivers);
+        // in this case, we should ignore the error, we have no one to notify.
+        let _ = self.0.state_tx.send(latest_snapshot);
+
+        Ok(())
+    }
+
+    /// Deletes all data from the backing store, and reloads at the pre-genesis version.
+    pub(crate) fn clear(&self) -> Result<()> {
+        for cf_name in crate::state_sync::COLUMN_FAMILIES {
+            let cf = self
+                .0
+                .db
+                .cf_handle(cf_name)
+                .unwrap_or_else(|| panic!("{cf_name} column family not found"));
+            let mut batch = rocksdb::WriteBatch::default();
+            for tuple in self.0.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+                let (key, _) = tuple?;
+                batch.delete_cf(cf, key);
+            }
+            self.0.db.write(batch)?;
+        }
+        self.reload()
+    }
+
+    /// Returns the internal handle to RocksDB.
     pub(crate) fn db(&self) -> Arc<DB> {
         self.0.db.clone()
     }
@@ -256,7 +404,17 @@ impl TreeWriter for Inner {
     /// nodes (`DbNodeKey` -> `Node`) and the JMT values,
     /// (`VersionedKeyHash` -> `Option<Vec<u8>>`).
     fn write_node_batch(&self, node_batch: &NodeBatch) -> Result<()> {
-        let node_batch = node_batch.clone();
+        let mut write_batch = WriteBatch::default();
+        self.stage_node_batch(node_batch, &mut write_batch)?;
+        self.db.write(write_batch)?;
+        Ok(())
+    }
+}
+
+impl Inner {
+    /// Adds the writes of a [`NodeBatch`] to `write_batch`, so that they can be applied
+    /// atomically along with other changes.
+    fn stage_node_batch(&self, node_batch: &NodeBatch, write_batch: &mut WriteBatch) -> Result<()> {
         let jmt_cf = self
             .db
             .cf_handle("jmt")
@@ -267,7 +425,7 @@ impl TreeWriter for Inner {
             let db_node_key_bytes = db_node_key.encode()?;
             let value_bytes = &node.try_to_vec()?;
             tracing::trace!(?db_node_key_bytes, value_bytes = ?hex::encode(value_bytes));
-            self.db.put_cf(jmt_cf, db_node_key_bytes, value_bytes)?;
+            write_batch.put_cf(jmt_cf, db_node_key_bytes, value_bytes);
         }
         let jmt_values_cf = self
             .db
@@ -280,7 +438,7 @@ impl TreeWriter for Inner {
             let value_bytes = &some_value.try_to_vec()?;
             tracing::trace!(?key_bytes, value_bytes = ?hex::encode(value_bytes));
 
-            self.db.put_cf(jmt_values_cf, key_bytes, value_bytes)?;
+            write_batch.put_cf(jmt_values_cf, key_bytes, value_bytes);
         }
 
         Ok(())
@@ -331,7 +489,7 @@ impl VersionedKeyHash {
         buf
     }
 
-    pub fn _decode(buf: Vec<u8>) -> Result<Self> {
+    pub fn decode(buf: Vec<u8>) -> Result<Self> {
         if buf.len() != 40 {
             Err(anyhow::anyhow!(
                 "could not decode buffer into VersionedKey (invalid size)"
@@ -0,0 +1,527 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::Result;
+use jmt::{
+    storage::{NodeBatch, NodeKey},
+    KeyHash,
+};
+use parking_lot::Mutex;
+use rocksdb::{IteratorMode, ReadOptions, WriteBatch};
+use tracing::Span;
+
+use super::{DbNodeKey, Inner, Storage, VersionedKeyHash};
+use crate::metrics;
+
+/// The maximum number of deletions to accumulate before writing them out.
+const PRUNE_BATCH_SIZE: usize = 10_000;
+
+/// A policy determining which historical versions of the verifiable state
+/// are retained when pruning.
+///
+/// The latest version is always retained.  A version is retained if it is
+/// one of the `keep_recent` most recent versions, or if it is a multiple of
+/// `keep_every`.  Retained versions can still be read (and proven against)
+/// after pruning; all other historical versions are discarded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// The number of most recent versions to retain.
+    pub keep_recent: u64,
+    /// If set, retain every version that is a multiple of this number.
+    pub keep_every: Option<u64>,
+}
+
+impl RetentionPolicy {
+    /// Retain only the `n` most recent versions.
+    pub fn keep_recent(n: u64) -> Self {
+        Self {
+            keep_recent: n.max(1),
+            keep_every: None,
+        }
+    }
+
+    /// Retain the latest version, and every version that is a multiple of `k`.
+    pub fn keep_every(k: u64) -> Self {
+        Self {
+            keep_recent: 1,
+            keep_every: Some(k.max(1)),
+        }
+    }
+
+    /// Returns `true` if `version` is retained when the latest version is `latest`.
+    pub fn retains(&self, version: jmt::Version, latest: jmt::Version) -> bool {
+        version <= latest
+            && (self.retains_recent(version, latest) || self.retains_periodic(version, version))
+    }
+
+    /// Returns `true` if any version at or after `last` is within the recent window.
+    fn retains_recent(&self, last: jmt::Version, latest: jmt::Version) -> bool {
+        last >= latest.saturating_sub(self.keep_recent.max(1) - 1)
+    }
+
+    /// Returns `true` if any version in `first..=last` is a multiple of `keep_every`.
+    fn retains_periodic(&self, first: jmt::Version, last: jmt::Version) -> bool {
+        match self.keep_every {
+            Some(k) => (last / k.max(1)) * k.max(1) >= first,
+            None => false,
+        }
+    }
+}
+
+/// The pruning state of a [`Storage`] instance.
+#[derive(Debug, Default)]
+pub(super) struct PruningState {
+    /// Whether the stale index is being recorded, which is only done once pruning is enabled.
+    enabled: AtomicBool,
+    /// The `stale_since` version of the first stale index entry not yet processed by pruning.
+    ///
+    /// All earlier entries have been deleted, so this lets pruning skip over their tombstones,
+    /// rather than scanning the column family from the start every time.
+    cursor: Mutex<jmt::Version>,
+}
+
+impl PruningState {
+    pub(super) fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Acquire)
+    }
+}
+
+/// An entry in the `jmt_stale` column family, recording that a JMT node or
+/// value is no longer part of the tree as of `stale_since`.
+///
+/// Entries are keyed by the big-endian `stale_since` version first, so that
+/// iterating the column family visits the oldest stale entries first.
+#[derive(Clone, Debug)]
+struct StaleIndexKey {
+    stale_since: jmt::Version,
+    entry: StaleEntry,
+}
+
+#[derive(Clone, Debug)]
+enum StaleEntry {
+    Node(NodeKey),
+    Value(VersionedKeyHash),
+    /// A key deleted from the tree, whose entry in the `jmt_deleted_keys`
+    /// index is only needed while a version where it was present is retained.
+    DeletedKey {
+        /// The version at which the key's last value was written.
+        version: jmt::Version,
+        key: String,
+    },
+}
+
+impl StaleEntry {
+    /// The version at which the node or value was first written.
+    fn version(&self) -> jmt::Version {
+        match self {
+            StaleEntry::Node(node_key) => node_key.version(),
+            StaleEntry::Value(versioned_key_hash) => versioned_key_hash.version,
+            StaleEntry::DeletedKey { version, .. } => *version,
+        }
+    }
+}
+
+impl StaleIndexKey {
+    fn encode(&self) -> Result<Vec<u8>> {
+        let mut bytes = self.stale_since.to_be_bytes().to_vec();
+        match &self.entry {
+            StaleEntry::Node(node_key) => {
+                bytes.push(0);
+                bytes.extend(DbNodeKey::from(node_key.clone()).encode()?);
+            }
+            StaleEntry::Value(versioned_key_hash) => {
+                bytes.push(1);
+                bytes.extend(versioned_key_hash.encode());
+            }
+            StaleEntry::DeletedKey { version, key } => {
+                bytes.push(2);
+                bytes.extend(version.to_be_bytes());
+                bytes.extend(key.as_bytes());
+            }
+        }
+        Ok(bytes)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        anyhow::ensure!(bytes.len() > 9, "stale index key is too short");
+        let stale_since = u64::from_be_bytes(bytes[0..8].try_into().expect("8 bytes"));
+        let entry = match bytes[8] {
+            0 => StaleEntry::Node(DbNodeKey::decode(&bytes[9..])?.into_inner()),
+            1 => StaleEntry::Value(VersionedKeyHash::decode(bytes[9..].to_vec())?),
+            2 => {
+                anyhow::ensure!(bytes.len() >= 17, "stale index key is too short");
+                StaleEntry::DeletedKey {
+                    version: u64::from_be_bytes(bytes[9..17].try_into().expect("8 bytes")),
+                    key: String::from_utf8(bytes[17..].to_vec())?,
+                }
+            }
+            tag => anyhow::bail!("unknown stale index entry tag {}", tag),
+        };
+        Ok(Self { stale_since, entry })
+    }
+}
+
+impl Inner {
+    /// Adds the records of the nodes and values made stale by a new version of
+    /// the tree to `batch`, so that they're written atomically with the new
+    /// version itself.
+    ///
+    /// JMT reports the stale nodes itself, but stale values must be found by
+    /// looking up the previous version of each value written in `node_batch`.
+    /// The `deleted_keys` are those deleted from the tree at `version`.
+    pub(super) fn stage_stale_index<'a>(
+        &self,
+        node_batch: &NodeBatch,
+        stale_nodes: impl Iterator<Item = (jmt::Version, &'a NodeKey)>,
+        deleted_keys: impl Iterator<Item = (KeyHash, &'a str)>,
+        version: jmt::Version,
+        batch: &mut WriteBatch,
+    ) -> Result<()> {
+        let jmt_stale_cf = self
+            .db
+            .cf_handle("jmt_stale")
+            .expect("jmt_stale column family not found");
+
+        for (stale_since, node_key) in stale_nodes {
+            let key = StaleIndexKey {
+                stale_since,
+                entry: StaleEntry::Node(node_key.clone()),
+            };
+            batch.put_cf(jmt_stale_cf, key.encode()?, b"");
+        }
+
+        for ((version, key_hash), _) in node_batch.values() {
+            if let Some(previous) = self.previous_value_version(*key_hash, *version)? {
+                let key = StaleIndexKey {
+                    stale_since: *version,
+                    entry: StaleEntry::Value(VersionedKeyHash::new(previous, *key_hash)),
+                };
+                batch.put_cf(jmt_stale_cf, key.encode()?, b"");
+            }
+        }
+
+        for (key_hash, deleted_key) in deleted_keys {
+            let key = StaleIndexKey {
+                stale_since: version,
+                entry: StaleEntry::DeletedKey {
+                    version: self
+                        .previous_value_version(key_hash, version)?
+                        .unwrap_or(version),
+                    key: deleted_key.to_owned(),
+                },
+            };
+            batch.put_cf(jmt_stale_cf, key.encode()?, b"");
+        }
+
+        Ok(())
+    }
+
+    /// Returns the most recent version strictly before `version` at which a
+    /// value was written for `key_hash`, if any.
+    fn previous_value_version(
+        &self,
+        key_hash: KeyHash,
+        version: jmt::Version,
+    ) -> Result<Option<jmt::Version>> {
+        let jmt_values_cf = self
+            .db
+            .cf_handle("jmt_values")
+            .expect("jmt_values column family not found");
+
+        let mut readopts = ReadOptions::default();
+        readopts.set_iterate_lower_bound(VersionedKeyHash::new(0, key_hash).encode());
+        // The upper bound is excluded from the iteration results.
+        readopts.set_iterate_upper_bound(VersionedKeyHash::new(version, key_hash).encode());
+        let mut iterator = self
+            .db
+            .iterator_cf_opt(jmt_values_cf, readopts, IteratorMode::End);
+
+        let Some(tuple) = iterator.next() else {
+            return Ok(None);
+        };
+        let (key, _value) = tuple?;
+        Ok(Some(VersionedKeyHash::decode(key.to_vec())?.version))
+    }
+
+    /// Deletes all stale nodes and values not referenced by any version
+    /// retained under `policy`, returning the number of deleted entries.
+    fn prune(&self, policy: RetentionPolicy) -> Result<usize> {
+        let latest = self.snapshots.read().latest().version();
+        if latest == u64::MAX {
+            // Nothing has been committed yet, so there's nothing to prune.
+            return Ok(0);
+        }
+
+        let jmt_cf = self
+            .db
+            .cf_handle("jmt")
+            .expect("jmt column family not found");
+        let jmt_values_cf = self
+            .db
+            .cf_handle("jmt_values")
+            .expect("jmt_values column family not found");
+        let jmt_stale_cf = self
+            .db
+            .cf_handle("jmt_stale")
+            .expect("jmt_stale column family not found");
+        let jmt_deleted_keys_cf = self
+            .db
+            .cf_handle("jmt_deleted_keys")
+            .expect("jmt_deleted_keys column family not found");
+
+        // Holding the cursor also keeps concurrent calls from pruning the same entries.
+        let mut cursor = self.pruning.cursor.lock();
+
+        // Entries are ordered by the version they became stale at, so the ones
+        // which may still be needed by a version in the recent window are all
+        // at or after the first version at which an entry would be retained.
+        let end = latest
+            .saturating_add(2)
+            .saturating_sub(policy.keep_recent.max(1))
+            .max(*cursor);
+
+        let mut readopts = ReadOptions::default();
+        readopts.set_iterate_lower_bound(cursor.to_be_bytes().to_vec());
+        // The upper bound is excluded from the iteration results.
+        readopts.set_iterate_upper_bound(end.to_be_bytes().to_vec());
+
+        let mut batch = WriteBatch::default();
+        let mut pruned = 0;
+        for tuple in self
+            .db
+            .iterator_cf_opt(jmt_stale_cf, readopts, IteratorMode::Start)
+        {
+            let (key, _) = tuple?;
+            let index = StaleIndexKey::decode(&key)?;
+
+            // The node or value was part of the tree for versions `first..=last`.
+            let first = index.entry.version();
+            let last = index.stale_since.saturating_sub(1);
+            debug_assert!(!policy.retains_recent(last, latest));
+
+            // Otherwise, the entry is either needed by some periodically
+            // retained version forever, or it can be deleted right now.  Either
+            // way, we no longer need to track it.
+            if !policy.retains_periodic(first, last) {
+                match &index.entry {
+                    StaleEntry::Node(node_key) => {
+                        batch.delete_cf(jmt_cf, DbNodeKey::from(node_key.clone()).encode()?);
+                        pruned += 1;
+                    }
+                    StaleEntry::Value(versioned_key_hash) => {
+                        batch.delete_cf(jmt_values_cf, versioned_key_hash.encode());
+                        pruned += 1;
+                    }
+                    // Only forget the key if it wasn't deleted again since.
+                    StaleEntry::DeletedKey { key, .. } => {
+                        let deleted_at = self.db.get_cf(jmt_deleted_keys_cf, key)?;
+                        if deleted_at.as_deref() == Some(&index.stale_since.to_be_bytes()[..]) {
+                            batch.delete_cf(jmt_deleted_keys_cf, key);
+                        }
+                    }
+                }
+            }
+            batch.delete_cf(jmt_stale_cf, key);
+
+            if batch.len() >= PRUNE_BATCH_SIZE {
+                self.db.write(std::mem::take(&mut batch))?;
+            }
+        }
+        self.db.write(batch)?;
+        *cursor = end;
+
+        Ok(pruned)
+    }
+}
+
+impl Storage {
+    /// Starts recording the nodes and values made stale by each commit, so
+    /// that they can be pruned later.
+    ///
+    /// Recording the stale index has a cost on every commit, so it's only
+    /// done once pruning is enabled.
+    pub fn enable_pruning(&self) {
+        self.0.pruning.enabled.store(true, Ordering::Release);
+    }
+
+    /// Prunes historical versions of the verifiable state according to
+    /// `policy`, returning the number of deleted JMT nodes and values.
+    ///
+    /// Only nodes and values that became stale after pruning was enabled with
+    /// [`Storage::enable_pruning`] are pruned.  Existing [`Snapshot`](crate::Snapshot)s
+    /// remain readable after pruning, since they pin the data they refer to.
+    pub async fn prune(&self, policy: RetentionPolicy) -> Result<usize> {
+        let span = Span::current();
+        let inner = self.0.clone();
+
+        tokio::task::Builder::new()
+            .name("Storage::prune")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    let pruned = inner.prune(policy)?;
+                    metrics::counter!(metrics::STORAGE_PRUNED_ENTRIES_TOTAL, pruned as u64);
+                    tracing::debug!(pruned, "pruned stale versions");
+                    Ok(pruned)
+                })
+            })?
+            .await?
+    }
+
+    /// Enables pruning, and spawns a background task that prunes historical
+    /// versions according to `policy` after every new version is committed.
+    pub fn spawn_pruning(&self, policy: RetentionPolicy) -> tokio::task::JoinHandle<()> {
+        self.enable_pruning();
+
+        let storage = self.clone();
+        let mut snapshot_rx = self.subscribe();
+
+        tokio::task::Builder::new()
+            .name("Storage::pruning")
+            .spawn(async move {
+                tracing::info!(?policy, "starting state pruning");
+                while snapshot_rx.changed().await.is_ok() {
+                    if let Err(error) = storage.prune(policy).await {
+                        tracing::error!(?error, "failed to prune state");
+                    }
+                }
+            })
+            .expect("should be able to spawn tasks")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Snapshot, StateDelta, StateRead, StateWrite, TempStorage};
+
+    #[test]
+    fn retention_policy() {
+        let policy = RetentionPolicy {
+            keep_recent: 3,
+            keep_every: Some(10),
+        };
+        let retained = (0..=25)
+            .filter(|v| policy.retains(*v, 25))
+            .collect::<Vec<_>>();
+        assert_eq!(retained, vec![0, 10, 20, 23, 24, 25]);
+
+        let policy = RetentionPolicy::keep_recent(1);
+        assert!(policy.retains(7, 7));
+        assert!(!policy.retains(6, 7));
+    }
+
+    #[tokio::test]
+    /// Retained versions can still be proven against after pruning, and pruned ones can't.
+    async fn prune_keeps_retained_versions() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        storage.enable_pruning();
+        let mut root_hashes = Vec::new();
+
+        for version in 0u64..20 {
+            let mut delta = StateDelta::new(storage.latest_snapshot());
+            delta.put_raw("counter".to_owned(), version.to_be_bytes().to_vec());
+            delta.put_raw(
+                format!("key/{}", version % 4),
+                version.to_be_bytes().to_vec(),
+            );
+            root_hashes.push(storage.commit(delta).await?);
+        }
+
+        let policy = RetentionPolicy {
+            keep_recent: 3,
+            keep_every: Some(5),
+        };
+        let pruned = storage.prune(policy).await?;
+        assert!(pruned > 0);
+
+        for version in 0u64..20 {
+            // Use a fresh snapshot, since cached snapshots pin pruned data.
+            let snapshot = Snapshot::new(storage.db(), version);
+            let result = snapshot.get_with_proof(b"counter".to_vec()).await;
+
+            if policy.retains(version, 19) {
+                let (value, proof) = result?;
+                let value = value.expect("counter is present at every version");
+                assert_eq!(value, version.to_be_bytes().to_vec());
+                assert!(ics23::verify_membership::<ics23::HostFunctionsManager>(
+                    &proof,
+                    &jmt::ics23_spec(),
+                    &root_hashes[version as usize].0.to_vec(),
+                    b"counter",
+                    &value,
+                ));
+            } else {
+                assert!(result.is_err(), "version {version} should have been pruned");
+            }
+        }
+
+        // Pruning again is a no-op, since the stale index has been consumed.
+        assert_eq!(storage.prune(policy).await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// The stale index is only recorded once pruning is enabled.
+    async fn stale_index_requires_pruning_enabled() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let policy = RetentionPolicy::keep_recent(1);
+
+        for version in 0u64..5 {
+            let mut delta = StateDelta::new(storage.latest_snapshot());
+            delta.put_raw("counter".to_owned(), version.to_be_bytes().to_vec());
+            storage.commit(delta).await?;
+        }
+        assert_eq!(storage.prune(policy).await?, 0);
+
+        storage.enable_pruning();
+        for version in 5u64..10 {
+            let mut delta = StateDelta::new(storage.latest_snapshot());
+            delta.put_raw("counter".to_owned(), version.to_be_bytes().to_vec());
+            storage.commit(delta).await?;
+        }
+        assert!(storage.prune(policy).await? > 0);
+
+        // Versions which became stale before pruning was enabled are kept.
+        let snapshot = Snapshot::new(storage.db(), 2);
+        assert!(snapshot.get_with_proof(b"counter".to_vec()).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// Deleted keys are forgotten once no retained version contains them.
+    async fn prune_forgets_deleted_keys() -> anyhow::Result<()> {
+        use futures::TryStreamExt;
+
+        let storage = TempStorage::new().await?;
+        storage.enable_pruning();
+
+        for version in 0u64..30 {
+            let mut delta = StateDelta::new(storage.latest_snapshot());
+            delta.put_raw(format!("a/{version:02}"), version.to_be_bytes().to_vec());
+            // Each key lives for 3 versions.
+            if version >= 3 {
+                delta.delete(format!("a/{:02}", version - 3));
+            }
+            storage.commit(delta).await?;
+        }
+        storage.prune(RetentionPolicy::keep_recent(5)).await?;
+
+        let db = storage.db();
+        let jmt_deleted_keys_cf = db
+            .cf_handle("jmt_deleted_keys")
+            .expect("jmt_deleted_keys column family not found");
+        let deleted_keys = db
+            .iterator_cf(jmt_deleted_keys_cf, IteratorMode::Start)
+            .map(|tuple| -> anyhow::Result<String> { Ok(String::from_utf8(tuple?.0.to_vec())?) })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        assert_eq!(deleted_keys, vec!["a/23", "a/24", "a/25", "a/26"]);
+
+        // The oldest retained version can still range over the keys it contains.
+        let snapshot = Snapshot::historical(storage.db(), 25)?.expect("version 25 is retained");
+        let keys = snapshot.prefix_keys("a/").try_collect::<Vec<_>>().await?;
+        assert_eq!(keys, vec!["a/23", "a/24", "a/25"]);
+
+        Ok(())
+    }
+}
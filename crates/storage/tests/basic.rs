@@ -662,3 +662,40 @@ async fn simple_flow() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Checks that each commit reports the keys it wrote and the events it recorded
+#[tokio::test]
+async fn change_feed() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut changes = storage.subscribe_changes();
+
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_raw("a".to_owned(), b"a".to_vec());
+    state.put_raw("b".to_owned(), b"b".to_vec());
+    state.nonverifiable_put_raw(b"i".to_vec(), b"i".to_vec());
+    state.record(tendermint::abci::Event::new("test", [("key", "value")]));
+    let root_hash = storage.commit(state).await?;
+
+    let first = changes.next().await.expect("stream is open")?;
+    assert_eq!(first.version, 0);
+    assert_eq!(first.root_hash, root_hash);
+    assert_eq!(first.verifiable.len(), 2);
+    assert_eq!(first.verifiable["a"], Some(b"a".to_vec()));
+    assert_eq!(first.nonverifiable[&b"i".to_vec()], Some(b"i".to_vec()));
+    assert_eq!(first.events.len(), 1);
+    assert_eq!(first.events[0].kind, "test");
+
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.delete("a".to_owned());
+    state.nonverifiable_delete(b"i".to_vec());
+    storage.commit(state).await?;
+
+    let second = changes.next().await.expect("stream is open")?;
+    assert_eq!(second.version, 1);
+    assert_eq!(second.verifiable.len(), 1);
+    assert_eq!(second.verifiable["a"], None);
+    assert_eq!(second.nonverifiable[&b"i".to_vec()], None);
+    assert!(second.events.is_empty());
+
+    Ok(())
+}
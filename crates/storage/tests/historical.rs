@@ -0,0 +1,127 @@
+use futures::TryStreamExt;
+use penumbra_storage::*;
+
+#[tokio::test]
+/// Versions older than the snapshot cache can still be read, until they're pruned.
+async fn historical_snapshots() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    assert!(storage.snapshot(0).is_none());
+
+    for version in 0u64..30 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("counter".to_owned(), version.to_be_bytes().to_vec());
+        delta.put_raw(format!("a/{version}"), version.to_be_bytes().to_vec());
+        delta.nonverifiable_put_raw(b"counter".to_vec(), version.to_be_bytes().to_vec());
+        storage.commit(delta).await?;
+    }
+
+    // Version 2 has long since been evicted from the snapshot cache.
+    let snapshot = storage.snapshot(2).expect("version 2 is retained");
+    assert_eq!(snapshot.version(), 2);
+    assert_eq!(
+        snapshot.get_raw("counter").await?,
+        Some(2u64.to_be_bytes().to_vec())
+    );
+    assert_eq!(snapshot.get_raw("a/5").await?, None);
+
+    // Prefix queries only see the keys present at that version.
+    let keys = snapshot.prefix_keys("a/").try_collect::<Vec<_>>().await?;
+    assert_eq!(keys.len(), 3);
+    let values = snapshot.prefix_raw("a/").try_collect::<Vec<_>>().await?;
+    assert_eq!(values.len(), 3);
+
+    // Reconstructed snapshots prove against the root hash of their version.
+    let (value, proof) = snapshot.get_with_proof(b"counter".to_vec()).await?;
+    assert!(ics23::verify_membership::<ics23::HostFunctionsManager>(
+        &proof,
+        &jmt::ics23_spec(),
+        &snapshot.root_hash().await?.0.to_vec(),
+        b"counter",
+        &value.expect("counter is present"),
+    ));
+
+    // The nonverifiable state isn't versioned, so it can't be read at an old version.
+    assert!(snapshot.nonverifiable_get_raw(b"counter").await.is_err());
+    let values = snapshot
+        .nonverifiable_prefix_raw(b"")
+        .try_collect::<Vec<_>>()
+        .await;
+    assert!(values.is_err());
+
+    // Future versions don't exist yet.
+    assert!(storage.snapshot(30).is_none());
+
+    // Pruned versions can no longer be reconstructed.
+    storage.prune(RetentionPolicy::keep_recent(5)).await?;
+    assert!(storage.snapshot(2).is_none());
+    assert!(storage.snapshot(25).is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Range queries on historical snapshots find keys deleted after their version.
+async fn historical_snapshots_see_deleted_keys() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+
+    for version in 0u64..30 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw(format!("a/{version}"), version.to_be_bytes().to_vec());
+        // Each key lives for 3 versions.
+        if version >= 3 {
+            delta.delete(format!("a/{}", version - 3));
+        }
+        // Delete and rewrite a key, so that it's in both key indexes.
+        match version {
+            10 => delta.put_raw("b/rewritten".to_owned(), b"old".to_vec()),
+            12 => delta.delete("b/rewritten".to_owned()),
+            14 => delta.put_raw("b/rewritten".to_owned(), b"new".to_vec()),
+            _ => {}
+        }
+        storage.commit(delta).await?;
+    }
+
+    let snapshot = storage.snapshot(5).expect("version 5 is retained");
+    assert_eq!(snapshot.get_raw("a/2").await?, None);
+    let keys = snapshot.prefix_keys("a/").try_collect::<Vec<_>>().await?;
+    assert_eq!(keys, vec!["a/3", "a/4", "a/5"]);
+    let values = snapshot.prefix_raw("a/").try_collect::<Vec<_>>().await?;
+    assert_eq!(
+        values,
+        vec![
+            ("a/3".to_owned(), 3u64.to_be_bytes().to_vec()),
+            ("a/4".to_owned(), 4u64.to_be_bytes().to_vec()),
+            ("a/5".to_owned(), 5u64.to_be_bytes().to_vec()),
+        ]
+    );
+
+    // Reverse iteration merges the deleted keys in the same order.
+    let keys = snapshot
+        .range_keys(KeyRange::prefix("a/").rev())
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(keys, vec!["a/5", "a/4", "a/3"]);
+
+    // Rewritten keys are only listed once, with the value at that version.
+    for (version, value) in [(11, Some(b"old")), (13, None), (20, Some(b"new"))] {
+        let snapshot = storage.snapshot(version).expect("version is retained");
+        let values = snapshot.prefix_raw("b/").try_collect::<Vec<_>>().await?;
+        assert_eq!(
+            values,
+            value
+                .map(|value| ("b/rewritten".to_owned(), value.to_vec()))
+                .into_iter()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    // The latest snapshot doesn't see any deleted keys.
+    let keys = storage
+        .latest_snapshot()
+        .prefix_keys("a/")
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(keys, vec!["a/27", "a/28", "a/29"]);
+
+    Ok(())
+}
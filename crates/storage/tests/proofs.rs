@@ -0,0 +1,53 @@
+use futures::TryStreamExt;
+use penumbra_storage::*;
+
+#[tokio::test]
+/// Batch proofs and proof-carrying range queries verify against one root hash.
+async fn batch_and_range_proofs() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    for i in 0..10 {
+        delta.put_raw(format!("a/{i}"), format!("value {i}").into_bytes());
+    }
+    delta.put_raw("b".to_owned(), b"b".to_vec());
+    storage.commit(delta).await?;
+
+    let snapshot = storage.latest_snapshot();
+    let root = snapshot.root_hash().await?;
+
+    // Batch proofs can mix present and absent keys.
+    let keys = vec![b"a/1".to_vec(), b"a/7".to_vec(), b"a/missing".to_vec()];
+    let (values, proof) = snapshot.get_with_proofs(keys.clone()).await?;
+    assert_eq!(
+        values,
+        vec![Some(b"value 1".to_vec()), Some(b"value 7".to_vec()), None]
+    );
+    let entries = keys.into_iter().zip(values).collect::<Vec<_>>();
+    verify_batch_proof(&root, &entries, &proof)?;
+
+    // A batch proof doesn't verify claims it doesn't cover, or wrong values.
+    let mut wrong_value = entries.clone();
+    wrong_value[0].1 = Some(b"value 2".to_vec());
+    assert!(verify_batch_proof(&root, &wrong_value, &proof).is_err());
+    let mut wrong_absence = entries;
+    wrong_absence[1].1 = None;
+    assert!(verify_batch_proof(&root, &wrong_absence, &proof).is_err());
+
+    // Every entry of a prefix query comes with a proof against the same root.
+    let entries = snapshot
+        .prefix_with_proofs("a/")
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(entries.len(), 10);
+    for (key, value, proof) in &entries {
+        verify_proof(&root, key.as_bytes(), Some(value.as_slice()), proof)?;
+        assert!(verify_proof(&root, key.as_bytes(), Some(&b"wrong"[..]), proof).is_err());
+    }
+
+    // Non-existence proofs verify too.
+    let (value, proof) = snapshot.get_with_proof(b"a/missing".to_vec()).await?;
+    assert_eq!(value, None);
+    verify_proof(&root, b"a/missing", None, &proof)?;
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use futures::TryStreamExt;
+use penumbra_storage::*;
+use proptest::prelude::*;
+
+/// The keys written and queried by the tests, chosen to include prefixes of
+/// one another, so that range bounds fall both on and between keys.
+const KEYS: &[&str] = &["a", "b", "b/", "b/a", "b/b", "b/b/c", "b/c", "c", "ca", "d"];
+
+/// The layer a write lands in: the first two are committed as successive
+/// versions, and the rest are stacked `StateDelta`s on top of the latest one.
+const LAYERS: usize = 5;
+
+#[derive(Debug, Clone)]
+struct Write {
+    layer: usize,
+    key: &'static str,
+    /// The value to write, or `None` to delete the key.
+    value: Option<Vec<u8>>,
+    nonverifiable: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Query {
+    start: Option<&'static str>,
+    end: Option<&'static str>,
+    reverse: bool,
+    seek: Option<&'static str>,
+}
+
+fn key() -> impl Strategy<Value = &'static str> {
+    prop::sample::select(KEYS)
+}
+
+fn write() -> impl Strategy<Value = Write> {
+    (
+        0..LAYERS,
+        key(),
+        prop::option::weighted(0.7, any::<u8>().prop_map(|v| vec![v])),
+        any::<bool>(),
+    )
+        .prop_map(|(layer, key, value, nonverifiable)| Write {
+            layer,
+            key,
+            value,
+            nonverifiable,
+        })
+}
+
+fn query() -> impl Strategy<Value = Query> {
+    (
+        prop::option::of(key()),
+        prop::option::of(key()),
+        any::<bool>(),
+        prop::option::of(key()),
+    )
+        .prop_map(|(start, end, reverse, seek)| Query {
+            start,
+            end,
+            reverse,
+            seek,
+        })
+}
+
+impl Write {
+    fn apply<S: StateWrite>(&self, state: &mut S) {
+        match (&self.value, self.nonverifiable) {
+            (Some(value), false) => state.put_raw(self.key.to_owned(), value.clone()),
+            (None, false) => state.delete(self.key.to_owned()),
+            (Some(value), true) => {
+                state.nonverifiable_put_raw(self.key.as_bytes().to_vec(), value.clone())
+            }
+            (None, true) => state.nonverifiable_delete(self.key.as_bytes().to_vec()),
+        }
+    }
+}
+
+impl Query {
+    fn range(&self) -> KeyRange {
+        let mut range = match (self.start, self.end) {
+            (Some(start), Some(end)) => KeyRange::new(start..end),
+            (Some(start), None) => KeyRange::new(start..),
+            (None, Some(end)) => KeyRange::new(..end),
+            (None, None) => KeyRange::all(),
+        };
+        if self.reverse {
+            range = range.rev();
+        }
+        if let Some(seek) = self.seek {
+            range = range.seek(seek);
+        }
+        range
+    }
+
+    /// The entries of `model` this query should return, in order.
+    fn expected(&self, model: &BTreeMap<&'static str, Vec<u8>>) -> Vec<(String, Vec<u8>)> {
+        let mut entries = model
+            .iter()
+            .filter(|(key, _)| self.start.map_or(true, |start| start <= **key))
+            .filter(|(key, _)| self.end.map_or(true, |end| **key < end))
+            .filter(|(key, _)| match self.seek {
+                Some(seek) if self.reverse => **key <= seek,
+                Some(seek) => seek <= **key,
+                None => true,
+            })
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect::<Vec<_>>();
+        if self.reverse {
+            entries.reverse();
+        }
+        entries
+    }
+}
+
+/// Applies `writes` to storage and a stack of deltas, then checks every query
+/// against a model of the resulting state.
+async fn check(writes: Vec<Write>, queries: Vec<Query>) -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let layer = |i: usize| writes.iter().filter(move |write| write.layer == i);
+
+    for i in 0..2 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        layer(i).for_each(|write| write.apply(&mut delta));
+        storage.commit(delta).await?;
+    }
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    layer(2).for_each(|write| write.apply(&mut delta));
+    let mut fork = delta.fork();
+    layer(3).for_each(|write| write.apply(&mut fork));
+    let mut nested = StateDelta::new(&mut fork);
+    layer(4).for_each(|write| write.apply(&mut nested));
+
+    // Later writes to a key shadow earlier ones, in layer order.
+    let mut verifiable = BTreeMap::new();
+    let mut nonverifiable = BTreeMap::new();
+    for i in 0..LAYERS {
+        for write in layer(i) {
+            let model = if write.nonverifiable {
+                &mut nonverifiable
+            } else {
+                &mut verifiable
+            };
+            match &write.value {
+                Some(value) => model.insert(write.key, value.clone()),
+                None => model.remove(write.key),
+            };
+        }
+    }
+
+    for query in queries {
+        let expected = query.expected(&verifiable);
+        let actual = nested
+            .range_raw(query.range())
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(actual, expected, "range_raw, {query:?}");
+
+        let actual = nested
+            .range_keys(query.range())
+            .try_collect::<Vec<_>>()
+            .await?;
+        let expected_keys = expected.into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+        assert_eq!(actual, expected_keys, "range_keys, {query:?}");
+
+        let expected = query
+            .expected(&nonverifiable)
+            .into_iter()
+            .map(|(key, value)| (key.into_bytes(), value))
+            .collect::<Vec<_>>();
+        let actual = nested
+            .nonverifiable_range_raw(query.range())
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(actual, expected, "nonverifiable_range_raw, {query:?}");
+    }
+
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    /// Range queries agree with a model of the state, in either direction,
+    /// across committed versions and every kind of delta layer.
+    fn range_queries_match_model(
+        writes in prop::collection::vec(write(), 0..40),
+        queries in prop::collection::vec(query(), 1..10),
+    ) {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(check(writes, queries))
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+/// Tombstones in upper layers hide keys in lower layers from reverse scans.
+async fn reverse_scan_skips_tombstones() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    for key in ["a/1", "a/2", "a/3", "b/1"] {
+        delta.put_raw(key.to_owned(), key.as_bytes().to_vec());
+    }
+    storage.commit(delta).await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.delete("a/3".to_owned());
+    delta.put_raw("a/25".to_owned(), b"a/25".to_vec());
+
+    let keys = delta
+        .range_keys(KeyRange::prefix("a/").rev())
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(keys, vec!["a/25", "a/2", "a/1"]);
+
+    let keys = delta
+        .range_keys(KeyRange::prefix("a/").rev().seek("a/2"))
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(keys, vec!["a/2", "a/1"]);
+
+    let keys = delta
+        .range_keys(KeyRange::prefix("a/").seek("a/2"))
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(keys, vec!["a/2", "a/25"]);
+
+    Ok(())
+}
@@ -128,6 +128,7 @@ impl TendermintProxyService for TendermintProxy {
             data: res.data.to_vec(),
             log: res.log.to_string(),
             hash: res.hash.as_bytes().to_vec(),
+            codespace: res.codespace,
         }))
     }
 
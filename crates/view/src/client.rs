@@ -26,7 +26,9 @@ use penumbra_transaction::{plan::TransactionPlan, Transaction, WitnessData};
 use tonic::codegen::Bytes;
 use tracing::instrument;
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{
+    SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo, TransactionRejection,
+};
 
 /// The view protocol is used by a view client, who wants to do some
 /// transaction-related actions, to request data from a view service, which is
@@ -457,6 +459,7 @@ where
                 tonic::Request::new(pb::BalancesRequest {
                     account_filter: Some(address_index.into()),
                     asset_id_filter: asset_id.map(Into::into),
+                    account_group_id: None,
                 }),
             );
 
@@ -703,6 +706,7 @@ where
                 &mut self2,
                 tonic::Request::new(pb::TransactionInfoByHashRequest {
                     id: Some(id.into()),
+                    account_group_id: None,
                 }),
             )
             .await?
@@ -747,6 +751,7 @@ where
             let rsp = self2.transaction_info(tonic::Request::new(pb::TransactionInfoRequest {
                 start_height,
                 end_height,
+                account_group_id: None,
             }));
             let pb_txs: Vec<_> = rsp.await?.into_inner().try_collect().await?;
 
@@ -801,9 +806,14 @@ where
                 tonic::Request::new(pb::BroadcastTransactionRequest {
                     transaction: Some(transaction.into()),
                     await_detection,
+                    account_group_id: None,
                 }),
             )
-            .await?
+            .await
+            .map_err(|status| match TransactionRejection::from_status(&status) {
+                Some(rejection) => anyhow::Error::new(rejection),
+                None => anyhow::Error::new(status),
+            })?
             .into_inner();
 
             let id = rsp
@@ -845,6 +855,7 @@ where
         let request = pb::WitnessAndBuildRequest {
             transaction_plan: Some(transaction_plan.into()),
             authorization_data: Some(authorization_data.into()),
+            account_group_id: None,
         };
         let mut self2 = self.clone();
         async move {
@@ -0,0 +1,172 @@
+use std::pin::Pin;
+
+use anyhow::Context;
+use async_stream::try_stream;
+use decaf377_fmd::DetectionKey;
+use futures::stream::{StreamExt, TryStreamExt};
+use penumbra_chain::NoteSource;
+use penumbra_compact_block::CompactBlock;
+use penumbra_proto::{
+    client::v1alpha1::{
+        oblivious_query_service_client::ObliviousQueryServiceClient,
+        tendermint_proxy_service_client::TendermintProxyServiceClient, CompactBlockRangeRequest,
+    },
+    view::v1alpha1::{self as pb, detection_service_server},
+    DomainType,
+};
+use penumbra_transaction::Transaction;
+use sha2::Digest;
+use tonic::async_trait;
+use url::Url;
+
+use crate::worker::fetch_block;
+
+/// A fuzzy message detection service, which examines the clues of the transactions on the chain
+/// with the detection keys registered by its clients, and streams back the candidate
+/// transactions in each block.
+///
+/// The service holds no state of its own: each client registers the detection keys of its
+/// addresses for the lifetime of a single
+/// [`CandidateTransactions`](pb::CandidateTransactionsRequest) stream, and
+/// the service follows the chain through the `pd` node at `node` on its behalf.  It can be run
+/// standalone, or alongside a [`ViewService`](crate::ViewService), which can use it in place of
+/// trial-decrypting every note on the chain.
+#[derive(Clone)]
+pub struct DetectionService {
+    // The Url for the pd gRPC endpoint on remote node.
+    node: Url,
+}
+
+impl DetectionService {
+    /// Constructs a new [`DetectionService`] following the chain through the `pd` node at `node`.
+    pub fn new(node: Url) -> Self {
+        Self { node }
+    }
+}
+
+#[async_trait]
+impl detection_service_server::DetectionService for DetectionService {
+    type CandidateTransactionsStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::CandidateTransactionsResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+
+    async fn candidate_transactions(
+        &self,
+        request: tonic::Request<pb::CandidateTransactionsRequest>,
+    ) -> Result<tonic::Response<Self::CandidateTransactionsStream>, tonic::Status> {
+        let pb::CandidateTransactionsRequest {
+            detection_keys,
+            start_height,
+            keep_alive,
+        } = request.into_inner();
+
+        if detection_keys.is_empty() {
+            return Err(tonic::Status::invalid_argument(
+                "at least one detection key is required",
+            ));
+        }
+        let detection_keys = detection_keys
+            .into_iter()
+            .map(|detection_key| {
+                detection_key
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("detection key must be 32 bytes"))
+                    .and_then(|bytes| DetectionKey::from_bytes(bytes).map_err(Into::into))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid detection key: {e}")))?;
+
+        let mut client = ObliviousQueryServiceClient::connect(self.node.to_string())
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error connecting to node: {e}")))?;
+        let mut tm_client = TendermintProxyServiceClient::connect(self.node.to_string())
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error connecting to node: {e}")))?;
+
+        let mut blocks = client
+            .compact_block_range(CompactBlockRangeRequest {
+                chain_id: String::new(),
+                start_height,
+                end_height: 0,
+                keep_alive,
+            })
+            .await?
+            .into_inner();
+
+        let stream = try_stream! {
+            while let Some(block) = blocks.message().await? {
+                let block: CompactBlock = block.try_into()?;
+
+                // Only transactions which create notes or swaps carry clues, so we can skip
+                // fetching the full block if the compact block has no state payloads from any
+                // transaction.
+                let has_transactions = block
+                    .state_payloads
+                    .iter()
+                    .any(|payload| matches!(payload.source(), Some(NoteSource::Transaction { .. })));
+
+                let mut transaction_ids = Vec::new();
+                if has_transactions {
+                    let full_block = fetch_block(&mut tm_client, block.height as i64).await?;
+                    let txs = full_block.data.context("missing block data")?.txs;
+
+                    for tx_bytes in txs {
+                        // A proposer can include a transaction that fails to decode, but it
+                        // can't have created any notes, so it can't be a candidate.
+                        let transaction = match Transaction::decode(tx_bytes.as_slice()) {
+                            Ok(transaction) => transaction,
+                            Err(error) => {
+                                tracing::warn!(
+                                    height = block.height,
+                                    ?error,
+                                    "skipping undecodable transaction"
+                                );
+                                continue;
+                            }
+                        };
+
+                        let detected = transaction
+                            .transaction_body
+                            .detection_data
+                            .iter()
+                            .flat_map(|detection_data| detection_data.fmd_clues.iter())
+                            .any(|clue| {
+                                detection_keys
+                                    .iter()
+                                    .any(|detection_key| detection_key.examine(clue))
+                            });
+
+                        if detected {
+                            let id: [u8; 32] = sha2::Sha256::digest(tx_bytes.as_slice()).into();
+                            transaction_ids.push(penumbra_transaction::Id(id).into());
+                        }
+                    }
+                }
+
+                tracing::debug!(
+                    height = block.height,
+                    candidates = transaction_ids.len(),
+                    "detected candidate transactions"
+                );
+
+                yield pb::CandidateTransactionsResponse {
+                    height: block.height,
+                    transaction_ids,
+                };
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!(
+                        "error detecting candidate transactions: {e}"
+                    ))
+                })
+                .boxed(),
+        ))
+    }
+}
@@ -1,8 +1,11 @@
 #![recursion_limit = "256"]
 mod client;
+mod detection;
 mod metrics;
+mod multi;
 mod note_record;
 mod planner;
+mod rejection;
 mod service;
 mod status;
 mod storage;
@@ -15,10 +18,15 @@ use worker::Worker;
 
 pub use crate::metrics::register_metrics;
 pub use client::ViewClient;
+pub use detection::DetectionService;
+pub use multi::MultiViewService;
 pub use note_record::SpendableNoteRecord;
 pub use planner::Planner;
+pub use rejection::TransactionRejection;
 pub use service::ViewService;
 pub use status::StatusStreamResponse;
 pub use storage::Storage;
 pub use swap_record::SwapRecord;
+pub use sync::{scan_block, trial_decrypt_blocks, DecryptedBlock, FilteredBlock};
 pub use transaction_info::TransactionInfo;
+pub use worker::scan_and_record_block;
@@ -0,0 +1,575 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    sync::Arc,
+};
+
+use anyhow::Context;
+use camino::Utf8PathBuf;
+use penumbra_keys::{keys::AccountGroupId, FullViewingKey};
+use penumbra_proto::{
+    core::crypto::v1alpha1 as pbc,
+    view::v1alpha1::{
+        self as pb, view_protocol_service_server::ViewProtocolService,
+        wallet_service_server::WalletService,
+    },
+};
+use tokio::sync::RwLock;
+use tonic::async_trait;
+use url::Url;
+
+use crate::{worker::WorkerHandle, Storage, ViewService, Worker};
+
+/// A view service which syncs several wallets at once, sharing a single
+/// scanning task between them.
+///
+/// Each block is fetched once and scanned with the full viewing key of every
+/// wallet, while each wallet keeps its own [`Storage`], which must be for the
+/// same chain as the others.  Requests are routed to a wallet by their account
+/// group ID, which may only be omitted while a single wallet is synced.
+///
+/// Wallets can be added and removed while the service runs, either directly or
+/// through the [`WalletService`] gRPC interface.  The storage of the wallets added
+/// this way is kept in `storage_dir` (or in memory, if none is given), one
+/// database per account group, and is reloaded when the service is restarted.
+#[derive(Clone)]
+pub struct MultiViewService {
+    // The directory holding the storage of the wallets added at runtime, if any.
+    storage_dir: Option<Utf8PathBuf>,
+    // The Url for the pd gRPC endpoint on remote node.
+    node: Url,
+    worker: WorkerHandle,
+    // The wallets, or `None` for a wallet which is being added or removed, so that only one
+    // request at a time can touch its storage.
+    wallets: Arc<RwLock<BTreeMap<AccountGroupId, Option<Wallet>>>>,
+}
+
+#[derive(Clone)]
+struct Wallet {
+    service: ViewService,
+    // Whether the wallet's storage is managed by the service, in `storage_dir`.
+    managed: bool,
+}
+
+impl MultiViewService {
+    /// Constructs a new [`MultiViewService`], spawning a sync task internally, and
+    /// loading any wallets previously added to `storage_dir`.
+    ///
+    /// If a `detection_service` is provided, it is used to sync every wallet, as for
+    /// [`ViewService::with_detection_service`].
+    pub async fn new(
+        node: Url,
+        storage_dir: Option<Utf8PathBuf>,
+        detection_service: Option<Url>,
+    ) -> anyhow::Result<Self> {
+        let (worker, handle) = Worker::new_shared(node.clone(), detection_service).await?;
+
+        tokio::spawn(worker.run());
+
+        let service = Self {
+            storage_dir,
+            node,
+            worker: handle,
+            wallets: Default::default(),
+        };
+
+        if let Some(storage_dir) = &service.storage_dir {
+            std::fs::create_dir_all(storage_dir)?;
+
+            for entry in storage_dir.read_dir_utf8()? {
+                let path = entry?.into_path();
+                if path.extension() != Some("sqlite") {
+                    continue;
+                }
+
+                let storage = Storage::load(&path)
+                    .await
+                    .with_context(|| format!("could not load wallet storage at {path}"))?;
+                service.insert(storage, true).await?;
+            }
+        }
+
+        Ok(service)
+    }
+
+    /// Starts syncing the wallet with the given full viewing key, returning its
+    /// account group ID.
+    pub async fn add_wallet(&self, fvk: &FullViewingKey) -> anyhow::Result<AccountGroupId> {
+        let account_group_id = fvk.account_group_id();
+        self.reserve(account_group_id).await?;
+
+        let result = async {
+            let storage = Storage::load_or_initialize(
+                self.storage_path(&account_group_id),
+                fvk,
+                self.node.clone(),
+            )
+            .await?;
+            self.start(account_group_id, storage, true).await
+        }
+        .await;
+
+        self.finish_adding(account_group_id, result).await
+    }
+
+    /// Starts syncing the wallet in an existing `storage`, returning its account
+    /// group ID.
+    ///
+    /// Unlike the wallets added with [`Self::add_wallet`], the wallet is not
+    /// reloaded when the service is restarted, and can't be removed.
+    pub async fn add_storage(&self, storage: Storage) -> anyhow::Result<AccountGroupId> {
+        self.insert(storage, false).await
+    }
+
+    /// Stops syncing the wallet with the given account group ID, deleting its
+    /// storage.
+    pub async fn remove_wallet(&self, account_group_id: &AccountGroupId) -> anyhow::Result<()> {
+        {
+            let mut wallets = self.wallets.write().await;
+            match wallets.get(account_group_id) {
+                None => anyhow::bail!("wallet {} is not synced", account_group_id),
+                Some(None) => {
+                    anyhow::bail!("wallet {} is being added or removed", account_group_id)
+                }
+                Some(Some(wallet)) if !wallet.managed => {
+                    anyhow::bail!("wallet {} was not added at runtime", account_group_id)
+                }
+                Some(Some(_)) => {}
+            }
+            // Keep the entry reserved until the storage is deleted, so that the wallet can't be
+            // added again in the meantime.
+            wallets.insert(*account_group_id, None);
+        }
+
+        // Wait for the worker to stop using the storage before deleting it.
+        let result = async {
+            self.worker.remove_wallet(*account_group_id).await?;
+
+            if let Some(path) = self.storage_path(account_group_id) {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("could not delete wallet storage at {path}"))?;
+            }
+
+            Ok::<_, anyhow::Error>(())
+        }
+        .await;
+
+        self.wallets.write().await.remove(account_group_id);
+
+        result
+    }
+
+    /// Returns the account group IDs of the synced wallets.
+    pub async fn wallets(&self) -> Vec<AccountGroupId> {
+        self.wallets
+            .read()
+            .await
+            .iter()
+            .filter(|(_, wallet)| wallet.is_some())
+            .map(|(account_group_id, _)| *account_group_id)
+            .collect()
+    }
+
+    async fn insert(&self, storage: Storage, managed: bool) -> anyhow::Result<AccountGroupId> {
+        let account_group_id = storage.full_viewing_key().await?.account_group_id();
+        self.reserve(account_group_id).await?;
+
+        let result = self.start(account_group_id, storage, managed).await;
+
+        self.finish_adding(account_group_id, result).await
+    }
+
+    /// Reserves the entry for the wallet with the given account group ID while it's being
+    /// added, failing if it's already synced or being added.
+    async fn reserve(&self, account_group_id: AccountGroupId) -> anyhow::Result<()> {
+        match self.wallets.write().await.entry(account_group_id) {
+            Entry::Occupied(entry) if entry.get().is_none() => {
+                anyhow::bail!("wallet {} is being added or removed", account_group_id)
+            }
+            Entry::Occupied(_) => anyhow::bail!("wallet {} is already synced", account_group_id),
+            Entry::Vacant(entry) => {
+                entry.insert(None);
+                Ok(())
+            }
+        }
+    }
+
+    /// Starts syncing the wallet in `storage`, whose entry was reserved by [`Self::reserve`].
+    async fn start(
+        &self,
+        account_group_id: AccountGroupId,
+        storage: Storage,
+        managed: bool,
+    ) -> anyhow::Result<()> {
+        let state = self.worker.add_wallet(storage.clone()).await?;
+        let service = ViewService::with_wallet_state(storage, self.node.clone(), state).await?;
+
+        self.wallets
+            .write()
+            .await
+            .insert(account_group_id, Some(Wallet { service, managed }));
+
+        Ok(())
+    }
+
+    /// Finishes adding a wallet, releasing its reserved entry if it couldn't be added.
+    async fn finish_adding(
+        &self,
+        account_group_id: AccountGroupId,
+        result: anyhow::Result<()>,
+    ) -> anyhow::Result<AccountGroupId> {
+        if result.is_err() {
+            self.wallets.write().await.remove(&account_group_id);
+        }
+
+        result.map(|()| account_group_id)
+    }
+
+    fn storage_path(&self, account_group_id: &AccountGroupId) -> Option<Utf8PathBuf> {
+        self.storage_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{account_group_id}.sqlite")))
+    }
+
+    /// Returns the view service for the wallet with the given account group ID, or
+    /// for the only synced wallet, if none is given.
+    async fn wallet(
+        &self,
+        account_group_id: Option<&pbc::AccountGroupId>,
+    ) -> Result<ViewService, tonic::Status> {
+        let wallets = self.wallets.read().await;
+
+        match account_group_id {
+            Some(account_group_id) => {
+                let account_group_id: AccountGroupId =
+                    account_group_id.clone().try_into().map_err(|e| {
+                        tonic::Status::invalid_argument(format!("invalid account group ID: {e:#}"))
+                    })?;
+
+                match wallets.get(&account_group_id) {
+                    Some(Some(wallet)) => Ok(wallet.service.clone()),
+                    Some(None) => Err(tonic::Status::unavailable(format!(
+                        "wallet {account_group_id} is being added or removed"
+                    ))),
+                    None => Err(tonic::Status::not_found(format!(
+                        "wallet {account_group_id} is not synced"
+                    ))),
+                }
+            }
+            None => {
+                // A wallet which is being added or removed still counts, so that the wallet a
+                // request is routed to doesn't depend on the timing of other requests.
+                if wallets.len() > 1 {
+                    return Err(tonic::Status::invalid_argument(
+                        "an account group ID is required when several wallets are synced",
+                    ));
+                }
+                match wallets.values().next() {
+                    Some(Some(wallet)) => Ok(wallet.service.clone()),
+                    Some(None) => Err(tonic::Status::unavailable(
+                        "the wallet is being added or removed",
+                    )),
+                    None => Err(tonic::Status::failed_precondition("no wallets are synced")),
+                }
+            }
+        }
+    }
+
+    /// Returns the view service for any synced wallet, for requests about the chain
+    /// rather than a specific wallet.
+    async fn any_wallet(&self) -> Result<ViewService, tonic::Status> {
+        self.wallets
+            .read()
+            .await
+            .values()
+            .flatten()
+            .next()
+            .map(|wallet| wallet.service.clone())
+            .ok_or_else(|| tonic::Status::failed_precondition("no wallets are synced"))
+    }
+}
+
+#[async_trait]
+impl ViewProtocolService for MultiViewService {
+    type NotesStream = <ViewService as ViewProtocolService>::NotesStream;
+    type NotesForVotingStream = <ViewService as ViewProtocolService>::NotesForVotingStream;
+    type AssetsStream = <ViewService as ViewProtocolService>::AssetsStream;
+    type StatusStreamStream = <ViewService as ViewProtocolService>::StatusStreamStream;
+    type TransactionInfoStream = <ViewService as ViewProtocolService>::TransactionInfoStream;
+    type BalancesStream = <ViewService as ViewProtocolService>::BalancesStream;
+    type OwnedPositionIdsStream = <ViewService as ViewProtocolService>::OwnedPositionIdsStream;
+
+    async fn status(
+        &self,
+        request: tonic::Request<pb::StatusRequest>,
+    ) -> Result<tonic::Response<pb::StatusResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::status(&wallet, request).await
+    }
+
+    async fn status_stream(
+        &self,
+        request: tonic::Request<pb::StatusStreamRequest>,
+    ) -> Result<tonic::Response<Self::StatusStreamStream>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::status_stream(&wallet, request).await
+    }
+
+    async fn notes(
+        &self,
+        request: tonic::Request<pb::NotesRequest>,
+    ) -> Result<tonic::Response<Self::NotesStream>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::notes(&wallet, request).await
+    }
+
+    async fn notes_for_voting(
+        &self,
+        request: tonic::Request<pb::NotesForVotingRequest>,
+    ) -> Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::notes_for_voting(&wallet, request).await
+    }
+
+    async fn witness(
+        &self,
+        request: tonic::Request<pb::WitnessRequest>,
+    ) -> Result<tonic::Response<pb::WitnessResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::witness(&wallet, request).await
+    }
+
+    async fn witness_and_build(
+        &self,
+        request: tonic::Request<pb::WitnessAndBuildRequest>,
+    ) -> Result<tonic::Response<pb::WitnessAndBuildResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::witness_and_build(&wallet, request).await
+    }
+
+    async fn assets(
+        &self,
+        request: tonic::Request<pb::AssetsRequest>,
+    ) -> Result<tonic::Response<Self::AssetsStream>, tonic::Status> {
+        let wallet = self.any_wallet().await?;
+        ViewProtocolService::assets(&wallet, request).await
+    }
+
+    async fn chain_parameters(
+        &self,
+        request: tonic::Request<pb::ChainParametersRequest>,
+    ) -> Result<tonic::Response<pb::ChainParametersResponse>, tonic::Status> {
+        let wallet = self.any_wallet().await?;
+        ViewProtocolService::chain_parameters(&wallet, request).await
+    }
+
+    async fn fmd_parameters(
+        &self,
+        request: tonic::Request<pb::FmdParametersRequest>,
+    ) -> Result<tonic::Response<pb::FmdParametersResponse>, tonic::Status> {
+        let wallet = self.any_wallet().await?;
+        ViewProtocolService::fmd_parameters(&wallet, request).await
+    }
+
+    async fn address_by_index(
+        &self,
+        request: tonic::Request<pb::AddressByIndexRequest>,
+    ) -> Result<tonic::Response<pb::AddressByIndexResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::address_by_index(&wallet, request).await
+    }
+
+    async fn index_by_address(
+        &self,
+        request: tonic::Request<pb::IndexByAddressRequest>,
+    ) -> Result<tonic::Response<pb::IndexByAddressResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::index_by_address(&wallet, request).await
+    }
+
+    async fn ephemeral_address(
+        &self,
+        request: tonic::Request<pb::EphemeralAddressRequest>,
+    ) -> Result<tonic::Response<pb::EphemeralAddressResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::ephemeral_address(&wallet, request).await
+    }
+
+    async fn balances(
+        &self,
+        request: tonic::Request<pb::BalancesRequest>,
+    ) -> Result<tonic::Response<Self::BalancesStream>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::balances(&wallet, request).await
+    }
+
+    async fn note_by_commitment(
+        &self,
+        request: tonic::Request<pb::NoteByCommitmentRequest>,
+    ) -> Result<tonic::Response<pb::NoteByCommitmentResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::note_by_commitment(&wallet, request).await
+    }
+
+    async fn swap_by_commitment(
+        &self,
+        request: tonic::Request<pb::SwapByCommitmentRequest>,
+    ) -> Result<tonic::Response<pb::SwapByCommitmentResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::swap_by_commitment(&wallet, request).await
+    }
+
+    async fn nullifier_status(
+        &self,
+        request: tonic::Request<pb::NullifierStatusRequest>,
+    ) -> Result<tonic::Response<pb::NullifierStatusResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::nullifier_status(&wallet, request).await
+    }
+
+    async fn transaction_info_by_hash(
+        &self,
+        request: tonic::Request<pb::TransactionInfoByHashRequest>,
+    ) -> Result<tonic::Response<pb::TransactionInfoByHashResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::transaction_info_by_hash(&wallet, request).await
+    }
+
+    async fn transaction_info(
+        &self,
+        request: tonic::Request<pb::TransactionInfoRequest>,
+    ) -> Result<tonic::Response<Self::TransactionInfoStream>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::transaction_info(&wallet, request).await
+    }
+
+    async fn transaction_planner(
+        &self,
+        request: tonic::Request<pb::TransactionPlannerRequest>,
+    ) -> Result<tonic::Response<pb::TransactionPlannerResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::transaction_planner(&wallet, request).await
+    }
+
+    async fn broadcast_transaction(
+        &self,
+        request: tonic::Request<pb::BroadcastTransactionRequest>,
+    ) -> Result<tonic::Response<pb::BroadcastTransactionResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::broadcast_transaction(&wallet, request).await
+    }
+
+    async fn owned_position_ids(
+        &self,
+        request: tonic::Request<pb::OwnedPositionIdsRequest>,
+    ) -> Result<tonic::Response<Self::OwnedPositionIdsStream>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::owned_position_ids(&wallet, request).await
+    }
+
+    async fn authorize_and_build(
+        &self,
+        request: tonic::Request<pb::AuthorizeAndBuildRequest>,
+    ) -> Result<tonic::Response<pb::AuthorizeAndBuildResponse>, tonic::Status> {
+        let wallet = self
+            .wallet(request.get_ref().account_group_id.as_ref())
+            .await?;
+        ViewProtocolService::authorize_and_build(&wallet, request).await
+    }
+}
+
+#[async_trait]
+impl WalletService for MultiViewService {
+    async fn add_wallet(
+        &self,
+        request: tonic::Request<pb::AddWalletRequest>,
+    ) -> Result<tonic::Response<pb::AddWalletResponse>, tonic::Status> {
+        let fvk: FullViewingKey = request
+            .into_inner()
+            .full_viewing_key
+            .ok_or_else(|| tonic::Status::invalid_argument("missing full viewing key"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("invalid full viewing key: {e:#}"))
+            })?;
+
+        let account_group_id = MultiViewService::add_wallet(self, &fvk)
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(format!("{e:#}")))?;
+
+        Ok(tonic::Response::new(pb::AddWalletResponse {
+            account_group_id: Some(account_group_id.into()),
+        }))
+    }
+
+    async fn remove_wallet(
+        &self,
+        request: tonic::Request<pb::RemoveWalletRequest>,
+    ) -> Result<tonic::Response<pb::RemoveWalletResponse>, tonic::Status> {
+        let account_group_id: AccountGroupId = request
+            .into_inner()
+            .account_group_id
+            .ok_or_else(|| tonic::Status::invalid_argument("missing account group ID"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("invalid account group ID: {e:#}"))
+            })?;
+
+        MultiViewService::remove_wallet(self, &account_group_id)
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(format!("{e:#}")))?;
+
+        Ok(tonic::Response::new(pb::RemoveWalletResponse {}))
+    }
+
+    async fn wallets(
+        &self,
+        _request: tonic::Request<pb::WalletsRequest>,
+    ) -> Result<tonic::Response<pb::WalletsResponse>, tonic::Status> {
+        Ok(tonic::Response::new(pb::WalletsResponse {
+            account_group_ids: MultiViewService::wallets(self)
+                .await
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,369 @@
+use std::{pin::Pin, sync::Mutex, time::Duration};
+
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID, STAKING_TOKEN_DENOM};
+use penumbra_chain::{params::ChainParameters, test_keys, NoteSource};
+use penumbra_compact_block::{CompactBlock, StatePayload};
+use penumbra_keys::{
+    keys::{AddressIndex, SeedPhrase, SpendKey},
+    Address,
+};
+use penumbra_proto::client::v1alpha1::{
+    oblivious_query_service_server::{ObliviousQueryService, ObliviousQueryServiceServer},
+    ChainParametersRequest, ChainParametersResponse, CompactBlockRangeRequest,
+    CompactBlockRangeResponse, EpochByHeightRequest, EpochByHeightResponse, InfoRequest,
+    InfoResponse, ValidatorInfoRequest, ValidatorInfoResponse,
+};
+use penumbra_shielded_pool::Note;
+use rand_core::OsRng;
+use tokio::sync::watch;
+use tokio_stream::wrappers::TcpListenerStream;
+
+use super::*;
+
+const CHAIN_ID: &str = "penumbra-test";
+
+/// A mock `pd` node serving a chain of compact blocks, which records the height each compact block
+/// stream was requested from.
+#[derive(Clone)]
+struct MockNode {
+    blocks: Arc<Mutex<Vec<CompactBlock>>>,
+    // The number of blocks produced so far.
+    produced: watch::Receiver<usize>,
+    produced_tx: Arc<watch::Sender<usize>>,
+    stream_starts: Arc<Mutex<Vec<u64>>>,
+}
+
+impl MockNode {
+    /// Starts serving a mock node with no blocks, returning it along with its URL.
+    async fn start() -> anyhow::Result<(Self, Url)> {
+        let (produced_tx, produced) = watch::channel(0);
+        let node = Self {
+            blocks: Default::default(),
+            produced,
+            produced_tx: Arc::new(produced_tx),
+            stream_starts: Default::default(),
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?).parse()?;
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(ObliviousQueryServiceServer::new(node.clone()))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        Ok((node, url))
+    }
+
+    /// Produces `count` more blocks, each with a note sent to the test wallet.
+    fn produce_blocks(&self, count: usize) {
+        let mut blocks = self.blocks.lock().unwrap();
+        for _ in 0..count {
+            let note = Note::generate(
+                &mut OsRng,
+                &test_keys::ADDRESS_0,
+                Value {
+                    amount: 1u64.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+            );
+            let height = blocks.len() as u64;
+            blocks.push(CompactBlock {
+                height,
+                // Notes which weren't created by a transaction don't need to be fetched from the
+                // node, which only serves compact blocks.
+                state_payloads: vec![StatePayload::Note {
+                    source: NoteSource::Genesis,
+                    note: Box::new(note.payload()),
+                }],
+                ..Default::default()
+            });
+        }
+        let _ = self.produced_tx.send(blocks.len());
+    }
+
+    /// The heights each compact block stream was requested from, in order.
+    fn stream_starts(&self) -> Vec<u64> {
+        self.stream_starts.lock().unwrap().clone()
+    }
+}
+
+#[tonic::async_trait]
+impl ObliviousQueryService for MockNode {
+    type CompactBlockRangeStream = Pin<
+        Box<dyn futures::Stream<Item = Result<CompactBlockRangeResponse, tonic::Status>> + Send>,
+    >;
+    type ValidatorInfoStream =
+        Pin<Box<dyn futures::Stream<Item = Result<ValidatorInfoResponse, tonic::Status>> + Send>>;
+
+    async fn compact_block_range(
+        &self,
+        request: tonic::Request<CompactBlockRangeRequest>,
+    ) -> Result<tonic::Response<Self::CompactBlockRangeStream>, tonic::Status> {
+        let start_height = request.into_inner().start_height;
+        self.stream_starts.lock().unwrap().push(start_height);
+
+        let blocks = self.blocks.clone();
+        let mut produced = self.produced.clone();
+        let stream = async_stream::stream! {
+            'blocks: for height in start_height.. {
+                // Wait for the block to be produced, like a node keeping the stream alive.
+                loop {
+                    let produced_blocks = *produced.borrow();
+                    if produced_blocks as u64 > height {
+                        break;
+                    }
+                    if produced.changed().await.is_err() {
+                        break 'blocks;
+                    }
+                }
+                let block = blocks.lock().unwrap()[height as usize].clone();
+                yield Ok::<_, tonic::Status>(CompactBlockRangeResponse::from(block));
+            }
+        };
+
+        Ok(tonic::Response::new(Box::pin(stream)))
+    }
+
+    async fn chain_parameters(
+        &self,
+        _request: tonic::Request<ChainParametersRequest>,
+    ) -> Result<tonic::Response<ChainParametersResponse>, tonic::Status> {
+        Ok(tonic::Response::new(ChainParametersResponse {
+            chain_parameters: Some(chain_params(CHAIN_ID).into()),
+        }))
+    }
+
+    async fn epoch_by_height(
+        &self,
+        _request: tonic::Request<EpochByHeightRequest>,
+    ) -> Result<tonic::Response<EpochByHeightResponse>, tonic::Status> {
+        Err(tonic::Status::unimplemented("not served by the mock node"))
+    }
+
+    async fn validator_info(
+        &self,
+        _request: tonic::Request<ValidatorInfoRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorInfoStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("not served by the mock node"))
+    }
+
+    async fn info(
+        &self,
+        _request: tonic::Request<InfoRequest>,
+    ) -> Result<tonic::Response<InfoResponse>, tonic::Status> {
+        Err(tonic::Status::unimplemented("not served by the mock node"))
+    }
+}
+
+fn chain_params(chain_id: &str) -> ChainParameters {
+    ChainParameters {
+        chain_id: chain_id.to_string(),
+        ..Default::default()
+    }
+}
+
+fn random_fvk() -> FullViewingKey {
+    SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+        .full_viewing_key()
+        .clone()
+}
+
+/// Creates in-memory storage for the wallet with the given full viewing key.
+async fn storage(fvk: &FullViewingKey, chain_id: &str) -> anyhow::Result<Storage> {
+    let storage = Storage::initialize(None::<&str>, fvk.clone(), chain_params(chain_id)).await?;
+    // The mock node doesn't serve denom metadata, so the asset of the notes it sends must already
+    // be known.
+    storage.record_asset(STAKING_TOKEN_DENOM.clone()).await?;
+    Ok(storage)
+}
+
+/// Waits for the wallet in `storage` to be synced up to `height`.
+async fn wait_for_sync(storage: &Storage, height: u64) {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while storage.last_sync_height().await.unwrap() < Some(height) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("wallet is synced in time");
+}
+
+/// Requests the default address of a wallet from the `service`.
+async fn default_address(
+    service: &MultiViewService,
+    account_group_id: Option<AccountGroupId>,
+) -> Result<Address, tonic::Status> {
+    let response = ViewProtocolService::address_by_index(
+        service,
+        tonic::Request::new(pb::AddressByIndexRequest {
+            address_index: Some(AddressIndex::from(0u32).into()),
+            account_group_id: account_group_id.map(Into::into),
+            ..Default::default()
+        }),
+    )
+    .await?
+    .into_inner();
+
+    Ok(response
+        .address
+        .expect("address is set")
+        .try_into()
+        .expect("address is valid"))
+}
+
+#[tokio::test]
+async fn wallets_are_synced_from_one_stream() -> anyhow::Result<()> {
+    let (node, url) = MockNode::start().await?;
+    let service = MultiViewService::new(url, None, None).await?;
+
+    let test_wallet = storage(&test_keys::FULL_VIEWING_KEY, CHAIN_ID).await?;
+    let other_wallet = storage(&random_fvk(), CHAIN_ID).await?;
+    service.add_storage(test_wallet.clone()).await?;
+    service.add_storage(other_wallet.clone()).await?;
+
+    node.produce_blocks(3);
+    wait_for_sync(&test_wallet, 2).await;
+    wait_for_sync(&other_wallet, 2).await;
+
+    // Both wallets were synced from a single stream, each finding only its own notes.
+    assert_eq!(node.stream_starts(), vec![0]);
+    assert_eq!(test_wallet.notes(false, None, None, None).await?.len(), 3);
+    assert!(other_wallet
+        .notes(false, None, None, None)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn adding_a_wallet_behind_the_stream_restarts_it() -> anyhow::Result<()> {
+    let (node, url) = MockNode::start().await?;
+    let service = MultiViewService::new(url, None, None).await?;
+
+    let test_wallet = storage(&test_keys::FULL_VIEWING_KEY, CHAIN_ID).await?;
+    service.add_storage(test_wallet.clone()).await?;
+    node.produce_blocks(3);
+    wait_for_sync(&test_wallet, 2).await;
+
+    // The new wallet needs the blocks the stream has already passed, so it's restarted from the
+    // height the new wallet needs.
+    let other_wallet = storage(&random_fvk(), CHAIN_ID).await?;
+    service.add_storage(other_wallet.clone()).await?;
+    wait_for_sync(&other_wallet, 2).await;
+    assert_eq!(node.stream_starts(), vec![0, 0]);
+
+    // Both wallets keep syncing from the restarted stream, without the first one rescanning the
+    // blocks it had already scanned.
+    node.produce_blocks(1);
+    wait_for_sync(&test_wallet, 3).await;
+    wait_for_sync(&other_wallet, 3).await;
+    assert_eq!(test_wallet.notes(false, None, None, None).await?.len(), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn removing_a_wallet_deletes_its_storage() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let storage_dir =
+        Utf8PathBuf::from_path_buf(dir.path().to_path_buf()).expect("temporary path is UTF-8");
+    let (node, url) = MockNode::start().await?;
+    let service = MultiViewService::new(url, Some(storage_dir.clone()), None).await?;
+
+    let test_wallet = storage(&test_keys::FULL_VIEWING_KEY, CHAIN_ID).await?;
+    let test_id = service.add_storage(test_wallet.clone()).await?;
+    let other_id = service.add_wallet(&random_fvk()).await?;
+    let other_path = storage_dir.join(format!("{other_id}.sqlite"));
+    assert!(other_path.exists());
+
+    node.produce_blocks(2);
+    wait_for_sync(&test_wallet, 1).await;
+
+    // Only wallets added at runtime can be removed.
+    assert!(service.remove_wallet(&test_id).await.is_err());
+
+    service.remove_wallet(&other_id).await?;
+    assert!(!other_path.exists());
+    assert_eq!(service.wallets().await, vec![test_id]);
+    assert_eq!(
+        default_address(&service, Some(other_id))
+            .await
+            .unwrap_err()
+            .code(),
+        tonic::Code::NotFound
+    );
+    assert!(service.remove_wallet(&other_id).await.is_err());
+
+    // The remaining wallet keeps syncing.
+    node.produce_blocks(1);
+    wait_for_sync(&test_wallet, 2).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn requests_are_routed_by_account_group_id() -> anyhow::Result<()> {
+    let (_node, url) = MockNode::start().await?;
+    let service = MultiViewService::new(url, None, None).await?;
+
+    let test_fvk = &*test_keys::FULL_VIEWING_KEY;
+    let test_id = service
+        .add_storage(storage(test_fvk, CHAIN_ID).await?)
+        .await?;
+
+    // While a single wallet is synced, the account group ID can be omitted.
+    assert_eq!(
+        default_address(&service, None).await?,
+        test_fvk.payment_address(0u32.into()).0
+    );
+
+    let other_fvk = random_fvk();
+    let other_id = service
+        .add_storage(storage(&other_fvk, CHAIN_ID).await?)
+        .await?;
+
+    assert_eq!(
+        default_address(&service, Some(test_id)).await?,
+        test_fvk.payment_address(0u32.into()).0
+    );
+    assert_eq!(
+        default_address(&service, Some(other_id)).await?,
+        other_fvk.payment_address(0u32.into()).0
+    );
+
+    // Once several wallets are synced, requests must say which one they're for.
+    assert_eq!(
+        default_address(&service, None).await.unwrap_err().code(),
+        tonic::Code::InvalidArgument
+    );
+    assert_eq!(
+        default_address(&service, Some(random_fvk().account_group_id()))
+            .await
+            .unwrap_err()
+            .code(),
+        tonic::Code::NotFound
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallets_for_another_chain_are_rejected() -> anyhow::Result<()> {
+    let (_node, url) = MockNode::start().await?;
+    let service = MultiViewService::new(url, None, None).await?;
+
+    let test_id = service
+        .add_storage(storage(&test_keys::FULL_VIEWING_KEY, CHAIN_ID).await?)
+        .await?;
+
+    let error = service
+        .add_storage(storage(&random_fvk(), "another-chain").await?)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("is for chain another-chain"));
+    assert_eq!(service.wallets().await, vec![test_id]);
+
+    Ok(())
+}
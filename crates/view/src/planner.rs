@@ -6,12 +6,12 @@ use std::{
 
 use anyhow::{anyhow, Result};
 
-use penumbra_asset::{asset::DenomMetadata, Balance, Value};
+use penumbra_asset::{asset::DenomMetadata, Balance, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_chain::params::{ChainParameters, FmdParameters};
 use penumbra_dao::DaoDeposit;
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
-    lp::plan::PositionWithdrawPlan,
+    lp::plan::{PositionRewardClaimPlan, PositionWithdrawPlan},
     lp::position::{self, Position},
     lp::Reserves,
     swap::SwapPlaintext,
@@ -19,7 +19,8 @@ use penumbra_dex::{
     swap_claim::SwapClaimPlan,
     TradingPair,
 };
-use penumbra_fee::Fee;
+use penumbra_fee::{Fee, Gas, GasCost};
+use penumbra_governance::VotingReceiptToken;
 use penumbra_ibc::{IbcAction, Ics20Withdrawal};
 use penumbra_keys::{
     keys::{AccountGroupId, AddressIndex},
@@ -27,7 +28,10 @@ use penumbra_keys::{
 };
 use penumbra_num::Amount;
 use penumbra_proto::view::v1alpha1::{NotesForVotingRequest, NotesRequest};
-use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+use penumbra_shielded_pool::{
+    gas::{output_gas_cost, spend_gas_cost},
+    Note, OutputPlan, SpendPlan,
+};
 use penumbra_stake::{rate::RateData, validator};
 use penumbra_stake::{IdentityKey, UndelegateClaimPlan};
 use penumbra_tct as tct;
@@ -35,6 +39,7 @@ use penumbra_transaction::{
     action::{
         Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote, Vote,
     },
+    gas::{clue_gas_cost, delegator_vote_gas_cost, memo_gas_cost, transaction_base_gas_cost},
     memo::MemoPlaintext,
     plan::{ActionPlan, DelegatorVotePlan, MemoPlan, TransactionPlan},
     proposal,
@@ -144,14 +149,28 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
     /// Add a fee to the transaction plan.
     ///
+    /// The fee may be paid in the staking token, or in any asset allowed by the
+    /// chain parameters, which is checked when the plan is finished.
+    ///
+    /// A fee paid in the staking token is the least that will be paid: when the
+    /// transaction is [`plan`](Planner::plan)ned, it is raised to the minimum
+    /// fee for the gas the transaction uses, if it is lower.  So if no fee is
+    /// set, the minimum fee is paid.
+    ///
     /// This function should be called once.
     #[instrument(skip(self))]
     pub fn fee(&mut self, fee: Fee) -> &mut Self {
-        self.balance += fee.0;
+        self.balance -= fee.0;
         self.plan.fee = fee;
         self
     }
 
+    /// Replace the fee of the transaction plan, adjusting the balance to match.
+    fn replace_fee(&mut self, fee: Fee) {
+        self.balance += self.plan.fee.0;
+        self.fee(fee);
+    }
+
     /// Spend a specific positioned note in the transaction.
     ///
     /// If you don't use this method to specify spends, they will be filled in automatically from
@@ -193,6 +212,19 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Claim the rewards accumulated by a withdrawn liquidity position.
+    #[instrument(skip(self))]
+    pub fn position_reward_claim(
+        &mut self,
+        position_id: position::Id,
+        rewards: Amount,
+    ) -> &mut Self {
+        self.action(ActionPlan::PositionRewardClaim(
+            PositionRewardClaimPlan::new(position_id, rewards),
+        ));
+        self
+    }
+
     /// Perform a swap claim based on an input swap NFT with a pre-paid fee.
     #[instrument(skip(self))]
     pub fn swap_claim(&mut self, plan: SwapClaimPlan) -> &mut Self {
@@ -433,17 +465,34 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // Gather all the information needed from the view service
         let chain_params = view.chain_params().await?;
         let fmd_params = view.fmd_parameters().await?;
-        let mut spendable_notes = Vec::new();
-        let mut voting_notes = Vec::new();
-        let (spendable_requests, voting_requests) = self.notes_requests(account_group_id, source);
-        for request in spendable_requests {
-            let notes = view.notes(request).await?;
-            spendable_notes.extend(notes);
-        }
-        for request in voting_requests {
-            let notes = view.notes_for_voting(request).await?;
-            voting_notes.push(notes);
-        }
+        let (spendable_notes, voting_notes) = loop {
+            let mut spendable_notes = Vec::new();
+            let mut voting_notes = Vec::new();
+            let (spendable_requests, voting_requests) =
+                self.notes_requests(account_group_id, source);
+            for request in spendable_requests {
+                let notes = view.notes(request).await?;
+                spendable_notes.extend(notes);
+            }
+            for request in voting_requests {
+                let notes = view.notes_for_voting(request).await?;
+                voting_notes.push(notes);
+            }
+
+            // The minimum fee depends on the gas used by the transaction, which depends on the
+            // notes spent to pay for it, so raising the fee may require spending more notes: keep
+            // going until the fee covers the gas of the transaction spending the notes we have.
+            let fee_asset_id = self.plan.fee.asset_id();
+            let minimum_fee = self
+                .gas_estimate(&spendable_notes, &voting_notes)
+                .minimum_fee_in(&chain_params, fee_asset_id)
+                .ok_or_else(|| anyhow!("fees cannot be paid in asset {}", fee_asset_id))?;
+            if self.plan.fee.amount() >= minimum_fee.amount() {
+                break (spendable_notes, voting_notes);
+            }
+            tracing::debug!(?minimum_fee, fee = ?self.plan.fee, "raising fee to minimum fee");
+            self.replace_fee(minimum_fee);
+        };
 
         // Plan the transaction using the gathered information
 
@@ -457,6 +506,52 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         )
     }
 
+    /// Estimate the gas used by the transaction that would be planned with the spendable and
+    /// votable notes provided, including the change outputs, memo, and clues it would need.
+    fn gas_estimate(
+        &self,
+        spendable_notes: &[SpendableNoteRecord],
+        votable_notes: &[Vec<(SpendableNoteRecord, IdentityKey)>],
+    ) -> Gas {
+        let mut gas = transaction_base_gas_cost();
+        gas += self.plan.actions.iter().map(GasCost::gas_cost).sum();
+        gas += self.ibc_actions.iter().map(GasCost::gas_cost).sum();
+
+        // Track the balance of the spends and votes that would be added, to count change outputs
+        let mut balance = self.balance.clone();
+        for record in spendable_notes {
+            gas += spend_gas_cost();
+            balance += record.note.value();
+        }
+        for (records, (proposal, VoteIntent { rate_data, .. })) in
+            votable_notes.iter().zip(self.vote_intents.iter())
+        {
+            for (record, identity_key) in records {
+                let Some(rate_data) = rate_data.get(identity_key) else { continue };
+                if record.height_spent.is_none() {
+                    gas += spend_gas_cost();
+                    balance += record.note.value();
+                }
+                gas += delegator_vote_gas_cost();
+                balance += Value {
+                    amount: rate_data
+                        .unbonded_amount(record.note.amount().value())
+                        .into(),
+                    asset_id: VotingReceiptToken::new(*proposal).id(),
+                };
+            }
+        }
+
+        let num_outputs = (self.plan.num_outputs() + balance.provided().count()) as u64;
+        gas += output_gas_cost() * num_outputs;
+        if num_outputs > 0 || self.plan.memo_plan.is_some() {
+            gas += memo_gas_cost();
+        }
+        gas += clue_gas_cost() * num_outputs;
+
+        gas
+    }
+
     /// Add spends and change outputs as required to balance the transaction, using the spendable
     /// notes provided. It is the caller's responsibility to ensure that the notes are the result of
     /// collected responses to the requests generated by an immediately preceding call to
@@ -484,6 +579,15 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // Fill in the chain id based on the view service
         self.plan.chain_id = chain_params.chain_id.clone();
 
+        // Check that the fee can be paid in the chosen asset, since otherwise the
+        // transaction would be rejected by the chain.
+        let fee_asset_id = self.plan.fee.asset_id();
+        if fee_asset_id != *STAKING_TOKEN_ASSET_ID
+            && !chain_params.allowed_fee_assets.contains(&fee_asset_id)
+        {
+            anyhow::bail!("fees cannot be paid in asset {}", fee_asset_id);
+        }
+
         // Add the required spends to the planner
         for record in spendable_notes {
             self.spend(record.note, record.position);
@@ -587,6 +691,21 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self.plan
             .add_all_clue_plans(&mut self.rng, precision_bits.into());
 
+        // Now that the transaction is complete, check that the fee covers its gas, since
+        // otherwise the transaction would be rejected by the chain.
+        let minimum_fee = self
+            .plan
+            .gas_cost()
+            .minimum_fee_in(chain_params, fee_asset_id)
+            .ok_or_else(|| anyhow!("fees cannot be paid in asset {}", fee_asset_id))?;
+        if self.plan.fee.amount() < minimum_fee.amount() {
+            anyhow::bail!(
+                "fee {} is less than the minimum fee {} for the transaction",
+                self.plan.fee.amount(),
+                minimum_fee.amount()
+            );
+        }
+
         tracing::debug!(plan = ?self.plan, "finished balancing transaction");
 
         // Clear the planner and pull out the plan to return
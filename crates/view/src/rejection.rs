@@ -0,0 +1,131 @@
+use penumbra_app::TransactionError;
+use tonic::metadata::MetadataMap;
+
+/// The gRPC metadata key carrying the ABCI codespace of a rejection.
+const CODESPACE_KEY: &str = "penumbra-rejection-codespace";
+/// The gRPC metadata key carrying the ABCI code of a rejection.
+const CODE_KEY: &str = "penumbra-rejection-code";
+
+/// A transaction rejected by the fullnode, or by the view service's own
+/// pre-submission checks.
+///
+/// The view service reports rejections as `InvalidArgument` errors from
+/// `BroadcastTransaction`, with the ABCI code and codespace of the rejection in
+/// the response metadata, so that clients can tell why a transaction was
+/// rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRejection {
+    /// The ABCI codespace of the `code`.
+    pub codespace: String,
+    /// The ABCI code of the rejection.
+    pub code: u32,
+    /// The log message explaining the rejection.
+    pub log: String,
+}
+
+impl TransactionRejection {
+    /// Creates a rejection of a transaction that failed the transaction checks with `error`.
+    pub fn from_error(error: &anyhow::Error) -> Self {
+        Self {
+            codespace: TransactionError::CODESPACE.to_string(),
+            code: TransactionError::abci_code(error),
+            // Use the alternate format specifier to include the chain of error causes.
+            log: format!("{error:#}"),
+        }
+    }
+
+    /// The category of the rejection, if it was rejected by the transaction checks.
+    pub fn category(&self) -> Option<TransactionError> {
+        if self.codespace == TransactionError::CODESPACE {
+            TransactionError::from_code(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Reads the rejection reported by `status`, if it reports one.
+    pub fn from_status(status: &tonic::Status) -> Option<Self> {
+        let metadata = status.metadata();
+        let codespace = metadata.get(CODESPACE_KEY)?.to_str().ok()?;
+        let code = metadata.get(CODE_KEY)?.to_str().ok()?.parse().ok()?;
+
+        Some(Self {
+            codespace: codespace.to_string(),
+            code,
+            log: status.message().to_string(),
+        })
+    }
+
+    /// Reports the rejection as a gRPC status.
+    pub fn to_status(&self) -> tonic::Status {
+        let mut metadata = MetadataMap::new();
+        // Codespaces are chosen by the application, so they're always ASCII in
+        // practice, but if one isn't, leave it out rather than fail.
+        if let Ok(codespace) = self.codespace.parse() {
+            metadata.insert(CODESPACE_KEY, codespace);
+        }
+        if let Ok(code) = self.code.to_string().parse() {
+            metadata.insert(CODE_KEY, code);
+        }
+
+        tonic::Status::with_metadata(tonic::Code::InvalidArgument, self.log.clone(), metadata)
+    }
+}
+
+impl std::fmt::Display for TransactionRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.category() {
+            Some(category) => write!(f, "transaction rejected ({category}): {}", self.log),
+            None => write!(
+                f,
+                "transaction rejected (code {} in codespace {:?}): {}",
+                self.code, self.codespace, self.log
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TransactionRejection {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+
+    use super::*;
+
+    #[test]
+    fn rejection_round_trips_through_status() {
+        let error: anyhow::Result<()> = Err(anyhow::anyhow!("fee of 0 doesn't cover gas"));
+        let error = error
+            .context(TransactionError::InsufficientFee)
+            .unwrap_err();
+        let rejection = TransactionRejection::from_error(&error);
+        assert_eq!(
+            rejection.category(),
+            Some(TransactionError::InsufficientFee)
+        );
+
+        let status = rejection.to_status();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(TransactionRejection::from_status(&status), Some(rejection));
+    }
+
+    #[test]
+    fn rejection_from_other_codespace_has_no_category() {
+        let rejection = TransactionRejection {
+            codespace: "mempool".to_string(),
+            code: TransactionError::InsufficientFee.code(),
+            log: "mempool is over its rate limit, try again later".to_string(),
+        };
+        assert_eq!(rejection.category(), None);
+
+        let status = rejection.to_status();
+        assert_eq!(TransactionRejection::from_status(&status), Some(rejection));
+    }
+
+    #[test]
+    fn status_without_rejection_metadata() {
+        let status = tonic::Status::invalid_argument("some other error");
+        assert_eq!(TransactionRejection::from_status(&status), None);
+    }
+}
@@ -44,7 +44,7 @@ use tonic::{async_trait, transport::Channel};
 use tracing::instrument;
 use url::Url;
 
-use crate::{Planner, Storage, Worker};
+use crate::{worker::WalletState, Planner, Storage, TransactionRejection, Worker};
 
 /// A service that synchronizes private chain state and responds to queries
 /// about it.
@@ -89,11 +89,45 @@ impl ViewService {
     /// by this method, rather than calling it multiple times.  That way, each clone
     /// will be backed by the same scanning task, rather than each spawning its own.
     pub async fn new(storage: Storage, node: Url) -> Result<Self, anyhow::Error> {
+        Self::new_inner(storage, node, None).await
+    }
+
+    /// Constructs a new [`ViewService`] like [`Self::new`], but whose sync task uses the
+    /// [`DetectionService`](crate::DetectionService) at `detection_service` to find the
+    /// candidate transactions in each block, only trial-decrypting the notes they created.
+    ///
+    /// Since detection keys are specific to an address, only notes sent to addresses in use by the
+    /// wallet will be found: the default address of each account it has used, the other addresses
+    /// it has received notes at, and the addresses issued in response to `AddressByIndex` and
+    /// `EphemeralAddress` requests, which are registered as they're issued. Notes sent to
+    /// addresses derived elsewhere will be missed.
+    pub async fn with_detection_service(
+        storage: Storage,
+        node: Url,
+        detection_service: Url,
+    ) -> Result<Self, anyhow::Error> {
+        Self::new_inner(storage, node, Some(detection_service)).await
+    }
+
+    async fn new_inner(
+        storage: Storage,
+        node: Url,
+        detection_service: Option<Url>,
+    ) -> Result<Self, anyhow::Error> {
         let (worker, sct, error_slot, sync_height_rx) =
-            Worker::new(storage.clone(), node.clone()).await?;
+            Worker::new(storage.clone(), node.clone(), detection_service).await?;
 
         tokio::spawn(worker.run());
 
+        Self::with_wallet_state(storage, node, (sct, error_slot, sync_height_rx)).await
+    }
+
+    /// Constructs a new [`ViewService`] reading the state of a wallet synced by a shared worker.
+    pub(crate) async fn with_wallet_state(
+        storage: Storage,
+        node: Url,
+        (sct, error_slot, sync_height_rx): WalletState,
+    ) -> Result<Self, anyhow::Error> {
         let fvk = storage.full_viewing_key().await?;
         let account_group_id = fvk.account_group_id();
 
@@ -151,10 +185,10 @@ impl ViewService {
         use penumbra_app::ActionHandler;
 
         // 1. Pre-check the transaction for (stateless) validity.
-        transaction
-            .check_stateless(())
-            .await
-            .context("transaction pre-submission checks failed")?;
+        if let Err(e) = transaction.check_stateless(()).await {
+            let e = e.context("transaction pre-submission checks failed");
+            return Err(TransactionRejection::from_error(&e).into());
+        }
 
         // 2. Broadcast the transaction to the network.
         // Note that "synchronous" here means "wait for the tx to be accepted by
@@ -169,11 +203,12 @@ impl ViewService {
             .into_inner();
         tracing::info!(?node_rsp);
         if node_rsp.code != 0 {
-            return Err(anyhow::anyhow!(
-                "Error submitting transaction: code {}, log: {}",
-                node_rsp.code,
-                node_rsp.log,
-            ));
+            return Err(TransactionRejection {
+                codespace: node_rsp.codespace,
+                code: node_rsp.code.try_into()?,
+                log: node_rsp.log,
+            }
+            .into());
         }
 
         // 3. Optionally wait for the transaction to be detected by the view service.
@@ -308,7 +343,10 @@ impl ViewProtocolService for ViewService {
         let pb::BroadcastTransactionRequest {
             transaction,
             await_detection,
+            account_group_id,
         } = request.into_inner();
+        self.check_account_group_id(account_group_id.as_ref())
+            .await?;
 
         let transaction: Transaction = transaction
             .ok_or_else(|| tonic::Status::invalid_argument("missing transaction"))?
@@ -319,8 +357,11 @@ impl ViewProtocolService for ViewService {
         let id = self
             .broadcast_transaction(transaction, await_detection)
             .await
-            .map_err(|e| {
-                tonic::Status::internal(format!("could not broadcast transaction: {:#}", e))
+            .map_err(|e| match e.downcast::<TransactionRejection>() {
+                Ok(rejection) => rejection.to_status(),
+                Err(e) => {
+                    tonic::Status::internal(format!("could not broadcast transaction: {:#}", e))
+                }
             })?;
 
         let detection_height = if await_detection {
@@ -461,6 +502,8 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::AddressByIndexRequest>,
     ) -> Result<tonic::Response<pb::AddressByIndexResponse>, tonic::Status> {
+        self.check_account_group_id(request.get_ref().account_group_id.as_ref())
+            .await?;
         let fvk =
             self.storage.full_viewing_key().await.map_err(|_| {
                 tonic::Status::failed_precondition("Error retrieving full viewing key")
@@ -475,6 +518,12 @@ impl ViewProtocolService for ViewService {
                 tonic::Status::invalid_argument(format!("Could not parse address index: {e:#}"))
             })?;
 
+        // Record the address as issued, so that a detection service is asked for its notes.
+        self.storage
+            .record_issued_address(address_index)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error recording address: {e:#}")))?;
+
         Ok(tonic::Response::new(pb::AddressByIndexResponse {
             address: Some(fvk.payment_address(address_index).0.into()),
         }))
@@ -484,6 +533,8 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::IndexByAddressRequest>,
     ) -> Result<tonic::Response<pb::IndexByAddressResponse>, tonic::Status> {
+        self.check_account_group_id(request.get_ref().account_group_id.as_ref())
+            .await?;
         let fvk =
             self.storage.full_viewing_key().await.map_err(|_| {
                 tonic::Status::failed_precondition("Error retrieving full viewing key")
@@ -507,6 +558,8 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::EphemeralAddressRequest>,
     ) -> Result<tonic::Response<pb::EphemeralAddressResponse>, tonic::Status> {
+        self.check_account_group_id(request.get_ref().account_group_id.as_ref())
+            .await?;
         let fvk =
             self.storage.full_viewing_key().await.map_err(|_| {
                 tonic::Status::failed_precondition("Error retrieving full viewing key")
@@ -521,8 +574,17 @@ impl ViewProtocolService for ViewService {
                 tonic::Status::invalid_argument(format!("Could not parse address index: {e:#}"))
             })?;
 
+        let (address, _dtk) = fvk.ephemeral_address(OsRng, address_index);
+
+        // Each ephemeral address has its own randomized index, and detection key, so record it as
+        // issued, so that a detection service is asked for its notes.
+        self.storage
+            .record_issued_address(fvk.incoming().index_for_diversifier(address.diversifier()))
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error recording address: {e:#}")))?;
+
         Ok(tonic::Response::new(pb::EphemeralAddressResponse {
-            address: Some(fvk.ephemeral_address(OsRng, address_index).0.into()),
+            address: Some(address.into()),
         }))
     }
 
@@ -531,6 +593,8 @@ impl ViewProtocolService for ViewService {
         request: tonic::Request<pb::TransactionInfoByHashRequest>,
     ) -> Result<tonic::Response<pb::TransactionInfoByHashResponse>, tonic::Status> {
         self.check_worker().await?;
+        self.check_account_group_id(request.get_ref().account_group_id.as_ref())
+            .await?;
 
         let request = request.into_inner();
 
@@ -723,6 +787,8 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::BalancesRequest>,
     ) -> Result<tonic::Response<Self::BalancesStream>, tonic::Status> {
+        self.check_account_group_id(request.get_ref().account_group_id.as_ref())
+            .await?;
         let request = request.into_inner();
 
         let account_filter = request.account_filter.map_or(None, |x| {
@@ -1042,6 +1108,8 @@ impl ViewProtocolService for ViewService {
         request: tonic::Request<pb::TransactionInfoRequest>,
     ) -> Result<tonic::Response<Self::TransactionInfoStream>, tonic::Status> {
         self.check_worker().await?;
+        self.check_account_group_id(request.get_ref().account_group_id.as_ref())
+            .await?;
         // Fetch transactions from storage.
         let txs = self
             .storage
@@ -1055,6 +1123,7 @@ impl ViewProtocolService for ViewService {
 
                 let rsp = self2.transaction_info_by_hash(tonic::Request::new(pb::TransactionInfoByHashRequest {
                     id: Some(tx.2.id().into()),
+                    account_group_id: None,
                 })).await?.into_inner();
 
                 yield pb::TransactionInfoResponse {
@@ -1158,7 +1227,10 @@ impl ViewProtocolService for ViewService {
         let pb::WitnessAndBuildRequest {
             transaction_plan,
             authorization_data,
+            account_group_id,
         } = request.into_inner();
+        self.check_account_group_id(account_group_id.as_ref())
+            .await?;
 
         let transaction_plan: TransactionPlan = transaction_plan
             .ok_or_else(|| tonic::Status::invalid_argument("missing transaction plan"))?
@@ -1271,7 +1343,10 @@ impl ViewProtocolService for ViewService {
         let pb::OwnedPositionIdsRequest {
             position_state,
             trading_pair,
+            account_group_id,
         } = request.into_inner();
+        self.check_account_group_id(account_group_id.as_ref())
+            .await?;
 
         let position_state: Option<position::State> = position_state
             .map(|state| state.try_into())
@@ -27,7 +27,13 @@ use r2d2_sqlite::{
     SqliteConnectionManager,
 };
 use sha2::{Digest, Sha256};
-use std::{collections::BTreeMap, num::NonZeroU64, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroU64,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 use tct::StateCommitment;
 use tokio::{
     sync::broadcast::{self, error::RecvError},
@@ -60,6 +66,7 @@ pub struct Storage {
     scanned_notes_tx: tokio::sync::broadcast::Sender<SpendableNoteRecord>,
     scanned_nullifiers_tx: tokio::sync::broadcast::Sender<Nullifier>,
     scanned_swaps_tx: tokio::sync::broadcast::Sender<SwapRecord>,
+    issued_addresses_tx: tokio::sync::broadcast::Sender<AddressIndex>,
 }
 
 impl Storage {
@@ -132,6 +139,7 @@ impl Storage {
             scanned_notes_tx: broadcast::channel(128).0,
             scanned_nullifiers_tx: broadcast::channel(512).0,
             scanned_swaps_tx: broadcast::channel(128).0,
+            issued_addresses_tx: broadcast::channel(128).0,
         };
 
         spawn_blocking(move || {
@@ -222,6 +230,7 @@ impl Storage {
                 scanned_notes_tx: broadcast::channel(128).0,
                 scanned_nullifiers_tx: broadcast::channel(512).0,
                 scanned_swaps_tx: broadcast::channel(128).0,
+                issued_addresses_tx: broadcast::channel(128).0,
             })
         })
         .await?
@@ -532,6 +541,57 @@ impl Storage {
         .await?
     }
 
+    /// The indices of every address which has received a note recorded in storage, or which has
+    /// been issued with [`Storage::record_issued_address`].
+    pub async fn address_indices(&self) -> anyhow::Result<BTreeSet<AddressIndex>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT address_index FROM spendable_notes
+                    UNION
+                    SELECT address_index FROM issued_addresses",
+                )?
+                .query_and_then([], |row| {
+                    let address_index: Vec<u8> = row.get("address_index")?;
+                    AddressIndex::try_from(address_index.as_slice())
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records that the address with the given `address_index` has been handed out, notifying
+    /// the subscribers of [`Storage::issued_addresses`] if it wasn't already recorded.
+    pub async fn record_issued_address(&self, address_index: AddressIndex) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let address_index_bytes = address_index.to_bytes().to_vec();
+
+        let inserted = spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT OR IGNORE INTO issued_addresses (address_index) VALUES (?1)",
+                    [address_index_bytes],
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        if inserted > 0 {
+            // This will fail to be broadcast if there is no active receiver, which is fine.
+            let _ = self.issued_addresses_tx.send(address_index);
+        }
+
+        Ok(())
+    }
+
+    /// Subscribes to the indices of the addresses newly recorded with
+    /// [`Storage::record_issued_address`].
+    pub fn issued_addresses(&self) -> broadcast::Receiver<AddressIndex> {
+        self.issued_addresses_tx.subscribe()
+    }
+
     pub async fn state_commitment_tree(&self) -> anyhow::Result<tct::Tree> {
         let pool = self.pool.clone();
         spawn_blocking(move || {
@@ -104,6 +104,12 @@ CREATE INDEX spendable_notes_idx ON spendable_notes (
     height_spent       -- null if unspent, so spent/unspent is first
 );
 
+-- the addresses handed out by the view service, whose notes need to be detected
+-- even before any have been received
+CREATE TABLE issued_addresses (
+    address_index           BLOB PRIMARY KEY NOT NULL
+);
+
 -- This table records the mapping from swap commitments to swap plaintexts.
 -- For now we just store the swap plaintexts as a blob.
 CREATE TABLE swaps (
@@ -5,12 +5,11 @@ use penumbra_chain::{
     NoteSource,
 };
 use penumbra_compact_block::{CompactBlock, StatePayload};
-use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
+use penumbra_dex::swap::SwapPlaintext;
 use penumbra_keys::FullViewingKey;
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{Note, NotePayload};
 use penumbra_tct as tct;
-use tracing::Instrument;
 
 use crate::{SpendableNoteRecord, Storage, SwapRecord};
 
@@ -38,6 +37,92 @@ impl FilteredBlock {
     }
 }
 
+/// The notes and swaps meant for a full viewing key, found by trial-decrypting a compact block.
+#[derive(Debug, Clone, Default)]
+pub struct DecryptedBlock {
+    pub notes: BTreeMap<tct::StateCommitment, Note>,
+    pub swaps: BTreeMap<tct::StateCommitment, SwapPlaintext>,
+}
+
+/// Trial-decrypts the notes and swaps in a batch of compact blocks with `fvk`, returning the ones
+/// meant for it in each block, in the same order as the blocks.
+///
+/// Each block is given along with the IDs of its candidate transactions, if they were detected by
+/// a [`DetectionService`](crate::DetectionService), in which case notes created by any other
+/// transaction are not trial-decrypted.
+///
+/// The notes and swaps of all the blocks are trial-decrypted together, so that catching up on a
+/// long chain is spread across threads if the `parallel` feature is enabled. This is CPU-bound,
+/// so async callers should run it on a blocking thread.
+pub fn trial_decrypt_blocks<'a>(
+    fvk: &FullViewingKey,
+    blocks: impl IntoIterator<Item = (&'a CompactBlock, Option<&'a BTreeSet<[u8; 32]>>)>,
+) -> Vec<DecryptedBlock> {
+    let mut decrypted_blocks = Vec::new();
+    // The note payloads of all the blocks, and the index of the block each one is from.
+    let mut note_payloads = Vec::new();
+    let mut note_blocks = Vec::new();
+    // The swap payloads of all the blocks, each with the index of the block it's from.
+    let mut swap_payloads = Vec::new();
+
+    for (block, candidates) in blocks {
+        for payload in block.state_payloads.iter() {
+            match payload {
+                StatePayload::Note { note, source } => {
+                    // If a detection service has told us which transactions are candidates for
+                    // this block, we only need to trial-decrypt the notes they created.
+                    if let (Some(candidates), NoteSource::Transaction { id }) = (candidates, source)
+                    {
+                        if !candidates.contains(id) {
+                            continue;
+                        }
+                    }
+                    note_payloads.push((**note).clone());
+                    note_blocks.push(decrypted_blocks.len());
+                }
+                StatePayload::Swap { swap, .. } => {
+                    swap_payloads.push((decrypted_blocks.len(), swap));
+                }
+                StatePayload::RolledUp(_) => {}
+            }
+        }
+
+        decrypted_blocks.push(DecryptedBlock::default());
+    }
+
+    let notes = NotePayload::trial_decrypt_batch(&note_payloads, fvk);
+    for (index, note) in note_blocks.into_iter().zip(notes) {
+        if let Some(note) = note {
+            decrypted_blocks[index].notes.insert(note.commit(), note);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    let swaps = {
+        use rayon::prelude::*;
+        swap_payloads
+            .into_par_iter()
+            .filter_map(|(index, swap)| Some((index, swap.trial_decrypt(fvk)?)))
+            .collect::<Vec<_>>()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let swaps = swap_payloads
+        .into_iter()
+        .filter_map(|(index, swap)| Some((index, swap.trial_decrypt(fvk)?)))
+        .collect::<Vec<_>>();
+    for (index, swap) in swaps {
+        decrypted_blocks[index]
+            .swaps
+            .insert(swap.swap_commitment(), swap);
+    }
+
+    decrypted_blocks
+}
+
+/// Scans a compact block for notes and swaps meant for `fvk`, inserting its commitments into the
+/// `state_commitment_tree`.
+///
+/// The `decrypted` notes and swaps are those found in the block by [`trial_decrypt_blocks`].
 #[tracing::instrument(skip_all, fields(height = %height))]
 pub async fn scan_block(
     fvk: &FullViewingKey,
@@ -55,61 +140,24 @@ pub async fn scan_block(
         // proposal_started,
         ..
     }: CompactBlock,
+    DecryptedBlock { notes, swaps }: DecryptedBlock,
     storage: &Storage,
 ) -> anyhow::Result<FilteredBlock> {
-    // Trial-decrypt a note with our own specific viewing key
-    let trial_decrypt_note = |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<Note>> {
-        // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-        // need this so the task is 'static and not dependent on key lifetime
-        let fvk2 = fvk.clone();
-        tokio::spawn(
-            async move { note_payload.trial_decrypt(&fvk2) }.instrument(tracing::Span::current()),
-        )
-    };
-    // Trial-decrypt a swap with our own specific viewing key
-    let trial_decrypt_swap =
-        |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<SwapPlaintext>> {
-            // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-            // need this so the task is 'static and not dependent on key lifetime
-            let fvk2 = fvk.clone();
-            tokio::spawn(
-                async move { swap_payload.trial_decrypt(&fvk2) }
-                    .instrument(tracing::Span::current()),
-            )
-        };
-
     // Nullifiers we've found in this block
     let spent_nullifiers: Vec<Nullifier> = nullifiers;
 
-    // Trial-decrypt the notes in this block, keeping track of the ones that were meant for us
-    let mut note_decryptions = Vec::new();
-    let mut swap_decryptions = Vec::new();
-    let mut unknown_commitments = Vec::new();
-
-    for payload in state_payloads.iter() {
-        match payload {
-            StatePayload::Note { note, .. } => {
-                note_decryptions.push(trial_decrypt_note((**note).clone()));
-            }
-            StatePayload::Swap { swap, .. } => {
-                swap_decryptions.push(trial_decrypt_swap((**swap).clone()));
-            }
-            StatePayload::RolledUp(commitment) => unknown_commitments.push(*commitment),
-        }
-    }
-    // Having started trial decryption in the background, ask the Storage for scanning advice:
+    // Ask the Storage for scanning advice about the commitments we can't trial-decrypt, and
+    // combine it with the notes and swaps we found meant for us.
+    let unknown_commitments = state_payloads
+        .iter()
+        .filter_map(|payload| match payload {
+            StatePayload::RolledUp(commitment) => Some(*commitment),
+            _ => None,
+        })
+        .collect();
     let mut note_advice = storage.scan_advice(unknown_commitments).await?;
-    for decryption in note_decryptions {
-        if let Some(note) = decryption.await.unwrap() {
-            note_advice.insert(note.commit(), note);
-        }
-    }
-    let mut swap_advice = BTreeMap::new();
-    for decryption in swap_decryptions {
-        if let Some(swap) = decryption.await.unwrap() {
-            swap_advice.insert(swap.swap_commitment(), swap);
-        }
-    }
+    note_advice.extend(notes);
+    let swap_advice = swaps;
 
     // Newly detected spendable notes.
     let mut new_notes = Vec::new();
@@ -238,3 +286,159 @@ pub async fn scan_block(
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_chain::test_keys;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    const CANDIDATE: [u8; 32] = [1; 32];
+    const OTHER: [u8; 32] = [2; 32];
+
+    /// A compact block at `height` with a note sent to the test wallet from each of `sources`.
+    fn block_with_notes(height: u64, sources: &[NoteSource]) -> CompactBlock {
+        let state_payloads = sources
+            .iter()
+            .map(|source| {
+                let note = Note::generate(
+                    &mut OsRng,
+                    &test_keys::ADDRESS_0,
+                    Value {
+                        amount: 1u64.into(),
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    },
+                );
+                StatePayload::Note {
+                    source: *source,
+                    note: Box::new(note.payload()),
+                }
+            })
+            .collect();
+
+        CompactBlock {
+            height,
+            state_payloads,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn candidate_filter_skips_notes_of_other_transactions() {
+        let block = block_with_notes(
+            1,
+            &[
+                NoteSource::Transaction { id: CANDIDATE },
+                NoteSource::Transaction { id: OTHER },
+                NoteSource::Genesis,
+            ],
+        );
+        let commitments = block
+            .state_payloads
+            .iter()
+            .map(|payload| *payload.commitment())
+            .collect::<Vec<_>>();
+
+        // Without candidates, every note is trial-decrypted.
+        let decrypted = trial_decrypt_blocks(&test_keys::FULL_VIEWING_KEY, [(&block, None)]);
+        assert_eq!(decrypted[0].notes.len(), 3);
+
+        // With candidates, notes created by other transactions are skipped, but notes which
+        // weren't created by a transaction have no clue to detect, so they're always decrypted.
+        let candidates = BTreeSet::from([CANDIDATE]);
+        let decrypted =
+            trial_decrypt_blocks(&test_keys::FULL_VIEWING_KEY, [(&block, Some(&candidates))]);
+        let notes = &decrypted[0].notes;
+        assert_eq!(notes.len(), 2);
+        assert!(notes.contains_key(&commitments[0]));
+        assert!(!notes.contains_key(&commitments[1]));
+        assert!(notes.contains_key(&commitments[2]));
+    }
+
+    #[test]
+    fn candidate_filters_apply_to_their_own_block() {
+        let sources = [
+            NoteSource::Transaction { id: CANDIDATE },
+            NoteSource::Transaction { id: OTHER },
+        ];
+        let blocks = (1..=3)
+            .map(|height| block_with_notes(height, &sources))
+            .collect::<Vec<_>>();
+        let commitments = |block: &CompactBlock| {
+            block
+                .state_payloads
+                .iter()
+                .map(|payload| *payload.commitment())
+                .collect::<Vec<_>>()
+        };
+
+        // Each block is decrypted with its own candidates, or without a filter if it has none.
+        let candidates = BTreeSet::from([CANDIDATE]);
+        let other_candidates = BTreeSet::from([OTHER]);
+        let decrypted = trial_decrypt_blocks(
+            &test_keys::FULL_VIEWING_KEY,
+            [
+                (&blocks[0], Some(&candidates)),
+                (&blocks[1], None),
+                (&blocks[2], Some(&other_candidates)),
+            ],
+        );
+        assert_eq!(decrypted.len(), 3);
+
+        let expected = [
+            BTreeSet::from([commitments(&blocks[0])[0]]),
+            commitments(&blocks[1]).into_iter().collect(),
+            BTreeSet::from([commitments(&blocks[2])[1]]),
+        ];
+        for (decrypted, expected) in decrypted.iter().zip(expected) {
+            let found = decrypted.notes.keys().copied().collect::<BTreeSet<_>>();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_block_records_only_candidate_notes() -> anyhow::Result<()> {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let storage =
+            Storage::initialize(None::<&str>, fvk.clone(), ChainParameters::default()).await?;
+        let mut sct = tct::Tree::new();
+
+        let block = block_with_notes(
+            0,
+            &[
+                NoteSource::Transaction { id: CANDIDATE },
+                NoteSource::Transaction { id: OTHER },
+                NoteSource::Genesis,
+            ],
+        );
+        let candidates = BTreeSet::from([CANDIDATE]);
+        let decrypted = trial_decrypt_blocks(fvk, [(&block, Some(&candidates))])
+            .pop()
+            .expect("one block was decrypted");
+
+        let filtered_block = scan_block(fvk, &mut sct, block, decrypted, &storage).await?;
+
+        // The skipped note isn't recorded, but its commitment still takes up its position in the
+        // tree, so the positions of the notes after it are unaffected.
+        let recorded = filtered_block
+            .new_notes
+            .iter()
+            .map(|record| (record.source, record.position.commitment()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            recorded,
+            vec![
+                (NoteSource::Transaction { id: CANDIDATE }, 0),
+                (NoteSource::Genesis, 2),
+            ]
+        );
+        assert_eq!(
+            filtered_block.inbound_transaction_ids(),
+            BTreeSet::from([CANDIDATE])
+        );
+
+        Ok(())
+    }
+}
@@ -1,18 +1,25 @@
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     sync::{Arc, Mutex},
 };
 
 use penumbra_compact_block::CompactBlock;
 use penumbra_dex::lp::{position, LpNft};
-use penumbra_keys::FullViewingKey;
+use penumbra_keys::{
+    keys::{AccountGroupId, AddressIndex},
+    FullViewingKey,
+};
 use penumbra_proto::client::v1alpha1::specific_query_service_client::SpecificQueryServiceClient;
 use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
         oblivious_query_service_client::ObliviousQueryServiceClient,
         tendermint_proxy_service_client::TendermintProxyServiceClient, CompactBlockRangeRequest,
-        GetBlockByHeightRequest,
+        CompactBlockRangeResponse, GetBlockByHeightRequest,
+    },
+    view::v1alpha1::{
+        detection_service_client::DetectionServiceClient, CandidateTransactionsRequest,
+        CandidateTransactionsResponse,
     },
     DomainType,
 };
@@ -20,36 +27,131 @@ use penumbra_sct::Nullifier;
 use penumbra_transaction::Transaction;
 use proto::client::v1alpha1::DenomMetadataByIdRequest;
 use sha2::Digest;
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{broadcast, mpsc, oneshot, watch, RwLock};
 use tonic::transport::Channel;
 use url::Url;
 
 use crate::{
-    sync::{scan_block, FilteredBlock},
+    sync::{scan_block, trial_decrypt_blocks, DecryptedBlock, FilteredBlock},
     Storage,
 };
 
+/// The maximum number of compact blocks trial-decrypted together while catching up.
+///
+/// Only blocks which have already been buffered are batched together, so this doesn't delay the
+/// scanning of new blocks once the worker has caught up with the chain.
+const SCAN_BATCH_SIZE: usize = 100;
+
+/// How long to wait before reconnecting to the node after the compact block stream fails.
+const RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// The state shared between a wallet synced by a [`Worker`] and the view services reading it:
+///
+/// - a shared, in-memory SCT instance;
+/// - a shared error slot;
+/// - a channel for notifying the client of sync progress.
+pub type WalletState = (
+    Arc<RwLock<penumbra_tct::Tree>>,
+    Arc<Mutex<Option<anyhow::Error>>>,
+    watch::Receiver<u64>,
+);
+
+/// Syncs one or more wallets with the chain, fetching each compact block once and scanning it
+/// with the full viewing key of every wallet.
 pub struct Worker {
-    storage: Storage,
     client: ObliviousQueryServiceClient<Channel>,
+    tm_client: TendermintProxyServiceClient<Channel>,
+    specific_client: SpecificQueryServiceClient<Channel>,
+    // The Url for the detection service to get candidate transactions from, if any.
+    detection_service: Option<Url>,
+    // The wallets being synced, by account group ID.
+    wallets: BTreeMap<AccountGroupId, Wallet>,
+    // Commands to add and remove wallets while the worker runs, if it was created with a
+    // [`WorkerHandle`].
+    commands: Option<mpsc::UnboundedReceiver<Command>>,
+}
+
+/// A handle to a running [`Worker`], used to add and remove the wallets it syncs.
+#[derive(Clone)]
+pub struct WorkerHandle {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+/// A command to the [`Worker`], acknowledged once it has been carried out.
+enum Command {
+    Add(Box<Wallet>, oneshot::Sender<anyhow::Result<()>>),
+    Remove(AccountGroupId, oneshot::Sender<()>),
+}
+
+/// A single wallet synced by a [`Worker`].
+struct Wallet {
+    storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
     fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
+    chain_id: String,
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     sync_height_tx: watch::Sender<u64>,
-    tm_client: TendermintProxyServiceClient<Channel>,
-    specific_client: SpecificQueryServiceClient<Channel>,
+    // The height of the next block to scan.
+    next_height: u64,
+    // The candidate transactions detected for the wallet, if using a detection service.
+    candidates: Option<Candidates>,
+}
+
+/// The candidate transactions detected for a wallet by a detection service.
+struct Candidates {
+    detection_service: Url,
+    stream: mpsc::Receiver<Result<CandidateTransactionsResponse, tonic::Status>>,
+    // The addresses issued since the detection keys were registered.
+    issued_addresses: broadcast::Receiver<AddressIndex>,
+}
+
+impl Candidates {
+    /// Registers the detection keys for the addresses in use by the wallet in `storage` with the
+    /// `detection_service`, streaming the candidate transactions from `start_height` onwards.
+    async fn register(
+        detection_service: Url,
+        storage: &Storage,
+        fvk: &FullViewingKey,
+        start_height: u64,
+    ) -> anyhow::Result<Self> {
+        // Subscribe before reading the addresses in use, so that none issued in between are
+        // missed.
+        let issued_addresses = storage.issued_addresses();
+        let stream = candidate_transactions(&detection_service, storage, fvk, start_height).await?;
+
+        Ok(Self {
+            detection_service,
+            stream,
+            issued_addresses,
+        })
+    }
+
+    /// Whether any addresses have been issued since the detection keys were registered.
+    fn addresses_issued(&mut self) -> bool {
+        let mut issued = false;
+        loop {
+            match self.issued_addresses.try_recv() {
+                Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => issued = true,
+                Err(_) => return issued,
+            }
+        }
+    }
 }
 
 impl Worker {
-    /// Creates a new worker, returning:
+    /// Creates a new worker syncing the wallet in `storage`, returning:
     ///
     /// - the worker itself;
     /// - a shared, in-memory SCT instance;
     /// - a shared error slot;
     /// - a channel for notifying the client of sync progress.
+    ///
+    /// If a `detection_service` is provided, the worker only trial-decrypts the notes created by
+    /// the candidate transactions it detects for the addresses in use by the wallet.
     pub async fn new(
         storage: Storage,
         node: Url,
+        detection_service: Option<Url>,
     ) -> Result<
         (
             Self,
@@ -59,110 +161,125 @@ impl Worker {
         ),
         anyhow::Error,
     > {
-        let fvk = storage.full_viewing_key().await?;
+        let (wallet, (sct, error_slot, sync_height_rx)) = Wallet::new(storage).await?;
 
-        // Create a shared, in-memory SCT.
-        let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
-        // Create a shared error slot
-        let error_slot = Arc::new(Mutex::new(None));
-        // Create a channel for the worker to notify of sync height changes.
-        let (sync_height_tx, mut sync_height_rx) =
-            watch::channel(storage.last_sync_height().await?.unwrap_or(0));
-        // Mark the current height as seen, since it's not new.
-        sync_height_rx.borrow_and_update();
+        let mut worker = Self::connect(node, detection_service, None).await?;
+        worker.add_wallet(wallet).await?;
 
-        let client = ObliviousQueryServiceClient::connect(node.to_string()).await?;
+        Ok((worker, sct, error_slot, sync_height_rx))
+    }
 
-        let specific_client = SpecificQueryServiceClient::connect(node.to_string()).await?;
+    /// Creates a new worker with no wallets, returning the worker itself and a handle to add and
+    /// remove wallets while it runs.
+    ///
+    /// The worker keeps running until the handle and all its clones are dropped, even if it has
+    /// no wallets to sync.
+    pub async fn new_shared(
+        node: Url,
+        detection_service: Option<Url>,
+    ) -> anyhow::Result<(Self, WorkerHandle)> {
+        let (commands_tx, commands_rx) = mpsc::unbounded_channel();
 
-        let tm_client = TendermintProxyServiceClient::connect(node.to_string()).await?;
+        let worker = Self::connect(node, detection_service, Some(commands_rx)).await?;
 
         Ok((
-            Self {
-                storage,
-                client,
-                sct: sct.clone(),
-                fvk,
-                error_slot: error_slot.clone(),
-                sync_height_tx,
-                tm_client,
-                specific_client,
+            worker,
+            WorkerHandle {
+                commands: commands_tx,
             },
-            sct,
-            error_slot,
-            sync_height_rx,
         ))
     }
 
-    pub async fn fetch_transactions(
-        &self,
-        filtered_block: &FilteredBlock,
-    ) -> anyhow::Result<Vec<Transaction>> {
-        let inbound_transaction_ids = filtered_block.inbound_transaction_ids();
-        let spent_nullifiers = filtered_block
-            .spent_nullifiers
-            .iter()
-            .cloned()
-            .collect::<BTreeSet<Nullifier>>();
-
-        // Only make a block request if we detected transactions in the FilteredBlock.
-        // TODO: in the future, we could perform chaff downloads.
-        if spent_nullifiers.is_empty() && inbound_transaction_ids.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        tracing::debug!(
-            height = filtered_block.height,
-            "fetching full transaction data"
-        );
-
-        let block = fetch_block(&mut self.tm_client.clone(), filtered_block.height as i64).await?;
+    async fn connect(
+        node: Url,
+        detection_service: Option<Url>,
+        commands: Option<mpsc::UnboundedReceiver<Command>>,
+    ) -> anyhow::Result<Self> {
+        let client = ObliviousQueryServiceClient::connect(node.to_string()).await?;
 
-        let mut transactions = Vec::new();
+        let specific_client = SpecificQueryServiceClient::connect(node.to_string()).await?;
 
-        for tx_bytes in block.data.as_ref().expect("block data").txs.iter() {
-            let tx_id: [u8; 32] = sha2::Sha256::digest(tx_bytes.as_slice())
-                .as_slice()
-                .try_into()
-                .unwrap();
+        let tm_client = TendermintProxyServiceClient::connect(node.to_string()).await?;
 
-            let transaction = Transaction::decode(tx_bytes.as_slice())?;
+        Ok(Self {
+            client,
+            tm_client,
+            specific_client,
+            detection_service,
+            wallets: BTreeMap::new(),
+            commands,
+        })
+    }
 
-            // Check if the transaction is a known inbound transaction or spends one of our nullifiers.
-            if inbound_transaction_ids.contains(&tx_id)
-                || transaction
-                    .spent_nullifiers()
-                    .any(|nf| spent_nullifiers.contains(&nf))
-            {
-                transactions.push(transaction)
+    /// Starts syncing a wallet from its last sync height.
+    ///
+    /// Every wallet is synced from the same stream of compact blocks, so the wallet must be for
+    /// the same chain as the others.
+    async fn add_wallet(&mut self, mut wallet: Wallet) -> anyhow::Result<()> {
+        if let Some(other) = self.wallets.values().next() {
+            if other.chain_id != wallet.chain_id {
+                anyhow::bail!(
+                    "wallet {} is for chain {}, but the other wallets are for chain {}",
+                    wallet.fvk.account_group_id(),
+                    wallet.chain_id,
+                    other.chain_id
+                );
             }
         }
-        tracing::debug!(
-            transactions_in_block = block.data.expect("block data").txs.len(),
-            matched = transactions.len(),
-            "filtered relevant transactions"
+
+        // If we're using a detection service, stream the candidate transactions for the wallet's
+        // detection keys alongside the compact blocks.
+        if let Some(detection_service) = &self.detection_service {
+            wallet.candidates = Some(
+                Candidates::register(
+                    detection_service.clone(),
+                    &wallet.storage,
+                    &wallet.fvk,
+                    wallet.next_height,
+                )
+                .await?,
+            );
+        }
+
+        tracing::info!(
+            account_group_id = %wallet.fvk.account_group_id(),
+            next_height = wallet.next_height,
+            "syncing wallet"
         );
+        self.wallets.insert(wallet.fvk.account_group_id(), wallet);
 
-        Ok(transactions)
+        Ok(())
     }
 
-    pub async fn sync(&mut self) -> Result<(), anyhow::Error> {
-        // Do a single sync run, up to whatever the latest block height is
-        tracing::info!("starting client sync");
-
-        let chain_id = self.storage.chain_params().await?.chain_id;
+    /// Waits for the next command from the [`WorkerHandle`], if any.
+    ///
+    /// Returns `None` once the handle and all its clones are dropped, and never returns if the
+    /// worker has no handle.
+    async fn next_command(
+        commands: &mut Option<mpsc::UnboundedReceiver<Command>>,
+    ) -> Option<Command> {
+        match commands {
+            Some(commands) => commands.recv().await,
+            None => std::future::pending().await,
+        }
+    }
 
-        let start_height = self
-            .storage
-            .last_sync_height()
-            .await?
-            .map(|h| h + 1)
-            .unwrap_or(0);
+    /// Opens a stream of compact blocks from `start_height`, buffered by a separate task.
+    async fn compact_blocks(
+        &mut self,
+        start_height: u64,
+    ) -> anyhow::Result<mpsc::Receiver<Result<CompactBlockRangeResponse, tonic::Status>>> {
+        let chain_id = self
+            .wallets
+            .values()
+            .next()
+            .map(|wallet| wallet.chain_id.clone())
+            .unwrap_or_default();
 
         let mut stream = self
             .client
             .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
-                chain_id: chain_id.clone(),
+                chain_id,
                 start_height,
                 end_height: 0,
                 // Instruct the server to keep feeding us blocks as they're created.
@@ -176,7 +293,7 @@ impl Worker {
         // purposes: first, it allows buffering to smooth performance; second,
         // it makes it slightly more difficult for a remote server to observe
         // the exact timings of the scanning of each CompactBlock.
-        let (tx, mut buffered_stream) = tokio::sync::mpsc::channel(1000);
+        let (tx, buffered_stream) = mpsc::channel(1000);
         tokio::spawn(async move {
             while let Some(block) = stream.message().await.transpose() {
                 if tx.send(block).await.is_err() {
@@ -185,167 +302,190 @@ impl Worker {
             }
         });
 
-        while let Some(block) = buffered_stream.recv().await {
-            let block: CompactBlock = block?.try_into()?;
-
-            let height = block.height;
+        Ok(buffered_stream)
+    }
 
-            // Lock the SCT only while processing this block.
-            let mut sct_guard = self.sct.write().await;
+    pub async fn sync(&mut self) -> Result<(), anyhow::Error> {
+        tracing::info!("starting client sync");
 
-            if !block.requires_scanning() {
-                // Optimization: if the block is empty, seal the in-memory SCT,
-                // and skip touching the database:
-                sct_guard.end_block().unwrap();
-                // We also need to end the epoch, since if there are no funding streams, then an
-                // epoch boundary won't necessarily require scanning:
-                if block.epoch_root.is_some() {
-                    sct_guard
-                        .end_epoch()
-                        .expect("ending the epoch must succeed");
+        loop {
+            if self.wallets.is_empty() {
+                // A worker without a handle can't be given any more wallets to sync.
+                if self.commands.is_none() {
+                    return Ok(());
                 }
-                self.storage.record_empty_block(height).await?;
-                // Notify all watchers of the new height we just recorded.
-                self.sync_height_tx.send(height)?;
-            } else {
-                // Otherwise, scan the block and commit its changes:
-                let filtered_block =
-                    scan_block(&self.fvk, &mut sct_guard, block, &self.storage).await?;
-
-                // Download any transactions we detected.
-                let transactions = self.fetch_transactions(&filtered_block).await?;
-
-                // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
-                // registry based on transaction contents.
-                for transaction in &transactions {
-                    for action in transaction.actions() {
-                        match action {
-                            penumbra_transaction::Action::PositionOpen(position_open) => {
-                                let position_id = position_open.position.id();
-
-                                // Record every possible permutation.
-
-                                let lp_nft = LpNft::new(position_id, position::State::Opened);
-                                let _id = lp_nft.asset_id();
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                let lp_nft = LpNft::new(position_id, position::State::Closed);
-                                let _id = lp_nft.asset_id();
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                let lp_nft = LpNft::new(position_id, position::State::Withdrawn);
-                                let _id = lp_nft.asset_id();
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                let lp_nft = LpNft::new(position_id, position::State::Claimed);
-                                let _id = lp_nft.asset_id();
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                // Record the position itself
-                                self.storage
-                                    .record_position(position_open.position.clone())
-                                    .await?;
-                            }
-                            penumbra_transaction::Action::PositionClose(position_close) => {
-                                let position_id = position_close.position_id;
-
-                                // Update the position record
-                                self.storage
-                                    .update_position(position_id, position::State::Closed)
-                                    .await?;
-                            }
-                            penumbra_transaction::Action::PositionWithdraw(position_withdraw) => {
-                                let position_id = position_withdraw.position_id;
+                // Otherwise, wait to be given a wallet to sync, or for the handle to be dropped.
+                match Self::next_command(&mut self.commands).await {
+                    Some(command) => {
+                        self.handle_command(command).await;
+                        continue;
+                    }
+                    None => return Ok(()),
+                }
+            }
 
-                                // Update the position record
-                                self.storage
-                                    .update_position(position_id, position::State::Withdrawn)
-                                    .await?;
-                            }
-                            penumbra_transaction::Action::PositionRewardClaim(position_claim) => {
-                                let position_id = position_claim.position_id;
+            // Stream blocks from the lowest height needed by any of the wallets; the others skip
+            // the blocks they've already scanned.
+            let mut next_stream_height = self
+                .wallets
+                .values()
+                .map(|wallet| wallet.next_height)
+                .min()
+                .expect("there is at least one wallet");
+            let mut buffered_stream = match self.compact_blocks(next_stream_height).await {
+                Ok(buffered_stream) => buffered_stream,
+                Err(e) => {
+                    tracing::warn!(?e, "could not stream compact blocks, retrying");
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                    continue;
+                }
+            };
 
-                                // Update the position record
-                                self.storage
-                                    .update_position(position_id, position::State::Claimed)
-                                    .await?;
+            loop {
+                tokio::select! {
+                    Some(command) = Self::next_command(&mut self.commands) => {
+                        let needs_older_blocks = matches!(
+                            &command,
+                            Command::Add(wallet, _) if wallet.next_height < next_stream_height
+                        );
+                        self.handle_command(command).await;
+                        // Restart the stream if a new wallet needs blocks we've already passed,
+                        // or if there are no wallets left to stream blocks for.
+                        if needs_older_blocks || self.wallets.is_empty() {
+                            break;
+                        }
+                    }
+                    block = buffered_stream.recv() => {
+                        // Scan any blocks already buffered along with this one, so that while
+                        // catching up, many blocks are trial-decrypted at once.
+                        let blocks = match Self::next_blocks(block, &mut buffered_stream) {
+                            Ok(blocks) => blocks,
+                            Err(e) => {
+                                // The wallets haven't scanned any of the blocks in the failed
+                                // batch, so reopening the stream from their next heights picks up
+                                // where they left off.
+                                tracing::warn!(?e, "error streaming compact blocks, reconnecting");
+                                tokio::time::sleep(RECONNECT_DELAY).await;
+                                break;
                             }
-                            _ => (),
                         };
-                    }
-                }
+                        next_stream_height = blocks
+                            .last()
+                            .expect("there is at least one block")
+                            .height
+                            + 1;
 
-                // Record any new assets we detected.
-                for note_record in &filtered_block.new_notes {
-                    // If the asset is already known, skip it.
+                        self.scan_blocks(blocks).await;
 
-                    if self
-                        .storage
-                        .asset_by_id(&note_record.note.asset_id())
-                        .await?
-                        .is_some()
-                    {
-                        continue;
-                    } else {
-                        // If the asset is unknown, we may be able to query for its denom metadata and store that.
-
-                        if let Some(denom_metadata) = self
-                            .specific_client
-                            .denom_metadata_by_id(DenomMetadataByIdRequest {
-                                asset_id: Some(note_record.note.asset_id().into()),
-                                chain_id: chain_id.clone(),
-                            })
-                            .await?
-                            .into_inner()
-                            .denom_metadata
-                        {
-                            // If we get metadata: great, record it.
-                            self.storage
-                                .record_asset(denom_metadata.try_into()?)
-                                .await?;
-                        } else {
-                            // Otherwise we are dealing with an unknown/novel asset ID, but we don't have the original raw denom field naming the asset.
-                            // For now, we can just record the asset ID with the denom value as "Unknown".
-
-                            self.storage
-                                .record_unknown_asset(note_record.note.asset_id())
-                                .await?;
+                        // Check if we should stop waiting for blocks to arrive, because the view
+                        // services are dropped and we're supposed to shut down.
+                        if self.wallets.is_empty() && self.commands.is_none() {
+                            return Ok(());
                         }
                     }
                 }
+            }
+        }
+    }
 
-                // Commit the block to the database.
+    /// Takes the next `block` from the stream, along with any blocks already buffered after it, up
+    /// to [`SCAN_BATCH_SIZE`] blocks in total.
+    fn next_blocks(
+        block: Option<Result<CompactBlockRangeResponse, tonic::Status>>,
+        buffered_stream: &mut mpsc::Receiver<Result<CompactBlockRangeResponse, tonic::Status>>,
+    ) -> anyhow::Result<Vec<CompactBlock>> {
+        let block = block.ok_or_else(|| anyhow::anyhow!("the compact block stream was closed"))?;
 
-                self.storage
-                    .record_block(filtered_block.clone(), transactions, &mut sct_guard)
-                    .await?;
-                // Notify all watchers of the new height we just recorded.
-                self.sync_height_tx.send(filtered_block.height)?;
+        let mut blocks: Vec<CompactBlock> = vec![block?.try_into()?];
+        while blocks.len() < SCAN_BATCH_SIZE {
+            match buffered_stream.try_recv() {
+                Ok(block) => blocks.push(block?.try_into()?),
+                Err(_) => break,
             }
-            #[cfg(feature = "sct-divergence-check")]
-            sct_divergence_check(&mut self.specific_client, height, sct_guard.root()).await?;
+        }
 
-            // Release the SCT RwLock
-            drop(sct_guard);
+        Ok(blocks)
+    }
 
-            // Check if we should stop waiting for blocks to arrive, because the view
-            // services are dropped and we're supposed to shut down.
-            if self.sync_height_tx.is_closed() {
-                return Ok(());
+    async fn handle_command(&mut self, command: Command) {
+        match command {
+            Command::Add(wallet, ack) => {
+                // A wallet which can't be synced shouldn't affect the others, so report the error
+                // to whoever added it rather than stopping the worker.
+                let result = self.add_wallet(*wallet).await;
+                if let Err(e) = &result {
+                    tracing::info!(?e, "could not start syncing wallet");
+                }
+                let _ = ack.send(result);
+            }
+            Command::Remove(account_group_id, ack) => {
+                tracing::info!(%account_group_id, "no longer syncing wallet");
+                // Drop the wallet before acknowledging the command, so that the worker no longer
+                // touches its storage once the command is acknowledged.
+                drop(self.wallets.remove(&account_group_id));
+                let _ = ack.send(());
             }
         }
+    }
 
-        Ok(())
+    /// Scans a batch of consecutive compact blocks for every wallet, skipping the blocks each
+    /// wallet has already scanned.
+    ///
+    /// The wallets are scanned concurrently.  A wallet which fails to scan a block stops being
+    /// synced, with the error recorded in its error slot, without affecting the other wallets.
+    async fn scan_blocks(&mut self, blocks: Vec<CompactBlock>) {
+        let (tm_client, specific_client) = (&self.tm_client, &self.specific_client);
+        let scans = self
+            .wallets
+            .iter_mut()
+            .filter_map(|(account_group_id, wallet)| {
+                let blocks = blocks
+                    .iter()
+                    .skip_while(|block| block.height < wallet.next_height)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if blocks.first().map(|block| block.height) != Some(wallet.next_height) {
+                    return None;
+                }
+
+                // The clients share the same underlying channel, so each scan can have its own.
+                let mut tm_client = tm_client.clone();
+                let mut specific_client = specific_client.clone();
+
+                Some(async move {
+                    match wallet
+                        .scan_blocks(blocks, &mut tm_client, &mut specific_client)
+                        .await
+                    {
+                        Err(e) => {
+                            tracing::info!(?e, %account_group_id, "view worker error");
+                            wallet.error_slot.lock().unwrap().replace(e);
+                            Some(*account_group_id)
+                        }
+                        // The view services for the wallet were dropped, so we can stop syncing it.
+                        Ok(()) if wallet.sync_height_tx.is_closed() => Some(*account_group_id),
+                        Ok(()) => None,
+                    }
+                })
+            });
+
+        let stopped = futures::future::join_all(scans).await;
+
+        for account_group_id in stopped.into_iter().flatten() {
+            self.wallets.remove(&account_group_id);
+        }
     }
 
     pub async fn run(mut self) -> Result<(), anyhow::Error> {
         self.run_inner().await.map_err(|e| {
             tracing::info!(?e, "view worker error");
-            self.error_slot.lock().unwrap().replace(e);
+            for wallet in self.wallets.values() {
+                wallet
+                    .error_slot
+                    .lock()
+                    .unwrap()
+                    .replace(anyhow::anyhow!("{:#}", e));
+            }
             anyhow::anyhow!("view worker error")
         })
     }
@@ -359,7 +499,443 @@ impl Worker {
     }
 }
 
-async fn fetch_block(
+impl WorkerHandle {
+    /// Starts syncing the wallet in `storage` alongside the other wallets of the worker,
+    /// returning the [`WalletState`] shared with its view services.
+    ///
+    /// Fails if the worker can't sync the wallet, such as if it's for a different chain than the
+    /// other wallets.
+    pub async fn add_wallet(&self, storage: Storage) -> anyhow::Result<WalletState> {
+        let (wallet, state) = Wallet::new(storage).await?;
+
+        let (ack_tx, ack_rx) = oneshot::channel();
+        self.commands
+            .send(Command::Add(Box::new(wallet), ack_tx))
+            .map_err(|_| anyhow::anyhow!("view worker is no longer running"))?;
+        ack_rx
+            .await
+            .map_err(|_| anyhow::anyhow!("view worker is no longer running"))??;
+
+        Ok(state)
+    }
+
+    /// Stops syncing the wallet with the given `account_group_id`, returning once the worker no
+    /// longer uses its storage.
+    pub async fn remove_wallet(&self, account_group_id: AccountGroupId) -> anyhow::Result<()> {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        self.commands
+            .send(Command::Remove(account_group_id, ack_tx))
+            .map_err(|_| anyhow::anyhow!("view worker is no longer running"))?;
+        ack_rx
+            .await
+            .map_err(|_| anyhow::anyhow!("view worker is no longer running"))
+    }
+}
+
+impl Wallet {
+    async fn new(storage: Storage) -> anyhow::Result<(Self, WalletState)> {
+        let fvk = storage.full_viewing_key().await?;
+        let chain_id = storage.chain_params().await?.chain_id;
+
+        // Create a shared, in-memory SCT.
+        let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
+        // Create a shared error slot
+        let error_slot = Arc::new(Mutex::new(None));
+        // Create a channel for the worker to notify of sync height changes.
+        let last_sync_height = storage.last_sync_height().await?;
+        let (sync_height_tx, mut sync_height_rx) = watch::channel(last_sync_height.unwrap_or(0));
+        // Mark the current height as seen, since it's not new.
+        sync_height_rx.borrow_and_update();
+
+        Ok((
+            Self {
+                storage,
+                sct: sct.clone(),
+                fvk,
+                chain_id,
+                error_slot: error_slot.clone(),
+                sync_height_tx,
+                next_height: last_sync_height.map(|h| h + 1).unwrap_or(0),
+                candidates: None,
+            },
+            (sct, error_slot, sync_height_rx),
+        ))
+    }
+
+    /// Scans a batch of consecutive compact blocks for the wallet, trial-decrypting them all at
+    /// once before committing the changes of each block in turn.
+    async fn scan_blocks(
+        &mut self,
+        blocks: Vec<CompactBlock>,
+        tm_client: &mut TendermintProxyServiceClient<Channel>,
+        specific_client: &mut SpecificQueryServiceClient<Channel>,
+    ) -> anyhow::Result<()> {
+        // If addresses have been issued since the detection keys were registered, their notes
+        // can only be in blocks we haven't scanned yet, so register the keys again, detecting
+        // candidates from the next block onwards.
+        if let Some(candidates) = &mut self.candidates {
+            if candidates.addresses_issued() {
+                tracing::info!(
+                    account_group_id = %self.fvk.account_group_id(),
+                    "registering detection keys for newly issued addresses"
+                );
+                *candidates = Candidates::register(
+                    candidates.detection_service.clone(),
+                    &self.storage,
+                    &self.fvk,
+                    self.next_height,
+                )
+                .await?;
+            }
+        }
+
+        // If we're using a detection service, wait for the candidate transactions of each block
+        // which needs scanning.
+        let mut candidates = Vec::with_capacity(blocks.len());
+        for block in &blocks {
+            candidates.push(match &mut self.candidates {
+                Some(Candidates { stream, .. }) if block.requires_scanning() => {
+                    Some(next_candidates(stream, block.height).await?)
+                }
+                _ => None,
+            });
+        }
+
+        // Trial decryption is CPU-bound, so run it on a blocking thread rather than holding up
+        // the async runtime.
+        let fvk = self.fvk.clone();
+        let span = tracing::Span::current();
+        let (blocks, decrypted_blocks) = tokio::task::spawn_blocking(move || {
+            let decrypted_blocks = span.in_scope(|| {
+                trial_decrypt_blocks(
+                    &fvk,
+                    blocks
+                        .iter()
+                        .zip(candidates.iter())
+                        .map(|(block, candidates)| (block, candidates.as_ref())),
+                )
+            });
+            (blocks, decrypted_blocks)
+        })
+        .await?;
+
+        for (block, decrypted) in blocks.into_iter().zip(decrypted_blocks) {
+            self.scan(block, decrypted, tm_client, specific_client)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Scans a compact block for the wallet, given the notes and swaps found in it by trial
+    /// decryption, and commits its changes.
+    async fn scan(
+        &mut self,
+        block: CompactBlock,
+        decrypted: DecryptedBlock,
+        tm_client: &mut TendermintProxyServiceClient<Channel>,
+        specific_client: &mut SpecificQueryServiceClient<Channel>,
+    ) -> anyhow::Result<()> {
+        let height = block.height;
+
+        // Lock the SCT only while processing this block.
+        let mut sct_guard = self.sct.write().await;
+
+        scan_and_record_block(
+            &self.fvk,
+            &self.chain_id,
+            &mut sct_guard,
+            block,
+            decrypted,
+            &self.storage,
+            Some((&mut *tm_client, &mut *specific_client)),
+        )
+        .await?;
+        // Notify all watchers of the new height we just recorded.
+        self.sync_height_tx.send(height)?;
+
+        #[cfg(feature = "sct-divergence-check")]
+        sct_divergence_check(specific_client, height, sct_guard.root()).await?;
+
+        // Release the SCT RwLock
+        drop(sct_guard);
+
+        self.next_height = height + 1;
+
+        Ok(())
+    }
+}
+
+/// Scans a compact block, given the notes and swaps found in it by [`trial_decrypt_blocks`], and
+/// commits its changes to `storage` and the `sct`, returning the filtered block if it needed
+/// scanning.
+///
+/// With node `clients`, the transactions the block's new notes and spent nullifiers came from are
+/// fetched, and the metadata of newly seen assets is queried. Without, as when scanning blocks
+/// offline, no transactions are recorded and newly seen assets are recorded as unknown.
+pub async fn scan_and_record_block(
+    fvk: &FullViewingKey,
+    chain_id: &str,
+    sct: &mut penumbra_tct::Tree,
+    block: CompactBlock,
+    decrypted: DecryptedBlock,
+    storage: &Storage,
+    mut clients: Option<(
+        &mut TendermintProxyServiceClient<Channel>,
+        &mut SpecificQueryServiceClient<Channel>,
+    )>,
+) -> anyhow::Result<Option<FilteredBlock>> {
+    let height = block.height;
+
+    if !block.requires_scanning() {
+        // Optimization: if the block is empty, seal the in-memory SCT,
+        // and skip touching the database:
+        sct.end_block().unwrap();
+        // We also need to end the epoch, since if there are no funding streams, then an
+        // epoch boundary won't necessarily require scanning:
+        if block.epoch_root.is_some() {
+            sct.end_epoch().expect("ending the epoch must succeed");
+        }
+        storage.record_empty_block(height).await?;
+        return Ok(None);
+    }
+
+    // Otherwise, scan the block and commit its changes:
+    let filtered_block = scan_block(fvk, sct, block, decrypted, storage).await?;
+
+    // Download any transactions we detected.
+    let transactions = match &mut clients {
+        Some((tm_client, _)) => fetch_transactions(&filtered_block, tm_client).await?,
+        None => Vec::new(),
+    };
+
+    // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
+    // registry based on transaction contents.
+    for transaction in &transactions {
+        for action in transaction.actions() {
+            match action {
+                penumbra_transaction::Action::PositionOpen(position_open) => {
+                    let position_id = position_open.position.id();
+
+                    // Record every possible permutation.
+
+                    let lp_nft = LpNft::new(position_id, position::State::Opened);
+                    let _id = lp_nft.asset_id();
+                    let denom = lp_nft.denom();
+                    storage.record_asset(denom).await?;
+
+                    let lp_nft = LpNft::new(position_id, position::State::Closed);
+                    let _id = lp_nft.asset_id();
+                    let denom = lp_nft.denom();
+                    storage.record_asset(denom).await?;
+
+                    let lp_nft = LpNft::new(position_id, position::State::Withdrawn);
+                    let _id = lp_nft.asset_id();
+                    let denom = lp_nft.denom();
+                    storage.record_asset(denom).await?;
+
+                    let lp_nft = LpNft::new(position_id, position::State::Claimed);
+                    let _id = lp_nft.asset_id();
+                    let denom = lp_nft.denom();
+                    storage.record_asset(denom).await?;
+
+                    // Record the position itself
+                    storage
+                        .record_position(position_open.position.clone())
+                        .await?;
+                }
+                penumbra_transaction::Action::PositionClose(position_close) => {
+                    let position_id = position_close.position_id;
+
+                    // Update the position record
+                    storage
+                        .update_position(position_id, position::State::Closed)
+                        .await?;
+                }
+                penumbra_transaction::Action::PositionWithdraw(position_withdraw) => {
+                    let position_id = position_withdraw.position_id;
+
+                    // Update the position record
+                    storage
+                        .update_position(position_id, position::State::Withdrawn)
+                        .await?;
+                }
+                penumbra_transaction::Action::PositionRewardClaim(position_claim) => {
+                    let position_id = position_claim.position_id;
+
+                    // Update the position record
+                    storage
+                        .update_position(position_id, position::State::Claimed)
+                        .await?;
+                }
+                _ => (),
+            };
+        }
+    }
+
+    // Record any new assets we detected.
+    for note_record in &filtered_block.new_notes {
+        // If the asset is already known, skip it.
+
+        if storage
+            .asset_by_id(&note_record.note.asset_id())
+            .await?
+            .is_some()
+        {
+            continue;
+        } else {
+            // If the asset is unknown, we may be able to query for its denom metadata and store that.
+
+            let denom_metadata = match &mut clients {
+                Some((_, specific_client)) => {
+                    specific_client
+                        .denom_metadata_by_id(DenomMetadataByIdRequest {
+                            asset_id: Some(note_record.note.asset_id().into()),
+                            chain_id: chain_id.to_string(),
+                        })
+                        .await?
+                        .into_inner()
+                        .denom_metadata
+                }
+                None => None,
+            };
+            if let Some(denom_metadata) = denom_metadata {
+                // If we get metadata: great, record it.
+                storage.record_asset(denom_metadata.try_into()?).await?;
+            } else {
+                // Otherwise we are dealing with an unknown/novel asset ID, but we don't have the original raw denom field naming the asset.
+                // For now, we can just record the asset ID with the denom value as "Unknown".
+
+                storage
+                    .record_unknown_asset(note_record.note.asset_id())
+                    .await?;
+            }
+        }
+    }
+
+    // Commit the block to the database.
+    storage
+        .record_block(filtered_block.clone(), transactions, sct)
+        .await?;
+
+    Ok(Some(filtered_block))
+}
+
+/// Fetches the transactions that created the notes or spent the nullifiers in `filtered_block`.
+async fn fetch_transactions(
+    filtered_block: &FilteredBlock,
+    tm_client: &mut TendermintProxyServiceClient<Channel>,
+) -> anyhow::Result<Vec<Transaction>> {
+    let inbound_transaction_ids = filtered_block.inbound_transaction_ids();
+    let spent_nullifiers = filtered_block
+        .spent_nullifiers
+        .iter()
+        .cloned()
+        .collect::<BTreeSet<Nullifier>>();
+
+    // Only make a block request if we detected transactions in the FilteredBlock.
+    // TODO: in the future, we could perform chaff downloads.
+    if spent_nullifiers.is_empty() && inbound_transaction_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    tracing::debug!(
+        height = filtered_block.height,
+        "fetching full transaction data"
+    );
+
+    let block = fetch_block(tm_client, filtered_block.height as i64).await?;
+
+    let mut transactions = Vec::new();
+
+    for tx_bytes in block.data.as_ref().expect("block data").txs.iter() {
+        let tx_id: [u8; 32] = sha2::Sha256::digest(tx_bytes.as_slice())
+            .as_slice()
+            .try_into()
+            .unwrap();
+
+        let transaction = Transaction::decode(tx_bytes.as_slice())?;
+
+        // Check if the transaction is a known inbound transaction or spends one of our nullifiers.
+        if inbound_transaction_ids.contains(&tx_id)
+            || transaction
+                .spent_nullifiers()
+                .any(|nf| spent_nullifiers.contains(&nf))
+        {
+            transactions.push(transaction)
+        }
+    }
+    tracing::debug!(
+        transactions_in_block = block.data.expect("block data").txs.len(),
+        matched = transactions.len(),
+        "filtered relevant transactions"
+    );
+
+    Ok(transactions)
+}
+
+/// The indices of the addresses of a wallet to register detection keys for: the default address
+/// of every account up to the highest one the wallet has used, and every other address it has
+/// received notes at or issued.
+fn detection_address_indices(in_use: BTreeSet<AddressIndex>) -> BTreeSet<AddressIndex> {
+    let max_account = in_use.iter().map(|index| index.account).max().unwrap_or(0);
+
+    (0..=max_account)
+        .map(AddressIndex::from)
+        .chain(in_use)
+        .collect()
+}
+
+/// Registers the detection keys for the addresses in use by the wallet in `storage` with the
+/// `detection_service`, returning a buffered stream of the candidate transactions it detects from
+/// `start_height` onwards.
+///
+/// The addresses in use are those which have received notes or were issued by the view service.
+async fn candidate_transactions(
+    detection_service: &Url,
+    storage: &Storage,
+    fvk: &FullViewingKey,
+    start_height: u64,
+) -> anyhow::Result<mpsc::Receiver<Result<CandidateTransactionsResponse, tonic::Status>>> {
+    // Each address has its own detection key, so notes sent to any address we don't register
+    // won't be found at all.  We only know about the addresses which have received notes or were
+    // issued by the view service, so make sure this doesn't go unnoticed.
+    let address_indices = detection_address_indices(storage.address_indices().await?);
+    tracing::warn!(
+        account_group_id = %fvk.account_group_id(),
+        ?address_indices,
+        "syncing with a detection service, which will only find notes sent to the addresses \
+         issued by the view service or already in use by the wallet: notes sent to addresses \
+         derived elsewhere will be missed until the wallet is resynced without a detection service"
+    );
+    let detection_keys = address_indices
+        .into_iter()
+        .map(|index| fvk.payment_address(index).1.to_bytes().to_vec())
+        .collect();
+
+    let mut client = DetectionServiceClient::connect(detection_service.to_string()).await?;
+    let mut stream = client
+        .candidate_transactions(CandidateTransactionsRequest {
+            detection_keys,
+            start_height,
+            keep_alive: true,
+        })
+        .await?
+        .into_inner();
+
+    let (tx, buffered_stream) = mpsc::channel(1000);
+    tokio::spawn(async move {
+        while let Some(response) = stream.message().await.transpose() {
+            if tx.send(response).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(buffered_stream)
+}
+
+pub(crate) async fn fetch_block(
     client: &mut TendermintProxyServiceClient<Channel>,
     height: i64,
 ) -> Result<proto::tendermint::types::Block, anyhow::Error> {
@@ -371,6 +947,37 @@ async fn fetch_block(
         .expect("block not found"))
 }
 
+/// Waits for the candidate transactions detected in the block at `height`, skipping those of any
+/// earlier blocks, which didn't need to be scanned.
+async fn next_candidates(
+    candidate_stream: &mut mpsc::Receiver<Result<CandidateTransactionsResponse, tonic::Status>>,
+    height: u64,
+) -> anyhow::Result<BTreeSet<[u8; 32]>> {
+    while let Some(response) = candidate_stream.recv().await {
+        let response = response?;
+        if response.height < height {
+            continue;
+        }
+        if response.height > height {
+            anyhow::bail!(
+                "detection service skipped block {}, sending block {} instead",
+                height,
+                response.height
+            );
+        }
+        return response
+            .transaction_ids
+            .into_iter()
+            .map(|id| Ok(penumbra_transaction::Id::try_from(id)?.0))
+            .collect();
+    }
+
+    Err(anyhow::anyhow!(
+        "detection service closed the stream before block {}",
+        height
+    ))
+}
+
 #[cfg(feature = "sct-divergence-check")]
 async fn sct_divergence_check(
     client: &mut SpecificQueryServiceClient<Channel>,
@@ -407,3 +1014,73 @@ async fn sct_divergence_check(
         Err(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_chain::{params::ChainParameters, test_keys};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn detection_keys_cover_the_addresses_in_use() {
+        // A new wallet only has its default address in use.
+        assert_eq!(
+            detection_address_indices(BTreeSet::new()),
+            BTreeSet::from([AddressIndex::from(0u32)])
+        );
+
+        // Otherwise, every account up to the highest one in use is covered, along with any
+        // ephemeral addresses which have received notes.
+        let ephemeral = AddressIndex {
+            account: 2,
+            randomizer: [7; 12],
+        };
+        assert_eq!(
+            detection_address_indices(BTreeSet::from([AddressIndex::from(0u32), ephemeral])),
+            BTreeSet::from([
+                AddressIndex::from(0u32),
+                AddressIndex::from(1u32),
+                AddressIndex::from(2u32),
+                ephemeral,
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn issued_addresses_are_registered() -> anyhow::Result<()> {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let storage =
+            Storage::initialize(None::<&str>, fvk.clone(), ChainParameters::default()).await?;
+        let mut candidates = Candidates {
+            detection_service: Url::parse("http://127.0.0.1:8082")?,
+            stream: mpsc::channel(1).1,
+            issued_addresses: storage.issued_addresses(),
+        };
+        assert!(!candidates.addresses_issued());
+
+        // Issuing an address means the detection keys need to be registered again...
+        let (address, _dtk) = fvk.ephemeral_address(OsRng, AddressIndex::from(3u32));
+        let ephemeral = fvk.incoming().index_for_diversifier(address.diversifier());
+        storage.record_issued_address(ephemeral).await?;
+        assert!(candidates.addresses_issued());
+
+        // ...but issuing it again doesn't.
+        storage.record_issued_address(ephemeral).await?;
+        assert!(!candidates.addresses_issued());
+
+        // The issued address, and the default address of its account, now have detection keys.
+        assert_eq!(
+            detection_address_indices(storage.address_indices().await?),
+            BTreeSet::from([
+                AddressIndex::from(0u32),
+                AddressIndex::from(1u32),
+                AddressIndex::from(2u32),
+                AddressIndex::from(3u32),
+                ephemeral,
+            ])
+        );
+
+        Ok(())
+    }
+}
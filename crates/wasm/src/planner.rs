@@ -124,7 +124,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     ///
     /// This function should be called once.
     pub fn fee(&mut self, fee: Fee) -> &mut Self {
-        self.balance += fee.0;
+        self.balance -= fee.0;
         self.plan.fee = fee;
         self
     }
@@ -70,6 +70,7 @@ fn main() -> anyhow::Result<()> {
             "../../proto/penumbra/penumbra/core/chain/v1alpha1/chain.proto",
             "../../proto/penumbra/penumbra/core/ibc/v1alpha1/ibc.proto",
             "../../proto/penumbra/penumbra/core/dex/v1alpha1/dex.proto",
+            "../../proto/penumbra/penumbra/core/eddy/v1alpha1/eddy.proto",
             "../../proto/penumbra/penumbra/core/transparent_proofs/v1alpha1/transparent_proofs.proto",
             "../../proto/penumbra/penumbra/core/governance/v1alpha1/governance.proto",
             "../../proto/rust-vendored/tendermint/types/validator.proto",